@@ -0,0 +1,167 @@
+//! Access Controlled Token Example Contract.
+
+//! This contract showcases how role-based access control composes with the
+//! `pausable` utility and the fungible token module:
+//! - accounts holding the `MINTER_ROLE` can mint new tokens,
+//! - the admin set in the constructor, holding the `DEFAULT_ADMIN_ROLE`, grants
+//!   and revokes roles,
+//! - the admin can separate the authority to pause (e.g. a guardian holding the
+//!   `PAUSER_ROLE`) from the authority to unpause, by configuring the role
+//!   required for each of them. Until configured, both map to the admin.
+
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, Address, Env, String, Symbol,
+};
+use stellar_access_control::{self as access_control, AccessControl, DEFAULT_ADMIN_ROLE};
+use stellar_access_control_macros::only_role;
+use stellar_fungible::{self as fungible, burnable::FungibleBurnable, FungibleToken};
+use stellar_pausable::{self as pausable, Pausable};
+use stellar_pausable_macros::when_not_paused;
+
+pub const MINTER_ROLE: Symbol = symbol_short!("minter");
+pub const PAUSER_ROLE: Symbol = symbol_short!("pauser");
+pub const UNPAUSER_ROLE: Symbol = symbol_short!("unpauser");
+
+#[contracttype]
+pub enum RoleKey {
+    PauseAuthority,
+    UnpauseAuthority,
+}
+
+#[contract]
+pub struct ExampleContract;
+
+// Ensures `caller` holds the role configured under `key`, or the admin role
+// when no role is configured. Authorization is left to the calling function,
+// as `pausable::pause` and `pausable::unpause` already require it.
+fn ensure_pause_authority(e: &Env, caller: &Address, key: &RoleKey) {
+    let role = e.storage().instance().get(key).unwrap_or(DEFAULT_ADMIN_ROLE);
+    access_control::only_role(e, caller, &role);
+}
+
+#[contractimpl]
+impl ExampleContract {
+    pub fn __constructor(e: &Env, admin: Address, initial_supply: i128) {
+        fungible::metadata::set_metadata(
+            e,
+            18,
+            String::from_str(e, "My Token"),
+            String::from_str(e, "TKN"),
+        );
+        fungible::mintable::mint(e, &admin, initial_supply);
+        access_control::grant_role_no_auth(e, &admin, &admin, &DEFAULT_ADMIN_ROLE);
+    }
+
+    #[only_role("admin")]
+    pub fn set_pause_role(e: &Env, caller: Address, role: Symbol) {
+        caller.require_auth();
+        e.storage().instance().set(&RoleKey::PauseAuthority, &role);
+    }
+
+    #[only_role("admin")]
+    pub fn set_unpause_role(e: &Env, caller: Address, role: Symbol) {
+        caller.require_auth();
+        e.storage().instance().set(&RoleKey::UnpauseAuthority, &role);
+    }
+
+    #[when_not_paused]
+    #[only_role("minter")]
+    pub fn mint(e: &Env, caller: Address, account: Address, amount: i128) {
+        caller.require_auth();
+        fungible::mintable::mint(e, &account, amount);
+    }
+}
+
+#[contractimpl]
+impl AccessControl for ExampleContract {
+    fn has_role(e: &Env, account: Address, role: Symbol) -> bool {
+        access_control::has_role(e, &account, &role)
+    }
+
+    fn get_role_admin(e: &Env, role: Symbol) -> Symbol {
+        access_control::get_role_admin(e, &role)
+    }
+
+    fn grant_role(e: &Env, admin: Address, account: Address, role: Symbol) {
+        access_control::grant_role(e, &admin, &account, &role);
+    }
+
+    fn revoke_role(e: &Env, admin: Address, account: Address, role: Symbol) {
+        access_control::revoke_role(e, &admin, &account, &role);
+    }
+
+    fn renounce_role(e: &Env, account: Address, role: Symbol) {
+        access_control::renounce_role(e, &account, &role);
+    }
+}
+
+#[contractimpl]
+impl Pausable for ExampleContract {
+    fn paused(e: &Env) -> bool {
+        pausable::paused(e)
+    }
+
+    fn pause(e: &Env, caller: Address) {
+        ensure_pause_authority(e, &caller, &RoleKey::PauseAuthority);
+        pausable::pause(e, &caller);
+    }
+
+    fn unpause(e: &Env, caller: Address) {
+        ensure_pause_authority(e, &caller, &RoleKey::UnpauseAuthority);
+        pausable::unpause(e, &caller);
+    }
+}
+
+#[contractimpl]
+impl FungibleToken for ExampleContract {
+    fn total_supply(e: &Env) -> i128 {
+        fungible::total_supply(e)
+    }
+
+    fn balance(e: &Env, account: Address) -> i128 {
+        fungible::balance(e, &account)
+    }
+
+    fn allowance(e: &Env, owner: Address, spender: Address) -> i128 {
+        fungible::allowance(e, &owner, &spender)
+    }
+
+    #[when_not_paused]
+    fn transfer(e: &Env, from: Address, to: Address, amount: i128) {
+        fungible::transfer(e, &from, &to, amount);
+    }
+
+    #[when_not_paused]
+    fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, amount: i128) {
+        fungible::transfer_from(e, &spender, &from, &to, amount);
+    }
+
+    fn approve(e: &Env, owner: Address, spender: Address, amount: i128, live_until_ledger: u32) {
+        fungible::approve(e, &owner, &spender, amount, live_until_ledger);
+    }
+
+    fn decimals(e: &Env) -> u32 {
+        fungible::metadata::decimals(e)
+    }
+
+    fn name(e: &Env) -> String {
+        fungible::metadata::name(e)
+    }
+
+    fn symbol(e: &Env) -> String {
+        fungible::metadata::symbol(e)
+    }
+}
+
+#[contractimpl]
+impl FungibleBurnable for ExampleContract {
+    #[when_not_paused]
+    fn burn(e: &Env, from: Address, amount: i128) {
+        fungible::burnable::burn(e, &from, amount)
+    }
+
+    #[when_not_paused]
+    fn burn_from(e: &Env, spender: Address, from: Address, amount: i128) {
+        fungible::burnable::burn_from(e, &spender, &from, amount)
+    }
+}
@@ -0,0 +1,5 @@
+#![no_std]
+#![allow(dead_code)]
+
+mod contract;
+mod test;
@@ -0,0 +1,201 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, Address, Env};
+use stellar_access_control::DEFAULT_ADMIN_ROLE;
+
+use crate::contract::{
+    ExampleContract, ExampleContractClient, MINTER_ROLE, PAUSER_ROLE, UNPAUSER_ROLE,
+};
+
+fn create_client<'a>(e: &Env, admin: &Address, initial_supply: i128) -> ExampleContractClient<'a> {
+    let address = e.register(ExampleContract, (admin, initial_supply));
+    ExampleContractClient::new(e, &address)
+}
+
+#[test]
+fn initial_state() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    assert_eq!(client.total_supply(), 1000);
+    assert_eq!(client.balance(&admin), 1000);
+    assert!(client.has_role(&admin, &DEFAULT_ADMIN_ROLE));
+    assert!(!client.has_role(&admin, &MINTER_ROLE));
+    assert!(!client.has_role(&admin, &PAUSER_ROLE));
+    assert!(!client.paused());
+}
+
+#[test]
+fn minter_can_mint() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    e.mock_all_auths();
+    client.grant_role(&admin, &minter, &MINTER_ROLE);
+    assert!(client.has_role(&minter, &MINTER_ROLE));
+
+    client.mint(&minter, &recipient, &100);
+    assert_eq!(client.balance(&recipient), 100);
+    assert_eq!(client.total_supply(), 1100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #120)")]
+fn mint_without_minter_role_fails() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    e.mock_all_auths();
+    client.mint(&admin, &recipient, &100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #120)")]
+fn mint_after_revoke_fails() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    e.mock_all_auths();
+    client.grant_role(&admin, &minter, &MINTER_ROLE);
+    client.revoke_role(&admin, &minter, &MINTER_ROLE);
+    client.mint(&minter, &minter, &100);
+}
+
+#[test]
+fn admin_can_pause_and_unpause_by_default() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    e.mock_all_auths();
+    client.pause(&admin);
+    assert!(client.paused());
+
+    client.unpause(&admin);
+    assert!(!client.paused());
+}
+
+#[test]
+fn pauser_can_pause_but_not_unpause() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let pauser = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    e.mock_all_auths();
+    client.grant_role(&admin, &pauser, &PAUSER_ROLE);
+    client.set_pause_role(&admin, &PAUSER_ROLE);
+
+    // the admin no longer holds the pause authority
+    assert!(client.try_pause(&admin).is_err());
+
+    client.pause(&pauser);
+    assert!(client.paused());
+
+    // the unpause authority still maps to the admin
+    assert!(client.try_unpause(&pauser).is_err());
+    client.unpause(&admin);
+    assert!(!client.paused());
+}
+
+#[test]
+fn unpause_role_can_be_configured() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let pauser = Address::generate(&e);
+    let unpauser = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    e.mock_all_auths();
+    client.grant_role(&admin, &pauser, &PAUSER_ROLE);
+    client.grant_role(&admin, &unpauser, &UNPAUSER_ROLE);
+    client.set_pause_role(&admin, &PAUSER_ROLE);
+    client.set_unpause_role(&admin, &UNPAUSER_ROLE);
+
+    assert!(client.try_pause(&unpauser).is_err());
+    client.pause(&pauser);
+
+    assert!(client.try_unpause(&admin).is_err());
+    assert!(client.try_unpause(&pauser).is_err());
+    client.unpause(&unpauser);
+    assert!(!client.paused());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #120)")]
+fn pause_without_pauser_role_fails() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    e.mock_all_auths();
+    client.set_pause_role(&admin, &PAUSER_ROLE);
+    // roles are not interchangeable
+    client.grant_role(&admin, &minter, &MINTER_ROLE);
+    client.pause(&minter);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #120)")]
+fn set_pause_role_by_non_admin_fails() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let attacker = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    e.mock_all_auths();
+    client.set_pause_role(&attacker, &MINTER_ROLE);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #100)")]
+fn mint_fails_when_paused() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    e.mock_all_auths();
+    client.grant_role(&admin, &operator, &MINTER_ROLE);
+    client.grant_role(&admin, &operator, &PAUSER_ROLE);
+    client.set_pause_role(&admin, &PAUSER_ROLE);
+    client.pause(&operator);
+    client.mint(&operator, &operator, &100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #120)")]
+fn grant_role_by_non_admin_fails() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let attacker = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    e.mock_all_auths();
+    client.grant_role(&attacker, &attacker, &MINTER_ROLE);
+}
+
+#[test]
+fn minter_can_renounce_role() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    e.mock_all_auths();
+    client.grant_role(&admin, &minter, &MINTER_ROLE);
+    client.renounce_role(&minter, &MINTER_ROLE);
+    assert!(!client.has_role(&minter, &MINTER_ROLE));
+    assert!(client.try_mint(&minter, &minter, &100).is_err());
+}
@@ -15,13 +15,17 @@ use soroban_sdk::{
 };
 use stellar_fungible::{
     self as fungible, burnable::FungibleBurnable, impl_token_interface, mintable::FungibleMintable,
-    FungibleToken,
+    ContractOverrides, FungibleToken, PausableBase,
 };
 use stellar_pausable::{self as pausable, Pausable};
-use stellar_pausable_macros::when_not_paused;
 
 pub const OWNER: Symbol = symbol_short!("OWNER");
 
+// With the `pausable` feature, every transfer, burn and mint is checked against
+// the pause, so that they don't have to be annotated with `#[when_not_paused]`
+// one by one.
+type Token = PausableBase;
+
 #[contract]
 pub struct ExampleContract;
 
@@ -91,14 +95,12 @@ impl FungibleToken for ExampleContract {
         fungible::allowance(e, &owner, &spender)
     }
 
-    #[when_not_paused]
     fn transfer(e: &Env, from: Address, to: Address, amount: i128) {
-        fungible::transfer(e, &from, &to, amount);
+        Token::transfer(e, &from, &to, amount);
     }
 
-    #[when_not_paused]
     fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, amount: i128) {
-        fungible::transfer_from(e, &spender, &from, &to, amount);
+        Token::transfer_from(e, &spender, &from, &to, amount);
     }
 
     fn approve(e: &Env, owner: Address, spender: Address, amount: i128, live_until_ledger: u32) {
@@ -120,20 +122,17 @@ impl FungibleToken for ExampleContract {
 
 #[contractimpl]
 impl FungibleBurnable for ExampleContract {
-    #[when_not_paused]
     fn burn(e: &Env, from: Address, amount: i128) {
-        fungible::burnable::burn(e, &from, amount)
+        Token::burn(e, &from, amount)
     }
 
-    #[when_not_paused]
     fn burn_from(e: &Env, spender: Address, from: Address, amount: i128) {
-        fungible::burnable::burn_from(e, &spender, &from, amount)
+        Token::burn_from(e, &spender, &from, amount)
     }
 }
 
 #[contractimpl]
 impl FungibleMintable for ExampleContract {
-    #[when_not_paused]
     fn mint(e: &Env, account: Address, amount: i128) {
         // When `ownable` module is available,
         // the following checks should be equivalent to:
@@ -141,7 +140,7 @@ impl FungibleMintable for ExampleContract {
         let owner: Address = e.storage().instance().get(&OWNER).expect("owner should be set");
         owner.require_auth();
 
-        fungible::mintable::mint(e, &account, amount);
+        Token::mint(e, &account, amount);
     }
 }
 
@@ -76,6 +76,7 @@ fn transfer_from_fails_when_paused() {
     let client = create_client(&e, &owner, 1000);
 
     e.mock_all_auths();
+    client.approve(&owner, &spender, &200, &100);
     client.pause(&owner);
     client.transfer_from(&spender, &owner, &recipient, &200);
 }
@@ -0,0 +1,57 @@
+//! Fungible Receiver Example Contract.
+//!
+//! Demonstrates a reference implementation of the `FungibleReceiver` trait,
+//! which a contract must implement to receive tokens through
+//! `transfer_and_call`.
+//!
+//! The contract accepts a single token, set at construction, and credits the
+//! received tokens to the internal ledger of their sender. Every receipt is
+//! announced with a `receipt` event.
+
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Bytes, Env};
+use stellar_fungible::FungibleReceiver;
+
+#[contracttype]
+pub enum DataKey {
+    Token,
+    Credit(Address),
+}
+
+#[contract]
+pub struct ExampleContract;
+
+#[contractimpl]
+impl ExampleContract {
+    pub fn __constructor(e: &Env, token: Address) {
+        e.storage().instance().set(&DataKey::Token, &token);
+    }
+
+    pub fn credit(e: &Env, account: Address) -> i128 {
+        e.storage().persistent().get(&DataKey::Credit(account)).unwrap_or(0)
+    }
+}
+
+#[contractimpl]
+impl FungibleReceiver for ExampleContract {
+    fn on_token_received(
+        e: Env,
+        operator: Address,
+        from: Address,
+        amount: i128,
+        _data: Bytes,
+    ) -> bool {
+        // Only the accepted token can credit the ledger: the authorization of
+        // a contract address succeeds only when it is the direct caller.
+        let token: Address =
+            e.storage().instance().get(&DataKey::Token).expect("token should be set");
+        token.require_auth();
+
+        let credit = Self::credit(&e, from.clone()) + amount;
+        e.storage().persistent().set(&DataKey::Credit(from.clone()), &credit);
+
+        let topics = (symbol_short!("receipt"), from);
+        e.events().publish(topics, (operator, amount));
+
+        true
+    }
+}
@@ -0,0 +1,99 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    Address, Bytes, Env, IntoVal,
+};
+use stellar_event_assertion::EventAssertion;
+use stellar_fungible::{balance, mintable::mint, transfer_and_call};
+
+use crate::contract::{ExampleContract, ExampleContractClient};
+
+#[contract]
+struct Token;
+
+#[contractimpl]
+impl Token {
+    pub fn mint(e: &Env, to: Address, amount: i128) {
+        mint(e, &to, amount);
+    }
+
+    pub fn balance(e: &Env, account: Address) -> i128 {
+        balance(e, &account)
+    }
+
+    pub fn transfer_and_call(e: &Env, from: Address, to: Address, amount: i128, data: Bytes) {
+        transfer_and_call(e, &from, &to, amount, data);
+    }
+}
+
+fn create_token<'a>(e: &Env) -> TokenClient<'a> {
+    let address = e.register(Token, ());
+    TokenClient::new(e, &address)
+}
+
+fn create_receiver<'a>(e: &Env, token: &Address) -> ExampleContractClient<'a> {
+    let address = e.register(ExampleContract, (token,));
+    ExampleContractClient::new(e, &address)
+}
+
+#[test]
+fn transfer_and_call_credits_receiver() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let holder = Address::generate(&e);
+    let token = create_token(&e);
+    let receiver = create_receiver(&e, &token.address);
+
+    token.mint(&holder, &100);
+    token.transfer_and_call(&holder, &receiver.address, &60, &Bytes::new(&e));
+
+    let event_assert = EventAssertion::new(&e, receiver.address.clone());
+    let (topics, data) = event_assert.assert_event_from(&receiver.address, "receipt");
+    let event_from: Address = topics.get_unchecked(1).into_val(&e);
+    let (operator, amount): (Address, i128) = data.into_val(&e);
+    assert_eq!(event_from, holder);
+    assert_eq!(operator, holder);
+    assert_eq!(amount, 60);
+
+    assert_eq!(receiver.credit(&holder), 60);
+    assert_eq!(token.balance(&receiver.address), 60);
+    assert_eq!(token.balance(&holder), 40);
+
+    token.transfer_and_call(&holder, &receiver.address, &15, &Bytes::new(&e));
+    assert_eq!(receiver.credit(&holder), 75);
+}
+
+#[test]
+fn transfer_and_call_from_other_token_reverts() {
+    let e = Env::default();
+    let holder = Address::generate(&e);
+    let token = create_token(&e);
+    let other_token = create_token(&e);
+    let receiver = create_receiver(&e, &token.address);
+
+    other_token.mint(&holder, &100);
+
+    // only the holder authorizes the transfer, so that the receiver can't be
+    // fooled by a mocked authorization of `token`
+    let args = (&holder, &receiver.address, 60i128, Bytes::new(&e));
+    e.mock_auths(&[MockAuth {
+        address: &holder,
+        invoke: &MockAuthInvoke {
+            contract: &other_token.address,
+            fn_name: "transfer_and_call",
+            args: args.into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    let result =
+        other_token.try_transfer_and_call(&holder, &receiver.address, &60, &Bytes::new(&e));
+    assert!(result.is_err());
+
+    assert_eq!(receiver.credit(&holder), 0);
+    assert_eq!(other_token.balance(&receiver.address), 0);
+    assert_eq!(other_token.balance(&holder), 100);
+}
@@ -0,0 +1,187 @@
+//! Governance Token Example Contract.
+
+//! This contract showcases how to compose a wrapped token, delegated voting
+//! power and the `pausable` utility:
+//! - accounts deposit an underlying token (e.g. a Stellar Asset Contract) and
+//!   receive the same amount of governance tokens, which they can later
+//!   withdraw back,
+//! - holders delegate their voting power, to themselves or to another account.
+//!   Tokens that were never delegated don't count as votes,
+//! - transfers move the voting power between the delegates of the sender and
+//!   the recipient,
+//! - a guardian, set in the constructor, can pause deposits, withdrawals and
+//!   transfers.
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token,
+    Address, Env, String, Symbol,
+};
+use stellar_fungible::{self as fungible, FungibleToken};
+use stellar_pausable::{self as pausable, Pausable};
+use stellar_pausable_macros::when_not_paused;
+
+pub const GUARDIAN: Symbol = symbol_short!("GUARDIAN");
+pub const UNDERLYING: Symbol = symbol_short!("UNDERLYNG");
+
+#[contracttype]
+pub enum VotesKey {
+    Delegate(Address),
+    Votes(Address),
+}
+
+#[contract]
+pub struct ExampleContract;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExampleContractError {
+    Unauthorized = 1,
+}
+
+fn underlying(e: &Env) -> token::Client<'_> {
+    let address: Address =
+        e.storage().instance().get(&UNDERLYING).expect("underlying should be set");
+    token::Client::new(e, &address)
+}
+
+// Moves `amount` of voting power from the delegate of `from` to the delegate of
+// `to`. Either side is skipped when the account is `None` (mint or burn) or
+// has no delegate.
+fn move_voting_power(e: &Env, from: Option<&Address>, to: Option<&Address>, amount: i128) {
+    let from_delegate = from.and_then(|account| ExampleContract::delegates(e, account.clone()));
+    let to_delegate = to.and_then(|account| ExampleContract::delegates(e, account.clone()));
+    move_delegated_votes(e, from_delegate.as_ref(), to_delegate.as_ref(), amount);
+}
+
+fn move_delegated_votes(e: &Env, from: Option<&Address>, to: Option<&Address>, amount: i128) {
+    if from == to || amount == 0 {
+        return;
+    }
+    if let Some(delegate) = from {
+        let votes = ExampleContract::get_votes(e, delegate.clone()) - amount;
+        e.storage().persistent().set(&VotesKey::Votes(delegate.clone()), &votes);
+    }
+    if let Some(delegate) = to {
+        let votes = ExampleContract::get_votes(e, delegate.clone()) + amount;
+        e.storage().persistent().set(&VotesKey::Votes(delegate.clone()), &votes);
+    }
+}
+
+#[contractimpl]
+impl ExampleContract {
+    pub fn __constructor(e: &Env, guardian: Address, underlying: Address) {
+        fungible::metadata::set_metadata(
+            e,
+            7,
+            String::from_str(e, "Governance Token"),
+            String::from_str(e, "GOV"),
+        );
+        e.storage().instance().set(&GUARDIAN, &guardian);
+        e.storage().instance().set(&UNDERLYING, &underlying);
+    }
+
+    #[when_not_paused]
+    pub fn deposit(e: &Env, account: Address, amount: i128) {
+        // the authorization of the deposit covers the transfer of the
+        // underlying tokens, as the latter is a sub-invocation of the former
+        account.require_auth();
+        underlying(e).transfer(&account, &e.current_contract_address(), &amount);
+        fungible::mintable::mint(e, &account, amount);
+        move_voting_power(e, None, Some(&account), amount);
+    }
+
+    #[when_not_paused]
+    pub fn withdraw(e: &Env, account: Address, amount: i128) {
+        fungible::burnable::burn(e, &account, amount);
+        move_voting_power(e, Some(&account), None, amount);
+        underlying(e).transfer(&e.current_contract_address(), &account, &amount);
+    }
+
+    pub fn delegate(e: &Env, account: Address, delegatee: Address) {
+        account.require_auth();
+        let previous = Self::delegates(e, account.clone());
+        e.storage().persistent().set(&VotesKey::Delegate(account.clone()), &delegatee);
+        let balance = fungible::balance(e, &account);
+        move_delegated_votes(e, previous.as_ref(), Some(&delegatee), balance);
+        e.events().publish((symbol_short!("delegate"), account), delegatee);
+    }
+
+    pub fn delegates(e: &Env, account: Address) -> Option<Address> {
+        e.storage().persistent().get(&VotesKey::Delegate(account))
+    }
+
+    pub fn get_votes(e: &Env, account: Address) -> i128 {
+        e.storage().persistent().get(&VotesKey::Votes(account)).unwrap_or(0)
+    }
+}
+
+#[contractimpl]
+impl Pausable for ExampleContract {
+    fn paused(e: &Env) -> bool {
+        pausable::paused(e)
+    }
+
+    fn pause(e: &Env, caller: Address) {
+        let guardian: Address =
+            e.storage().instance().get(&GUARDIAN).expect("guardian should be set");
+        if guardian != caller {
+            panic_with_error!(e, ExampleContractError::Unauthorized);
+        }
+
+        pausable::pause(e, &caller);
+    }
+
+    fn unpause(e: &Env, caller: Address) {
+        let guardian: Address =
+            e.storage().instance().get(&GUARDIAN).expect("guardian should be set");
+        if guardian != caller {
+            panic_with_error!(e, ExampleContractError::Unauthorized);
+        }
+
+        pausable::unpause(e, &caller);
+    }
+}
+
+#[contractimpl]
+impl FungibleToken for ExampleContract {
+    fn total_supply(e: &Env) -> i128 {
+        fungible::total_supply(e)
+    }
+
+    fn balance(e: &Env, account: Address) -> i128 {
+        fungible::balance(e, &account)
+    }
+
+    fn allowance(e: &Env, owner: Address, spender: Address) -> i128 {
+        fungible::allowance(e, &owner, &spender)
+    }
+
+    #[when_not_paused]
+    fn transfer(e: &Env, from: Address, to: Address, amount: i128) {
+        fungible::transfer(e, &from, &to, amount);
+        move_voting_power(e, Some(&from), Some(&to), amount);
+    }
+
+    #[when_not_paused]
+    fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, amount: i128) {
+        fungible::transfer_from(e, &spender, &from, &to, amount);
+        move_voting_power(e, Some(&from), Some(&to), amount);
+    }
+
+    fn approve(e: &Env, owner: Address, spender: Address, amount: i128, live_until_ledger: u32) {
+        fungible::approve(e, &owner, &spender, amount, live_until_ledger);
+    }
+
+    fn decimals(e: &Env) -> u32 {
+        fungible::metadata::decimals(e)
+    }
+
+    fn name(e: &Env) -> String {
+        fungible::metadata::name(e)
+    }
+
+    fn symbol(e: &Env) -> String {
+        fungible::metadata::symbol(e)
+    }
+}
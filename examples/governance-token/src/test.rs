@@ -0,0 +1,109 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, token::StellarAssetClient, Address, Env};
+
+use crate::contract::{ExampleContract, ExampleContractClient};
+
+fn setup<'a>(e: &Env) -> (ExampleContractClient<'a>, StellarAssetClient<'a>, Address) {
+    let issuer = Address::generate(e);
+    let guardian = Address::generate(e);
+    let underlying = e.register_stellar_asset_contract_v2(issuer).address();
+    let address = e.register(ExampleContract, (&guardian, &underlying));
+    (ExampleContractClient::new(e, &address), StellarAssetClient::new(e, &underlying), guardian)
+}
+
+#[test]
+fn deposit_gives_voting_power_once_delegated() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, underlying, _) = setup(&e);
+    let alice = Address::generate(&e);
+    underlying.mint(&alice, &1000);
+
+    client.deposit(&alice, &400);
+    assert_eq!(client.balance(&alice), 400);
+    assert_eq!(client.total_supply(), 400);
+    // undelegated tokens don't count as votes
+    assert_eq!(client.get_votes(&alice), 0);
+
+    client.delegate(&alice, &alice);
+    assert_eq!(client.get_votes(&alice), 400);
+
+    client.deposit(&alice, &100);
+    assert_eq!(client.get_votes(&alice), 500);
+
+    client.withdraw(&alice, &200);
+    assert_eq!(client.get_votes(&alice), 300);
+    assert_eq!(underlying.balance(&alice), 700);
+}
+
+#[test]
+fn delegation_moves_voting_power() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, underlying, _) = setup(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    underlying.mint(&alice, &1000);
+
+    client.deposit(&alice, &1000);
+    client.delegate(&alice, &alice);
+    client.delegate(&alice, &bob);
+
+    assert_eq!(client.delegates(&alice), Some(bob.clone()));
+    assert_eq!(client.get_votes(&alice), 0);
+    assert_eq!(client.get_votes(&bob), 1000);
+}
+
+#[test]
+fn transfer_moves_voting_power() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, underlying, _) = setup(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let carol = Address::generate(&e);
+    underlying.mint(&alice, &1000);
+
+    client.deposit(&alice, &1000);
+    client.delegate(&alice, &alice);
+    client.delegate(&bob, &carol);
+
+    client.transfer(&alice, &bob, &300);
+    assert_eq!(client.get_votes(&alice), 700);
+    assert_eq!(client.get_votes(&carol), 300);
+    assert_eq!(client.get_votes(&bob), 0);
+}
+
+#[test]
+fn pause_blocks_transfers() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, underlying, guardian) = setup(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    underlying.mint(&alice, &1000);
+    client.deposit(&alice, &1000);
+
+    client.pause(&guardian);
+    assert!(client.try_transfer(&alice, &bob, &100).is_err());
+    assert!(client.try_deposit(&alice, &100).is_err());
+    assert!(client.try_withdraw(&alice, &100).is_err());
+
+    client.unpause(&guardian);
+    client.transfer(&alice, &bob, &100);
+    assert_eq!(client.balance(&bob), 100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn pause_by_non_guardian_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, _, _) = setup(&e);
+    let attacker = Address::generate(&e);
+
+    client.pause(&attacker);
+}
@@ -0,0 +1,79 @@
+//! Non-Fungible Allowlist Example Contract.
+//!
+//! Demonstrates an allowlist mint: only the accounts included in a Merkle tree
+//! can mint, once each. The contract only stores the root of the tree, while
+//! the allowlist itself and the proofs are distributed off chain.
+//!
+//! The leaves of the tree are the SHA-256 hashes of the XDR encoding of the
+//! allowlisted addresses, and the tree is built with
+//! `stellar_crypto::merkle::hash_pair`.
+//!
+//! **IMPORTANT**: This example is for demonstration purposes, and access
+//! control to sensitive operations is not taken into consideration!
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, xdr::ToXdr, Address,
+    BytesN, Env, String, Vec,
+};
+use stellar_crypto::merkle;
+use stellar_default_impl_macro::default_impl;
+use stellar_non_fungible::{Balance, Base, NonFungibleToken, TokenId};
+
+#[contracttype]
+pub enum DataKey {
+    Root,
+    Claimed(Address),
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExampleContractError {
+    AlreadyClaimed = 1,
+    InvalidProof = 2,
+}
+
+#[contract]
+pub struct ExampleContract;
+
+#[contractimpl]
+impl ExampleContract {
+    pub fn __constructor(e: &Env, root: BytesN<32>) {
+        Base::set_metadata(
+            e,
+            String::from_str(e, "www.mytoken.com"),
+            String::from_str(e, "My Token"),
+            String::from_str(e, "TKN"),
+        );
+        e.storage().instance().set(&DataKey::Root, &root);
+    }
+
+    pub fn has_claimed(e: &Env, account: Address) -> bool {
+        e.storage().persistent().has(&DataKey::Claimed(account))
+    }
+
+    pub fn claim_mint(e: &Env, to: Address, proof: Vec<BytesN<32>>) -> TokenId {
+        to.require_auth();
+
+        let key = DataKey::Claimed(to.clone());
+        if e.storage().persistent().has(&key) {
+            panic_with_error!(e, ExampleContractError::AlreadyClaimed);
+        }
+
+        let root: BytesN<32> =
+            e.storage().instance().get(&DataKey::Root).expect("root should be set");
+        let leaf: BytesN<32> = e.crypto().sha256(&to.clone().to_xdr(e)).into();
+        if !merkle::verify_proof(e, &root, &leaf, &proof) {
+            panic_with_error!(e, ExampleContractError::InvalidProof);
+        }
+
+        e.storage().persistent().set(&key, &true);
+        Base::sequential_mint(e, &to)
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl NonFungibleToken for ExampleContract {
+    type ContractType = Base;
+}
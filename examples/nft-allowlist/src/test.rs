@@ -0,0 +1,70 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, vec, xdr::ToXdr, Address, BytesN, Env};
+use stellar_crypto::merkle::hash_pair;
+
+use crate::contract::{ExampleContract, ExampleContractClient, ExampleContractError};
+
+fn leaf(e: &Env, account: &Address) -> BytesN<32> {
+    e.crypto().sha256(&account.clone().to_xdr(e)).into()
+}
+
+/// Builds the allowlist of `alice`, `bob` and `carol`, and returns the root of
+/// the tree along with the hash of the inner node of `alice` and `bob`.
+fn allowlist(e: &Env, alice: &Address, bob: &Address, carol: &Address) -> (BytesN<32>, BytesN<32>) {
+    let inner = hash_pair(e, &leaf(e, alice), &leaf(e, bob));
+    (hash_pair(e, &inner, &leaf(e, carol)), inner)
+}
+
+fn create_client<'a>(e: &Env, root: &BytesN<32>) -> ExampleContractClient<'a> {
+    let address = e.register(ExampleContract, (root,));
+    ExampleContractClient::new(e, &address)
+}
+
+#[test]
+fn claim_mint_with_valid_proof_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (alice, bob, carol) = (Address::generate(&e), Address::generate(&e), Address::generate(&e));
+    let (root, inner) = allowlist(&e, &alice, &bob, &carol);
+    let client = create_client(&e, &root);
+
+    let token_id = client.claim_mint(&bob, &vec![&e, leaf(&e, &alice), leaf(&e, &carol)]);
+    assert_eq!(client.owner_of(&token_id), bob);
+    assert!(client.has_claimed(&bob));
+
+    let token_id = client.claim_mint(&carol, &vec![&e, inner]);
+    assert_eq!(client.owner_of(&token_id), carol);
+    assert!(!client.has_claimed(&alice));
+}
+
+#[test]
+fn claim_mint_with_invalid_proof_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (alice, bob, carol) = (Address::generate(&e), Address::generate(&e), Address::generate(&e));
+    let outsider = Address::generate(&e);
+    let (root, inner) = allowlist(&e, &alice, &bob, &carol);
+    let client = create_client(&e, &root);
+
+    // a valid proof for `carol` doesn't allow anyone else to mint
+    let result = client.try_claim_mint(&outsider, &vec![&e, inner]);
+    assert_eq!(result, Err(Ok(ExampleContractError::InvalidProof.into())));
+    assert_eq!(client.balance(&outsider), 0);
+}
+
+#[test]
+fn double_claim_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (alice, bob, carol) = (Address::generate(&e), Address::generate(&e), Address::generate(&e));
+    let (root, inner) = allowlist(&e, &alice, &bob, &carol);
+    let client = create_client(&e, &root);
+
+    client.claim_mint(&carol, &vec![&e, inner.clone()]);
+    let result = client.try_claim_mint(&carol, &vec![&e, inner]);
+    assert_eq!(result, Err(Ok(ExampleContractError::AlreadyClaimed.into())));
+    assert_eq!(client.balance(&carol), 1);
+}
@@ -0,0 +1,64 @@
+//! Non-Fungible Receiver Example Contract.
+//!
+//! Demonstrates a reference implementation of the `NonFungibleReceiver` trait,
+//! which a contract must implement to receive tokens through
+//! `Base::safe_transfer` and `Base::safe_transfer_from`.
+//!
+//! The owner configures whether the incoming tokens are accepted. Every
+//! accepted token is announced with a `received` event, while a rejection
+//! reverts the whole transfer.
+
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Bytes, Env};
+use stellar_non_fungible::{NonFungibleReceiver, TokenId};
+
+#[contracttype]
+pub enum DataKey {
+    Owner,
+    Accept,
+}
+
+#[contract]
+pub struct ExampleContract;
+
+#[contractimpl]
+impl ExampleContract {
+    pub fn __constructor(e: &Env, owner: Address, accept: bool) {
+        e.storage().instance().set(&DataKey::Owner, &owner);
+        e.storage().instance().set(&DataKey::Accept, &accept);
+    }
+
+    pub fn accepts(e: &Env) -> bool {
+        e.storage().instance().get(&DataKey::Accept).expect("accept should be set")
+    }
+
+    pub fn set_accept(e: &Env, accept: bool) {
+        let owner: Address =
+            e.storage().instance().get(&DataKey::Owner).expect("owner should be set");
+        owner.require_auth();
+
+        e.storage().instance().set(&DataKey::Accept, &accept);
+    }
+}
+
+#[contractimpl]
+impl NonFungibleReceiver for ExampleContract {
+    fn on_non_fungible_received(
+        e: Env,
+        operator: Address,
+        from: Address,
+        token_id: TokenId,
+        _data: Bytes,
+    ) -> bool {
+        if !Self::accepts(&e) {
+            return false;
+        }
+
+        // NOTE: any contract can call this function, a receiver which only
+        // expects tokens of a given collection should keep track of the
+        // collection address and check the ownership of `token_id` there.
+        let topics = (symbol_short!("received"), from);
+        e.events().publish(topics, (operator, token_id));
+
+        true
+    }
+}
@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Bytes, Env, IntoVal};
+use stellar_event_assertion::EventAssertion;
+use stellar_non_fungible::{Base, TokenId};
+
+use crate::contract::{ExampleContract, ExampleContractClient};
+
+#[contract]
+struct Collection;
+
+#[contractimpl]
+impl Collection {
+    pub fn mint(e: &Env, to: Address) -> TokenId {
+        Base::sequential_mint(e, &to)
+    }
+
+    pub fn owner_of(e: &Env, token_id: TokenId) -> Address {
+        Base::owner_of(e, token_id)
+    }
+
+    pub fn safe_transfer(e: &Env, from: Address, to: Address, token_id: TokenId, data: Bytes) {
+        Base::safe_transfer(e, &from, &to, token_id, data);
+    }
+}
+
+fn create_receiver<'a>(e: &Env, owner: &Address, accept: bool) -> ExampleContractClient<'a> {
+    let address = e.register(ExampleContract, (owner, accept));
+    ExampleContractClient::new(e, &address)
+}
+
+fn create_collection<'a>(e: &Env) -> CollectionClient<'a> {
+    let address = e.register(Collection, ());
+    CollectionClient::new(e, &address)
+}
+
+#[test]
+fn safe_transfer_to_accepting_receiver_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+    let holder = Address::generate(&e);
+    let receiver = create_receiver(&e, &owner, true);
+    let collection = create_collection(&e);
+
+    let token_id = collection.mint(&holder);
+    collection.safe_transfer(&holder, &receiver.address, &token_id, &Bytes::new(&e));
+
+    let event_assert = EventAssertion::new(&e, receiver.address.clone());
+    let (topics, data) = event_assert.assert_event_from(&receiver.address, "received");
+    let event_from: Address = topics.get_unchecked(1).into_val(&e);
+    let (operator, event_token_id): (Address, TokenId) = data.into_val(&e);
+    assert_eq!(event_from, holder);
+    assert_eq!(operator, holder);
+    assert_eq!(event_token_id, token_id);
+
+    assert_eq!(collection.owner_of(&token_id), receiver.address);
+}
+
+#[test]
+fn safe_transfer_to_rejecting_receiver_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+    let holder = Address::generate(&e);
+    let receiver = create_receiver(&e, &owner, false);
+    let collection = create_collection(&e);
+
+    let token_id = collection.mint(&holder);
+    let result =
+        collection.try_safe_transfer(&holder, &receiver.address, &token_id, &Bytes::new(&e));
+    assert!(result.is_err());
+    assert_eq!(collection.owner_of(&token_id), holder);
+}
+
+#[test]
+fn set_accept_toggles_receipts() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+    let holder = Address::generate(&e);
+    let receiver = create_receiver(&e, &owner, true);
+    let collection = create_collection(&e);
+
+    receiver.set_accept(&false);
+    assert!(!receiver.accepts());
+
+    let token_id = collection.mint(&holder);
+    let result =
+        collection.try_safe_transfer(&holder, &receiver.address, &token_id, &Bytes::new(&e));
+    assert!(result.is_err());
+
+    receiver.set_accept(&true);
+    collection.safe_transfer(&holder, &receiver.address, &token_id, &Bytes::new(&e));
+    assert_eq!(collection.owner_of(&token_id), receiver.address);
+}
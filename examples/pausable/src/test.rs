@@ -3,6 +3,7 @@
 extern crate std;
 
 use soroban_sdk::{testutils::Address as _, Address, Env};
+use stellar_event_assertion::EventAssertion;
 
 use crate::contract::{ExampleContract, ExampleContractClient};
 
@@ -30,6 +31,7 @@ fn pause_works() {
     e.mock_all_auths();
     client.pause(&owner);
 
+    EventAssertion::new(&e, client.address.clone()).assert_paused(&owner);
     assert!(client.paused());
 }
 
@@ -55,6 +57,7 @@ fn unpause_works() {
     client.pause(&owner);
     client.unpause(&owner);
 
+    EventAssertion::new(&e, client.address.clone()).assert_unpaused(&owner);
     assert!(!client.paused());
 }
 
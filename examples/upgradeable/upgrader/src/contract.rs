@@ -1,5 +1,5 @@
-/// Helper contract to perform upgrade+migrate or rollback+downgrade in a single
-/// transaction.
+/// Helper contract to schedule upgrades, and to perform upgrade+migrate or
+/// rollback+downgrade in a single transaction.
 use soroban_sdk::{contract, contractimpl, symbol_short, Address, BytesN, Env, Symbol, Val};
 use stellar_upgradeable::UpgradeableClient;
 
@@ -17,6 +17,21 @@ impl Upgrader {
         contract_client.upgrade(&wasm_hash, &operator);
     }
 
+    /// Schedules the upgrade to `wasm_hash`, executable from the ledger `eta`
+    /// on. The upgrade is executed in a later transaction with `upgrade` or
+    /// `upgrade_and_migrate`, once the delay has elapsed.
+    pub fn schedule_upgrade(
+        env: Env,
+        contract_address: Address,
+        operator: Address,
+        wasm_hash: BytesN<32>,
+        eta: u32,
+    ) {
+        let contract_client = UpgradeableClient::new(&env, &contract_address);
+
+        contract_client.schedule_upgrade(&wasm_hash, &operator, &eta);
+    }
+
     pub fn upgrade_and_migrate(
         env: Env,
         contract_address: Address,
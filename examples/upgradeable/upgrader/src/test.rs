@@ -3,10 +3,28 @@
 extern crate std;
 
 use contract_v2::Data;
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, TryIntoVal};
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger as _},
+    Address, BytesN, Env, TryIntoVal,
+};
+use stellar_upgradeable::{
+    UpgradeableClient, UpgradeableError, UpgradeableInternal, DEFAULT_MIN_UPGRADE_DELAY,
+};
+use stellar_upgradeable_macros::Upgradeable;
 
 use crate::contract::{Upgrader, UpgraderClient};
 
+#[derive(Upgradeable)]
+#[contract]
+struct TimelockedContract;
+
+impl UpgradeableInternal for TimelockedContract {
+    fn _upgrade_auth(_e: &Env, operator: &Address) {
+        operator.require_auth();
+    }
+}
+
 mod contract_v1 {
     soroban_sdk::contractimport!(file = "../testdata/upgradeable_v1_example.wasm");
 }
@@ -64,3 +82,41 @@ fn test_upgrade_with_upgrader() {
     assert!(client_v2.try_rollback(&()).is_err());
     assert!(client_v2.try_migrate(&data).is_err());
 }
+
+#[test]
+fn test_schedule_and_upgrade_in_same_ledger_fails() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(TimelockedContract, ());
+
+    let upgrader = env.register(Upgrader, ());
+    let upgrader_client = UpgraderClient::new(&env, &upgrader);
+
+    let new_wasm_hash = install_new_wasm(&env);
+
+    // the upgrade can't be scheduled for the current ledger
+    let result = upgrader_client.try_schedule_upgrade(
+        &contract_id,
+        &admin,
+        &new_wasm_hash,
+        &env.ledger().sequence(),
+    );
+    assert_eq!(result, Err(Ok(UpgradeableError::UpgradeDelayTooShort.into())));
+
+    // nor executed in the ledger it is scheduled
+    let eta = env.ledger().sequence() + DEFAULT_MIN_UPGRADE_DELAY;
+    upgrader_client.schedule_upgrade(&contract_id, &admin, &new_wasm_hash, &eta);
+    let result = upgrader_client.try_upgrade(&contract_id, &admin, &new_wasm_hash);
+    assert_eq!(result, Err(Ok(UpgradeableError::UpgradeTooEarly.into())));
+
+    assert_eq!(
+        UpgradeableClient::new(&env, &contract_id).pending_upgrade(),
+        Some((new_wasm_hash.clone(), eta))
+    );
+
+    // the upgrade goes through once due
+    env.ledger().set_sequence_number(eta);
+    upgrader_client.upgrade(&contract_id, &admin, &new_wasm_hash);
+}
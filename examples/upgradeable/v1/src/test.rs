@@ -3,7 +3,11 @@
 extern crate std;
 
 use contract_v2::Data;
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    Address, BytesN, Env,
+};
+use stellar_upgradeable::DEFAULT_MIN_UPGRADE_DELAY;
 
 use crate::contract::{ExampleContract, ExampleContractClient};
 
@@ -31,8 +35,14 @@ fn test_upgrade() {
 
     let client_v1 = ExampleContractClient::new(&env, &address);
 
-    // install the new wasm and upgrade
+    // install the new wasm, schedule the upgrade and execute it once due
     let new_wasm_hash = install_new_wasm(&env);
+    let eta = DEFAULT_MIN_UPGRADE_DELAY;
+    client_v1.schedule_upgrade(&new_wasm_hash, &admin, &eta);
+    assert_eq!(client_v1.pending_upgrade(), Some((new_wasm_hash.clone(), eta)));
+    assert!(client_v1.try_upgrade(&new_wasm_hash, &admin).is_err());
+
+    env.ledger().set_sequence_number(eta);
     client_v1.upgrade(&new_wasm_hash, &admin);
 
     // init the upgraded client and migrate
@@ -17,6 +17,7 @@ pub const OWNER: Symbol = symbol_short!("OWNER");
 #[repr(u32)]
 pub enum ExampleContractError {
     Unauthorized = 1,
+    InvalidMigrationData = 2,
 }
 
 #[contracttype]
@@ -45,9 +46,16 @@ impl MigratableInternal for ExampleContract {
 
     fn _migrate(e: &Env, data: &Self::MigrationData) {
         e.storage().instance().get::<_, Address>(&OWNER).unwrap().require_auth();
+        if !Self::_validate_migration(e, data) {
+            panic_with_error!(e, ExampleContractError::InvalidMigrationData)
+        }
         e.storage().instance().set(&DATA_KEY, data);
     }
 
+    fn _validate_migration(_e: &Env, data: &Self::MigrationData) -> bool {
+        data.num1 <= data.num2
+    }
+
     fn _rollback(e: &Env, _data: &Self::RollbackData) {
         e.storage().instance().get::<_, Address>(&OWNER).unwrap().require_auth();
         e.storage().instance().remove(&DATA_KEY);
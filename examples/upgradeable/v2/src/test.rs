@@ -0,0 +1,36 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::Env;
+
+use crate::contract::{Data, ExampleContract, ExampleContractClient};
+
+#[test]
+fn validate_migration_accepts_valid_data() {
+    let env = Env::default();
+    let address = env.register(ExampleContract, ());
+    let client = ExampleContractClient::new(&env, &address);
+
+    assert!(client.validate_migration(&Data { num1: 12, num2: 34 }));
+}
+
+#[test]
+fn validate_migration_rejects_invalid_data() {
+    let env = Env::default();
+    let address = env.register(ExampleContract, ());
+    let client = ExampleContractClient::new(&env, &address);
+
+    assert!(!client.validate_migration(&Data { num1: 34, num2: 12 }));
+}
+
+#[test]
+fn validate_migration_rejects_when_migration_not_allowed() {
+    let env = Env::default();
+    let address = env.register(ExampleContract, ());
+    let client = ExampleContractClient::new(&env, &address);
+
+    env.as_contract(&address, || stellar_upgradeable::complete_migration(&env));
+
+    assert!(!client.validate_migration(&Data { num1: 12, num2: 34 }));
+}
@@ -0,0 +1,142 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, FnArg, Ident, ItemFn, LitStr, Pat, PatType, Token, Type,
+};
+
+/// Adds a role check at the beginning of the function that ensures the caller
+/// holds the given role.
+///
+/// This macro will inject an `only_role` check at the start of the function
+/// body. If the caller doesn't hold the role, the function will return early
+/// with a panic.
+///
+/// # Requirement:
+///
+/// - The first argument of the decorated function must be of type `Env` or
+///   `&Env`
+/// - The function must have an argument of type `Address` or `&Address` named
+///   `caller`, or named after the optional first argument of the macro.
+///
+/// # Notes
+///
+/// The injected check doesn't require the authorization of the caller, which
+/// is left to the function.
+///
+/// # Example:
+///
+/// ```ignore
+/// #[only_role("minter")]
+/// pub fn mint(e: &Env, caller: Address, to: Address, amount: i128) {
+///     // This code will only execute if `caller` holds the `minter` role
+/// }
+///
+/// #[only_role(operator, "pauser")]
+/// pub fn pause(e: &Env, operator: Address) {
+///     // This code will only execute if `operator` holds the `pauser` role
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn only_role(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let OnlyRoleArgs { caller, role } = parse_macro_input!(attr as OnlyRoleArgs);
+    let input_fn = parse_macro_input!(item as ItemFn);
+
+    let (env_ident, env_is_ref) = find_arg(&input_fn, None);
+    let (caller_ident, caller_is_ref) = find_arg(&input_fn, Some(&caller));
+
+    let env_arg = if env_is_ref {
+        quote! { #env_ident }
+    } else {
+        quote! { &#env_ident }
+    };
+    let caller_arg = if caller_is_ref {
+        quote! { #caller_ident }
+    } else {
+        quote! { &#caller_ident }
+    };
+
+    let fn_vis = &input_fn.vis;
+    let fn_sig = &input_fn.sig;
+    let fn_block = &input_fn.block;
+    let fn_attrs = &input_fn.attrs;
+
+    let output = quote! {
+        #(#fn_attrs)* // retain other macros
+        #fn_vis #fn_sig {
+            stellar_access_control::only_role(
+                #env_arg,
+                #caller_arg,
+                &soroban_sdk::Symbol::new(#env_arg, #role),
+            );
+
+            #fn_block
+        }
+    };
+
+    output.into()
+}
+
+struct OnlyRoleArgs {
+    caller: Ident,
+    role: LitStr,
+}
+
+impl Parse for OnlyRoleArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let caller = if input.peek(Ident) {
+            let caller = input.parse()?;
+            input.parse::<Token![,]>()?;
+            caller
+        } else {
+            Ident::new("caller", Span::call_site())
+        };
+        let role = input.parse()?;
+        Ok(Self { caller, role })
+    }
+}
+
+/// Returns the identifier of the argument named `name`, or of the first
+/// argument (the `Env`) when `name` is `None`, and whether it is a reference.
+fn find_arg(input_fn: &ItemFn, name: Option<&Ident>) -> (Ident, bool) {
+    let fn_name = &input_fn.sig.ident;
+    let expected = if name.is_some() { "Address or &Address" } else { "Env or &Env" };
+
+    let arg = input_fn.sig.inputs.iter().enumerate().find_map(|(index, arg)| {
+        let FnArg::Typed(PatType { pat, ty, .. }) = arg else {
+            return None;
+        };
+        let Pat::Ident(pat_ident) = &**pat else {
+            return None;
+        };
+        let matches = match name {
+            Some(name) => pat_ident.ident == *name,
+            None => index == 0,
+        };
+        matches.then(|| (pat_ident.ident.clone(), ty.clone()))
+    });
+
+    let Some((ident, ty)) = arg else {
+        match name {
+            Some(name) => panic!("function '{fn_name}' must have an argument named '{name}'"),
+            None => panic!("first argument of function '{fn_name}' must be a typed identifier"),
+        }
+    };
+
+    let (path, is_ref) = match &*ty {
+        Type::Reference(type_ref) => match &*type_ref.elem {
+            Type::Path(path) => (path.clone(), true),
+            _ => panic!("argument '{ident}' of function '{fn_name}' must be {expected}"),
+        },
+        Type::Path(path) => (path.clone(), false),
+        _ => panic!("argument '{ident}' of function '{fn_name}' must be {expected}"),
+    };
+
+    let type_name = if name.is_some() { "Address" } else { "Env" };
+    if !path.path.segments.last().is_some_and(|seg| seg.ident == type_name) {
+        panic!("argument '{ident}' of function '{fn_name}' must be {expected}");
+    }
+
+    (ident, is_ref)
+}
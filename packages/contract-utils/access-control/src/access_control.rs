@@ -0,0 +1,165 @@
+use soroban_sdk::{contracterror, Address, Env, Symbol};
+
+pub trait AccessControl {
+    /// Returns true if `account` holds `role`, and false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `account` - The address to check.
+    /// * `role` - The role to check.
+    fn has_role(e: &Env, account: Address, role: Symbol) -> bool {
+        crate::has_role(e, &account, &role)
+    }
+
+    /// Returns the admin role of `role`, whose holders can grant and revoke
+    /// `role`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `role` - The role to query the admin role of.
+    fn get_role_admin(e: &Env, role: Symbol) -> Symbol {
+        crate::get_role_admin(e, &role)
+    }
+
+    /// Grants `role` to `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `admin` - The address granting the role, holding its admin role.
+    /// * `account` - The address receiving the role.
+    /// * `role` - The role to grant.
+    ///
+    /// # Errors
+    ///
+    /// * [`AccessControlError::Unauthorized`] - Occurs when `admin` doesn't
+    ///   hold the admin role of `role`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["role_granted", role: Symbol, account: Address]`
+    /// * data - `[admin: Address]`
+    fn grant_role(e: &Env, admin: Address, account: Address, role: Symbol) {
+        crate::grant_role(e, &admin, &account, &role);
+    }
+
+    /// Revokes `role` from `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `admin` - The address revoking the role, holding its admin role.
+    /// * `account` - The address losing the role.
+    /// * `role` - The role to revoke.
+    ///
+    /// # Errors
+    ///
+    /// * [`AccessControlError::Unauthorized`] - Occurs when `admin` doesn't
+    ///   hold the admin role of `role`.
+    /// * [`AccessControlError::RoleNotHeld`] - Occurs when `account` doesn't
+    ///   hold `role`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["role_revoked", role: Symbol, account: Address]`
+    /// * data - `[admin: Address]`
+    fn revoke_role(e: &Env, admin: Address, account: Address, role: Symbol) {
+        crate::revoke_role(e, &admin, &account, &role);
+    }
+
+    /// Gives up `role` held by `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `account` - The address giving up the role.
+    /// * `role` - The role to give up.
+    ///
+    /// # Errors
+    ///
+    /// * [`AccessControlError::RoleNotHeld`] - Occurs when `account` doesn't
+    ///   hold `role`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["role_revoked", role: Symbol, account: Address]`
+    /// * data - `[account: Address]`
+    fn renounce_role(e: &Env, account: Address, role: Symbol) {
+        crate::renounce_role(e, &account, &role);
+    }
+}
+
+// ################## ERRORS ##################
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AccessControlError {
+    /// The caller doesn't hold the role required for the operation.
+    Unauthorized = 120,
+    /// The account doesn't hold the role to revoke.
+    RoleNotHeld = 121,
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when `role` is granted to `account`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `role` - The granted role.
+/// * `account` - The address receiving the role.
+/// * `caller` - The address granting the role.
+///
+/// # Events
+///
+/// * topics - `["role_granted", role: Symbol, account: Address]`
+/// * data - `[caller: Address]`
+pub fn emit_role_granted(e: &Env, role: &Symbol, account: &Address, caller: &Address) {
+    let topics = (Symbol::new(e, "role_granted"), role.clone(), account.clone());
+    e.events().publish(topics, caller)
+}
+
+/// Emits an event when `role` is revoked from `account`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `role` - The revoked role.
+/// * `account` - The address losing the role.
+/// * `caller` - The address revoking the role.
+///
+/// # Events
+///
+/// * topics - `["role_revoked", role: Symbol, account: Address]`
+/// * data - `[caller: Address]`
+pub fn emit_role_revoked(e: &Env, role: &Symbol, account: &Address, caller: &Address) {
+    let topics = (Symbol::new(e, "role_revoked"), role.clone(), account.clone());
+    e.events().publish(topics, caller)
+}
+
+/// Emits an event when the admin role of `role` changes.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `role` - The role whose admin role changed.
+/// * `previous_admin_role` - The previous admin role.
+/// * `admin_role` - The new admin role.
+///
+/// # Events
+///
+/// * topics - `["role_admin_changed", role: Symbol]`
+/// * data - `[previous_admin_role: Symbol, admin_role: Symbol]`
+pub fn emit_role_admin_changed(
+    e: &Env,
+    role: &Symbol,
+    previous_admin_role: &Symbol,
+    admin_role: &Symbol,
+) {
+    let topics = (Symbol::new(e, "role_admin_changed"), role.clone());
+    e.events().publish(topics, (previous_admin_role.clone(), admin_role.clone()))
+}
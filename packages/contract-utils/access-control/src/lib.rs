@@ -0,0 +1,60 @@
+//! Access Control Contract Module.
+//!
+//! This contract module implements role-based access control: accounts are
+//! granted roles, identified by a `Symbol`, and functions can be restricted to
+//! the holders of a given role. Typical roles are `minter` or `pauser`.
+//!
+//! Each role has an admin role, whose holders can grant and revoke it. By
+//! default, the admin role of every role is [`DEFAULT_ADMIN_ROLE`], which is
+//! also its own admin. The admin role of a role can be changed with
+//! [`set_role_admin()`], e.g. to let the holders of `minter_admin` manage the
+//! `minter` role.
+//!
+//! By implementing the trait [`AccessControl`] for your contract, you expose
+//! the following methods:
+//! - [`has_role()`]
+//! - [`get_role_admin()`]
+//! - [`grant_role()`]
+//! - [`revoke_role()`]
+//! - [`renounce_role()`]
+//!
+//! The initial admin must be set with [`grant_role_no_auth()`], typically in
+//! the constructor of the contract, as nobody holds the admin role yet:
+//!
+//! ```ignore
+//! pub fn __constructor(e: &Env, admin: Address) {
+//!     access_control::grant_role_no_auth(e, &admin, &admin, &DEFAULT_ADMIN_ROLE);
+//! }
+//! ```
+//!
+//! Functions can then be restricted with [`only_role()`], or with the
+//! `#[only_role]` macro from `stellar-access-control-macros`:
+//!
+//! ```ignore
+//! #[only_role("minter")]
+//! fn mint(e: &Env, caller: Address, to: Address, amount: i128) {
+//!     /* this body will execute ONLY when `caller` holds the `minter` role */
+//! }
+//! ```
+//!
+//! TL;DR
+//! to see it all in action, check out the
+//! `examples/access-controlled-token/src/contract.rs` file.
+
+#![no_std]
+
+mod access_control;
+mod storage;
+
+pub use crate::{
+    access_control::{
+        emit_role_admin_changed, emit_role_granted, emit_role_revoked, AccessControl,
+        AccessControlError,
+    },
+    storage::{
+        get_role_admin, grant_role, grant_role_no_auth, has_role, only_role, renounce_role,
+        revoke_role, revoke_role_no_auth, set_role_admin, StorageKey, DEFAULT_ADMIN_ROLE,
+    },
+};
+
+mod test;
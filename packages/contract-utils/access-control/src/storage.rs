@@ -0,0 +1,235 @@
+use soroban_sdk::{contracttype, panic_with_error, symbol_short, Address, Env, Symbol};
+use stellar_constants::{OWNER_EXTEND_AMOUNT, OWNER_TTL_THRESHOLD};
+
+use crate::{
+    access_control::AccessControlError, emit_role_admin_changed, emit_role_granted,
+    emit_role_revoked,
+};
+
+/// The default admin role of every role, including itself.
+pub const DEFAULT_ADMIN_ROLE: Symbol = symbol_short!("admin");
+
+/// Storage keys for the data associated with the access control module
+#[contracttype]
+pub enum StorageKey {
+    HasRole(Address, Symbol),
+    RoleAdmin(Symbol),
+}
+
+/// Returns true if `account` holds `role`, and false otherwise.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address to check.
+/// * `role` - The role to check.
+pub fn has_role(e: &Env, account: &Address, role: &Symbol) -> bool {
+    let key = StorageKey::HasRole(account.clone(), role.clone());
+    e.storage().persistent().get::<_, bool>(&key).is_some_and(|_| {
+        e.storage().persistent().extend_ttl(&key, OWNER_TTL_THRESHOLD, OWNER_EXTEND_AMOUNT);
+        true
+    })
+}
+
+/// Returns the admin role of `role`, whose holders can grant and revoke
+/// `role`. Defaults to [`DEFAULT_ADMIN_ROLE`].
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `role` - The role to query the admin role of.
+pub fn get_role_admin(e: &Env, role: &Symbol) -> Symbol {
+    e.storage().instance().get(&StorageKey::RoleAdmin(role.clone())).unwrap_or(DEFAULT_ADMIN_ROLE)
+}
+
+/// Grants `role` to `account`. Does nothing if `account` already holds it.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `admin` - The address granting the role, holding its admin role.
+/// * `account` - The address receiving the role.
+/// * `role` - The role to grant.
+///
+/// # Errors
+///
+/// * refer to [`only_role`] errors.
+///
+/// # Events
+///
+/// * topics - `["role_granted", role: Symbol, account: Address]`
+/// * data - `[admin: Address]`
+///
+/// # Notes
+///
+/// Authorization for `admin` is required.
+pub fn grant_role(e: &Env, admin: &Address, account: &Address, role: &Symbol) {
+    admin.require_auth();
+    only_role(e, admin, &get_role_admin(e, role));
+    grant_role_no_auth(e, admin, account, role);
+}
+
+/// Revokes `role` from `account`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `admin` - The address revoking the role, holding its admin role.
+/// * `account` - The address losing the role.
+/// * `role` - The role to revoke.
+///
+/// # Errors
+///
+/// * refer to [`only_role`] errors.
+/// * refer to [`revoke_role_no_auth`] errors.
+///
+/// # Events
+///
+/// * topics - `["role_revoked", role: Symbol, account: Address]`
+/// * data - `[admin: Address]`
+///
+/// # Notes
+///
+/// Authorization for `admin` is required.
+pub fn revoke_role(e: &Env, admin: &Address, account: &Address, role: &Symbol) {
+    admin.require_auth();
+    only_role(e, admin, &get_role_admin(e, role));
+    revoke_role_no_auth(e, admin, account, role);
+}
+
+/// Gives up `role` held by `account`, e.g. when the account is compromised.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address giving up the role.
+/// * `role` - The role to give up.
+///
+/// # Errors
+///
+/// * refer to [`revoke_role_no_auth`] errors.
+///
+/// # Events
+///
+/// * topics - `["role_revoked", role: Symbol, account: Address]`
+/// * data - `[account: Address]`
+///
+/// # Notes
+///
+/// Authorization for `account` is required.
+pub fn renounce_role(e: &Env, account: &Address, role: &Symbol) {
+    account.require_auth();
+    revoke_role_no_auth(e, account, account, role);
+}
+
+/// Sets `admin_role` as the admin role of `role`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `role` - The role to set the admin role of.
+/// * `admin_role` - The new admin role.
+///
+/// # Events
+///
+/// * topics - `["role_admin_changed", role: Symbol]`
+/// * data - `[previous_admin_role: Symbol, admin_role: Symbol]`
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute this function.
+pub fn set_role_admin(e: &Env, role: &Symbol, admin_role: &Symbol) {
+    let previous_admin_role = get_role_admin(e, role);
+    e.storage().instance().set(&StorageKey::RoleAdmin(role.clone()), admin_role);
+    emit_role_admin_changed(e, role, &previous_admin_role, admin_role);
+}
+
+/// Low-level function to grant `role` to `account` without checking the role
+/// of `caller`. Does nothing if `account` already holds it.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The address reported as granting the role in the event.
+/// * `account` - The address receiving the role.
+/// * `role` - The role to grant.
+///
+/// # Events
+///
+/// * topics - `["role_granted", role: Symbol, account: Address]`
+/// * data - `[caller: Address]`
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is meant for setting up the initial roles, typically in the constructor.
+/// Anywhere else, it is the responsibility of the implementer to establish
+/// appropriate access controls.
+pub fn grant_role_no_auth(e: &Env, caller: &Address, account: &Address, role: &Symbol) {
+    if has_role(e, account, role) {
+        return;
+    }
+    e.storage().persistent().set(&StorageKey::HasRole(account.clone(), role.clone()), &true);
+    emit_role_granted(e, role, account, caller);
+}
+
+/// Low-level function to revoke `role` from `account` without checking the
+/// role of `caller`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The address reported as revoking the role in the event.
+/// * `account` - The address losing the role.
+/// * `role` - The role to revoke.
+///
+/// # Errors
+///
+/// * [`AccessControlError::RoleNotHeld`] - Occurs when `account` doesn't hold
+///   `role`.
+///
+/// # Events
+///
+/// * topics - `["role_revoked", role: Symbol, account: Address]`
+/// * data - `[caller: Address]`
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute this function.
+pub fn revoke_role_no_auth(e: &Env, caller: &Address, account: &Address, role: &Symbol) {
+    if !has_role(e, account, role) {
+        panic_with_error!(e, AccessControlError::RoleNotHeld);
+    }
+    e.storage().persistent().remove(&StorageKey::HasRole(account.clone(), role.clone()));
+    emit_role_revoked(e, role, account, caller);
+}
+
+/// Helper to make a function callable only by the holders of `role`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The address to check.
+/// * `role` - The required role.
+///
+/// # Errors
+///
+/// * [`AccessControlError::Unauthorized`] - Occurs when `caller` doesn't hold
+///   `role`.
+///
+/// # Notes
+///
+/// Authorization for `caller` is NOT required by this function, and is left
+/// to the calling function.
+pub fn only_role(e: &Env, caller: &Address, role: &Symbol) {
+    if !has_role(e, caller, role) {
+        panic_with_error!(e, AccessControlError::Unauthorized);
+    }
+}
@@ -0,0 +1,164 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract, symbol_short,
+    testutils::{Address as _, Events},
+    vec, Address, Env, IntoVal, Symbol,
+};
+
+use crate::storage::{
+    get_role_admin, grant_role, grant_role_no_auth, has_role, only_role, renounce_role,
+    revoke_role, set_role_admin, DEFAULT_ADMIN_ROLE,
+};
+
+#[contract]
+struct MockContract;
+
+const MINTER: Symbol = symbol_short!("minter");
+
+fn setup(e: &Env, admin: &Address) -> Address {
+    let address = e.register(MockContract, ());
+    e.as_contract(&address, || {
+        grant_role_no_auth(e, admin, admin, &DEFAULT_ADMIN_ROLE);
+    });
+    address
+}
+
+#[test]
+fn grant_role_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let address = setup(&e, &admin);
+
+    e.as_contract(&address, || {
+        assert!(!has_role(&e, &minter, &MINTER));
+        grant_role(&e, &admin, &minter, &MINTER);
+        assert!(has_role(&e, &minter, &MINTER));
+        only_role(&e, &minter, &MINTER);
+
+        let events = e.events().all();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events,
+            vec![
+                &e,
+                (
+                    address.clone(),
+                    vec![
+                        &e,
+                        Symbol::new(&e, "role_granted").into_val(&e),
+                        MINTER.into_val(&e),
+                        minter.into_val(&e)
+                    ],
+                    admin.into_val(&e)
+                )
+            ]
+        );
+    });
+}
+
+#[test]
+fn revoke_role_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let address = setup(&e, &admin);
+
+    e.as_contract(&address, || {
+        grant_role(&e, &admin, &minter, &MINTER);
+    });
+
+    e.as_contract(&address, || {
+        revoke_role(&e, &admin, &minter, &MINTER);
+        assert!(!has_role(&e, &minter, &MINTER));
+    });
+}
+
+#[test]
+fn renounce_role_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let address = setup(&e, &admin);
+
+    e.as_contract(&address, || {
+        grant_role(&e, &admin, &minter, &MINTER);
+    });
+
+    e.as_contract(&address, || {
+        renounce_role(&e, &minter, &MINTER);
+        assert!(!has_role(&e, &minter, &MINTER));
+    });
+}
+
+#[test]
+fn role_admin_can_manage_role() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let minter_admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let address = setup(&e, &admin);
+    let minter_admin_role = Symbol::new(&e, "minter_admin");
+
+    e.as_contract(&address, || {
+        assert_eq!(get_role_admin(&e, &MINTER), DEFAULT_ADMIN_ROLE);
+        set_role_admin(&e, &MINTER, &minter_admin_role);
+        assert_eq!(get_role_admin(&e, &MINTER), minter_admin_role);
+        grant_role(&e, &admin, &minter_admin, &minter_admin_role);
+    });
+
+    e.as_contract(&address, || {
+        grant_role(&e, &minter_admin, &minter, &MINTER);
+        assert!(has_role(&e, &minter, &MINTER));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #120)")]
+fn grant_role_without_admin_role_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let attacker = Address::generate(&e);
+    let address = setup(&e, &admin);
+
+    e.as_contract(&address, || {
+        grant_role(&e, &attacker, &attacker, &MINTER);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #120)")]
+fn default_admin_loses_role_managed_by_another_admin_role() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let address = setup(&e, &admin);
+
+    e.as_contract(&address, || {
+        set_role_admin(&e, &MINTER, &Symbol::new(&e, "minter_admin"));
+        grant_role(&e, &admin, &minter, &MINTER);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #121)")]
+fn revoke_role_not_held_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let address = setup(&e, &admin);
+
+    e.as_contract(&address, || {
+        revoke_role(&e, &admin, &minter, &MINTER);
+    });
+}
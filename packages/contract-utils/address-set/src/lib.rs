@@ -0,0 +1,36 @@
+//! Address Set Utility Module.
+//!
+//! This utility module provides an enumerable set of addresses, the building
+//! block of allowlists and blocklists, e.g. the accounts enabled for clawback
+//! or the operators allowed to transfer tokens:
+//! - [`add()`]
+//! - [`remove()`]
+//! - [`contains()`]
+//! - [`len()`]
+//! - [`list()`]
+//! - [`extend_ttl()`]
+//!
+//! Membership checks read a single storage entry, while the members are also
+//! indexed by position, so that the set can be listed page by page.
+//!
+//! Multiple independent sets can live in the same contract, as every set is
+//! identified by a `Symbol` key chosen by the caller.
+//!
+//! ```ignore
+//! const ALLOWLIST: Symbol = symbol_short!("ALLOWED");
+//!
+//! fn mint(e: &Env, to: &Address, amount: i128) {
+//!     if !stellar_address_set::contains(e, &ALLOWLIST, to) {
+//!         panic!("not allowed");
+//!     }
+//!     /* mint the tokens */
+//! }
+//! ```
+
+#![no_std]
+
+mod storage;
+
+pub use crate::storage::{add, contains, extend_ttl, len, list, remove, AddressSetStorageKey};
+
+mod test;
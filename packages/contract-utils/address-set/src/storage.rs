@@ -0,0 +1,160 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+/// Storage keys for the data associated with an address set.
+#[contracttype]
+pub enum AddressSetStorageKey {
+    /// The number of members of the set identified by the `Symbol`.
+    Length(Symbol),
+    /// The position of a member in the set identified by the `Symbol`.
+    Position(Symbol, Address),
+    /// The member at a given position in the set identified by the `Symbol`.
+    Member(Symbol, u32),
+}
+
+/// Returns `true` if `address` is a member of the set identified by `key`, and
+/// `false` otherwise.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `key` - The identifier of the set.
+/// * `address` - The address to check.
+pub fn contains(e: &Env, key: &Symbol, address: &Address) -> bool {
+    e.storage().persistent().has(&AddressSetStorageKey::Position(key.clone(), address.clone()))
+}
+
+/// Returns the number of members of the set identified by `key`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `key` - The identifier of the set.
+pub fn len(e: &Env, key: &Symbol) -> u32 {
+    e.storage().persistent().get(&AddressSetStorageKey::Length(key.clone())).unwrap_or(0)
+}
+
+/// Returns up to `limit` members of the set identified by `key`, starting at
+/// position `offset`. Returns an empty list once `offset` reaches the end of
+/// the set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `key` - The identifier of the set.
+/// * `offset` - The position of the first member to return.
+/// * `limit` - The maximum number of members to return.
+///
+/// # Notes
+///
+/// The members are not sorted, and removing a member moves the last member to
+/// its position. Pages read while the set is being modified might therefore
+/// skip or repeat members.
+pub fn list(e: &Env, key: &Symbol, offset: u32, limit: u32) -> Vec<Address> {
+    let end = len(e, key).min(offset.saturating_add(limit));
+
+    let mut members = Vec::new(e);
+    for position in offset..end {
+        members.push_back(read_member(e, key, position));
+    }
+    members
+}
+
+/// Extends the TTL of the storage entries of `address` in the set identified
+/// by `key`, if it is a member.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `key` - The identifier of the set.
+/// * `address` - The member whose entries get their TTL extended.
+/// * `threshold` - The TTL below which the entries are extended.
+/// * `extend_to` - The TTL the entries are extended to.
+pub fn extend_ttl(e: &Env, key: &Symbol, address: &Address, threshold: u32, extend_to: u32) {
+    let position_key = AddressSetStorageKey::Position(key.clone(), address.clone());
+    if let Some(position) = e.storage().persistent().get::<_, u32>(&position_key) {
+        e.storage().persistent().extend_ttl(&position_key, threshold, extend_to);
+        e.storage().persistent().extend_ttl(
+            &AddressSetStorageKey::Member(key.clone(), position),
+            threshold,
+            extend_to,
+        );
+    }
+}
+
+/// Adds `address` to the set identified by `key`. Returns `true` if the
+/// address was added, and `false` if it was already a member.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `key` - The identifier of the set.
+/// * `address` - The address to add.
+///
+/// # Notes
+///
+/// This function does not enforce authorization. Ensure that authorization is
+/// handled at a higher level.
+pub fn add(e: &Env, key: &Symbol, address: &Address) -> bool {
+    if contains(e, key, address) {
+        return false;
+    }
+
+    let position = len(e, key);
+    write_member(e, key, position, address);
+    // NOTE: can't overflow, as every member takes up storage entries.
+    e.storage().persistent().set(&AddressSetStorageKey::Length(key.clone()), &(position + 1));
+    true
+}
+
+/// Removes `address` from the set identified by `key`. Returns `true` if the
+/// address was removed, and `false` if it was not a member.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `key` - The identifier of the set.
+/// * `address` - The address to remove.
+///
+/// # Notes
+///
+/// * The last member of the set is moved to the position of the removed one, so
+///   that removals don't leave gaps.
+/// * This function does not enforce authorization. Ensure that authorization is
+///   handled at a higher level.
+pub fn remove(e: &Env, key: &Symbol, address: &Address) -> bool {
+    let position_key = AddressSetStorageKey::Position(key.clone(), address.clone());
+    let Some(position) = e.storage().persistent().get::<_, u32>(&position_key) else {
+        return false;
+    };
+
+    // NOTE: can't underflow, as `address` is a member.
+    let last_position = len(e, key) - 1;
+    if position != last_position {
+        let last = read_member(e, key, last_position);
+        write_member(e, key, position, &last);
+    }
+
+    e.storage().persistent().remove(&position_key);
+    e.storage().persistent().remove(&AddressSetStorageKey::Member(key.clone(), last_position));
+    if last_position == 0 {
+        e.storage().persistent().remove(&AddressSetStorageKey::Length(key.clone()));
+    } else {
+        e.storage().persistent().set(&AddressSetStorageKey::Length(key.clone()), &last_position);
+    }
+    true
+}
+
+fn read_member(e: &Env, key: &Symbol, position: u32) -> Address {
+    // NOTE: can't fail, as every position below the length holds a member.
+    e.storage().persistent().get(&AddressSetStorageKey::Member(key.clone(), position)).unwrap()
+
+    // NOTE: We don't extend the TTL here. Contracts usually manage TTL's
+    // themselves, see `extend_ttl`.
+}
+
+fn write_member(e: &Env, key: &Symbol, position: u32, address: &Address) {
+    e.storage().persistent().set(&AddressSetStorageKey::Member(key.clone(), position), address);
+    e.storage()
+        .persistent()
+        .set(&AddressSetStorageKey::Position(key.clone(), address.clone()), &position);
+}
@@ -0,0 +1,110 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, symbol_short, testutils::Address as _, vec, Address, Env, Symbol};
+
+use crate::storage::{add, contains, len, list, remove, AddressSetStorageKey};
+
+#[contract]
+struct MockContract;
+
+const KEY: Symbol = symbol_short!("ALLOWED");
+
+#[test]
+fn add_and_contains_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        assert!(!contains(&e, &KEY, &alice));
+        assert!(add(&e, &KEY, &alice));
+        assert!(contains(&e, &KEY, &alice));
+        assert!(!contains(&e, &KEY, &bob));
+        assert_eq!(len(&e, &KEY), 1);
+
+        // adding twice is a no-op
+        assert!(!add(&e, &KEY, &alice));
+        assert_eq!(len(&e, &KEY), 1);
+    });
+}
+
+#[test]
+fn remove_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let carol = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        add(&e, &KEY, &alice);
+        add(&e, &KEY, &bob);
+        add(&e, &KEY, &carol);
+
+        assert!(remove(&e, &KEY, &alice));
+        assert!(!contains(&e, &KEY, &alice));
+        assert_eq!(len(&e, &KEY), 2);
+        // the last member takes the position of the removed one
+        assert_eq!(list(&e, &KEY, 0, 10), vec![&e, carol.clone(), bob.clone()]);
+
+        // removing a non-member is a no-op
+        assert!(!remove(&e, &KEY, &alice));
+
+        remove(&e, &KEY, &bob);
+        remove(&e, &KEY, &carol);
+        assert_eq!(len(&e, &KEY), 0);
+        assert!(!e.storage().persistent().has(&AddressSetStorageKey::Length(KEY)));
+        assert!(!e.storage().persistent().has(&AddressSetStorageKey::Member(KEY, 0)));
+    });
+}
+
+#[test]
+fn sets_are_independent() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let other_key = symbol_short!("BLOCKED");
+    let alice = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        add(&e, &KEY, &alice);
+        assert!(!contains(&e, &other_key, &alice));
+        assert_eq!(len(&e, &other_key), 0);
+    });
+}
+
+#[test]
+fn list_paginates_across_many_addresses() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let members: std::vec::Vec<Address> = (0..10).map(|_| Address::generate(&e)).collect();
+
+    e.as_contract(&address, || {
+        for member in &members {
+            add(&e, &KEY, member);
+        }
+    });
+
+    e.as_contract(&address, || {
+        assert_eq!(len(&e, &KEY), 10);
+
+        let mut listed = std::vec::Vec::new();
+        let mut offset = 0;
+        loop {
+            let page = list(&e, &KEY, offset, 4);
+            if page.is_empty() {
+                break;
+            }
+            assert!(page.len() <= 4);
+            offset += page.len();
+            listed.extend(page.iter());
+        }
+        assert_eq!(listed, members);
+
+        assert_eq!(list(&e, &KEY, 8, 4).len(), 2);
+        assert!(list(&e, &KEY, 10, 4).is_empty());
+        assert_eq!(list(&e, &KEY, 0, u32::MAX).len(), 10);
+    });
+}
@@ -0,0 +1,37 @@
+//! Bitmap Utility Module.
+//!
+//! This utility module provides a compact way of tracking boolean flags
+//! indexed by a `u32`, such as claimed indices of an airdrop or used nonces.
+//!
+//! Instead of storing one entry per index, bits are packed into `u64` chunks,
+//! so that 64 consecutive indices share a single storage entry:
+//! - [`get()`]
+//! - [`set()`]
+//! - [`unset()`]
+//! - [`find_last_set()`]
+//! - [`extend_ttl()`]
+//!
+//! Multiple independent bitmaps can live in the same contract, as every bitmap
+//! is identified by a `Symbol` key chosen by the caller.
+//!
+//! ```ignore
+//! const CLAIMED: Symbol = symbol_short!("CLAIMED");
+//!
+//! fn claim(e: &Env, index: u32) {
+//!     if stellar_bitmap::get(e, &CLAIMED, index) {
+//!         panic!("already claimed");
+//!     }
+//!     stellar_bitmap::set(e, &CLAIMED, index);
+//!     /* transfer the claimed tokens */
+//! }
+//! ```
+
+#![no_std]
+
+mod storage;
+
+pub use crate::storage::{
+    extend_ttl, find_last_set, get, set, unset, BitmapStorageKey, BITS_PER_CHUNK,
+};
+
+mod test;
@@ -0,0 +1,134 @@
+use soroban_sdk::{contracttype, Env, Symbol};
+
+/// Number of indices packed into a single storage entry.
+pub const BITS_PER_CHUNK: u32 = u64::BITS;
+
+/// Storage key for the chunks of a bitmap.
+#[contracttype]
+pub enum BitmapStorageKey {
+    /// The chunk `chunk_index` of the bitmap identified by the `Symbol`.
+    Chunk(Symbol, u32),
+}
+
+/// Returns `true` if the bit at `index` is set in the bitmap identified by
+/// `key`, and `false` otherwise.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `key` - The identifier of the bitmap.
+/// * `index` - The index of the bit to read.
+pub fn get(e: &Env, key: &Symbol, index: u32) -> bool {
+    let (chunk_index, mask) = locate(index);
+    read_chunk(e, key, chunk_index) & mask != 0
+}
+
+/// Returns the highest index lower than or equal to `index` whose bit is set in
+/// the bitmap identified by `key`, or `None` if there is no such index.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `key` - The identifier of the bitmap.
+/// * `index` - The index from which the search starts, going downwards.
+///
+/// # Notes
+///
+/// The search reads one storage entry per chunk, so the number of reads grows
+/// with the distance to the found index divided by [`BITS_PER_CHUNK`].
+pub fn find_last_set(e: &Env, key: &Symbol, index: u32) -> Option<u32> {
+    let (mut chunk_index, _) = locate(index);
+    // keep only the bits at or below `index` in its own chunk
+    let mut mask = u64::MAX >> (BITS_PER_CHUNK - 1 - index % BITS_PER_CHUNK);
+
+    loop {
+        let chunk = read_chunk(e, key, chunk_index) & mask;
+        if chunk != 0 {
+            let offset = BITS_PER_CHUNK - 1 - chunk.leading_zeros();
+            return Some(chunk_index * BITS_PER_CHUNK + offset);
+        }
+        if chunk_index == 0 {
+            return None;
+        }
+        chunk_index -= 1;
+        mask = u64::MAX;
+    }
+}
+
+/// Extends the TTL of the storage entry holding the bit at `index` in the
+/// bitmap identified by `key`, if the entry exists.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `key` - The identifier of the bitmap.
+/// * `index` - The index whose chunk gets its TTL extended.
+/// * `threshold` - The TTL below which the entry is extended.
+/// * `extend_to` - The TTL the entry is extended to.
+pub fn extend_ttl(e: &Env, key: &Symbol, index: u32, threshold: u32, extend_to: u32) {
+    let (chunk_index, _) = locate(index);
+    let storage_key = BitmapStorageKey::Chunk(key.clone(), chunk_index);
+    if e.storage().persistent().has(&storage_key) {
+        e.storage().persistent().extend_ttl(&storage_key, threshold, extend_to);
+    }
+}
+
+/// Sets the bit at `index` in the bitmap identified by `key`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `key` - The identifier of the bitmap.
+/// * `index` - The index of the bit to set.
+///
+/// # Notes
+///
+/// This function does not enforce authorization. Ensure that authorization is
+/// handled at a higher level.
+pub fn set(e: &Env, key: &Symbol, index: u32) {
+    let (chunk_index, mask) = locate(index);
+    let chunk = read_chunk(e, key, chunk_index);
+    write_chunk(e, key, chunk_index, chunk | mask);
+}
+
+/// Clears the bit at `index` in the bitmap identified by `key`. The storage
+/// entry is removed once all the bits of its chunk are cleared.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `key` - The identifier of the bitmap.
+/// * `index` - The index of the bit to clear.
+///
+/// # Notes
+///
+/// This function does not enforce authorization. Ensure that authorization is
+/// handled at a higher level.
+pub fn unset(e: &Env, key: &Symbol, index: u32) {
+    let (chunk_index, mask) = locate(index);
+    let chunk = read_chunk(e, key, chunk_index);
+    write_chunk(e, key, chunk_index, chunk & !mask);
+}
+
+/// Returns the index of the chunk holding `index`, along with the mask
+/// selecting its bit within the chunk.
+fn locate(index: u32) -> (u32, u64) {
+    (index / BITS_PER_CHUNK, 1 << (index % BITS_PER_CHUNK))
+}
+
+fn read_chunk(e: &Env, key: &Symbol, chunk_index: u32) -> u64 {
+    e.storage().persistent().get(&BitmapStorageKey::Chunk(key.clone(), chunk_index)).unwrap_or(0)
+
+    // NOTE: We don't extend the TTL here. Contracts usually manage TTL's
+    // themselves, and archived entries need to be restored before being
+    // accessed, so a bit can't be silently reset by an expired chunk.
+}
+
+fn write_chunk(e: &Env, key: &Symbol, chunk_index: u32, chunk: u64) {
+    let storage_key = BitmapStorageKey::Chunk(key.clone(), chunk_index);
+    if chunk == 0 {
+        e.storage().persistent().remove(&storage_key);
+    } else {
+        e.storage().persistent().set(&storage_key, &chunk);
+    }
+}
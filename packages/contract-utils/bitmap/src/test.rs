@@ -0,0 +1,114 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, symbol_short, Env, Symbol};
+
+use crate::storage::{find_last_set, get, set, unset, BitmapStorageKey};
+
+#[contract]
+struct MockContract;
+
+const KEY: Symbol = symbol_short!("CLAIMED");
+
+#[test]
+fn unset_bits_read_as_false() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        assert!(!get(&e, &KEY, 0));
+        assert!(!get(&e, &KEY, u32::MAX));
+    });
+}
+
+#[test]
+fn set_and_get_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set(&e, &KEY, 5);
+
+        assert!(get(&e, &KEY, 5));
+        assert!(!get(&e, &KEY, 4));
+        assert!(!get(&e, &KEY, 6));
+    });
+}
+
+#[test]
+fn set_across_chunk_boundaries_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        for index in [63, 64, 127, 128, u32::MAX] {
+            set(&e, &KEY, index);
+        }
+
+        for index in [63, 64, 127, 128, u32::MAX] {
+            assert!(get(&e, &KEY, index));
+        }
+        for index in [0, 62, 65, 126, 129, u32::MAX - 1] {
+            assert!(!get(&e, &KEY, index));
+        }
+
+        // 63 is in the first chunk, 64 and 127 in the second one
+        let chunk: u64 = e.storage().persistent().get(&BitmapStorageKey::Chunk(KEY, 1)).unwrap();
+        assert_eq!(chunk, 1 | 1 << 63);
+    });
+}
+
+#[test]
+fn unset_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set(&e, &KEY, 1);
+        set(&e, &KEY, 2);
+
+        unset(&e, &KEY, 1);
+        assert!(!get(&e, &KEY, 1));
+        assert!(get(&e, &KEY, 2));
+
+        // the chunk is removed once it is empty
+        unset(&e, &KEY, 2);
+        assert!(!e.storage().persistent().has(&BitmapStorageKey::Chunk(KEY, 0)));
+    });
+}
+
+#[test]
+fn bitmaps_are_independent() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let other = symbol_short!("NONCES");
+
+    e.as_contract(&address, || {
+        set(&e, &KEY, 10);
+
+        assert!(get(&e, &KEY, 10));
+        assert!(!get(&e, &other, 10));
+    });
+}
+
+#[test]
+fn find_last_set_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        assert_eq!(find_last_set(&e, &KEY, 1000), None);
+
+        for index in [0, 63, 64, 200] {
+            set(&e, &KEY, index);
+        }
+
+        assert_eq!(find_last_set(&e, &KEY, 0), Some(0));
+        assert_eq!(find_last_set(&e, &KEY, 62), Some(0));
+        assert_eq!(find_last_set(&e, &KEY, 63), Some(63));
+        assert_eq!(find_last_set(&e, &KEY, 127), Some(64));
+        assert_eq!(find_last_set(&e, &KEY, 199), Some(64));
+        assert_eq!(find_last_set(&e, &KEY, 1000), Some(200));
+    });
+}
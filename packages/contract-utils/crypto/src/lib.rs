@@ -0,0 +1,46 @@
+//! Cryptography Utility Module.
+//!
+//! This utility module provides cryptographic helpers built on top of the
+//! host functions of the Soroban environment.
+//!
+//! [`merkle`] verifies the inclusion of a leaf in a Merkle tree, given the
+//! root of the tree and a proof. It is typically used for allowlists and
+//! airdrops, where only the root is stored on chain, while the full list is
+//! distributed off chain:
+//! - [`merkle::verify_proof()`]
+//! - [`merkle::process_proof()`]
+//! - [`merkle::hash_pair()`]
+//!
+//! ```ignore
+//! const ROOT: Symbol = symbol_short!("ROOT");
+//!
+//! fn claim(e: &Env, account: Address, proof: Vec<BytesN<32>>) {
+//!     let root: BytesN<32> = e.storage().instance().get(&ROOT).unwrap();
+//!     let leaf: BytesN<32> = e.crypto().sha256(&account.clone().to_xdr(e)).into();
+//!     if !stellar_crypto::merkle::verify_proof(e, &root, &leaf, &proof) {
+//!         panic!("not allowlisted");
+//!     }
+//!     /* mark `account` as claimed and mint */
+//! }
+//! ```
+//!
+//! A complete allowlist mint can be found in
+//! `examples/nft-allowlist/src/contract.rs`.
+//!
+//! [`signature`] verifies signatures produced outside of the Stellar network,
+//! e.g. by Ethereum accounts for cross-chain bridges:
+//! - [`signature::recover()`]
+//! - [`signature::verify_ed25519()`]
+//! - [`signature::account_public_key()`]
+//!
+//! Inputs rejected before reaching the host functions revert with a
+//! [`CryptoError`].
+
+#![no_std]
+
+pub mod merkle;
+pub mod signature;
+
+pub use signature::CryptoError;
+
+mod test;
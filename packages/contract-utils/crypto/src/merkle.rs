@@ -0,0 +1,56 @@
+use soroban_sdk::{Bytes, BytesN, Env, Vec};
+
+/// Returns `true` if `leaf` belongs to the Merkle tree whose root is `root`,
+/// according to `proof`, and `false` otherwise.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `root` - The root of the Merkle tree.
+/// * `leaf` - The hash of the element whose inclusion is verified.
+/// * `proof` - The sibling hashes from `leaf` up to `root`.
+///
+/// # Notes
+///
+/// * The tree must be built with [`hash_pair`], i.e. with SHA-256 and the pairs
+///   of nodes sorted before being hashed, so that the proof doesn't need to
+///   carry the position of the nodes.
+/// * The leaves should be hashes of the elements rather than the elements
+///   themselves, and should not be 64 bytes long before hashing, otherwise an
+///   inner node could be presented as a leaf.
+pub fn verify_proof(
+    e: &Env,
+    root: &BytesN<32>,
+    leaf: &BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+) -> bool {
+    process_proof(e, leaf, proof) == *root
+}
+
+/// Returns the root of the Merkle tree obtained by hashing `leaf` with each
+/// node of `proof` in turn.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `leaf` - The hash of the element whose inclusion is verified.
+/// * `proof` - The sibling hashes from `leaf` up to the root.
+pub fn process_proof(e: &Env, leaf: &BytesN<32>, proof: &Vec<BytesN<32>>) -> BytesN<32> {
+    proof.iter().fold(leaf.clone(), |node, sibling| hash_pair(e, &node, &sibling))
+}
+
+/// Returns the SHA-256 hash of the concatenation of `a` and `b`, sorted in
+/// ascending order. Sorting makes the hash independent of the order of the
+/// nodes, which is how the inner nodes of the tree must be computed.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `a` - The first node.
+/// * `b` - The second node.
+pub fn hash_pair(e: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut payload = Bytes::from(first);
+    payload.append(&Bytes::from(second));
+    e.crypto().sha256(&payload).into()
+}
@@ -0,0 +1,128 @@
+use soroban_sdk::{contracterror, panic_with_error, xdr::ToXdr, Address, Bytes, BytesN, Env};
+
+/// The order `n` of the secp256k1 curve, big-endian.
+const SECP256K1_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// Half the order of the secp256k1 curve (`n / 2`), big-endian. The `s` part
+/// of a signature is expected not to exceed it.
+const SECP256K1_HALF_ORDER: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
+/// The XDR prefix of an `ScVal::Address` holding an ed25519 account, i.e. the
+/// `ScVal`, `ScAddress` and `PublicKey` discriminants.
+const ACCOUNT_XDR_PREFIX: [u8; 12] = [0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0];
+
+/// The largest valid secp256k1 recovery identifier.
+const MAX_RECOVERY_ID: u32 = 3;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CryptoError {
+    /// Indicates a secp256k1 recovery identifier greater than `3`.
+    InvalidRecoveryId = 130,
+    /// Indicates a secp256k1 signature whose `r` or `s` part is out of range,
+    /// including a non-normalized (high) `s`.
+    MalformedSignature = 131,
+}
+
+/// Recovers the SEC-1 encoded (uncompressed) secp256k1 public key which
+/// produced `signature` over `message_hash`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `message_hash` - The 32-byte digest of the signed message.
+/// * `signature` - The signature, as the concatenation of `r` and `s`.
+/// * `recovery_id` - The recovery identifier, in `0..=3`. Ethereum signatures
+///   carry it as `v`, with `v - 27` being the recovery identifier.
+///
+/// # Errors
+///
+/// * [`CryptoError::InvalidRecoveryId`] - When `recovery_id > 3`.
+/// * [`CryptoError::MalformedSignature`] - When `r` or `s` is `0` or not lower
+///   than the order of the curve, or when `s` is not normalized to its low
+///   form, as required by the host.
+///
+/// # Security Warning
+///
+/// `message_hash` must be computed by the contract with a secure hash
+/// function, e.g. `e.crypto().keccak256()`, over the message. Recovering a
+/// key from a digest supplied by the caller allows forging signatures.
+///
+/// A signature with a wrong recovery identifier may still recover a valid,
+/// but different, public key. The recovered key must always be compared
+/// against the expected signer.
+pub fn recover(
+    e: &Env,
+    message_hash: &BytesN<32>,
+    signature: &BytesN<64>,
+    recovery_id: u32,
+) -> BytesN<65> {
+    if recovery_id > MAX_RECOVERY_ID {
+        panic_with_error!(e, CryptoError::InvalidRecoveryId);
+    }
+
+    let bytes = signature.to_array();
+    let (r, s) = bytes.split_at(32);
+    let zero = [0u8; 32];
+    if r == zero
+        || s == zero
+        || r >= SECP256K1_ORDER.as_slice()
+        || s > SECP256K1_HALF_ORDER.as_slice()
+    {
+        panic_with_error!(e, CryptoError::MalformedSignature);
+    }
+
+    e.crypto_hazmat().secp256k1_recover(message_hash, signature, recovery_id)
+}
+
+/// Verifies that `signature` is a valid ed25519 signature of `message` by
+/// `public_key`, e.g. for signatures produced by Stellar accounts (see
+/// [`account_public_key`]) or by the accounts of other networks. It is used by
+/// the `permit` and `meta_transfer` extensions of the token crates.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `public_key` - The ed25519 public key of the signer.
+/// * `message` - The signed message.
+/// * `signature` - The signature to verify.
+///
+/// # Errors
+///
+/// * The invocation traps if the signature is invalid.
+pub fn verify_ed25519(e: &Env, public_key: &BytesN<32>, message: &Bytes, signature: &BytesN<64>) {
+    e.crypto().ed25519_verify(public_key, message, signature);
+}
+
+/// Returns the ed25519 public key of the Stellar account `account`, or `None`
+/// if `account` is not a Stellar account (`G...` address), e.g. a contract.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address whose public key is returned.
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: The returned key is the master key of the account. The
+/// signers and thresholds configured on the account are not visible to
+/// contracts, so a signature of the master key alone doesn't mean the account
+/// authorized anything: the master key may have a weight of `0`, or the account
+/// may require several signers. Only `require_auth` enforces the signer
+/// configuration of an account.
+pub fn account_public_key(e: &Env, account: &Address) -> Option<BytesN<32>> {
+    let xdr = account.clone().to_xdr(e);
+    if xdr.len() != 44 || xdr.slice(0..12) != Bytes::from_array(e, &ACCOUNT_XDR_PREFIX) {
+        return None;
+    }
+    let mut public_key = [0u8; 32];
+    xdr.slice(12..44).copy_into_slice(&mut public_key);
+    Some(BytesN::from_array(e, &public_key))
+}
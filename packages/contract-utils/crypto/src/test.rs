@@ -0,0 +1,197 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, BytesN, Env, Vec};
+
+use crate::{
+    merkle::{hash_pair, process_proof, verify_proof},
+    signature::{account_public_key, recover, verify_ed25519},
+};
+
+fn leaf(e: &Env, value: u8) -> BytesN<32> {
+    e.crypto().sha256(&BytesN::from_array(e, &[value; 32]).into()).into()
+}
+
+#[test]
+fn hash_pair_is_commutative() {
+    let e = Env::default();
+    let a = leaf(&e, 1);
+    let b = leaf(&e, 2);
+
+    assert_eq!(hash_pair(&e, &a, &b), hash_pair(&e, &b, &a));
+    assert_ne!(hash_pair(&e, &a, &b), hash_pair(&e, &a, &a));
+}
+
+#[test]
+fn verify_proof_works() {
+    let e = Env::default();
+    let leaves: std::vec::Vec<BytesN<32>> = (0..4).map(|i| leaf(&e, i)).collect();
+    let left = hash_pair(&e, &leaves[0], &leaves[1]);
+    let right = hash_pair(&e, &leaves[2], &leaves[3]);
+    let root = hash_pair(&e, &left, &right);
+
+    let proof = vec![&e, leaves[3].clone(), left.clone()];
+    assert!(verify_proof(&e, &root, &leaves[2], &proof));
+    assert_eq!(process_proof(&e, &leaves[2], &proof), root);
+
+    let proof = vec![&e, leaves[0].clone(), right.clone()];
+    assert!(verify_proof(&e, &root, &leaves[1], &proof));
+}
+
+#[test]
+fn verify_invalid_proof_fails() {
+    let e = Env::default();
+    let leaves: std::vec::Vec<BytesN<32>> = (0..4).map(|i| leaf(&e, i)).collect();
+    let left = hash_pair(&e, &leaves[0], &leaves[1]);
+    let right = hash_pair(&e, &leaves[2], &leaves[3]);
+    let root = hash_pair(&e, &left, &right);
+
+    // wrong sibling
+    let proof = vec![&e, leaves[2].clone(), left.clone()];
+    assert!(!verify_proof(&e, &root, &leaves[2], &proof));
+
+    // leaf not in the tree
+    let proof = vec![&e, leaves[3].clone(), left];
+    assert!(!verify_proof(&e, &root, &leaf(&e, 9), &proof));
+}
+
+#[test]
+fn single_leaf_tree_has_empty_proof() {
+    let e = Env::default();
+    let root = leaf(&e, 1);
+
+    assert!(verify_proof(&e, &root, &root, &Vec::new(&e)));
+}
+
+// Known-answer vectors: the well-known Ethereum test key
+// `0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318`,
+// controlling `0x2c7536E3605D9C16a7a3D7b1898e529396a65c23`, signing the
+// keccak256 digest of "hello".
+const MESSAGE: &[u8] = b"hello";
+const SECP256K1_SIGNATURE: &str = "bc81bfc04b658485b9820cf4fac760c84f0fc628da21c9e64ed8aaede215e633\
+                                   76cc1bfb23f9869c5b71a4069b48fce3ab0caaebc6bfb7a81cb12ac3b9b2f7b1";
+const SECP256K1_RECOVERY_ID: u32 = 1;
+const SECP256K1_PUBLIC_KEY: &str = "04\
+                                    4e3b81af9c2234cad09d679ce6035ed1392347ce64ce405f5dcd36228a25de6e\
+                                    47fd35c4215d1edf53e6f83de344615ce719bdb0fd878f6ed76f06dd277956de";
+const ETH_ADDRESS: &str = "2c7536e3605d9c16a7a3d7b1898e529396a65c23";
+
+// ed25519 key derived from the secret key `[7; 32]`, signing "hello".
+const ED25519_PUBLIC_KEY: &str = "ea4a6c63e29c520abef5507b132ec5f9954776aebebe7b92421eea691446d22c";
+const ED25519_SIGNATURE: &str = "359a315920d9541c3cc2a1dd1839f3e40bf23358a1d93a6ebd8303c0310ceb50\
+                                 25e679222ab016b4d822c5001e787e00c0ceaa6ac3c6e80248a944bd47104f0c";
+
+fn from_hex<const N: usize>(e: &Env, hex: &str) -> BytesN<N> {
+    let bytes: std::vec::Vec<u8> = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect();
+    BytesN::from_array(e, &bytes.try_into().unwrap())
+}
+
+fn message_hash(e: &Env) -> BytesN<32> {
+    e.crypto().keccak256(&Bytes::from_slice(e, MESSAGE)).into()
+}
+
+#[test]
+fn recover_known_answer_works() {
+    let e = Env::default();
+    let signature = from_hex::<64>(&e, SECP256K1_SIGNATURE);
+
+    let public_key = recover(&e, &message_hash(&e), &signature, SECP256K1_RECOVERY_ID);
+    assert_eq!(public_key, from_hex::<65>(&e, SECP256K1_PUBLIC_KEY));
+
+    // the Ethereum address is the last 20 bytes of the keccak256 digest of the
+    // key, without its SEC-1 prefix
+    let digest = e.crypto().keccak256(&Bytes::from(public_key).slice(1..));
+    let address = Bytes::from(digest.to_bytes()).slice(12..);
+    assert_eq!(address, Bytes::from(from_hex::<20>(&e, ETH_ADDRESS)));
+}
+
+#[test]
+fn recover_with_wrong_recovery_id_returns_other_key() {
+    let e = Env::default();
+    let signature = from_hex::<64>(&e, SECP256K1_SIGNATURE);
+
+    let public_key = recover(&e, &message_hash(&e), &signature, 0);
+    assert_ne!(public_key, from_hex::<65>(&e, SECP256K1_PUBLIC_KEY));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #130)")]
+fn recover_with_invalid_recovery_id_fails() {
+    let e = Env::default();
+    let signature = from_hex::<64>(&e, SECP256K1_SIGNATURE);
+
+    recover(&e, &message_hash(&e), &signature, 4);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #131)")]
+fn recover_with_zero_r_fails() {
+    let e = Env::default();
+    let mut signature = from_hex::<64>(&e, SECP256K1_SIGNATURE).to_array();
+    signature[..32].fill(0);
+
+    recover(&e, &message_hash(&e), &BytesN::from_array(&e, &signature), SECP256K1_RECOVERY_ID);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #131)")]
+fn recover_with_high_s_fails() {
+    let e = Env::default();
+    let mut signature = from_hex::<64>(&e, SECP256K1_SIGNATURE).to_array();
+    // `n - s`, the malleable counterpart of the signature
+    let order =
+        from_hex::<32>(&e, "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141")
+            .to_array();
+    let mut borrow = 0i16;
+    for i in (0..32).rev() {
+        let diff = order[i] as i16 - signature[32 + i] as i16 - borrow;
+        borrow = (diff < 0) as i16;
+        signature[32 + i] = diff.rem_euclid(256) as u8;
+    }
+
+    recover(&e, &message_hash(&e), &BytesN::from_array(&e, &signature), SECP256K1_RECOVERY_ID);
+}
+
+#[test]
+fn verify_ed25519_known_answer_works() {
+    let e = Env::default();
+
+    verify_ed25519(
+        &e,
+        &from_hex::<32>(&e, ED25519_PUBLIC_KEY),
+        &Bytes::from_slice(&e, MESSAGE),
+        &from_hex::<64>(&e, ED25519_SIGNATURE),
+    );
+}
+
+#[test]
+#[should_panic]
+fn verify_ed25519_with_tampered_message_fails() {
+    let e = Env::default();
+
+    verify_ed25519(
+        &e,
+        &from_hex::<32>(&e, ED25519_PUBLIC_KEY),
+        &Bytes::from_slice(&e, b"hellO"),
+        &from_hex::<64>(&e, ED25519_SIGNATURE),
+    );
+}
+
+#[test]
+fn account_public_key_works() {
+    let e = Env::default();
+    let account = Address::from_str(&e, "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF");
+
+    assert_eq!(account_public_key(&e, &account), Some(BytesN::from_array(&e, &[0; 32])));
+}
+
+#[test]
+fn account_public_key_of_contract_is_none() {
+    let e = Env::default();
+
+    assert_eq!(account_public_key(&e, &Address::generate(&e)), None);
+}
@@ -33,14 +33,24 @@ fn get_default_methods(trait_name: &str) -> Vec<syn::ImplItem> {
             syn::parse_quote! { fn symbol(e: &Env) -> String { Self::ContractType::symbol(e) } },
         ],
         "NonFungibleBurnable" => vec![
-            syn::parse_quote! { fn burn(e: &Env, from: Address, token_id: TokenId) { Base::burn(e, &from, token_id); } },
-            syn::parse_quote! { fn burn_from(e: &Env, spender: Address, from: Address, token_id: TokenId) { Base::burn_from(e, &spender, &from, token_id); } },
+            syn::parse_quote! { fn burn(e: &Env, from: Address, token_id: TokenId) { Self::ContractType::burn(e, &from, token_id); } },
+            syn::parse_quote! { fn burn_from(e: &Env, spender: Address, from: Address, token_id: TokenId) { Self::ContractType::burn_from(e, &spender, &from, token_id); } },
         ],
         "NonFungibleEnumerable" => vec![
             syn::parse_quote! { fn total_supply(e: &Env) -> Balance { Enumerable::total_supply(e) } },
             syn::parse_quote! { fn get_owner_token_id(e: &Env, owner: Address, index: TokenId) -> TokenId { Enumerable::get_owner_token_id(e, &owner, index) } },
             syn::parse_quote! { fn get_token_id(e: &Env, index: TokenId) -> TokenId { Enumerable::get_token_id(e, index) } },
         ],
+        "NonFungibleLockable" => vec![
+            syn::parse_quote! { fn is_locked(e: &Env, token_id: TokenId) -> bool { Lockable::is_locked(e, token_id) } },
+        ],
+        "AccessControl" => vec![
+            syn::parse_quote! { fn has_role(e: &Env, account: Address, role: Symbol) -> bool { stellar_access_control::has_role(e, &account, &role) } },
+            syn::parse_quote! { fn get_role_admin(e: &Env, role: Symbol) -> Symbol { stellar_access_control::get_role_admin(e, &role) } },
+            syn::parse_quote! { fn grant_role(e: &Env, admin: Address, account: Address, role: Symbol) { stellar_access_control::grant_role(e, &admin, &account, &role); } },
+            syn::parse_quote! { fn revoke_role(e: &Env, admin: Address, account: Address, role: Symbol) { stellar_access_control::revoke_role(e, &admin, &account, &role); } },
+            syn::parse_quote! { fn renounce_role(e: &Env, account: Address, role: Symbol) { stellar_access_control::renounce_role(e, &account, &role); } },
+        ],
         not_supported => {
             panic!("Trait {} is not supported by #[default_impl]", not_supported)
         }
@@ -82,12 +92,18 @@ pub fn generate_default_impl(item: TokenStream) -> TokenStream {
     // `existing_items` now contains the merged items
     let new_impl = ItemImpl { items: existing_items, ..input };
 
-    // Import the necessary trait if the trait is `NonFungibleToken`
+    // Import the necessary trait if the trait is `NonFungibleToken` or
+    // `NonFungibleBurnable`
     let expanded = if trait_name == "NonFungibleToken" {
         quote! {
             use stellar_non_fungible::ContractOverrides;
             #new_impl
         }
+    } else if trait_name == "NonFungibleBurnable" {
+        quote! {
+            use stellar_non_fungible::burnable::BurnableContractType;
+            #new_impl
+        }
     } else {
         quote! { #new_impl }
     };
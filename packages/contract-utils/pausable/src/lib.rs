@@ -30,6 +30,19 @@
 //! required for the Smart Contracts `e: &Env`, and take advantage of the
 //! storage by storing a flag for the pause mechanism.
 //!
+//! Maintenance can also be planned in advance: [`schedule_pause()`] makes the
+//! contract paused from a future ledger onwards, until the schedule is
+//! cancelled with [`cancel_scheduled_pause()`] or the contract is unpaused.
+//!
+//! Recurring maintenance is supported as well: [`set_maintenance_window()`]
+//! makes the contract paused during a window of ledgers that repeats every
+//! `period` ledgers, until it is removed with [`remove_maintenance_window()`].
+//!
+//! Pausing can also be granular: [`pause_feature()`] pauses a single feature,
+//! identified by a `Symbol`, and [`when_feature_not_paused()`] guards the
+//! functions belonging to it. [`paused_features()`] lists the features that
+//! are currently paused, e.g. for display purposes.
+//!
 //! We expect you to utilize these functions (`storage::*`) for implementing the
 //! methods of the `Pausable` trait, along with your custom business logic
 //! (authentication, etc.)
@@ -53,8 +66,17 @@ mod pausable;
 mod storage;
 
 pub use crate::{
-    pausable::{emit_paused, emit_unpaused, Pausable, PausableError},
-    storage::{pause, paused, unpause, when_not_paused, when_paused},
+    pausable::{
+        emit_feature_paused, emit_feature_unpaused, emit_maintenance_window_removed,
+        emit_maintenance_window_set, emit_pause_cancelled, emit_pause_scheduled, emit_paused,
+        emit_unpaused, Pausable, PausableError,
+    },
+    storage::{
+        cancel_scheduled_pause, feature_paused, maintenance_window, pause, pause_feature, paused,
+        paused_features, remove_maintenance_window, schedule_pause, scheduled_pause,
+        set_maintenance_window, unpause, unpause_feature, when_feature_not_paused, when_not_paused,
+        when_paused, MaintenanceWindow,
+    },
 };
 
 mod test;
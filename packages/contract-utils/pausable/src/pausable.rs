@@ -1,4 +1,6 @@
-use soroban_sdk::{contracterror, symbol_short, Address, Env};
+use soroban_sdk::{contracterror, symbol_short, Address, Env, Symbol};
+
+use crate::MaintenanceWindow;
 
 pub trait Pausable {
     /// Returns true if the contract is paused, and false otherwise.
@@ -79,6 +81,14 @@ pub enum PausableError {
     EnforcedPause = 100,
     /// The operation failed because the contract is not paused.
     ExpectedPause = 101,
+    /// The scheduled pause ledger is not in the future.
+    InvalidPauseSchedule = 102,
+    /// The operation failed because no pause is scheduled.
+    NoScheduledPause = 103,
+    /// The maintenance window is empty or doesn't fit within its period.
+    InvalidMaintenanceWindow = 104,
+    /// The operation failed because no maintenance window is set.
+    NoMaintenanceWindow = 105,
 }
 
 // ################## EVENTS ##################
@@ -114,3 +124,103 @@ pub fn emit_unpaused(e: &Env, caller: &Address) {
     let topics = (symbol_short!("unpaused"),);
     e.events().publish(topics, caller)
 }
+
+/// Emits an event when a pause is scheduled.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The address of the caller.
+/// * `at_ledger` - The ledger number from which the contract is paused.
+///
+/// # Events
+///
+/// * topics - `["pause_scheduled"]`
+/// * data - `[caller: Address, at_ledger: u32]`
+pub fn emit_pause_scheduled(e: &Env, caller: &Address, at_ledger: u32) {
+    let topics = (Symbol::new(e, "pause_scheduled"),);
+    e.events().publish(topics, (caller, at_ledger))
+}
+
+/// Emits an event when a scheduled pause is cancelled.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The address of the caller.
+///
+/// # Events
+///
+/// * topics - `["pause_cancelled"]`
+/// * data - `[caller: Address]`
+pub fn emit_pause_cancelled(e: &Env, caller: &Address) {
+    let topics = (Symbol::new(e, "pause_cancelled"),);
+    e.events().publish(topics, caller)
+}
+
+/// Emits an event when a single feature is paused.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The address of the caller.
+/// * `feature` - The paused feature.
+///
+/// # Events
+///
+/// * topics - `["feature_paused", feature: Symbol]`
+/// * data - `[caller: Address]`
+pub fn emit_feature_paused(e: &Env, caller: &Address, feature: &Symbol) {
+    let topics = (Symbol::new(e, "feature_paused"), feature.clone());
+    e.events().publish(topics, caller)
+}
+
+/// Emits an event when a single feature is unpaused.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The address of the caller.
+/// * `feature` - The unpaused feature.
+///
+/// # Events
+///
+/// * topics - `["feature_unpaused", feature: Symbol]`
+/// * data - `[caller: Address]`
+pub fn emit_feature_unpaused(e: &Env, caller: &Address, feature: &Symbol) {
+    let topics = (Symbol::new(e, "feature_unpaused"), feature.clone());
+    e.events().publish(topics, caller)
+}
+
+/// Emits an event when a recurring maintenance window is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The address of the caller.
+/// * `window` - The maintenance window.
+///
+/// # Events
+///
+/// * topics - `["maintenance_window_set"]`
+/// * data - `[caller: Address, start_offset: u32, duration: u32, period: u32]`
+pub fn emit_maintenance_window_set(e: &Env, caller: &Address, window: &MaintenanceWindow) {
+    let topics = (Symbol::new(e, "maintenance_window_set"),);
+    e.events().publish(topics, (caller, window.start_offset, window.duration, window.period))
+}
+
+/// Emits an event when the recurring maintenance window is removed.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The address of the caller.
+///
+/// # Events
+///
+/// * topics - `["maintenance_window_removed"]`
+/// * data - `[caller: Address]`
+pub fn emit_maintenance_window_removed(e: &Env, caller: &Address) {
+    let topics = (Symbol::new(e, "maintenance_window_removed"),);
+    e.events().publish(topics, caller)
+}
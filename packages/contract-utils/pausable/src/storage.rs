@@ -1,24 +1,108 @@
-use soroban_sdk::{panic_with_error, symbol_short, Address, Env, Symbol};
+use soroban_sdk::{contracttype, panic_with_error, symbol_short, Address, Env, Symbol, Vec};
 
-use crate::{emit_paused, emit_unpaused, pausable::PausableError};
+use crate::{
+    emit_feature_paused, emit_feature_unpaused, emit_maintenance_window_removed,
+    emit_maintenance_window_set, emit_pause_cancelled, emit_pause_scheduled, emit_paused,
+    emit_unpaused, pausable::PausableError,
+};
+
+/// A window of ledgers, recurring every `period` ledgers, during which the
+/// contract is considered paused.
+///
+/// The `n`-th window (starting from `0`) covers the ledgers in
+/// `[start_offset + n * period, start_offset + n * period + duration)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintenanceWindow {
+    /// The ledger number at which the first window starts.
+    pub start_offset: u32,
+    /// The number of ledgers each window lasts.
+    pub duration: u32,
+    /// The number of ledgers between the starts of two consecutive windows.
+    pub period: u32,
+}
 
 /// Indicates whether the contract is in `Paused` state.
 pub const PAUSED: Symbol = symbol_short!("PAUSED");
 
+/// Stores the ledger number from which the contract is considered paused.
+pub const PAUSE_AT: Symbol = symbol_short!("PAUSE_AT");
+
+/// Stores the features that are individually paused.
+pub const PAUSED_FEATURES: Symbol = symbol_short!("PAUSED_FT");
+
+/// Stores the recurring maintenance window, if any.
+pub const MAINTENANCE_WINDOW: Symbol = symbol_short!("MAINT_WIN");
+
 /// Returns true if the contract is paused, and false otherwise.
 ///
 /// # Arguments
 ///
 /// * `e` - Access to Soroban environment.
+///
+/// # Notes
+///
+/// The contract is also considered paused once the ledger number of a
+/// scheduled pause (see [`schedule_pause`]) has been reached, and during each
+/// recurring maintenance window (see [`set_maintenance_window`]).
 pub fn paused(e: &Env) -> bool {
     // if not paused, consider default false (unpaused)
-    e.storage().instance().get(&PAUSED).unwrap_or(false)
+    if explicitly_paused(e) {
+        return true;
+    }
+
+    let sequence = e.ledger().sequence();
+    matches!(maintenance_window(e), Some(window) if in_maintenance_window(&window, sequence))
 
     // NOTE: We don't extend the TTL here. We don’t think utilities should
     // have any opinion on the TTLs, contracts usually manage TTL's themselves.
     // Extending the TTL in the utilities would be redundant in the most cases.
 }
 
+/// Returns the ledger number at which a pause is scheduled, if any.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+pub fn scheduled_pause(e: &Env) -> Option<u32> {
+    e.storage().instance().get(&PAUSE_AT)
+}
+
+/// Returns the recurring maintenance window, if any.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+pub fn maintenance_window(e: &Env) -> Option<MaintenanceWindow> {
+    e.storage().instance().get(&MAINTENANCE_WINDOW)
+}
+
+/// Returns the features that are currently paused individually, in the order
+/// they were paused.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+///
+/// # Notes
+///
+/// The features are not listed when the whole contract is paused, unless they
+/// were paused individually as well.
+pub fn paused_features(e: &Env) -> Vec<Symbol> {
+    e.storage().instance().get(&PAUSED_FEATURES).unwrap_or_else(|| Vec::new(e))
+}
+
+/// Returns true if `feature` is paused, either individually or because the
+/// whole contract is paused, and false otherwise.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `feature` - The feature to check.
+pub fn feature_paused(e: &Env, feature: &Symbol) -> bool {
+    paused(e) || paused_features(e).contains(feature)
+}
+
 /// Triggers `Paused` state.
 ///
 /// # Arguments
@@ -28,7 +112,9 @@ pub fn paused(e: &Env) -> bool {
 ///
 /// # Errors
 ///
-/// * refer to [`when_not_paused`] errors.
+/// * [`PausableError::EnforcedPause`] - Occurs when the contract is already
+///   paused, either with [`pause`] or by a scheduled pause that has been
+///   reached.
 ///
 /// # Events
 ///
@@ -37,11 +123,17 @@ pub fn paused(e: &Env) -> bool {
 ///
 /// # Notes
 ///
-/// Authorization for `caller` is required.
+/// * Authorization for `caller` is required.
+/// * The contract can be paused during a maintenance window, so that it stays
+///   paused once the window ends.
 pub fn pause(e: &Env, caller: &Address) {
     caller.require_auth();
-    when_not_paused(e);
+    if explicitly_paused(e) {
+        panic_with_error!(e, PausableError::EnforcedPause);
+    }
     e.storage().instance().set(&PAUSED, &true);
+    // an immediate pause supersedes any scheduled one
+    e.storage().instance().remove(&PAUSE_AT);
     emit_paused(e, caller);
 }
 
@@ -54,7 +146,8 @@ pub fn pause(e: &Env, caller: &Address) {
 ///
 /// # Errors
 ///
-/// * refer to [`when_paused`] errors.
+/// * [`PausableError::ExpectedPause`] - Occurs when the contract is neither
+///   paused with [`pause`] nor by a scheduled pause that has been reached.
 ///
 /// # Events
 ///
@@ -63,14 +156,233 @@ pub fn pause(e: &Env, caller: &Address) {
 ///
 /// # Notes
 ///
-/// Authorization for `caller` is required.
+/// * Authorization for `caller` is required.
+/// * If the contract is paused because a scheduled pause has been reached, the
+///   schedule is cleared as well.
+/// * A maintenance window doesn't count as a pause to lift: the contract stays
+///   paused until the window ends, or until it is removed with
+///   [`remove_maintenance_window`].
 pub fn unpause(e: &Env, caller: &Address) {
     caller.require_auth();
-    when_paused(e);
+    if !explicitly_paused(e) {
+        panic_with_error!(e, PausableError::ExpectedPause);
+    }
     e.storage().instance().set(&PAUSED, &false);
+    e.storage().instance().remove(&PAUSE_AT);
     emit_unpaused(e, caller);
 }
 
+/// Schedules the `Paused` state to be triggered at `at_ledger`. Overrides any
+/// previously scheduled pause.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The address of the caller.
+/// * `at_ledger` - The ledger number from which the contract is paused.
+///
+/// # Errors
+///
+/// * [`PausableError::InvalidPauseSchedule`] - Occurs when `at_ledger` is not
+///   greater than the current ledger number.
+/// * refer to [`when_not_paused`] errors.
+///
+/// # Events
+///
+/// * topics - `["pause_scheduled"]`
+/// * data - `[caller: Address, at_ledger: u32]`
+///
+/// # Notes
+///
+/// * Authorization for `caller` is required.
+/// * No further action is required for the pause to take effect: [`paused`]
+///   returns `true` once `at_ledger` is reached.
+pub fn schedule_pause(e: &Env, caller: &Address, at_ledger: u32) {
+    caller.require_auth();
+    when_not_paused(e);
+    if at_ledger <= e.ledger().sequence() {
+        panic_with_error!(e, PausableError::InvalidPauseSchedule);
+    }
+    e.storage().instance().set(&PAUSE_AT, &at_ledger);
+    emit_pause_scheduled(e, caller, at_ledger);
+}
+
+/// Cancels a scheduled pause that hasn't taken effect yet.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The address of the caller.
+///
+/// # Errors
+///
+/// * [`PausableError::NoScheduledPause`] - Occurs when no pause is scheduled.
+/// * refer to [`when_not_paused`] errors.
+///
+/// # Events
+///
+/// * topics - `["pause_cancelled"]`
+/// * data - `[caller: Address]`
+///
+/// # Notes
+///
+/// * Authorization for `caller` is required.
+/// * Once the scheduled ledger is reached, the contract is paused and must be
+///   unpaused with [`unpause`] instead.
+pub fn cancel_scheduled_pause(e: &Env, caller: &Address) {
+    caller.require_auth();
+    when_not_paused(e);
+    if scheduled_pause(e).is_none() {
+        panic_with_error!(e, PausableError::NoScheduledPause);
+    }
+    e.storage().instance().remove(&PAUSE_AT);
+    emit_pause_cancelled(e, caller);
+}
+
+/// Sets a maintenance window recurring every `period` ledgers, during which
+/// the contract is considered paused. Overrides any previously set window.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The address of the caller.
+/// * `start_offset` - The ledger number at which the first window starts.
+/// * `duration` - The number of ledgers each window lasts.
+/// * `period` - The number of ledgers between the starts of two consecutive
+///   windows.
+///
+/// # Errors
+///
+/// * [`PausableError::InvalidMaintenanceWindow`] - Occurs when `duration` is
+///   `0` or not less than `period`.
+///
+/// # Events
+///
+/// * topics - `["maintenance_window_set"]`
+/// * data - `[caller: Address, start_offset: u32, duration: u32, period: u32]`
+///
+/// # Notes
+///
+/// * Authorization for `caller` is required.
+/// * No further action is required for the windows to take effect: [`paused`]
+///   returns `true` within each of them.
+/// * [`unpause`] doesn't end an ongoing window, the window must be removed with
+///   [`remove_maintenance_window`] instead. Conversely, [`pause`] can still be
+///   called within a window.
+pub fn set_maintenance_window(
+    e: &Env,
+    caller: &Address,
+    start_offset: u32,
+    duration: u32,
+    period: u32,
+) {
+    caller.require_auth();
+    if duration == 0 || duration >= period {
+        panic_with_error!(e, PausableError::InvalidMaintenanceWindow);
+    }
+    let window = MaintenanceWindow { start_offset, duration, period };
+    e.storage().instance().set(&MAINTENANCE_WINDOW, &window);
+    emit_maintenance_window_set(e, caller, &window);
+}
+
+/// Removes the recurring maintenance window.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The address of the caller.
+///
+/// # Errors
+///
+/// * [`PausableError::NoMaintenanceWindow`] - Occurs when no maintenance window
+///   is set.
+///
+/// # Events
+///
+/// * topics - `["maintenance_window_removed"]`
+/// * data - `[caller: Address]`
+///
+/// # Notes
+///
+/// Authorization for `caller` is required.
+pub fn remove_maintenance_window(e: &Env, caller: &Address) {
+    caller.require_auth();
+    if maintenance_window(e).is_none() {
+        panic_with_error!(e, PausableError::NoMaintenanceWindow);
+    }
+    e.storage().instance().remove(&MAINTENANCE_WINDOW);
+    emit_maintenance_window_removed(e, caller);
+}
+
+/// Pauses `feature` only, leaving the rest of the contract operational.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The address of the caller.
+/// * `feature` - The feature to pause.
+///
+/// # Errors
+///
+/// * [`PausableError::EnforcedPause`] - Occurs when `feature` is already paused
+///   individually.
+///
+/// # Events
+///
+/// * topics - `["feature_paused", feature: Symbol]`
+/// * data - `[caller: Address]`
+///
+/// # Notes
+///
+/// Authorization for `caller` is required.
+pub fn pause_feature(e: &Env, caller: &Address, feature: &Symbol) {
+    caller.require_auth();
+    let mut features = paused_features(e);
+    if features.contains(feature) {
+        panic_with_error!(e, PausableError::EnforcedPause);
+    }
+    features.push_back(feature.clone());
+    e.storage().instance().set(&PAUSED_FEATURES, &features);
+    emit_feature_paused(e, caller, feature);
+}
+
+/// Unpauses `feature` after it was paused with [`pause_feature`].
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The address of the caller.
+/// * `feature` - The feature to unpause.
+///
+/// # Errors
+///
+/// * [`PausableError::ExpectedPause`] - Occurs when `feature` is not paused
+///   individually.
+///
+/// # Events
+///
+/// * topics - `["feature_unpaused", feature: Symbol]`
+/// * data - `[caller: Address]`
+///
+/// # Notes
+///
+/// * Authorization for `caller` is required.
+/// * This doesn't unpause the contract if it is paused as a whole.
+pub fn unpause_feature(e: &Env, caller: &Address, feature: &Symbol) {
+    caller.require_auth();
+    let mut features = paused_features(e);
+    let Some(index) = features.first_index_of(feature) else {
+        panic_with_error!(e, PausableError::ExpectedPause);
+    };
+    features.remove(index);
+    if features.is_empty() {
+        e.storage().instance().remove(&PAUSED_FEATURES);
+    } else {
+        e.storage().instance().set(&PAUSED_FEATURES, &features);
+    }
+    emit_feature_unpaused(e, caller, feature);
+}
+
 /// Helper to make a function callable only when the contract is NOT paused.
 ///
 /// # Arguments
@@ -102,3 +414,32 @@ pub fn when_paused(e: &Env) {
         panic_with_error!(e, PausableError::ExpectedPause);
     }
 }
+
+/// Helper to make a function callable only when `feature` is NOT paused,
+/// neither individually nor as part of the whole contract.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `feature` - The feature the function belongs to.
+///
+/// # Errors
+///
+/// * [`PausableError::EnforcedPause`] - Occurs when `feature` is paused.
+pub fn when_feature_not_paused(e: &Env, feature: &Symbol) {
+    if feature_paused(e, feature) {
+        panic_with_error!(e, PausableError::EnforcedPause);
+    }
+}
+
+/// Returns true if the contract is paused with [`pause`] or by a scheduled
+/// pause that has been reached, ignoring the maintenance window.
+fn explicitly_paused(e: &Env) -> bool {
+    e.storage().instance().get(&PAUSED).unwrap_or(false)
+        || matches!(scheduled_pause(e), Some(at_ledger) if e.ledger().sequence() >= at_ledger)
+}
+
+fn in_maintenance_window(window: &MaintenanceWindow, sequence: u32) -> bool {
+    sequence >= window.start_offset
+        && (sequence - window.start_offset) % window.period < window.duration
+}
@@ -4,11 +4,16 @@ extern crate std;
 
 use soroban_sdk::{
     contract,
-    testutils::{Address as _, Events},
+    testutils::{Address as _, Events, Ledger},
     vec, Address, Env, IntoVal, Symbol,
 };
 
-use crate::storage::{pause, paused, unpause, when_not_paused, when_paused, PAUSED};
+use crate::storage::{
+    cancel_scheduled_pause, feature_paused, maintenance_window, pause, pause_feature, paused,
+    paused_features, remove_maintenance_window, schedule_pause, scheduled_pause,
+    set_maintenance_window, unpause, unpause_feature, when_feature_not_paused, when_not_paused,
+    when_paused, MaintenanceWindow, PAUSED,
+};
 
 #[contract]
 struct MockContract;
@@ -135,3 +140,434 @@ fn when_paused_works() {
         when_paused(&e);
     });
 }
+
+#[test]
+fn scheduled_pause_takes_effect_at_ledger() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(100);
+    let address = e.register(MockContract, ());
+    let caller = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        schedule_pause(&e, &caller, 110);
+        assert_eq!(scheduled_pause(&e), Some(110));
+
+        let events = e.events().all();
+        assert_eq!(
+            events,
+            vec![
+                &e,
+                (
+                    address.clone(),
+                    vec![&e, Symbol::new(&e, "pause_scheduled").into_val(&e)],
+                    (caller.clone(), 110u32).into_val(&e)
+                )
+            ]
+        );
+
+        e.ledger().set_sequence_number(109);
+        assert!(!paused(&e));
+
+        e.ledger().set_sequence_number(110);
+        assert!(paused(&e));
+        when_paused(&e);
+    });
+}
+
+#[test]
+fn cancel_scheduled_pause_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(100);
+    let address = e.register(MockContract, ());
+    let caller = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        schedule_pause(&e, &caller, 110);
+    });
+
+    e.ledger().set_sequence_number(109);
+    e.as_contract(&address, || {
+        cancel_scheduled_pause(&e, &caller);
+        assert_eq!(scheduled_pause(&e), None);
+
+        e.ledger().set_sequence_number(110);
+        assert!(!paused(&e));
+    });
+}
+
+#[test]
+fn unpause_clears_reached_schedule() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(100);
+    let address = e.register(MockContract, ());
+    let caller = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        schedule_pause(&e, &caller, 110);
+    });
+
+    e.ledger().set_sequence_number(110);
+    e.as_contract(&address, || {
+        unpause(&e, &caller);
+        assert!(!paused(&e));
+        assert_eq!(scheduled_pause(&e), None);
+    });
+}
+
+#[test]
+fn pause_supersedes_schedule() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(100);
+    let address = e.register(MockContract, ());
+    let caller = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        schedule_pause(&e, &caller, 110);
+    });
+
+    e.as_contract(&address, || {
+        pause(&e, &caller);
+        assert!(paused(&e));
+        assert_eq!(scheduled_pause(&e), None);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn errors_schedule_pause_in_the_past() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(100);
+    let address = e.register(MockContract, ());
+    let caller = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        schedule_pause(&e, &caller, 100);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")]
+fn errors_cancel_without_schedule() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let caller = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        cancel_scheduled_pause(&e, &caller);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #100)")]
+fn errors_cancel_after_schedule_reached() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(100);
+    let address = e.register(MockContract, ());
+    let caller = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        schedule_pause(&e, &caller, 110);
+    });
+
+    e.ledger().set_sequence_number(110);
+    e.as_contract(&address, || {
+        cancel_scheduled_pause(&e, &caller);
+    });
+}
+
+#[test]
+fn paused_features_lists_paused_features() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let caller = Address::generate(&e);
+    let mint = Symbol::new(&e, "mint");
+    let burn = Symbol::new(&e, "burn");
+
+    e.as_contract(&address, || {
+        assert_eq!(paused_features(&e), vec![&e]);
+        pause_feature(&e, &caller, &mint);
+    });
+
+    e.as_contract(&address, || {
+        pause_feature(&e, &caller, &burn);
+        assert_eq!(paused_features(&e), vec![&e, mint.clone(), burn.clone()]);
+        assert!(feature_paused(&e, &mint));
+        assert!(feature_paused(&e, &burn));
+        assert!(!paused(&e));
+    });
+
+    e.as_contract(&address, || {
+        unpause_feature(&e, &caller, &mint);
+        assert_eq!(paused_features(&e), vec![&e, burn.clone()]);
+        assert!(!feature_paused(&e, &mint));
+
+        let events = e.events().all();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events,
+            vec![
+                &e,
+                (
+                    address.clone(),
+                    vec![&e, Symbol::new(&e, "feature_unpaused").into_val(&e), mint.into_val(&e)],
+                    caller.into_val(&e)
+                )
+            ]
+        );
+    });
+}
+
+#[test]
+fn feature_paused_when_contract_paused() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let caller = Address::generate(&e);
+    let mint = Symbol::new(&e, "mint");
+
+    e.as_contract(&address, || {
+        pause(&e, &caller);
+        assert!(feature_paused(&e, &mint));
+        assert_eq!(paused_features(&e), vec![&e]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #100)")]
+fn when_feature_not_paused_panics_when_feature_paused() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let caller = Address::generate(&e);
+    let mint = Symbol::new(&e, "mint");
+
+    e.as_contract(&address, || {
+        pause_feature(&e, &caller, &mint);
+        when_feature_not_paused(&e, &Symbol::new(&e, "burn"));
+        when_feature_not_paused(&e, &mint);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #101)")]
+fn unpause_feature_fails_when_not_paused() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let caller = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        unpause_feature(&e, &caller, &Symbol::new(&e, "mint"));
+    });
+}
+
+#[test]
+fn maintenance_window_pauses_within_window() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(100);
+    let address = e.register(MockContract, ());
+    let caller = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_maintenance_window(&e, &caller, 110, 5, 50);
+        assert_eq!(
+            maintenance_window(&e),
+            Some(MaintenanceWindow { start_offset: 110, duration: 5, period: 50 })
+        );
+
+        let events = e.events().all();
+        assert_eq!(
+            events,
+            vec![
+                &e,
+                (
+                    address.clone(),
+                    vec![&e, Symbol::new(&e, "maintenance_window_set").into_val(&e)],
+                    (caller.clone(), 110u32, 5u32, 50u32).into_val(&e)
+                )
+            ]
+        );
+
+        // before the first window
+        assert!(!paused(&e));
+        e.ledger().set_sequence_number(109);
+        assert!(!paused(&e));
+
+        // within the first window
+        e.ledger().set_sequence_number(110);
+        assert!(paused(&e));
+        e.ledger().set_sequence_number(114);
+        assert!(paused(&e));
+        when_paused(&e);
+
+        // after the first window
+        e.ledger().set_sequence_number(115);
+        assert!(!paused(&e));
+        when_not_paused(&e);
+    });
+}
+
+#[test]
+fn maintenance_window_recurs_across_periods() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let caller = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_maintenance_window(&e, &caller, 10, 3, 20);
+
+        for period in [0u32, 1, 7] {
+            let start = 10 + period * 20;
+            e.ledger().set_sequence_number(start - 1);
+            assert!(!paused(&e));
+            e.ledger().set_sequence_number(start);
+            assert!(paused(&e));
+            e.ledger().set_sequence_number(start + 2);
+            assert!(paused(&e));
+            e.ledger().set_sequence_number(start + 3);
+            assert!(!paused(&e));
+        }
+    });
+}
+
+#[test]
+fn remove_maintenance_window_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(10);
+    let address = e.register(MockContract, ());
+    let caller = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_maintenance_window(&e, &caller, 10, 3, 20);
+    });
+
+    e.as_contract(&address, || {
+        assert!(paused(&e));
+        remove_maintenance_window(&e, &caller);
+        assert_eq!(maintenance_window(&e), None);
+        assert!(!paused(&e));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #104)")]
+fn errors_maintenance_window_longer_than_period() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let caller = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_maintenance_window(&e, &caller, 10, 20, 20);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #104)")]
+fn errors_empty_maintenance_window() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let caller = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_maintenance_window(&e, &caller, 10, 0, 20);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #105)")]
+fn errors_remove_maintenance_window_without_window() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let caller = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        remove_maintenance_window(&e, &caller);
+    });
+}
+
+#[test]
+fn pause_within_maintenance_window_outlasts_window() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(10);
+    let address = e.register(MockContract, ());
+    let caller = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_maintenance_window(&e, &caller, 10, 3, 20);
+    });
+
+    e.as_contract(&address, || {
+        assert!(paused(&e));
+        pause(&e, &caller);
+        assert!(e.storage().instance().get::<_, bool>(&PAUSED).unwrap());
+    });
+
+    // the window is over, but the contract stays paused
+    e.ledger().set_sequence_number(13);
+    e.as_contract(&address, || {
+        assert!(paused(&e));
+        unpause(&e, &caller);
+        assert!(!paused(&e));
+    });
+}
+
+#[test]
+fn unpause_within_maintenance_window_keeps_window() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(10);
+    let address = e.register(MockContract, ());
+    let caller = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_maintenance_window(&e, &caller, 10, 3, 20);
+    });
+
+    e.as_contract(&address, || {
+        pause(&e, &caller);
+    });
+
+    // unpausing lifts the explicit pause, but doesn't end the ongoing window
+    e.as_contract(&address, || {
+        unpause(&e, &caller);
+        assert!(paused(&e));
+    });
+
+    e.ledger().set_sequence_number(13);
+    e.as_contract(&address, || {
+        assert!(!paused(&e));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #101)")]
+fn errors_unpause_within_maintenance_window_without_pause() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(10);
+    let address = e.register(MockContract, ());
+    let caller = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_maintenance_window(&e, &caller, 10, 3, 20);
+    });
+
+    e.as_contract(&address, || {
+        unpause(&e, &caller);
+    });
+}
@@ -12,9 +12,16 @@ use syn::DeriveInput;
 ///
 /// - Sets the current crate version (`CARGO_PKG_VERSION`) as `"binver"`
 ///   metadata using `contractmeta!`.
-/// - Implements the `upgrade` function with access control (`_upgrade_auth`).
+/// - Implements the `upgrade` function with access control (`_upgrade_auth`),
+///   executing only an upgrade scheduled with `schedule_upgrade` whose `eta` is
+///   reached.
+/// - Implements the `schedule_upgrade`, `cancel_scheduled_upgrade` (both with
+///   access control) and `pending_upgrade` functions.
 /// - Throws a compile-time error if `UpgradeableInternal` is not implemented.
 ///
+/// Contracts derived with earlier versions could be upgraded without
+/// scheduling; see the `stellar_upgradeable` crate docs for the migration path.
+///
 /// # Example
 /// ```ignore,rust
 /// #[derive(Upgradeable)]
@@ -35,10 +42,27 @@ pub fn derive_upgradeable(input: &DeriveInput) -> TokenStream {
             fn upgrade(e: &soroban_sdk::Env, new_wasm_hash: soroban_sdk::BytesN<32>, operator: soroban_sdk::Address) {
                 Self::_upgrade_auth(e, &operator);
 
+                stellar_upgradeable::consume_scheduled_upgrade(e, &new_wasm_hash);
                 stellar_upgradeable::start_migration(e);
 
                 e.deployer().update_current_contract_wasm(new_wasm_hash);
             }
+
+            fn schedule_upgrade(e: &soroban_sdk::Env, new_wasm_hash: soroban_sdk::BytesN<32>, operator: soroban_sdk::Address, eta: u32) {
+                Self::_upgrade_auth(e, &operator);
+
+                stellar_upgradeable::schedule_upgrade(e, &new_wasm_hash, eta);
+            }
+
+            fn cancel_scheduled_upgrade(e: &soroban_sdk::Env, operator: soroban_sdk::Address) {
+                Self::_upgrade_auth(e, &operator);
+
+                stellar_upgradeable::cancel_scheduled_upgrade(e);
+            }
+
+            fn pending_upgrade(e: &soroban_sdk::Env) -> Option<(soroban_sdk::BytesN<32>, u32)> {
+                stellar_upgradeable::pending_upgrade(e)
+            }
         }
     }
 }
@@ -57,8 +81,9 @@ pub fn derive_upgradeable(input: &DeriveInput) -> TokenStream {
 ///
 /// # Behavior
 ///
-/// - Implements the `migrate` and `rollback` functions for the `Migratable`
-///   trait.
+/// - Implements the `migrate`, `validate_migration` and `rollback` functions
+///   for the `Migratable` trait. `validate_migration` returns `true` only if a
+///   migration is currently allowed and `_validate_migration` accepts the data.
 /// - Throws a compile-time error if `MigratableInternal` is not implemented.
 ///
 /// # Example
@@ -86,6 +111,10 @@ pub fn derive_migratable(input: &DeriveInput) -> proc_macro2::TokenStream {
                 stellar_upgradeable::complete_migration(e);
             }
 
+            fn validate_migration(e: &soroban_sdk::Env, migration_data: MigrationData) -> bool {
+                stellar_upgradeable::can_migrate(e) && Self::_validate_migration(e, &migration_data)
+            }
+
             fn rollback(e: &soroban_sdk::Env, rollback_data: RollbackData) {
                 stellar_upgradeable::ensure_can_rollback(e);
 
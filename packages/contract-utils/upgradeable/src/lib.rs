@@ -8,6 +8,34 @@
 //! only be invoked after an upgrade or rollback is only allowed after a
 //! migration), ensuring safe and predictable transitions.
 //!
+//! Upgrades are timelocked: an upgrade must first be scheduled with
+//! `schedule_upgrade`, which records the new WASM hash and the earliest ledger
+//! (`eta`) at which `upgrade` can execute it. The delay gives the users a
+//! window to exit before the upgrade, during which it can still be cancelled
+//! with `cancel_scheduled_upgrade`. It can't be shorter than a minimum delay,
+//! one day by default, configurable with `set_min_upgrade_delay` to any
+//! non-zero number of ledgers.
+//!
+//! # Breaking change: mandatory upgrade timelock
+//!
+//! Contracts deriving `Upgradeable` with earlier versions of this crate could
+//! be upgraded with a single `upgrade` call. With this version, `upgrade`
+//! panics with `UpgradeNotScheduled` unless the same WASM hash has been
+//! scheduled beforehand, and with `UpgradeTooEarly` until the minimum delay
+//! has elapsed.
+//!
+//! Migrating an already deployed contract:
+//!
+//! 1. Upgrade it to a WASM built with this version as usual: the `upgrade`
+//!    being executed is still the one of the deployed, untimelocked WASM.
+//! 2. If one day doesn't fit the contract, configure the minimum delay with
+//!    `set_min_upgrade_delay` in `_migrate` (see `Migratable`), as the
+//!    constructor of the new WASM is not invoked on upgrade.
+//! 3. From then on, call `schedule_upgrade` with an `eta` at least the minimum
+//!    delay ahead, wait until `eta`, then call `upgrade`. Off-chain upgrade
+//!    scripts and helper contracts, such as the `Upgrader` example, need to be
+//!    updated accordingly.
+//!
 //! It is recommended to use this module via the `#[derive(Upgradeable)]` macro,
 //! or via the combination `#[derive(Upgradeable, Migratable)]` when custom
 //! migration or rollback logic is additionally needed.
@@ -61,7 +89,7 @@
 //! ```
 //! Check in the "/examples/upgradeable/" directory for the full example, where
 //! can also be found a helper `Upgrader` contract that performs upgrade+migrate
-//! or rollback+downgrade in a single transaction.
+//! or rollback+downgrade of a scheduled upgrade in a single transaction.
 
 #![no_std]
 
@@ -71,10 +99,13 @@ mod upgradeable;
 
 pub use crate::{
     storage::{
-        can_migrate, can_rollback, complete_migration, complete_rollback, ensure_can_migrate,
-        ensure_can_rollback, start_migration,
+        can_migrate, can_rollback, cancel_scheduled_upgrade, complete_migration, complete_rollback,
+        consume_scheduled_upgrade, ensure_can_migrate, ensure_can_rollback, min_upgrade_delay,
+        pending_upgrade, schedule_upgrade, set_min_upgrade_delay, start_migration,
+        DEFAULT_MIN_UPGRADE_DELAY,
     },
     upgradeable::{
-        Migratable, MigratableInternal, Upgradeable, UpgradeableClient, UpgradeableInternal,
+        Migratable, MigratableInternal, Upgradeable, UpgradeableClient, UpgradeableError,
+        UpgradeableInternal,
     },
 };
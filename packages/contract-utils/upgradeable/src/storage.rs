@@ -1,9 +1,18 @@
-use soroban_sdk::{contracttype, panic_with_error, symbol_short, Env, Symbol};
+use soroban_sdk::{contracttype, panic_with_error, symbol_short, BytesN, Env, Symbol};
+use stellar_constants::DAY_IN_LEDGERS;
 
 use crate::upgradeable::UpgradeableError;
 
 pub const UPGRADE_KEY: Symbol = symbol_short!("UPGRADE");
 
+pub const PENDING_UPGRADE_KEY: Symbol = symbol_short!("PENDUPG");
+
+pub const MIN_UPGRADE_DELAY_KEY: Symbol = symbol_short!("UPGDELAY");
+
+/// The minimum number of ledgers between scheduling and executing an upgrade,
+/// unless configured otherwise with [`set_min_upgrade_delay`].
+pub const DEFAULT_MIN_UPGRADE_DELAY: u32 = DAY_IN_LEDGERS;
+
 /// Represents the current upgrade state of the contract. Used to determine if
 /// migration or rollback operations are allowed.
 #[contracttype]
@@ -91,6 +100,121 @@ pub fn ensure_can_rollback(e: &Env) {
     }
 }
 
+/// Returns the hash of the WASM scheduled for the next upgrade together with
+/// the earliest ledger at which the upgrade can be executed, if any.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+pub fn pending_upgrade(e: &Env) -> Option<(BytesN<32>, u32)> {
+    e.storage().instance().get(&PENDING_UPGRADE_KEY)
+}
+
+/// Returns the minimum number of ledgers between scheduling and executing an
+/// upgrade. Defaults to [`DEFAULT_MIN_UPGRADE_DELAY`].
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+pub fn min_upgrade_delay(e: &Env) -> u32 {
+    e.storage().instance().get(&MIN_UPGRADE_DELAY_KEY).unwrap_or(DEFAULT_MIN_UPGRADE_DELAY)
+}
+
+/// Sets the minimum number of ledgers between scheduling and executing an
+/// upgrade. Upgrades already scheduled are not affected.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `delay` - The minimum upgrade delay, in ledgers.
+///
+/// # Errors
+///
+/// * [`UpgradeableError::InvalidUpgradeDelay`] - If `delay` is `0`, which would
+///   allow scheduling and executing an upgrade in the same ledger.
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function doesn't handle authorization. It is meant to
+/// be called from the constructor, or behind the same access control as the
+/// upgrade itself: shortening the delay defeats its purpose otherwise.
+pub fn set_min_upgrade_delay(e: &Env, delay: u32) {
+    if delay == 0 {
+        panic_with_error!(e, UpgradeableError::InvalidUpgradeDelay)
+    }
+    e.storage().instance().set(&MIN_UPGRADE_DELAY_KEY, &delay);
+}
+
+/// Schedules an upgrade to `new_wasm_hash`, executable from the ledger `eta`
+/// on. Replaces any previously scheduled upgrade.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `new_wasm_hash` - A 32-byte hash identifying the new WASM blob.
+/// * `eta` - The earliest ledger at which the upgrade can be executed.
+///
+/// # Errors
+///
+/// * [`UpgradeableError::UpgradeDelayTooShort`] - If `eta` is less than the
+///   current ledger number plus the minimum upgrade delay, see
+///   [`min_upgrade_delay`].
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function doesn't handle authorization.
+pub fn schedule_upgrade(e: &Env, new_wasm_hash: &BytesN<32>, eta: u32) {
+    if eta < e.ledger().sequence().saturating_add(min_upgrade_delay(e)) {
+        panic_with_error!(e, UpgradeableError::UpgradeDelayTooShort)
+    }
+    e.storage().instance().set(&PENDING_UPGRADE_KEY, &(new_wasm_hash.clone(), eta));
+}
+
+/// Cancels the scheduled upgrade.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+///
+/// # Errors
+///
+/// * [`UpgradeableError::UpgradeNotScheduled`] - If no upgrade is scheduled.
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function doesn't handle authorization.
+pub fn cancel_scheduled_upgrade(e: &Env) {
+    if pending_upgrade(e).is_none() {
+        panic_with_error!(e, UpgradeableError::UpgradeNotScheduled)
+    }
+    e.storage().instance().remove(&PENDING_UPGRADE_KEY);
+}
+
+/// Ensures that the upgrade to `new_wasm_hash` has been scheduled and is due,
+/// then clears the schedule, otherwise panics.
+///
+/// # Arguments
+///
+/// * `e` - The Soroban environment.
+/// * `new_wasm_hash` - A 32-byte hash identifying the new WASM blob.
+///
+/// # Errors
+///
+/// * [`UpgradeableError::UpgradeNotScheduled`] - If no upgrade to
+///   `new_wasm_hash` is scheduled.
+/// * [`UpgradeableError::UpgradeTooEarly`] - If the current ledger is before
+///   the `eta` of the scheduled upgrade.
+pub fn consume_scheduled_upgrade(e: &Env, new_wasm_hash: &BytesN<32>) {
+    match pending_upgrade(e) {
+        Some((hash, eta)) if hash == *new_wasm_hash =>
+            if e.ledger().sequence() < eta {
+                panic_with_error!(e, UpgradeableError::UpgradeTooEarly)
+            },
+        _ => panic_with_error!(e, UpgradeableError::UpgradeNotScheduled),
+    }
+    e.storage().instance().remove(&PENDING_UPGRADE_KEY);
+}
+
 /// Retrieves the current upgrade state from instance storage.
 ///
 /// If no state has been set, defaults to [`UpgradeState::Initial`].
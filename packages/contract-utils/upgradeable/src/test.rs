@@ -1,10 +1,12 @@
 #![cfg(test)]
 
-use soroban_sdk::{contract, Env};
+use soroban_sdk::{contract, testutils::Ledger as _, BytesN, Env};
 
 use crate::storage::{
-    can_migrate, can_rollback, complete_migration, complete_rollback, ensure_can_migrate,
-    ensure_can_rollback, start_migration,
+    can_migrate, can_rollback, cancel_scheduled_upgrade, complete_migration, complete_rollback,
+    consume_scheduled_upgrade, ensure_can_migrate, ensure_can_rollback, min_upgrade_delay,
+    pending_upgrade, schedule_upgrade, set_min_upgrade_delay, start_migration,
+    DEFAULT_MIN_UPGRADE_DELAY,
 };
 
 #[contract]
@@ -56,3 +58,121 @@ fn upgrade_ensure_can_rollback_panics_if_not_migrated() {
         ensure_can_rollback(&e);
     });
 }
+
+#[test]
+fn scheduled_upgrade_works_once_due() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let wasm_hash = BytesN::from_array(&e, &[1; 32]);
+
+    e.as_contract(&address, || {
+        assert_eq!(pending_upgrade(&e), None);
+
+        set_min_upgrade_delay(&e, 100);
+        schedule_upgrade(&e, &wasm_hash, 100);
+        assert_eq!(pending_upgrade(&e), Some((wasm_hash.clone(), 100)));
+    });
+
+    e.ledger().set_sequence_number(100);
+
+    e.as_contract(&address, || {
+        consume_scheduled_upgrade(&e, &wasm_hash);
+        assert_eq!(pending_upgrade(&e), None);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #113)")]
+fn scheduled_upgrade_panics_if_too_early() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let wasm_hash = BytesN::from_array(&e, &[1; 32]);
+
+    e.as_contract(&address, || {
+        set_min_upgrade_delay(&e, 100);
+        schedule_upgrade(&e, &wasm_hash, 100);
+    });
+
+    e.ledger().set_sequence_number(99);
+
+    e.as_contract(&address, || {
+        consume_scheduled_upgrade(&e, &wasm_hash);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #112)")]
+fn scheduled_upgrade_panics_if_hash_mismatch() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        schedule_upgrade(&e, &BytesN::from_array(&e, &[1; 32]), DEFAULT_MIN_UPGRADE_DELAY);
+        consume_scheduled_upgrade(&e, &BytesN::from_array(&e, &[2; 32]));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #112)")]
+fn cancelled_upgrade_cannot_be_executed() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let wasm_hash = BytesN::from_array(&e, &[1; 32]);
+
+    e.as_contract(&address, || {
+        schedule_upgrade(&e, &wasm_hash, DEFAULT_MIN_UPGRADE_DELAY);
+        cancel_scheduled_upgrade(&e);
+        assert_eq!(pending_upgrade(&e), None);
+
+        consume_scheduled_upgrade(&e, &wasm_hash);
+    });
+}
+
+#[test]
+fn min_upgrade_delay_defaults_and_is_configurable() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        assert_eq!(min_upgrade_delay(&e), DEFAULT_MIN_UPGRADE_DELAY);
+
+        set_min_upgrade_delay(&e, 10);
+        assert_eq!(min_upgrade_delay(&e), 10);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn schedule_upgrade_panics_if_delay_too_short() {
+    let e = Env::default();
+    e.ledger().set_sequence_number(50);
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_min_upgrade_delay(&e, 100);
+        schedule_upgrade(&e, &BytesN::from_array(&e, &[1; 32]), 149);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn schedule_upgrade_panics_if_eta_is_current_ledger() {
+    let e = Env::default();
+    e.ledger().set_sequence_number(50);
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        schedule_upgrade(&e, &BytesN::from_array(&e, &[1; 32]), 50);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #115)")]
+fn set_min_upgrade_delay_panics_if_zero() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_min_upgrade_delay(&e, 0);
+    });
+}
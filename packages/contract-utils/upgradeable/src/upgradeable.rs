@@ -28,7 +28,59 @@ pub trait Upgradeable {
     /// * `new_wasm_hash` - A 32-byte hash identifying the new WASM blob,
     ///   uploaded to the ledger.
     /// * `operator` - The authorized address performing the upgrade.
+    ///
+    /// # Errors
+    ///
+    /// When using the framework, the upgrade must have been scheduled with
+    /// [`Upgradeable::schedule_upgrade`]:
+    ///
+    /// * [`UpgradeableError::UpgradeNotScheduled`] - If no upgrade to
+    ///   `new_wasm_hash` is scheduled.
+    /// * [`UpgradeableError::UpgradeTooEarly`] - If the `eta` of the scheduled
+    ///   upgrade is not reached yet.
     fn upgrade(e: &Env, new_wasm_hash: BytesN<32>, operator: Address);
+
+    /// Schedules an upgrade to `new_wasm_hash`, executable with
+    /// [`Upgradeable::upgrade`] from the ledger `eta` on, which gives the
+    /// users a window to exit before the upgrade. Replaces any previously
+    /// scheduled upgrade.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `new_wasm_hash` - A 32-byte hash identifying the new WASM blob,
+    ///   uploaded to the ledger.
+    /// * `operator` - The authorized address scheduling the upgrade.
+    /// * `eta` - The earliest ledger at which the upgrade can be executed.
+    ///
+    /// # Errors
+    ///
+    /// When using the framework:
+    ///
+    /// * [`UpgradeableError::UpgradeDelayTooShort`] - If `eta` is closer than
+    ///   the minimum upgrade delay.
+    fn schedule_upgrade(e: &Env, new_wasm_hash: BytesN<32>, operator: Address, eta: u32);
+
+    /// Cancels the scheduled upgrade.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `operator` - The authorized address cancelling the upgrade.
+    ///
+    /// # Errors
+    ///
+    /// * [`UpgradeableError::UpgradeNotScheduled`] - If no upgrade is
+    ///   scheduled.
+    fn cancel_scheduled_upgrade(e: &Env, operator: Address);
+
+    /// Returns the hash of the WASM scheduled for the next upgrade together
+    /// with the earliest ledger at which the upgrade can be executed, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    fn pending_upgrade(e: &Env) -> Option<(BytesN<32>, u32)>;
 }
 
 /// Trait to be implemented for a custom upgrade authorization mechanism.
@@ -65,6 +117,15 @@ pub trait Migratable: MigratableInternal {
     /// * `migration_data` - Arbitrary data passed to the migration logic.
     fn migrate(e: &Env, migration_data: Self::MigrationData);
 
+    /// Read-only entry point to check whether a migration with the given data
+    /// would be accepted, without mutating any state.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The Soroban environment.
+    /// * `migration_data` - Arbitrary data passed to the migration logic.
+    fn validate_migration(e: &Env, migration_data: Self::MigrationData) -> bool;
+
     /// Entry point to handle a rollback of a migration.
     ///
     /// # Arguments
@@ -92,6 +153,17 @@ pub trait MigratableInternal {
     /// * `migration_data` - Migration-specific input data.
     fn _migrate(e: &Env, migration_data: &Self::MigrationData);
 
+    /// Checks whether the given data is valid for a migration, without
+    /// mutating any state. Defaults to accepting any data.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The Soroban environment.
+    /// * `migration_data` - Migration-specific input data.
+    fn _validate_migration(_e: &Env, _migration_data: &Self::MigrationData) -> bool {
+        true
+    }
+
     /// Applies rollback logic using the given data.
     ///
     /// # Arguments
@@ -109,4 +181,13 @@ pub enum UpgradeableError {
     MigrationNotAllowed = 110,
     /// When rollback is attempted but not allowed due to upgrade state.
     RollbackNotAllowed = 111,
+    /// When an upgrade is attempted or cancelled without being scheduled.
+    UpgradeNotScheduled = 112,
+    /// When a scheduled upgrade is attempted before its `eta`.
+    UpgradeTooEarly = 113,
+    /// When an upgrade is scheduled with an `eta` closer than the minimum
+    /// upgrade delay.
+    UpgradeDelayTooShort = 114,
+    /// When the minimum upgrade delay is set to zero.
+    InvalidUpgradeDelay = 115,
 }
@@ -1,6 +1,8 @@
 #![cfg(not(target_arch = "wasm32"))]
 
-use soroban_sdk::{symbol_short, testutils::Events, Address, Env, IntoVal, Symbol, Val, Vec};
+use soroban_sdk::{
+    symbol_short, testutils::Events, Address, Env, FromVal, IntoVal, Symbol, TryFromVal, Val, Vec,
+};
 use stellar_non_fungible::TokenId;
 
 pub struct EventAssertion<'a> {
@@ -14,6 +16,14 @@ impl<'a> EventAssertion<'a> {
     }
 
     fn find_event_by_symbol(&self, symbol_name: &str) -> Option<(Address, Vec<Val>, Val)> {
+        self.find_nth_event_by_symbol(symbol_name, 0)
+    }
+
+    fn find_nth_event_by_symbol(
+        &self,
+        symbol_name: &str,
+        index: usize,
+    ) -> Option<(Address, Vec<Val>, Val)> {
         let events = self.env.events().all();
 
         let target_symbol = match symbol_name {
@@ -24,11 +34,32 @@ impl<'a> EventAssertion<'a> {
             _ => Symbol::new(self.env, symbol_name),
         };
 
-        events.iter().find(|e| {
-            let topics: Vec<Val> = e.1.clone();
-            let topic_symbol: Symbol = topics.first().unwrap().into_val(self.env);
-            topic_symbol == target_symbol
-        })
+        events
+            .iter()
+            .filter(|e| {
+                let topics: Vec<Val> = e.1.clone();
+                let topic_symbol: Symbol = topics.first().unwrap().into_val(self.env);
+                e.0 == self.contract && topic_symbol == target_symbol
+            })
+            .nth(index)
+    }
+
+    /// Returns a copy of this assertion helper scoped to `contract`, for the
+    /// tests where several contracts emit events, e.g. through cross-contract
+    /// calls.
+    pub fn with_contract(&self, contract: &Address) -> Self {
+        Self { env: self.env, contract: contract.clone() }
+    }
+
+    /// Asserts that `contract` emitted an event whose first topic is `symbol`,
+    /// and returns the topics and the data of the first such event.
+    pub fn assert_event_from(&self, contract: &Address, symbol: &str) -> (Vec<Val>, Val) {
+        let event = self.with_contract(contract).find_event_by_symbol(symbol);
+
+        assert!(event.is_some(), "Event {symbol} from {contract:?} not found in event log");
+
+        let (_, topics, data) = event.unwrap();
+        (topics, data)
     }
 
     pub fn assert_fungible_transfer(&self, from: &Address, to: &Address, amount: i128) {
@@ -47,13 +78,43 @@ impl<'a> EventAssertion<'a> {
 
         let event_from: Address = topics.get_unchecked(1).into_val(self.env);
         let event_to: Address = topics.get_unchecked(2).into_val(self.env);
-        let event_amount: i128 = data.into_val(self.env);
+        // the data might also carry the balances and the transfer sequence
+        // number, when the `detailed_events` and `transfer_sequence` features
+        // of `stellar-fungible` are enabled
+        let event_amount: i128 = i128::try_from_val(self.env, &data).unwrap_or_else(|_| {
+            let data: Vec<Val> = data.into_val(self.env);
+            data.get_unchecked(0).into_val(self.env)
+        });
 
         assert_eq!(&event_from, from, "Transfer event has wrong from address");
         assert_eq!(&event_to, to, "Transfer event has wrong to address");
         assert_eq!(event_amount, amount, "Transfer event has wrong amount");
     }
 
+    /// Same as [`Self::assert_fungible_transfer`], additionally checking the
+    /// post-transfer balances published when the `detailed_events` feature of
+    /// `stellar-fungible` is enabled.
+    pub fn assert_fungible_transfer_detailed(
+        &self,
+        from: &Address,
+        to: &Address,
+        amount: i128,
+        from_balance: i128,
+        to_balance: i128,
+    ) {
+        self.assert_fungible_transfer(from, to, amount);
+
+        let (_, _, data) = self.find_event_by_symbol("transfer").unwrap();
+        let data: Vec<Val> =
+            Vec::try_from_val(self.env, &data).expect("Transfer event doesn't carry the balances");
+        assert!(data.len() >= 3, "Transfer event doesn't carry the balances");
+
+        let event_from_balance: i128 = data.get_unchecked(1).into_val(self.env);
+        let event_to_balance: i128 = data.get_unchecked(2).into_val(self.env);
+        assert_eq!(event_from_balance, from_balance, "Transfer event has wrong from balance");
+        assert_eq!(event_to_balance, to_balance, "Transfer event has wrong to balance");
+    }
+
     pub fn assert_non_fungible_transfer(&self, from: &Address, to: &Address, token_id: TokenId) {
         let transfer_event = self.find_event_by_symbol("transfer");
 
@@ -103,7 +164,7 @@ impl<'a> EventAssertion<'a> {
         let mint_event = events.iter().find(|e| {
             let topics: Vec<Val> = e.1.clone();
             let topic_symbol: Symbol = topics.first().unwrap().into_val(self.env);
-            topic_symbol == symbol_short!("mint")
+            e.0 == self.contract && topic_symbol == symbol_short!("mint")
         });
 
         assert!(mint_event.is_some(), "Mint event not found in event log");
@@ -124,6 +185,38 @@ impl<'a> EventAssertion<'a> {
         assert_eq!(event_token_id, token_id, "Mint event has wrong token_id");
     }
 
+    /// Asserts that the `index`-th (zero-based) mint event emitted by the
+    /// contract minted `amount` tokens to `to`.
+    pub fn assert_fungible_mint_at(&self, index: usize, to: &Address, amount: i128) {
+        let (event_to, data) = self.nth_mint_event(index);
+        let event_amount: i128 = data.into_val(self.env);
+
+        assert_eq!(&event_to, to, "Mint event {index} has wrong to address");
+        assert_eq!(event_amount, amount, "Mint event {index} has wrong amount");
+    }
+
+    /// Asserts that the `index`-th (zero-based) mint event emitted by the
+    /// contract minted `token_id` to `to`.
+    pub fn assert_non_fungible_mint_at(&self, index: usize, to: &Address, token_id: TokenId) {
+        let (event_to, data) = self.nth_mint_event(index);
+        let event_token_id: TokenId = data.into_val(self.env);
+
+        assert_eq!(&event_to, to, "Mint event {index} has wrong to address");
+        assert_eq!(event_token_id, token_id, "Mint event {index} has wrong token_id");
+    }
+
+    /// Returns the recipient and the data of the `index`-th mint event.
+    fn nth_mint_event(&self, index: usize) -> (Address, Val) {
+        let count = self.count_events_by_symbol("mint");
+        assert!(index < count, "Expected at least {} mint events, found {count}", index + 1);
+
+        let (_, topics, data) = self.find_nth_event_by_symbol("mint", index).unwrap();
+        assert_eq!(topics.len(), 2, "Mint event should have 2 topics");
+
+        let event_to: Address = topics.get_unchecked(1).into_val(self.env);
+        (event_to, data)
+    }
+
     pub fn assert_fungible_burn(&self, from: &Address, amount: i128) {
         let burn_event = self.find_event_by_symbol("burn");
 
@@ -177,6 +270,73 @@ impl<'a> EventAssertion<'a> {
         );
     }
 
+    /// Returns the number of events emitted by the contract whose first topic
+    /// is `symbol`.
+    pub fn count_events_by_symbol(&self, symbol: &str) -> usize {
+        let target_symbol = Symbol::new(self.env, symbol);
+
+        self.env
+            .events()
+            .all()
+            .iter()
+            .filter(|e| {
+                let topic_symbol: Symbol = e.1.first().unwrap().into_val(self.env);
+                e.0 == self.contract && topic_symbol == target_symbol
+            })
+            .count()
+    }
+
+    /// Asserts that the contract emitted exactly `expected` events whose first
+    /// topic is `symbol`.
+    pub fn assert_event_count_by_symbol(&self, symbol: &str, expected: usize) {
+        let count = self.count_events_by_symbol(symbol);
+        assert_eq!(count, expected, "Expected {expected} {symbol} events, found {count}");
+    }
+
+    /// Asserts that the contract was paused exactly `expected` times.
+    pub fn assert_paused_count(&self, expected: usize) {
+        self.assert_event_count_by_symbol("paused", expected);
+    }
+
+    /// Asserts that the contract was unpaused exactly `expected` times.
+    pub fn assert_unpaused_count(&self, expected: usize) {
+        self.assert_event_count_by_symbol("unpaused", expected);
+    }
+
+    /// Asserts that the contract emitted a `paused` event triggered by
+    /// `caller`.
+    pub fn assert_paused(&self, caller: &Address) {
+        self.assert_pause_event("paused", caller);
+    }
+
+    /// Asserts that the contract emitted an `unpaused` event triggered by
+    /// `caller`.
+    pub fn assert_unpaused(&self, caller: &Address) {
+        self.assert_pause_event("unpaused", caller);
+    }
+
+    fn assert_pause_event(&self, symbol: &str, caller: &Address) {
+        let event = self.find_event_by_symbol(symbol);
+
+        assert!(event.is_some(), "{symbol} event not found in event log");
+
+        let (contract, topics, data) = event.unwrap();
+        assert_eq!(contract, self.contract, "Event from wrong contract");
+        assert_eq!(topics.len(), 1, "{symbol} event should have 1 topic");
+
+        let event_caller: Address = data.into_val(self.env);
+        assert_eq!(&event_caller, caller, "{symbol} event has wrong caller");
+    }
+
+    /// Asserts that the contract emitted no event whose first topic is
+    /// `symbol_name`, e.g. to check that a zero-amount transfer is silent.
+    pub fn assert_no_event(&self, symbol_name: &str) {
+        assert!(
+            self.find_event_by_symbol(symbol_name).is_none(),
+            "Unexpected {symbol_name} event found in event log"
+        );
+    }
+
     pub fn assert_fungible_approve(
         &self,
         owner: &Address,
@@ -285,4 +445,35 @@ impl<'a> EventAssertion<'a> {
         assert_eq!(event_data.0, from_id, "ConsecutiveMint event has wrong from_token_id");
         assert_eq!(event_data.1, to_id, "ConsecutiveMint event has wrong to_token_id");
     }
+
+    pub fn assert_nft_transfer_batch(&self, from: &Address, to: &Address, token_ids: &[TokenId]) {
+        let target_symbol = Symbol::new(self.env, "transfer_batch");
+        let events = self.env.events().all();
+        // there is one `transfer_batch` event per recipient
+        let event = events.iter().find(|e| {
+            let topics: Vec<Val> = e.1.clone();
+            let topic_symbol: Symbol = topics.first().unwrap().into_val(self.env);
+            e.0 == self.contract
+                && topic_symbol == target_symbol
+                && topics.len() == 3
+                && Address::from_val(self.env, &topics.get_unchecked(2)) == *to
+        });
+
+        assert!(event.is_some(), "TransferBatch event not found in event log");
+
+        let (contract, topics, data) = event.unwrap();
+        assert_eq!(contract, self.contract, "Event from wrong contract");
+
+        let event_from: Address = topics.get_unchecked(1).into_val(self.env);
+        let event_token_ids: Vec<TokenId> = data.into_val(self.env);
+
+        assert_eq!(&event_from, from, "TransferBatch event has wrong from address");
+        assert_eq!(
+            event_token_ids,
+            Vec::from_slice(self.env, token_ids),
+            "TransferBatch event has wrong token ids"
+        );
+    }
 }
+
+mod test;
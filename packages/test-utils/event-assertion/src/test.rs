@@ -0,0 +1,216 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, Address, Env, FromVal,
+};
+
+use crate::EventAssertion;
+
+#[contract]
+struct Emitter;
+
+#[contractimpl]
+impl Emitter {
+    pub fn mint(e: &Env, to: Address, amount: i128) {
+        e.events().publish((symbol_short!("mint"), to), amount);
+    }
+}
+
+#[contract]
+struct Relayer;
+
+#[contractimpl]
+impl Relayer {
+    pub fn mint(e: &Env, emitter: Address, to: Address, amount: i128) {
+        e.events().publish((symbol_short!("mint"), to.clone()), amount * 2);
+        EmitterClient::new(e, &emitter).mint(&to, &amount);
+    }
+}
+
+#[contract]
+struct Breaker;
+
+#[contractimpl]
+impl Breaker {
+    pub fn cycle(e: &Env, caller: Address, pauses: u32, unpauses: u32) {
+        for _ in 0..pauses {
+            e.events().publish((symbol_short!("paused"),), caller.clone());
+        }
+        for _ in 0..unpauses {
+            e.events().publish((symbol_short!("unpaused"),), caller.clone());
+        }
+    }
+}
+
+#[test]
+fn assert_event_from_scopes_to_contract() {
+    let e = Env::default();
+    let emitter = e.register(Emitter, ());
+    let relayer = e.register(Relayer, ());
+    let to = Address::generate(&e);
+
+    RelayerClient::new(&e, &relayer).mint(&emitter, &to, &10);
+
+    let event_assert = EventAssertion::new(&e, relayer.clone());
+    event_assert.assert_event_count(2);
+
+    let (topics, data) = event_assert.assert_event_from(&relayer, "mint");
+    assert_eq!(Address::from_val(&e, &topics.get_unchecked(1)), to);
+    assert_eq!(i128::from_val(&e, &data), 20);
+
+    let (topics, data) = event_assert.assert_event_from(&emitter, "mint");
+    assert_eq!(Address::from_val(&e, &topics.get_unchecked(1)), to);
+    assert_eq!(i128::from_val(&e, &data), 10);
+}
+
+#[test]
+fn helpers_accept_contract_override() {
+    let e = Env::default();
+    let emitter = e.register(Emitter, ());
+    let relayer = e.register(Relayer, ());
+    let to = Address::generate(&e);
+
+    RelayerClient::new(&e, &relayer).mint(&emitter, &to, &10);
+
+    let event_assert = EventAssertion::new(&e, relayer.clone());
+    event_assert.assert_fungible_mint(&to, 20);
+    event_assert.with_contract(&emitter).assert_fungible_mint(&to, 10);
+}
+
+#[test]
+#[should_panic(expected = "not found in event log")]
+fn assert_event_from_fails_for_silent_contract() {
+    let e = Env::default();
+    let emitter = e.register(Emitter, ());
+    let silent = e.register(Emitter, ());
+    let to = Address::generate(&e);
+
+    EmitterClient::new(&e, &emitter).mint(&to, &10);
+
+    EventAssertion::new(&e, emitter.clone()).assert_event_from(&silent, "mint");
+}
+
+#[test]
+fn assert_paused_and_unpaused_counts() {
+    let e = Env::default();
+    let breaker = e.register(Breaker, ());
+    let caller = Address::generate(&e);
+
+    BreakerClient::new(&e, &breaker).cycle(&caller, &3, &2);
+
+    let event_assert = EventAssertion::new(&e, breaker.clone());
+    event_assert.assert_event_count(5);
+    event_assert.assert_paused_count(3);
+    event_assert.assert_unpaused_count(2);
+    event_assert.assert_event_count_by_symbol("mint", 0);
+}
+
+#[test]
+fn paused_count_ignores_other_contracts() {
+    let e = Env::default();
+    let breaker = e.register(Breaker, ());
+    let other = e.register(Breaker, ());
+    let caller = Address::generate(&e);
+
+    BreakerClient::new(&e, &breaker).cycle(&caller, &2, &2);
+
+    let event_assert = EventAssertion::new(&e, other.clone());
+    event_assert.assert_paused_count(0);
+    event_assert.with_contract(&breaker).assert_paused_count(2);
+}
+
+#[test]
+#[should_panic(expected = "Expected 1 paused events, found 2")]
+fn assert_paused_count_fails_on_mismatch() {
+    let e = Env::default();
+    let breaker = e.register(Breaker, ());
+    let caller = Address::generate(&e);
+
+    BreakerClient::new(&e, &breaker).cycle(&caller, &2, &0);
+
+    EventAssertion::new(&e, breaker.clone()).assert_paused_count(1);
+}
+
+#[test]
+fn assert_paused_and_unpaused_callers() {
+    let e = Env::default();
+    let breaker = e.register(Breaker, ());
+    let caller = Address::generate(&e);
+
+    BreakerClient::new(&e, &breaker).cycle(&caller, &1, &1);
+
+    let event_assert = EventAssertion::new(&e, breaker.clone());
+    event_assert.assert_paused(&caller);
+    event_assert.assert_unpaused(&caller);
+}
+
+#[test]
+#[should_panic(expected = "paused event has wrong caller")]
+fn assert_paused_fails_for_other_caller() {
+    let e = Env::default();
+    let breaker = e.register(Breaker, ());
+    let caller = Address::generate(&e);
+
+    BreakerClient::new(&e, &breaker).cycle(&caller, &1, &0);
+
+    EventAssertion::new(&e, breaker.clone()).assert_paused(&Address::generate(&e));
+}
+
+#[test]
+fn assert_no_event_works() {
+    let e = Env::default();
+    let breaker = e.register(Breaker, ());
+    let caller = Address::generate(&e);
+
+    BreakerClient::new(&e, &breaker).cycle(&caller, &1, &0);
+
+    let event_assert = EventAssertion::new(&e, breaker.clone());
+    event_assert.assert_no_event("unpaused");
+    event_assert.assert_no_event("transfer");
+}
+
+#[test]
+#[should_panic(expected = "Unexpected paused event found in event log")]
+fn assert_no_event_fails_when_emitted() {
+    let e = Env::default();
+    let breaker = e.register(Breaker, ());
+    let caller = Address::generate(&e);
+
+    BreakerClient::new(&e, &breaker).cycle(&caller, &1, &0);
+
+    EventAssertion::new(&e, breaker.clone()).assert_no_event("paused");
+}
+
+#[test]
+fn assert_fungible_mint_at_reads_each_event() {
+    let e = Env::default();
+    let emitter = e.register(Emitter, ());
+    let to = Address::generate(&e);
+    let other = Address::generate(&e);
+
+    e.as_contract(&emitter, || {
+        e.events().publish((symbol_short!("mint"), to.clone()), 10_i128);
+        e.events().publish((symbol_short!("mint"), other.clone()), 20_i128);
+    });
+
+    let event_assert = EventAssertion::new(&e, emitter.clone());
+    event_assert.assert_fungible_mint_at(0, &to, 10);
+    event_assert.assert_fungible_mint_at(1, &other, 20);
+}
+
+#[test]
+#[should_panic(expected = "Expected at least 3 mint events, found 2")]
+fn assert_fungible_mint_at_fails_past_last_event() {
+    let e = Env::default();
+    let emitter = e.register(Emitter, ());
+    let to = Address::generate(&e);
+
+    e.as_contract(&emitter, || {
+        e.events().publish((symbol_short!("mint"), to.clone()), 10_i128);
+        e.events().publish((symbol_short!("mint"), to.clone()), 20_i128);
+    });
+
+    EventAssertion::new(&e, emitter.clone()).assert_fungible_mint_at(2, &to, 30);
+}
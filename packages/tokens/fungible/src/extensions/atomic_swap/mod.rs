@@ -0,0 +1,50 @@
+/// Unlike other extensions, the `atomic_swap` extension does not provide a
+/// separate trait. It settles simple over-the-counter deals between two
+/// parties without an escrow: both legs of the deal are executed within the
+/// same invocation, so either both transfers happen or none of them does.
+///
+/// This module provides the following helper functions:
+/// - `atomic_swap`: Exchanges `amount_a` of `token_a` held by `party_a` for
+///   `amount_b` of `token_b` held by `party_b`.
+///
+/// The tokens are called through [`soroban_sdk::token::TokenClient`], so any
+/// SEP-41 token can be swapped. As a contract can't call itself, neither
+/// `token_a` nor `token_b` can be the contract executing the swap.
+mod storage;
+use soroban_sdk::{symbol_short, Address, Env};
+
+pub use self::storage::atomic_swap;
+
+mod test;
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating two parties swapped tokens.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `party_a` - The address giving `token_a`.
+/// * `party_b` - The address giving `token_b`.
+/// * `token_a` - The token given by `party_a`.
+/// * `amount_a` - The amount of `token_a` given by `party_a`.
+/// * `token_b` - The token given by `party_b`.
+/// * `amount_b` - The amount of `token_b` given by `party_b`.
+///
+/// # Events
+///
+/// * topics - `["swap", party_a: Address, party_b: Address]`
+/// * data - `[token_a: Address, amount_a: i128, token_b: Address, amount_b:
+///   i128]`
+pub fn emit_swap(
+    e: &Env,
+    party_a: &Address,
+    party_b: &Address,
+    token_a: &Address,
+    amount_a: i128,
+    token_b: &Address,
+    amount_b: i128,
+) {
+    let topics = (symbol_short!("swap"), party_a, party_b);
+    e.events().publish(topics, (token_a.clone(), amount_a, token_b.clone(), amount_b))
+}
@@ -0,0 +1,49 @@
+use soroban_sdk::{token::TokenClient, Address, Env};
+
+use crate::atomic_swap::emit_swap;
+
+/// Exchanges `amount_a` of `token_a` held by `party_a` for `amount_b` of
+/// `token_b` held by `party_b`. If any of the two transfers fails, the whole
+/// swap is reverted.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `party_a` - The address giving `token_a` and receiving `token_b`.
+/// * `token_a` - The token given by `party_a`.
+/// * `amount_a` - The amount of `token_a` given by `party_a`.
+/// * `party_b` - The address giving `token_b` and receiving `token_a`.
+/// * `token_b` - The token given by `party_b`.
+/// * `amount_b` - The amount of `token_b` given by `party_b`.
+///
+/// # Errors
+///
+/// * refer to the `transfer` errors of `token_a` and `token_b`.
+///
+/// # Events
+///
+/// * topics - `["swap", party_a: Address, party_b: Address]`
+/// * data - `[token_a: Address, amount_a: i128, token_b: Address, amount_b:
+///   i128]`
+///
+/// # Notes
+///
+/// * Authorization for both `party_a` and `party_b` is required, including for
+///   the `transfer` they are sub-invoking on their token.
+pub fn atomic_swap(
+    e: &Env,
+    party_a: &Address,
+    token_a: &Address,
+    amount_a: i128,
+    party_b: &Address,
+    token_b: &Address,
+    amount_b: i128,
+) {
+    party_a.require_auth();
+    party_b.require_auth();
+
+    TokenClient::new(e, token_a).transfer(party_a, party_b, &amount_a);
+    TokenClient::new(e, token_b).transfer(party_b, party_a, &amount_b);
+
+    emit_swap(e, party_a, party_b, token_a, amount_a, token_b, amount_b);
+}
@@ -0,0 +1,84 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::extensions::atomic_swap::atomic_swap;
+
+#[contract]
+struct MockContract;
+
+#[contractimpl]
+impl MockContract {
+    pub fn swap(
+        e: &Env,
+        party_a: Address,
+        token_a: Address,
+        amount_a: i128,
+        party_b: Address,
+        token_b: Address,
+        amount_b: i128,
+    ) {
+        atomic_swap(e, &party_a, &token_a, amount_a, &party_b, &token_b, amount_b);
+    }
+}
+
+fn create_token(e: &Env) -> Address {
+    e.register_stellar_asset_contract_v2(Address::generate(e)).address()
+}
+
+#[test]
+fn atomic_swap_exchanges_both_legs() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let token_a = create_token(&e);
+    let token_b = create_token(&e);
+    StellarAssetClient::new(&e, &token_a).mint(&alice, &100);
+    StellarAssetClient::new(&e, &token_b).mint(&bob, &500);
+
+    client.swap(&alice, &token_a, &100, &bob, &token_b, &500);
+
+    let event_assert = EventAssertion::new(&e, address.clone());
+    // both legs plus the swap itself
+    event_assert.assert_event_count(3);
+    let (topics, _) = event_assert.assert_event_from(&address, "swap");
+    assert_eq!(topics.len(), 3);
+
+    assert_eq!(TokenClient::new(&e, &token_a).balance(&alice), 0);
+    assert_eq!(TokenClient::new(&e, &token_a).balance(&bob), 100);
+    assert_eq!(TokenClient::new(&e, &token_b).balance(&alice), 500);
+    assert_eq!(TokenClient::new(&e, &token_b).balance(&bob), 0);
+}
+
+#[test]
+fn atomic_swap_reverts_when_one_leg_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let token_a = create_token(&e);
+    let token_b = create_token(&e);
+    StellarAssetClient::new(&e, &token_a).mint(&alice, &100);
+    StellarAssetClient::new(&e, &token_b).mint(&bob, &400);
+
+    // bob can't cover his leg of the deal
+    assert!(client.try_swap(&alice, &token_a, &100, &bob, &token_b, &500).is_err());
+
+    // alice's leg, executed first, has been reverted too
+    assert_eq!(TokenClient::new(&e, &token_a).balance(&alice), 100);
+    assert_eq!(TokenClient::new(&e, &token_a).balance(&bob), 0);
+    assert_eq!(TokenClient::new(&e, &token_b).balance(&bob), 400);
+}
@@ -1,5 +1,8 @@
 mod storage;
-pub use self::storage::{burn, burn_from};
+pub use self::storage::{
+    burn, burn_cooldown, burn_from, check_burn_cooldown, record_mint, set_burn_cooldown,
+    BurnableStorageKey, BURN_COOLDOWN_KEY,
+};
 
 mod test;
 
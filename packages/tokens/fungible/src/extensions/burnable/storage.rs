@@ -1,10 +1,112 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{contracttype, panic_with_error, symbol_short, Address, Env, Symbol};
 
 use crate::{
     extensions::burnable::emit_burn,
     storage::{spend_allowance, update},
+    FungibleTokenError,
 };
 
+/// Storage key for the burn cooldown, in ledgers.
+pub const BURN_COOLDOWN_KEY: Symbol = symbol_short!("BURNCOOL");
+
+/// Storage keys for the data associated with the burnable extension
+#[contracttype]
+pub enum BurnableStorageKey {
+    /// The ledger number of the last mint to an account. Only recorded while
+    /// a burn cooldown is configured.
+    LastMint(Address),
+}
+
+/// Returns the number of ledgers during which freshly minted tokens can't be
+/// burned. Defaults to `0`, i.e. no cooldown.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+pub fn burn_cooldown(e: &Env) -> u32 {
+    e.storage().instance().get(&BURN_COOLDOWN_KEY).unwrap_or(0)
+}
+
+/// Sets the number of ledgers during which an account can't burn tokens after
+/// receiving a mint. Setting it to `0` disables the cooldown.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `ledgers` - The length of the cooldown in ledgers.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::InvalidBurnCooldown`] - When `ledgers` is greater
+///   than the maximum TTL of the network, as the mints are recorded in entries
+///   living as long as the cooldown.
+///
+/// # Notes
+///
+/// Mints are only recorded while a cooldown is configured, so enabling the
+/// cooldown doesn't affect the tokens minted before.
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function bypasses authorization checks and should only
+/// be used internally or in trusted contexts.
+pub fn set_burn_cooldown(e: &Env, ledgers: u32) {
+    if ledgers > e.storage().max_ttl() {
+        panic_with_error!(e, FungibleTokenError::InvalidBurnCooldown);
+    }
+
+    if ledgers == 0 {
+        e.storage().instance().remove(&BURN_COOLDOWN_KEY);
+    } else {
+        e.storage().instance().set(&BURN_COOLDOWN_KEY, &ledgers);
+    }
+}
+
+/// Records a mint to `to` at the current ledger, if a burn cooldown is
+/// configured. It is invoked by [`crate::mintable::mint()`].
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `to` - The address receiving the minted tokens.
+pub fn record_mint(e: &Env, to: &Address) {
+    let cooldown = burn_cooldown(e);
+    if cooldown == 0 {
+        return;
+    }
+
+    // the record is only needed until the end of the cooldown, so it is kept
+    // in temporary storage with a matching TTL
+    let key = BurnableStorageKey::LastMint(to.clone());
+    e.storage().temporary().set(&key, &e.ledger().sequence());
+    e.storage().temporary().extend_ttl(&key, cooldown, cooldown);
+}
+
+/// Panics if `from` received a mint less than the burn cooldown ago.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `from` - The account whose tokens are about to be burned.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::BurnCooldownActive`] - When the cooldown following
+///   the last mint to `from` is not over.
+pub fn check_burn_cooldown(e: &Env, from: &Address) {
+    let cooldown = burn_cooldown(e);
+    if cooldown == 0 {
+        return;
+    }
+
+    let key = BurnableStorageKey::LastMint(from.clone());
+    if let Some(last_mint) = e.storage().temporary().get::<_, u32>(&key) {
+        if e.ledger().sequence() < last_mint.saturating_add(cooldown) {
+            panic_with_error!(e, FungibleTokenError::BurnCooldownActive);
+        }
+    }
+}
+
 /// Destroys `amount` of tokens from `from`. Updates the total
 /// supply accordingly.
 ///
@@ -16,6 +118,7 @@ use crate::{
 ///
 /// # Errors
 ///
+/// * refer to [`check_burn_cooldown`] errors.
 /// * refer to [`update`] errors.
 ///
 /// # Events
@@ -28,6 +131,7 @@ use crate::{
 /// Authorization for `from` is required.
 pub fn burn(e: &Env, from: &Address, amount: i128) {
     from.require_auth();
+    check_burn_cooldown(e, from);
     update(e, Some(from), None, amount);
     emit_burn(e, from, amount);
 }
@@ -46,6 +150,7 @@ pub fn burn(e: &Env, from: &Address, amount: i128) {
 ///
 /// # Errors
 ///
+/// * refer to [`check_burn_cooldown`] errors.
 /// * refer to [`spend_allowance`] errors.
 /// * refer to [`update`] errors.
 ///
@@ -59,6 +164,7 @@ pub fn burn(e: &Env, from: &Address, amount: i128) {
 /// Authorization for `spender` is required.
 pub fn burn_from(e: &Env, spender: &Address, from: &Address, amount: i128) {
     spender.require_auth();
+    check_burn_cooldown(e, from);
     spend_allowance(e, from, spender, amount);
     update(e, Some(from), None, amount);
     emit_burn(e, from, amount);
@@ -2,15 +2,19 @@
 
 extern crate std;
 
-use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger as _},
+    Address, Env,
+};
 use stellar_event_assertion::EventAssertion;
 
 use crate::{
     extensions::{
-        burnable::storage::{burn, burn_from},
+        burnable::storage::{burn, burn_from, set_burn_cooldown},
         mintable::mint,
     },
-    storage::{allowance, approve, balance, total_supply},
+    storage::{allowance, approve, balance, total_supply, StorageKey},
 };
 
 #[contract]
@@ -35,6 +39,24 @@ fn burn_works() {
     });
 }
 
+#[test]
+fn burn_zero_emits_event_without_storage_writes() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+    e.as_contract(&address, || {
+        burn(&e, &account, 0);
+        assert_eq!(balance(&e, &account), 0);
+        assert!(!e.storage().persistent().has(&StorageKey::Balance(account.clone())));
+        assert!(!e.storage().instance().has(&StorageKey::TotalSupply));
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_fungible_burn(&account, 0);
+    });
+}
+
 #[test]
 fn burn_with_allowance_works() {
     let e = Env::default();
@@ -106,3 +128,92 @@ fn burn_with_insufficient_allowance_panics() {
         burn_from(&e, &spender, &owner, 60);
     });
 }
+
+#[test]
+fn burn_without_cooldown_works_right_after_mint() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 100);
+        burn(&e, &account, 50);
+        assert_eq!(balance(&e, &account), 50);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #220)")]
+fn burn_before_cooldown_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(100);
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_burn_cooldown(&e, 50);
+        mint(&e, &account, 100);
+    });
+
+    e.ledger().set_sequence_number(149);
+
+    e.as_contract(&address, || {
+        burn(&e, &account, 50);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #220)")]
+fn burn_from_before_cooldown_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(100);
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_burn_cooldown(&e, 50);
+        mint(&e, &account, 100);
+        approve(&e, &account, &spender, 50, 1000);
+    });
+
+    e.as_contract(&address, || {
+        burn_from(&e, &spender, &account, 50);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #238)")]
+fn set_burn_cooldown_above_max_ttl_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        let ledgers = e.storage().max_ttl() + 1;
+        set_burn_cooldown(&e, ledgers);
+    });
+}
+
+#[test]
+fn burn_after_cooldown_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(100);
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_burn_cooldown(&e, 50);
+        mint(&e, &account, 100);
+    });
+
+    e.ledger().set_sequence_number(150);
+
+    e.as_contract(&address, || {
+        burn(&e, &account, 50);
+        assert_eq!(balance(&e, &account), 50);
+    });
+}
@@ -7,8 +7,21 @@
 /// This module provides the following helper functions:
 /// - `set_cap`: Sets the maximum token supply.
 /// - `query_cap`: Returns the maximum token supply.
+/// - `set_cap_treasury`: Opts in to minting the supply made available by a cap
+///   increase to a treasury.
+/// - `query_cap_treasury`: Returns the treasury, if any.
 /// - `check_cap`: Panics if minting a specified `amount` would exceed the cap.
 ///   Should be used before calling `mint()`.
+/// - `set_max_mint_per_call`: Sets the maximum amount mintable in a single
+///   call.
+/// - `query_max_mint_per_call`: Returns the maximum amount mintable in a single
+///   call, if any.
+/// - `check_max_mint_per_call`: Panics if `amount` exceeds the maximum amount
+///   mintable in a single call. Already enforced by `mint()`.
 mod storage;
-pub use self::storage::{check_cap, query_cap, set_cap, CAP_KEY};
+pub use self::storage::{
+    check_cap, check_max_mint_per_call, query_cap, query_cap_treasury, query_max_mint_per_call,
+    set_cap, set_cap_treasury, set_max_mint_per_call, CAP_KEY, CAP_TREASURY_KEY,
+    MAX_MINT_PER_CALL_KEY,
+};
 mod test;
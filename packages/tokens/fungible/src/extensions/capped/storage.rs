@@ -1,10 +1,21 @@
-use soroban_sdk::{panic_with_error, symbol_short, Env, Symbol};
+use soroban_sdk::{panic_with_error, symbol_short, Address, Env, Symbol};
 
-use crate::{FungibleTokenError, StorageKey};
+use crate::{
+    mintable::emit_mint,
+    storage::{total_supply, update},
+    FungibleTokenError, StorageKey,
+};
 
 /// Storage key
 pub const CAP_KEY: Symbol = symbol_short!("CAP");
 
+/// Storage key for the maximum amount of tokens mintable in a single call
+pub const MAX_MINT_PER_CALL_KEY: Symbol = symbol_short!("MAXMINT");
+
+/// Storage key for the treasury receiving the supply made available by cap
+/// increases
+pub const CAP_TREASURY_KEY: Symbol = symbol_short!("CAPTREAS");
+
 /// Set the maximum supply of tokens.
 ///
 /// # Arguments
@@ -22,11 +33,68 @@ pub const CAP_KEY: Symbol = symbol_short!("CAP");
 ///   contract.
 /// * Cap functionality is designed to be used in conjunction with the
 ///   `mintable` extension.
+/// * When a treasury is configured with [`set_cap_treasury`], raising an
+///   already set cap mints the supply it makes available to the treasury, i.e.
+///   the difference between the new cap and the greater of the old cap and the
+///   total supply. Setting the cap for the first time or lowering it doesn't
+///   mint anything.
+/// * The mint to the treasury is part of the cap change, so it bypasses the
+///   checks of [`crate::mintable::mint()`]: the maximum per call, the mint rate
+///   limit and the burn cooldown don't apply to it.
+///
+/// # Events
+///
+/// Only when the cap is raised and a treasury is configured:
+/// * topics - `["mint", treasury: Address]`
+/// * data - `[amount: i128]`
 pub fn set_cap(e: &Env, cap: i128) {
     if cap < 0 {
         panic_with_error!(e, FungibleTokenError::InvalidCap);
     }
+    let previous: Option<i128> = e.storage().instance().get(&CAP_KEY);
     e.storage().instance().set(&CAP_KEY, &cap);
+
+    if let (Some(previous), Some(treasury)) = (previous, query_cap_treasury(e)) {
+        // the supply may exceed the old cap if the cap was lowered below it
+        let available = cap - previous.max(total_supply(e));
+        if available > 0 {
+            check_cap(e, available);
+            update(e, None, Some(&treasury), available);
+            emit_mint(e, &treasury, available);
+        }
+    }
+}
+
+/// Sets the treasury to which the supply made available by raising the cap is
+/// minted, see [`set_cap`]. Passing `None` opts out of the automatic mints,
+/// which is the default.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `treasury` - The address receiving the newly available supply, if any.
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute this function.
+pub fn set_cap_treasury(e: &Env, treasury: Option<&Address>) {
+    match treasury {
+        Some(treasury) => e.storage().instance().set(&CAP_TREASURY_KEY, treasury),
+        None => e.storage().instance().remove(&CAP_TREASURY_KEY),
+    }
+}
+
+/// Returns the treasury to which the supply made available by raising the cap
+/// is minted, if any.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+pub fn query_cap_treasury(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&CAP_TREASURY_KEY)
 }
 
 /// Returns the maximum supply of tokens.
@@ -68,3 +136,62 @@ pub fn check_cap(e: &Env, amount: i128) {
         panic_with_error!(e, FungibleTokenError::ExceededCap);
     }
 }
+
+/// Sets the maximum amount of tokens that can be minted in a single call, on
+/// top of the cap on the total supply. Limits the impact of a single
+/// compromised or mistaken mint.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `max` - The maximum amount of tokens mintable in a single call.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::InvalidCap`] - Occurs when `max` is negative.
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute this function.
+pub fn set_max_mint_per_call(e: &Env, max: i128) {
+    if max < 0 {
+        panic_with_error!(e, FungibleTokenError::InvalidCap);
+    }
+    e.storage().instance().set(&MAX_MINT_PER_CALL_KEY, &max);
+}
+
+/// Returns the maximum amount of tokens that can be minted in a single call,
+/// if any.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+pub fn query_max_mint_per_call(e: &Env) -> Option<i128> {
+    e.storage().instance().get(&MAX_MINT_PER_CALL_KEY)
+}
+
+/// Panics if `amount` exceeds the maximum amount of tokens mintable in a
+/// single call. Does nothing if no maximum is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `amount` - The amount of tokens to be minted.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::ExceededMaxMintPerCall`] - Occurs when `amount`
+///   exceeds the maximum.
+///
+/// # Notes
+///
+/// This function is called by [`crate::mintable::mint()`], so there is no
+/// need to call it separately when minting through it.
+pub fn check_max_mint_per_call(e: &Env, amount: i128) {
+    if query_max_mint_per_call(e).is_some_and(|max| amount > max) {
+        panic_with_error!(e, FungibleTokenError::ExceededMaxMintPerCall);
+    }
+}
@@ -6,7 +6,11 @@ use soroban_sdk::{contract, testutils::Address as _, Address, Env};
 
 use crate::{
     extensions::{
-        capped::{check_cap, query_cap, set_cap},
+        capped::{
+            check_cap, query_cap, query_cap_treasury, query_max_mint_per_call, set_cap,
+            set_cap_treasury, set_max_mint_per_call,
+        },
+        mint_rate_limit::set_mint_rate_limit,
         mintable::mint,
     },
     storage::{balance, total_supply},
@@ -99,3 +103,168 @@ fn test_query_cap() {
         assert_eq!(cap, 1000);
     });
 }
+
+#[test]
+fn test_mint_under_max_per_call() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        assert_eq!(query_max_mint_per_call(&e), None);
+        set_max_mint_per_call(&e, 100);
+        assert_eq!(query_max_mint_per_call(&e), Some(100));
+
+        mint(&e, &user, 100);
+        mint(&e, &user, 60);
+
+        assert_eq!(balance(&e, &user), 160);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #222)")]
+fn test_mint_exceeds_max_per_call() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_max_mint_per_call(&e, 100);
+        mint(&e, &user, 101);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #206)")]
+fn test_mint_under_max_per_call_exceeds_cap() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_cap(&e, 150);
+        set_max_mint_per_call(&e, 100);
+
+        check_cap(&e, 100);
+        mint(&e, &user, 100);
+
+        // within the per-call maximum, but over the cap
+        check_cap(&e, 100);
+        mint(&e, &user, 100);
+    });
+}
+
+#[test]
+fn test_raise_cap_mints_delta_to_treasury() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+    let treasury = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_cap_treasury(&e, Some(&treasury));
+        assert_eq!(query_cap_treasury(&e), Some(treasury.clone()));
+
+        // setting the cap for the first time doesn't mint anything
+        set_cap(&e, 1000);
+        assert_eq!(total_supply(&e), 0);
+
+        mint(&e, &user, 600);
+        set_cap(&e, 1500);
+
+        assert_eq!(query_cap(&e), 1500);
+        assert_eq!(balance(&e, &treasury), 500);
+        assert_eq!(total_supply(&e), 1100);
+    });
+}
+
+#[test]
+fn test_raise_cap_mints_delta_regardless_of_mint_limits() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+    let treasury = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_cap_treasury(&e, Some(&treasury));
+        set_cap(&e, 1000);
+        set_max_mint_per_call(&e, 100);
+        set_mint_rate_limit(&e, 100, 10);
+
+        set_cap(&e, 1500);
+
+        assert_eq!(balance(&e, &treasury), 500);
+        assert_eq!(total_supply(&e), 500);
+
+        // the delta didn't consume the mint rate limit
+        mint(&e, &treasury, 100);
+        assert_eq!(total_supply(&e), 600);
+    });
+}
+
+#[test]
+fn test_lower_cap_does_not_mint() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+    let treasury = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_cap_treasury(&e, Some(&treasury));
+        set_cap(&e, 1000);
+        set_cap(&e, 400);
+
+        assert_eq!(query_cap(&e), 400);
+        assert_eq!(balance(&e, &treasury), 0);
+        assert_eq!(total_supply(&e), 0);
+    });
+}
+
+#[test]
+fn test_raise_cap_after_lowering_below_supply_mints_up_to_cap() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+    let treasury = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_cap(&e, 1000);
+        mint(&e, &user, 800);
+
+        set_cap_treasury(&e, Some(&treasury));
+        set_cap(&e, 500);
+        assert_eq!(total_supply(&e), 800);
+
+        // only the supply above the current 800 tokens is made available
+        set_cap(&e, 900);
+        assert_eq!(balance(&e, &treasury), 100);
+        assert_eq!(total_supply(&e), 900);
+
+        // still below the supply, nothing to mint
+        set_cap(&e, 600);
+        set_cap(&e, 850);
+        assert_eq!(total_supply(&e), 900);
+    });
+}
+
+#[test]
+fn test_raise_cap_without_treasury_does_not_mint() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+    let treasury = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_cap(&e, 1000);
+        set_cap(&e, 2000);
+        assert_eq!(total_supply(&e), 0);
+
+        // opting out again after opting in
+        set_cap_treasury(&e, Some(&treasury));
+        set_cap_treasury(&e, None);
+        assert_eq!(query_cap_treasury(&e), None);
+        set_cap(&e, 3000);
+
+        assert_eq!(query_cap(&e), 3000);
+        assert_eq!(balance(&e, &treasury), 0);
+        assert_eq!(total_supply(&e), 0);
+    });
+}
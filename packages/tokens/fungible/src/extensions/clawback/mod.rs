@@ -0,0 +1,42 @@
+/// Unlike other extensions, the `clawback` extension does not provide a
+/// separate trait. It allows an issuer of a regulated token to take back the
+/// tokens held by an account. Only the accounts explicitly enabled for
+/// clawback, typically at onboarding, are subject to it.
+///
+/// This module provides the following helper functions:
+/// - `enable_clawback`: Makes an account subject to clawback.
+/// - `disable_clawback`: Exempts an account from clawback.
+/// - `is_clawback_enabled`: Returns whether an account is subject to clawback.
+/// - `clawback_enabled_accounts`: Lists the accounts subject to clawback, page
+///   by page.
+/// - `clawback`: Destroys tokens held by an account enabled for clawback.
+/// - `force_transfer`: Moves tokens held by an account enabled for clawback to
+///   another account, without its approval.
+mod storage;
+use soroban_sdk::{symbol_short, Address, Env};
+
+pub use self::storage::{
+    clawback, clawback_enabled_accounts, disable_clawback, enable_clawback, force_transfer,
+    is_clawback_enabled, CLAWBACK_ENABLED_KEY,
+};
+
+mod test;
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating tokens were clawed back from an account.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The account whose tokens were clawed back.
+/// * `amount` - The amount of tokens clawed back.
+///
+/// # Events
+///
+/// * topics - `["clawback", from: Address]`
+/// * data - `[amount: i128]`
+pub fn emit_clawback(e: &Env, from: &Address, amount: i128) {
+    let topics = (symbol_short!("clawback"), from);
+    e.events().publish(topics, amount)
+}
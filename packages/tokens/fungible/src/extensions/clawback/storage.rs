@@ -0,0 +1,147 @@
+use soroban_sdk::{panic_with_error, symbol_short, Address, Env, Symbol, Vec};
+use stellar_constants::{BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD};
+
+use crate::{
+    emit_transfer, extensions::clawback::emit_clawback, storage::update, FungibleTokenError,
+};
+
+/// Identifies the set of the accounts enabled for clawback.
+pub const CLAWBACK_ENABLED_KEY: Symbol = symbol_short!("CLAWBACK");
+
+/// Returns `true` if `account` is subject to clawback, and `false` otherwise.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address to check.
+pub fn is_clawback_enabled(e: &Env, account: &Address) -> bool {
+    stellar_address_set::contains(e, &CLAWBACK_ENABLED_KEY, account) && {
+        stellar_address_set::extend_ttl(
+            e,
+            &CLAWBACK_ENABLED_KEY,
+            account,
+            BALANCE_TTL_THRESHOLD,
+            BALANCE_EXTEND_AMOUNT,
+        );
+        true
+    }
+}
+
+/// Returns up to `limit` of the accounts enabled for clawback, starting at
+/// position `offset`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `offset` - The position of the first account to return.
+/// * `limit` - The maximum number of accounts to return.
+pub fn clawback_enabled_accounts(e: &Env, offset: u32, limit: u32) -> Vec<Address> {
+    stellar_address_set::list(e, &CLAWBACK_ENABLED_KEY, offset, limit)
+}
+
+/// Makes `account` subject to clawback.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address to enable clawback for.
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute this function.
+pub fn enable_clawback(e: &Env, account: &Address) {
+    stellar_address_set::add(e, &CLAWBACK_ENABLED_KEY, account);
+}
+
+/// Exempts `account` from clawback.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address to disable clawback for.
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute this function.
+pub fn disable_clawback(e: &Env, account: &Address) {
+    stellar_address_set::remove(e, &CLAWBACK_ENABLED_KEY, account);
+}
+
+/// Destroys `amount` of tokens held by `from`, which must be enabled for
+/// clawback. Updates the total supply accordingly.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The account whose tokens are clawed back.
+/// * `amount` - The amount of tokens to claw back.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::ClawbackNotEnabled`] - When `from` is not enabled
+///   for clawback.
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["clawback", from: Address]`
+/// * data - `[amount: i128]`
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts (e.g. the issuer) can
+/// execute this function.
+pub fn clawback(e: &Env, from: &Address, amount: i128) {
+    if !is_clawback_enabled(e, from) {
+        panic_with_error!(e, FungibleTokenError::ClawbackNotEnabled);
+    }
+    update(e, Some(from), None, amount);
+    emit_clawback(e, from, amount);
+}
+
+/// Moves `amount` of tokens from `from`, which must be enabled for clawback, to
+/// `to`, without the approval of `from`. Unlike [`clawback`], the total supply
+/// is left unchanged, e.g. to move the tokens of a sanctioned account to a
+/// custody account.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The account whose tokens are moved.
+/// * `to` - The account receiving the tokens.
+/// * `amount` - The amount of tokens to move.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::ClawbackNotEnabled`] - When `from` is not enabled
+///   for clawback.
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["transfer", from: Address, to: Address]`
+/// * data - `[amount: i128]`
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// The authorization of `from` is NOT required. It is the responsibility of
+/// the implementer to gate this function with the admin check of the contract,
+/// so that only authorized accounts (e.g. the issuer) can execute it.
+pub fn force_transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
+    if !is_clawback_enabled(e, from) {
+        panic_with_error!(e, FungibleTokenError::ClawbackNotEnabled);
+    }
+    update(e, Some(from), Some(to), amount);
+    emit_transfer(e, from, to, amount);
+}
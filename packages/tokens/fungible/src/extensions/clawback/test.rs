@@ -0,0 +1,163 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, vec, Address, Env};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    extensions::{
+        clawback::{
+            clawback, clawback_enabled_accounts, disable_clawback, enable_clawback, force_transfer,
+            is_clawback_enabled,
+        },
+        mintable::mint,
+    },
+    storage::{balance, total_supply},
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn clawback_enabled_account_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 100);
+        enable_clawback(&e, &account);
+        assert!(is_clawback_enabled(&e, &account));
+    });
+
+    e.as_contract(&address, || {
+        clawback(&e, &account, 40);
+
+        assert_eq!(balance(&e, &account), 60);
+        assert_eq!(total_supply(&e), 60);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #215)")]
+fn clawback_not_enabled_account_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 100);
+        assert!(!is_clawback_enabled(&e, &account));
+        clawback(&e, &account, 40);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #215)")]
+fn clawback_after_disable_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 100);
+        enable_clawback(&e, &account);
+        clawback(&e, &account, 40);
+
+        disable_clawback(&e, &account);
+        assert!(!is_clawback_enabled(&e, &account));
+        clawback(&e, &account, 40);
+    });
+}
+
+#[test]
+fn clawback_enabled_accounts_lists_accounts() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let carol = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        enable_clawback(&e, &alice);
+        enable_clawback(&e, &bob);
+        enable_clawback(&e, &carol);
+        disable_clawback(&e, &alice);
+
+        assert_eq!(clawback_enabled_accounts(&e, 0, 10), vec![&e, carol.clone(), bob.clone()]);
+        assert_eq!(clawback_enabled_accounts(&e, 1, 10), vec![&e, bob.clone()]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #200)")]
+fn clawback_insufficient_balance_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 100);
+        enable_clawback(&e, &account);
+        clawback(&e, &account, 101);
+    });
+}
+
+#[test]
+fn force_transfer_works_without_holder_auth() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+    let custody = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 100);
+        enable_clawback(&e, &account);
+    });
+
+    e.as_contract(&address, || {
+        force_transfer(&e, &account, &custody, 70);
+
+        assert_eq!(balance(&e, &account), 30);
+        assert_eq!(balance(&e, &custody), 70);
+        assert_eq!(total_supply(&e), 100);
+        assert!(e.auths().is_empty());
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_fungible_transfer(&account, &custody, 70);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #200)")]
+fn force_transfer_insufficient_balance_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+    let custody = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 100);
+        enable_clawback(&e, &account);
+        force_transfer(&e, &account, &custody, 101);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #215)")]
+fn force_transfer_not_enabled_account_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+    let custody = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 100);
+        force_transfer(&e, &account, &custody, 40);
+    });
+}
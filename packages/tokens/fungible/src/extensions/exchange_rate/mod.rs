@@ -0,0 +1,48 @@
+/// Unlike other extensions, the `exchange_rate` extension does not provide a
+/// separate trait for the token. It turns the token into an interest-bearing
+/// token (like aTokens), whose balances grow through an exchange rate rather
+/// than through transfers.
+///
+/// Once the extension is used, the balances and the total supply kept by the
+/// base module are interpreted as *principal*, which transfers, mints and
+/// burns keep operating on. The displayed balance of an account is its
+/// principal multiplied by the current exchange rate, as reported by an
+/// [`ExchangeRateProvider`] contract.
+///
+/// This module provides the following helper functions:
+/// - `set_exchange_rate_provider`: Sets the contract reporting the rate.
+/// - `exchange_rate_provider`: Returns the contract reporting the rate.
+/// - `exchange_rate`: Returns the current exchange rate.
+/// - `balance`: Returns the displayed balance of an account.
+/// - `total_supply`: Returns the displayed total supply.
+///
+/// The exchange rate is a fixed-point number with [`EXCHANGE_RATE_SCALE`] as
+/// its unit, i.e. a rate of `EXCHANGE_RATE_SCALE` displays the principal as
+/// is.
+///
+/// **IMPORTANT**: When using this extension, the `balance` and `total_supply`
+/// entry-points of the token must return the displayed amounts of this module
+/// rather than `crate::balance()` and `crate::total_supply()`, while amounts
+/// passed to transfers, mints and burns remain in principal units.
+mod storage;
+use soroban_sdk::{contractclient, Env};
+
+pub use self::storage::{
+    balance, exchange_rate, exchange_rate_provider, set_exchange_rate_provider, total_supply,
+    EXCHANGE_RATE_PROVIDER_KEY, EXCHANGE_RATE_SCALE,
+};
+
+mod test;
+
+/// Interface of the contracts reporting the exchange rate used by this
+/// extension.
+#[contractclient(name = "ExchangeRateProviderClient")]
+pub trait ExchangeRateProvider {
+    /// Returns the current exchange rate between the principal and the
+    /// displayed amounts, scaled by [`EXCHANGE_RATE_SCALE`].
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    fn exchange_rate(e: Env) -> i128;
+}
@@ -0,0 +1,97 @@
+use soroban_sdk::{panic_with_error, symbol_short, Address, Env, Symbol};
+
+use crate::{exchange_rate::ExchangeRateProviderClient, math, FungibleTokenError};
+
+/// Storage key for the address of the exchange rate provider
+pub const EXCHANGE_RATE_PROVIDER_KEY: Symbol = symbol_short!("RATEPROV");
+
+/// The unit of the exchange rate, i.e. the rate at which the displayed amounts
+/// equal the principal.
+pub const EXCHANGE_RATE_SCALE: i128 = 10_000_000;
+
+/// Returns the address of the contract reporting the exchange rate.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::ExchangeRateProviderNotSet`] - Occurs when the
+///   provider has not been set.
+pub fn exchange_rate_provider(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&EXCHANGE_RATE_PROVIDER_KEY)
+        .unwrap_or_else(|| panic_with_error!(e, FungibleTokenError::ExchangeRateProviderNotSet))
+}
+
+/// Returns the current exchange rate, as reported by the provider.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::InvalidExchangeRate`] - When the reported rate is
+///   not positive.
+/// * refer to [`exchange_rate_provider`] errors.
+pub fn exchange_rate(e: &Env) -> i128 {
+    let rate = ExchangeRateProviderClient::new(e, &exchange_rate_provider(e)).exchange_rate();
+    if rate <= 0 {
+        panic_with_error!(e, FungibleTokenError::InvalidExchangeRate);
+    }
+    rate
+}
+
+/// Returns the displayed balance of `account`, i.e. its principal multiplied
+/// by the current exchange rate, rounding down.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `account` - The address for which the balance is being queried.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::MathOverflow`] - When the displayed balance can't be
+///   computed without overflowing.
+/// * refer to [`exchange_rate`] errors.
+pub fn balance(e: &Env, account: &Address) -> i128 {
+    math::mul_div(e, crate::balance(e, account), exchange_rate(e), EXCHANGE_RATE_SCALE)
+}
+
+/// Returns the displayed total supply, i.e. the total principal multiplied by
+/// the current exchange rate, rounding down.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::MathOverflow`] - When the displayed total supply
+///   can't be computed without overflowing.
+/// * refer to [`exchange_rate`] errors.
+pub fn total_supply(e: &Env) -> i128 {
+    math::mul_div(e, crate::total_supply(e), exchange_rate(e), EXCHANGE_RATE_SCALE)
+}
+
+/// Sets the contract reporting the exchange rate.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `provider` - The contract implementing
+///   [`crate::exchange_rate::ExchangeRateProvider`].
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute this function.
+pub fn set_exchange_rate_provider(e: &Env, provider: &Address) {
+    e.storage().instance().set(&EXCHANGE_RATE_PROVIDER_KEY, provider);
+}
@@ -0,0 +1,121 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, contractimpl, symbol_short, testutils::Address as _, Address, Env};
+
+use crate::{
+    extensions::{
+        exchange_rate::{
+            balance, exchange_rate, set_exchange_rate_provider, total_supply, EXCHANGE_RATE_SCALE,
+        },
+        mintable::mint,
+    },
+    transfer,
+};
+
+#[contract]
+struct MockContract;
+
+#[contract]
+struct RateProvider;
+
+#[contractimpl]
+impl RateProvider {
+    pub fn __constructor(e: &Env, rate: i128) {
+        e.storage().instance().set(&symbol_short!("rate"), &rate);
+    }
+
+    pub fn set_rate(e: &Env, rate: i128) {
+        e.storage().instance().set(&symbol_short!("rate"), &rate);
+    }
+
+    pub fn exchange_rate(e: &Env) -> i128 {
+        e.storage().instance().get(&symbol_short!("rate")).unwrap()
+    }
+}
+
+#[test]
+fn balance_grows_with_exchange_rate() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let provider = e.register(RateProvider, (EXCHANGE_RATE_SCALE,));
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_exchange_rate_provider(&e, &provider);
+        mint(&e, &account, 1000);
+
+        assert_eq!(exchange_rate(&e), EXCHANGE_RATE_SCALE);
+        assert_eq!(balance(&e, &account), 1000);
+        assert_eq!(total_supply(&e), 1000);
+    });
+
+    // 5% interest accrued
+    RateProviderClient::new(&e, &provider).set_rate(&(EXCHANGE_RATE_SCALE * 105 / 100));
+
+    e.as_contract(&address, || {
+        assert_eq!(balance(&e, &account), 1050);
+        assert_eq!(total_supply(&e), 1050);
+        // the principal is unchanged
+        assert_eq!(crate::balance(&e, &account), 1000);
+        assert_eq!(crate::total_supply(&e), 1000);
+    });
+}
+
+#[test]
+fn transfers_operate_on_principal() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let provider = e.register(RateProvider, (EXCHANGE_RATE_SCALE * 3 / 2,));
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_exchange_rate_provider(&e, &provider);
+        mint(&e, &from, 1000);
+    });
+
+    e.as_contract(&address, || {
+        transfer(&e, &from, &to, 333);
+
+        assert_eq!(crate::balance(&e, &from), 667);
+        assert_eq!(crate::balance(&e, &to), 333);
+        assert_eq!(balance(&e, &from), 1000);
+        assert_eq!(balance(&e, &to), 499);
+    });
+
+    RateProviderClient::new(&e, &provider).set_rate(&(EXCHANGE_RATE_SCALE * 2));
+
+    e.as_contract(&address, || {
+        assert_eq!(balance(&e, &from), 1334);
+        assert_eq!(balance(&e, &to), 666);
+        assert_eq!(total_supply(&e), 2000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #234)")]
+fn provider_not_set_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        balance(&e, &account);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #235)")]
+fn non_positive_exchange_rate_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let provider = e.register(RateProvider, (0_i128,));
+
+    e.as_contract(&address, || {
+        set_exchange_rate_provider(&e, &provider);
+        exchange_rate(&e);
+    });
+}
@@ -0,0 +1,43 @@
+/// Unlike other extensions, the `fee_on_transfer` extension does not provide a
+/// separate trait. It skims a fee, expressed in basis points, from every
+/// transfer. The fees are held by the token contract itself and tracked by a
+/// [`FeeAccumulator`], until they are withdrawn by the protocol.
+///
+/// This module provides the following helper functions:
+/// - `set_transfer_fee`: Sets the fee rate, in basis points.
+/// - `transfer_fee`: Returns the fee rate, in basis points.
+/// - `fee_accumulator`: Returns the accrued and withdrawn fees.
+/// - `transfer` and `transfer_from`: Counterparts of the base functions
+///   skimming the fee from the transferred amount.
+/// - `withdraw_fees`: Transfers the accrued fees and resets the accumulator.
+///
+/// **IMPORTANT**: When using this extension, the base `crate::transfer()` and
+/// `crate::transfer_from()` must NOT be exposed, as they would bypass the fee.
+mod storage;
+use soroban_sdk::{symbol_short, Address, Env};
+
+pub use self::storage::{
+    fee_accumulator, set_transfer_fee, transfer, transfer_fee, transfer_from, withdraw_fees,
+    FeeAccumulator, FEE_ACCUMULATOR_KEY, FEE_DENOMINATOR, TRANSFER_FEE_KEY,
+};
+
+mod test;
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating a fee was collected on a transfer.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address paying the fee.
+/// * `fee` - The amount of tokens collected.
+///
+/// # Events
+///
+/// * topics - `["fee", from: Address]`
+/// * data - `[fee: i128]`
+pub fn emit_fee_collected(e: &Env, from: &Address, fee: i128) {
+    let topics = (symbol_short!("fee"), from);
+    e.events().publish(topics, fee)
+}
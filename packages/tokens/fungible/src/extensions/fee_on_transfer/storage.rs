@@ -0,0 +1,202 @@
+use soroban_sdk::{contracttype, panic_with_error, symbol_short, Address, Env, Symbol};
+
+use crate::{
+    emit_transfer, extensions::fee_on_transfer::emit_fee_collected, math, spend_allowance, update,
+    FungibleTokenError,
+};
+
+/// Storage key for the fee rate
+pub const TRANSFER_FEE_KEY: Symbol = symbol_short!("FEE_BPS");
+
+/// Storage key for the [`FeeAccumulator`]
+pub const FEE_ACCUMULATOR_KEY: Symbol = symbol_short!("FEE_ACC");
+
+/// The denominator of the fee rate, i.e. a rate of `FEE_DENOMINATOR` basis
+/// points takes the whole transferred amount.
+pub const FEE_DENOMINATOR: u32 = 10_000;
+
+/// Tracks the fees collected by the token.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FeeAccumulator {
+    /// The fees collected and not yet withdrawn.
+    pub accrued: i128,
+    /// The fees withdrawn so far.
+    pub withdrawn: i128,
+}
+
+/// Returns the fee rate, in basis points. Defaults to `0`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+pub fn transfer_fee(e: &Env) -> u32 {
+    e.storage().instance().get(&TRANSFER_FEE_KEY).unwrap_or(0)
+}
+
+/// Returns the [`FeeAccumulator`] of the token.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+pub fn fee_accumulator(e: &Env) -> FeeAccumulator {
+    e.storage().instance().get(&FEE_ACCUMULATOR_KEY).unwrap_or_default()
+}
+
+/// Sets the fee rate, in basis points, skimmed from every transfer.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `fee_basis_points` - The fee rate, in basis points.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::InvalidFee`] - When `fee_basis_points` is greater
+///   than [`FEE_DENOMINATOR`].
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute this function.
+pub fn set_transfer_fee(e: &Env, fee_basis_points: u32) {
+    if fee_basis_points > FEE_DENOMINATOR {
+        panic_with_error!(e, FungibleTokenError::InvalidFee);
+    }
+    e.storage().instance().set(&TRANSFER_FEE_KEY, &fee_basis_points);
+}
+
+/// Transfers `amount` of tokens from `from` to `to`, minus the fee, which
+/// accrues in the [`FeeAccumulator`].
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `amount` - The amount of tokens debited from `from`, fee included.
+///
+/// # Errors
+///
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["transfer", from: Address, to: Address]`
+/// * data - `[amount: i128]`
+///
+/// * topics - `["fee", from: Address]`
+/// * data - `[fee: i128]`
+///
+/// # Notes
+///
+/// * Authorization for `from` is required.
+/// * The fee rounds down, and the `transfer` event carries the amount received
+///   by `to`.
+pub fn transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
+    from.require_auth();
+    transfer_with_fee(e, from, to, amount);
+}
+
+/// Transfers `amount` of tokens from `from` to `to` using the allowance
+/// mechanism, minus the fee, which accrues in the [`FeeAccumulator`]. The
+/// allowance is consumed by the whole `amount`, fee included.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `spender` - The address authorizing the transfer, and having its allowance
+///   consumed during the transfer.
+/// * `from` - The address holding the tokens which will be transferred.
+/// * `to` - The address receiving the transferred tokens.
+/// * `amount` - The amount of tokens debited from `from`, fee included.
+///
+/// # Errors
+///
+/// * refer to [`spend_allowance`] errors.
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["transfer", from: Address, to: Address]`
+/// * data - `[amount: i128]`
+///
+/// * topics - `["fee", from: Address]`
+/// * data - `[fee: i128]`
+///
+/// # Notes
+///
+/// Authorization for `spender` is required.
+pub fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, amount: i128) {
+    spender.require_auth();
+    spend_allowance(e, from, spender, amount);
+    transfer_with_fee(e, from, to, amount);
+}
+
+/// Transfers all the accrued fees to `to` and resets the accrued amount of the
+/// [`FeeAccumulator`]. Returns the amount withdrawn.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `admin` - The address authorizing the withdrawal.
+/// * `to` - The address receiving the fees.
+///
+/// # Errors
+///
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["transfer", contract: Address, to: Address]`
+/// * data - `[amount: i128]`
+///
+/// # Notes
+///
+/// Authorization for `admin` is required.
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function does not check who `admin` is ⚠️
+///
+/// It is the responsibility of the implementer to ensure that `admin` is an
+/// account allowed to withdraw the fees.
+pub fn withdraw_fees(e: &Env, admin: &Address, to: &Address) -> i128 {
+    admin.require_auth();
+
+    let mut accumulator = fee_accumulator(e);
+    let amount = accumulator.accrued;
+    let contract = e.current_contract_address();
+    update(e, Some(&contract), Some(to), amount);
+
+    accumulator.accrued = 0;
+    accumulator.withdrawn = math::add(e, accumulator.withdrawn, amount);
+    e.storage().instance().set(&FEE_ACCUMULATOR_KEY, &accumulator);
+
+    emit_transfer(e, &contract, to, amount);
+    amount
+}
+
+// ################## INTERNAL HELPERS ##################
+
+/// Moves `amount` minus the fee from `from` to `to`, and the fee to the token
+/// contract, accruing it in the [`FeeAccumulator`].
+fn transfer_with_fee(e: &Env, from: &Address, to: &Address, amount: i128) {
+    let fee = math::mul_div(e, amount, transfer_fee(e) as i128, FEE_DENOMINATOR as i128);
+    let net_amount = math::sub(e, amount, fee);
+
+    update(e, Some(from), Some(to), net_amount);
+    emit_transfer(e, from, to, net_amount);
+
+    if fee > 0 {
+        update(e, Some(from), Some(&e.current_contract_address()), fee);
+
+        let mut accumulator = fee_accumulator(e);
+        accumulator.accrued = math::add(e, accumulator.accrued, fee);
+        e.storage().instance().set(&FEE_ACCUMULATOR_KEY, &accumulator);
+
+        emit_fee_collected(e, from, fee);
+    }
+}
@@ -0,0 +1,104 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    extensions::{
+        fee_on_transfer::{
+            fee_accumulator, set_transfer_fee, transfer, transfer_from, withdraw_fees,
+            FeeAccumulator,
+        },
+        mintable::mint,
+    },
+    storage::{approve, balance, total_supply},
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn fees_accrue_over_transfers() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &alice, 10_000);
+        set_transfer_fee(&e, 100);
+    });
+
+    e.as_contract(&address, || {
+        transfer(&e, &alice, &bob, 1_000);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+        event_assert.assert_fungible_transfer(&alice, &bob, 990);
+    });
+
+    e.as_contract(&address, || {
+        transfer(&e, &bob, &alice, 500);
+    });
+
+    e.as_contract(&address, || {
+        approve(&e, &alice, &bob, 2_000, 1000);
+        transfer_from(&e, &bob, &alice, &bob, 2_000);
+
+        assert_eq!(fee_accumulator(&e), FeeAccumulator { accrued: 35, withdrawn: 0 });
+        assert_eq!(balance(&e, &address), 35);
+        assert_eq!(balance(&e, &alice), 10_000 - 1_000 + 495 - 2_000);
+        assert_eq!(balance(&e, &bob), 990 - 500 + 1_980);
+        assert_eq!(total_supply(&e), 10_000);
+    });
+}
+
+#[test]
+fn withdraw_fees_resets_accumulator() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let admin = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &alice, 10_000);
+        set_transfer_fee(&e, 250);
+        transfer(&e, &alice, &bob, 4_000);
+    });
+
+    e.as_contract(&address, || {
+        assert_eq!(withdraw_fees(&e, &admin, &treasury), 100);
+
+        assert_eq!(fee_accumulator(&e), FeeAccumulator { accrued: 0, withdrawn: 100 });
+        assert_eq!(balance(&e, &treasury), 100);
+        assert_eq!(balance(&e, &address), 0);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_fungible_transfer(&address, &treasury, 100);
+    });
+
+    e.as_contract(&address, || {
+        transfer(&e, &bob, &alice, 1_000);
+        assert_eq!(withdraw_fees(&e, &admin, &treasury), 25);
+        assert_eq!(fee_accumulator(&e), FeeAccumulator { accrued: 0, withdrawn: 125 });
+        assert_eq!(balance(&e, &treasury), 125);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #221)")]
+fn set_transfer_fee_above_denominator_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_transfer_fee(&e, 10_001);
+    });
+}
@@ -0,0 +1,71 @@
+/// Unlike other extensions, the `flash_mint` extension does not provide a
+/// separate trait for the token. It lends freshly minted tokens to a receiver
+/// contract for the duration of a callback: the borrowed tokens are minted to
+/// the receiver, which must hold them, plus the fee, by the end of the
+/// callback so that they can be burned.
+///
+/// # Limitation
+///
+/// This is NOT an implementation of ERC-3156 flash loans, and can't serve as
+/// one on Soroban. The host forbids re-entering a contract, and the token stays
+/// on the call stack for the whole callback. Neither the receiver nor any
+/// contract it calls can therefore invoke the token during
+/// [`FlashLoanReceiver::on_flash_loan`]: the borrowed tokens can't be
+/// transferred, approved or even queried, and the fee can't be paid in this
+/// token during the loan either. The receiver can only hold the borrowed
+/// tokens, and must already hold the fee when the loan starts.
+///
+/// This module provides the following helper functions:
+/// - `flash_loan`: Mints `amount` to `receiver`, invokes its
+///   [`FlashLoanReceiver::on_flash_loan`] callback and burns `amount + fee`
+///   back.
+/// - `max_flash_loan`: Returns the maximum amount of tokens that can be
+///   borrowed.
+/// - `flash_fee`: Returns the fee charged for borrowing `amount`.
+/// - `set_flash_fee`: Sets the fee rate, in basis points. Defaults to `0`.
+///
+/// The fee is burned along with the borrowed amount.
+mod storage;
+use soroban_sdk::{contractclient, symbol_short, Address, Bytes, Env};
+
+pub use self::storage::{flash_fee, flash_loan, max_flash_loan, set_flash_fee, FLASH_FEE_KEY};
+
+mod test;
+
+/// Interface of the contracts borrowing tokens with [`flash_loan`].
+#[contractclient(name = "FlashLoanReceiverClient")]
+pub trait FlashLoanReceiver {
+    /// Called by the token once `amount` has been minted to the receiver. By
+    /// the time it returns, the receiver must hold `amount + fee`. The token
+    /// can't be invoked from this callback, see the module docs.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `amount` - The amount of tokens borrowed.
+    /// * `fee` - The fee to be paid on top of `amount`.
+    /// * `data` - Arbitrary data forwarded from [`flash_loan`].
+    ///
+    /// Returns `true` to accept the loan. Any other outcome reverts it.
+    fn on_flash_loan(e: Env, amount: i128, fee: i128, data: Bytes) -> bool;
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating a flash loan has been repaid.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `receiver` - The address that borrowed the tokens.
+/// * `amount` - The amount of tokens borrowed.
+/// * `fee` - The fee paid on top of `amount`.
+///
+/// # Events
+///
+/// * topics - `["flash", receiver: Address]`
+/// * data - `[amount: i128, fee: i128]`
+pub fn emit_flash_loan(e: &Env, receiver: &Address, amount: i128, fee: i128) {
+    let topics = (symbol_short!("flash"), receiver);
+    e.events().publish(topics, (amount, fee))
+}
@@ -0,0 +1,114 @@
+use soroban_sdk::{panic_with_error, symbol_short, Address, Bytes, Env, Symbol};
+
+use crate::{
+    extensions::flash_mint::{emit_flash_loan, FlashLoanReceiverClient},
+    math,
+    storage::{balance, total_supply, update},
+    FungibleTokenError,
+};
+
+/// Storage key for the flash fee rate
+pub const FLASH_FEE_KEY: Symbol = symbol_short!("FLASHFEE");
+
+/// The denominator of the flash fee rate, in basis points.
+const FEE_DENOMINATOR: u32 = 10_000;
+
+/// Returns the maximum amount of tokens that can be borrowed, i.e. the amount
+/// that can be minted without overflowing the total supply.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+pub fn max_flash_loan(e: &Env) -> i128 {
+    i128::MAX - total_supply(e)
+}
+
+/// Returns the fee charged for borrowing `amount` of tokens. Defaults to `0`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `amount` - The amount of tokens to borrow.
+pub fn flash_fee(e: &Env, amount: i128) -> i128 {
+    let rate: u32 = e.storage().instance().get(&FLASH_FEE_KEY).unwrap_or(0);
+    if rate == 0 {
+        return 0;
+    }
+    math::mul_div(e, amount, rate as i128, FEE_DENOMINATOR as i128)
+}
+
+/// Sets the flash fee rate, in basis points of the borrowed amount.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `fee_basis_points` - The fee rate, in basis points.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::InvalidFee`] - When `fee_basis_points` is greater
+///   than `10_000`.
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute this function.
+pub fn set_flash_fee(e: &Env, fee_basis_points: u32) {
+    if fee_basis_points > FEE_DENOMINATOR {
+        panic_with_error!(e, FungibleTokenError::InvalidFee);
+    }
+    e.storage().instance().set(&FLASH_FEE_KEY, &fee_basis_points);
+}
+
+/// Lends `amount` of tokens to `receiver` for the duration of its
+/// [`crate::flash_mint::FlashLoanReceiver::on_flash_loan`] callback. The
+/// tokens are minted before the callback, then `amount` plus the fee are
+/// burned from `receiver`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `receiver` - The contract borrowing the tokens.
+/// * `amount` - The amount of tokens to borrow.
+/// * `data` - Arbitrary data forwarded to the callback.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::ExceededMaxFlashLoan`] - When `amount` is greater
+///   than [`max_flash_loan`].
+/// * [`FungibleTokenError::FlashLoanNotRepaid`] - When the callback doesn't
+///   return `true` or `receiver` doesn't hold `amount` plus the fee after it.
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["flash", receiver: Address]`
+/// * data - `[amount: i128, fee: i128]`
+///
+/// # Notes
+///
+/// No authorization is required, as the loan is repaid within the same
+/// invocation. As a contract can't be re-entered, neither `receiver` nor any
+/// contract it calls can invoke this token from the callback, so the borrowed
+/// tokens can't be used and the fee must be held by `receiver` beforehand. See
+/// the limitation in the [`crate::flash_mint`] module docs.
+pub fn flash_loan(e: &Env, receiver: &Address, amount: i128, data: Bytes) {
+    if amount > max_flash_loan(e) {
+        panic_with_error!(e, FungibleTokenError::ExceededMaxFlashLoan);
+    }
+    let fee = flash_fee(e, amount);
+
+    update(e, None, Some(receiver), amount);
+
+    let accepted =
+        FlashLoanReceiverClient::new(e, receiver).try_on_flash_loan(&amount, &fee, &data);
+    let repayment = math::add(e, amount, fee);
+    if !matches!(accepted, Ok(Ok(true))) || balance(e, receiver) < repayment {
+        panic_with_error!(e, FungibleTokenError::FlashLoanNotRepaid);
+    }
+
+    update(e, Some(receiver), None, repayment);
+    emit_flash_loan(e, receiver, amount, fee);
+}
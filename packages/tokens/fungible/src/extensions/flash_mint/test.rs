@@ -0,0 +1,117 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, Address, Bytes, Env,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    extensions::{
+        flash_mint::{flash_fee, flash_loan, max_flash_loan, set_flash_fee},
+        mintable::mint,
+    },
+    storage::{balance, total_supply},
+};
+
+#[contract]
+struct MockContract;
+
+#[contractimpl]
+impl MockContract {
+    pub fn flash_loan(e: &Env, receiver: Address, amount: i128, data: Bytes) {
+        flash_loan(e, &receiver, amount, data);
+    }
+}
+
+#[contract]
+struct Borrower;
+
+#[contractimpl]
+impl Borrower {
+    pub fn __constructor(e: &Env, accept: bool) {
+        e.storage().instance().set(&symbol_short!("accept"), &accept);
+    }
+
+    pub fn on_flash_loan(e: &Env, amount: i128, fee: i128, _data: Bytes) -> bool {
+        e.storage().instance().set(&symbol_short!("loan"), &(amount, fee));
+        e.storage().instance().get(&symbol_short!("accept")).unwrap()
+    }
+}
+
+#[test]
+fn flash_loan_repaid_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let borrower = e.register(Borrower, (true,));
+
+    e.as_contract(&address, || {
+        set_flash_fee(&e, 100);
+        assert_eq!(flash_fee(&e, 1000), 10);
+        // the borrower holds the fee beforehand
+        mint(&e, &borrower, 10);
+    });
+
+    client.flash_loan(&borrower, &1000, &Bytes::new(&e));
+
+    let event_assert = EventAssertion::new(&e, address.clone());
+    event_assert.assert_event_count(1);
+    event_assert.assert_event_from(&address, "flash");
+
+    e.as_contract(&borrower, || {
+        let loan: (i128, i128) = e.storage().instance().get(&symbol_short!("loan")).unwrap();
+        assert_eq!(loan, (1000, 10));
+    });
+    e.as_contract(&address, || {
+        assert_eq!(balance(&e, &borrower), 0);
+        assert_eq!(total_supply(&e), 0);
+    });
+}
+
+#[test]
+fn flash_loan_not_repaid_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let borrower = e.register(Borrower, (true,));
+
+    e.as_contract(&address, || set_flash_fee(&e, 100));
+
+    // the borrower can't pay the fee
+    assert!(client.try_flash_loan(&borrower, &1000, &Bytes::new(&e)).is_err());
+
+    e.as_contract(&address, || {
+        assert_eq!(balance(&e, &borrower), 0);
+        assert_eq!(total_supply(&e), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #226)")]
+fn flash_loan_rejected_by_receiver_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let borrower = e.register(Borrower, (false,));
+
+    client.flash_loan(&borrower, &1000, &Bytes::new(&e));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #227)")]
+fn flash_loan_exceeding_max_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let borrower = e.register(Borrower, (true,));
+    let holder = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &holder, 1);
+        assert_eq!(max_flash_loan(&e), i128::MAX - 1);
+    });
+
+    client.flash_loan(&borrower, &i128::MAX, &Bytes::new(&e));
+}
@@ -0,0 +1,30 @@
+/// Unlike other extensions, the `meta_transfer` extension does not provide a
+/// separate trait. It enables gasless transfers: the holder signs a transfer
+/// off-chain and a relayer submits it on-chain with `meta_transfer`.
+///
+/// The signature is an ed25519 signature from `from` over the payload returned
+/// by `meta_transfer_payload`, which binds the contract address, the sender,
+/// the recipient, the amount, the sender's current nonce and a deadline
+/// ledger after which the transfer can no longer be submitted. Every
+/// meta-transfer consumes the nonce, so it cannot be replayed.
+///
+/// Only Stellar accounts (`G...` addresses) can sign meta-transfers, as their
+/// address is their ed25519 public key.
+///
+/// **IMPORTANT**: Only the master key of an account can sign meta-transfers,
+/// and the signers and thresholds configured on the account are ignored. An
+/// account which disabled its master key, or which relies on multisig, keeps
+/// being spendable with its master key through `meta_transfer`.
+///
+/// This module provides the following helper functions:
+/// - `meta_transfer_nonce`: Returns the nonce the next meta-transfer of an
+///   account must be signed with.
+/// - `meta_transfer_payload`: Returns the payload to sign.
+/// - `meta_transfer`: Verifies the signature, consumes the nonce and transfers
+///   the tokens.
+mod storage;
+pub use self::storage::{
+    meta_transfer, meta_transfer_nonce, meta_transfer_payload, MetaTransferStorageKey,
+};
+
+mod test;
@@ -0,0 +1,132 @@
+use soroban_sdk::{
+    contracttype, panic_with_error, symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env,
+};
+use stellar_crypto::signature::{account_public_key, verify_ed25519};
+
+use crate::{emit_transfer, update, FungibleTokenError};
+
+/// Storage keys for the data associated with the meta-transfer extension
+#[contracttype]
+pub enum MetaTransferStorageKey {
+    Nonce(Address),
+}
+
+/// Returns the nonce the next meta-transfer of `account` must be signed with.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address signing the meta-transfers.
+pub fn meta_transfer_nonce(e: &Env, account: &Address) -> u32 {
+    e.storage().persistent().get(&MetaTransferStorageKey::Nonce(account.clone())).unwrap_or(0)
+}
+
+/// Returns the payload that must be signed to authorize a meta-transfer. It is
+/// the XDR encoding of the tuple `("meta_xfer", contract_address, from, to,
+/// amount, nonce, deadline)`. The leading `"meta_xfer"` symbol separates it
+/// from the payloads of other signed operations, such as permits.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `amount` - The amount of tokens to be transferred.
+/// * `nonce` - The nonce of `from`.
+/// * `deadline` - The last ledger number at which the transfer can happen.
+pub fn meta_transfer_payload(
+    e: &Env,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    nonce: u32,
+    deadline: u32,
+) -> Bytes {
+    (
+        symbol_short!("meta_xfer"),
+        e.current_contract_address(),
+        from.clone(),
+        to.clone(),
+        amount,
+        nonce,
+        deadline,
+    )
+        .to_xdr(e)
+}
+
+/// Transfers `amount` of tokens from `from` to `to`, provided `from` signed
+/// the transfer before `deadline`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `amount` - The amount of tokens to be transferred.
+/// * `nonce` - The current nonce of `from`, see [`meta_transfer_nonce`].
+/// * `deadline` - The last ledger number at which the transfer can happen.
+/// * `signature` - The ed25519 signature of `from` over the payload returned by
+///   [`meta_transfer_payload`].
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::SignatureExpired`] - When the current ledger number
+///   is greater than `deadline`.
+/// * [`FungibleTokenError::InvalidNonce`] - When `nonce` is not the current
+///   nonce of `from`.
+/// * [`FungibleTokenError::InvalidSigner`] - When `from` is not a Stellar
+///   account.
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["transfer", from: Address, to: Address]`
+/// * data - `[amount: i128]`
+///
+/// # Notes
+///
+/// * The function traps if `signature` is not a valid signature of `from` over
+///   the payload.
+/// * No authorization is required, as the signature authorizes the transfer.
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: The signature is checked against the master key of `from`
+/// (see [`stellar_crypto::signature::account_public_key`]), ignoring the
+/// signers and thresholds configured on the account. An account whose master
+/// key has a weight of `0`, or which requires several signers, can still have
+/// its tokens transferred with a signature of its master key alone. Contracts
+/// holding tokens for such accounts must not expose this function, and rely on
+/// `require_auth` instead.
+pub fn meta_transfer(
+    e: &Env,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    nonce: u32,
+    deadline: u32,
+    signature: &BytesN<64>,
+) {
+    if e.ledger().sequence() > deadline {
+        panic_with_error!(e, FungibleTokenError::SignatureExpired);
+    }
+
+    let current_nonce = meta_transfer_nonce(e, from);
+    if nonce != current_nonce {
+        panic_with_error!(e, FungibleTokenError::InvalidNonce);
+    }
+
+    let public_key = account_public_key(e, from)
+        .unwrap_or_else(|| panic_with_error!(e, FungibleTokenError::InvalidSigner));
+    let payload = meta_transfer_payload(e, from, to, amount, nonce, deadline);
+    verify_ed25519(e, &public_key, &payload, signature);
+
+    // NOTE: can't realistically overflow, as every meta-transfer must be signed
+    // by `from`.
+    e.storage()
+        .persistent()
+        .set(&MetaTransferStorageKey::Nonce(from.clone()), &(current_nonce + 1));
+
+    update(e, Some(from), Some(to), amount);
+    emit_transfer(e, from, to, amount);
+}
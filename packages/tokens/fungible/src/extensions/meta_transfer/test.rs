@@ -0,0 +1,148 @@
+#![cfg(test)]
+
+extern crate std;
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger as _},
+    xdr::{AccountId, PublicKey, ScAddress, ToXdr, Uint256},
+    Address, BytesN, Env, TryFromVal,
+};
+
+use crate::{
+    extensions::{
+        meta_transfer::{meta_transfer, meta_transfer_nonce, meta_transfer_payload},
+        mintable::mint,
+    },
+    storage::balance,
+};
+
+#[contract]
+struct MockContract;
+
+fn account(e: &Env, signer: &SigningKey) -> Address {
+    let public_key = PublicKey::PublicKeyTypeEd25519(Uint256(signer.verifying_key().to_bytes()));
+    Address::try_from_val(e, &ScAddress::Account(AccountId(public_key))).unwrap()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn sign(
+    e: &Env,
+    address: &Address,
+    signer: &SigningKey,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    nonce: u32,
+    deadline: u32,
+) -> BytesN<64> {
+    e.as_contract(address, || {
+        let payload = meta_transfer_payload(e, from, to, amount, nonce, deadline);
+        let payload: std::vec::Vec<u8> = payload.iter().collect();
+        BytesN::from_array(e, &signer.sign(&payload).to_bytes())
+    })
+}
+
+fn setup(e: &Env) -> (Address, SigningKey, Address) {
+    let address = e.register(MockContract, ());
+    let signer = SigningKey::from_bytes(&[7u8; 32]);
+    let from = account(e, &signer);
+
+    e.as_contract(&address, || {
+        mint(e, &from, 100);
+    });
+
+    (address, signer, from)
+}
+
+#[test]
+fn meta_transfer_works() {
+    let e = Env::default();
+    let (address, signer, from) = setup(&e);
+    let to = Address::generate(&e);
+    let signature = sign(&e, &address, &signer, &from, &to, 40, 0, 100);
+
+    e.as_contract(&address, || {
+        meta_transfer(&e, &from, &to, 40, 0, 100, &signature);
+
+        assert_eq!(balance(&e, &from), 60);
+        assert_eq!(balance(&e, &to), 40);
+        assert_eq!(meta_transfer_nonce(&e, &from), 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #217)")]
+fn meta_transfer_after_deadline_fails() {
+    let e = Env::default();
+    let (address, signer, from) = setup(&e);
+    let to = Address::generate(&e);
+    let signature = sign(&e, &address, &signer, &from, &to, 40, 0, 100);
+
+    e.ledger().set_sequence_number(101);
+
+    e.as_contract(&address, || {
+        meta_transfer(&e, &from, &to, 40, 0, 100, &signature);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #218)")]
+fn meta_transfer_with_replayed_nonce_fails() {
+    let e = Env::default();
+    let (address, signer, from) = setup(&e);
+    let to = Address::generate(&e);
+    let signature = sign(&e, &address, &signer, &from, &to, 40, 0, 100);
+
+    e.as_contract(&address, || {
+        meta_transfer(&e, &from, &to, 40, 0, 100, &signature);
+    });
+
+    e.as_contract(&address, || {
+        meta_transfer(&e, &from, &to, 40, 0, 100, &signature);
+    });
+}
+
+#[test]
+#[should_panic]
+fn meta_transfer_with_tampered_amount_fails() {
+    let e = Env::default();
+    let (address, signer, from) = setup(&e);
+    let to = Address::generate(&e);
+    let signature = sign(&e, &address, &signer, &from, &to, 40, 0, 100);
+
+    e.as_contract(&address, || {
+        meta_transfer(&e, &from, &to, 100, 0, 100, &signature);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #219)")]
+fn meta_transfer_from_contract_fails() {
+    let e = Env::default();
+    let (address, signer, _) = setup(&e);
+    let to = Address::generate(&e);
+    let signature = sign(&e, &address, &signer, &address, &to, 40, 0, 100);
+
+    e.as_contract(&address, || {
+        meta_transfer(&e, &address, &to, 40, 0, 100, &signature);
+    });
+}
+
+#[test]
+#[should_panic]
+fn meta_transfer_signed_without_domain_tag_fails() {
+    let e = Env::default();
+    let (address, signer, from) = setup(&e);
+    let to = Address::generate(&e);
+
+    // the payload of a version without the leading `"meta_xfer"` symbol
+    let payload = (address.clone(), from.clone(), to.clone(), 40i128, 0u32, 100u32).to_xdr(&e);
+    let payload: std::vec::Vec<u8> = payload.iter().collect();
+    let signature = BytesN::from_array(&e, &signer.sign(&payload).to_bytes());
+
+    e.as_contract(&address, || {
+        meta_transfer(&e, &from, &to, 40, 0, 100, &signature);
+    });
+}
@@ -8,7 +8,8 @@
 /// contract.
 mod storage;
 pub use self::storage::{
-    decimals, get_metadata, name, set_metadata, symbol, Metadata, METADATA_KEY,
+    decimals, from_base_units, get_metadata, name, set_metadata, symbol, to_base_units, token_info,
+    Metadata, TokenInfo, METADATA_KEY,
 };
 
 mod test;
@@ -1,6 +1,6 @@
 use soroban_sdk::{contracttype, panic_with_error, symbol_short, Env, String, Symbol};
 
-use crate::FungibleTokenError;
+use crate::{math, storage::total_supply, FungibleTokenError};
 
 /// Storage key that maps to [`Metadata`]
 pub const METADATA_KEY: Symbol = symbol_short!("METADATA");
@@ -13,6 +13,17 @@ pub struct Metadata {
     pub symbol: String,
 }
 
+/// Bundles the token metadata together with the total supply, so that they can
+/// be fetched in a single call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenInfo {
+    pub decimals: u32,
+    pub name: String,
+    pub symbol: String,
+    pub total_supply: i128,
+}
+
 /// Returns the token metadata such as decimals, name and symbol.
 ///
 /// # Arguments
@@ -69,6 +80,77 @@ pub fn symbol(e: &Env) -> String {
     get_metadata(e).symbol
 }
 
+/// Returns the token decimals, name, symbol and total supply in a single
+/// struct.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * refer to [`get_metadata`] errors.
+pub fn token_info(e: &Env) -> TokenInfo {
+    let Metadata { decimals, name, symbol } = get_metadata(e);
+    TokenInfo { decimals, name, symbol, total_supply: total_supply(e) }
+}
+
+/// Converts an amount expressed as a whole part and a fractional part into
+/// base units, using the token decimals. For instance, with `7` decimals,
+/// `(12, 5_000_000)` stands for `12.5` tokens and converts to `125_000_000`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `whole` - The whole part of the amount.
+/// * `frac` - The fractional part of the amount, in base units.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::InvalidFraction`] - When `frac` is negative or not
+///   less than `10^decimals`.
+/// * [`FungibleTokenError::MathOverflow`] - When the amount in base units
+///   overflows.
+/// * refer to [`get_metadata`] errors.
+///
+/// # Notes
+///
+/// The fractional part is always positive, so a negative amount such as
+/// `-0.5` is expressed as `(-1, 5_000_000)` with `7` decimals, matching the
+/// output of [`from_base_units`].
+pub fn to_base_units(e: &Env, whole: i128, frac: i128) -> i128 {
+    let unit = base_unit(e);
+    if frac < 0 || frac >= unit {
+        panic_with_error!(e, FungibleTokenError::InvalidFraction);
+    }
+    math::add(e, math::mul(e, whole, unit), frac)
+}
+
+/// Splits an amount of base units into its whole part and its fractional
+/// part, using the token decimals. This is the inverse of
+/// [`to_base_units`].
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `amount` - The amount in base units.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::MathOverflow`] - When `10^decimals` overflows.
+/// * refer to [`get_metadata`] errors.
+pub fn from_base_units(e: &Env, amount: i128) -> (i128, i128) {
+    let unit = base_unit(e);
+    (amount.div_euclid(unit), amount.rem_euclid(unit))
+}
+
+/// Returns the number of base units in one token, i.e. `10^decimals`.
+fn base_unit(e: &Env) -> i128 {
+    10i128
+        .checked_pow(decimals(e))
+        .unwrap_or_else(|| panic_with_error!(e, FungibleTokenError::MathOverflow))
+}
+
 /// Sets the token metadata such as decimals, name and symbol.
 ///
 /// # Arguments
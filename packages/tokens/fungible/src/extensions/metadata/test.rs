@@ -1,8 +1,16 @@
 #![cfg(test)]
 
-use soroban_sdk::{contract, Env, String};
+use soroban_sdk::{contract, testutils::Address as _, Address, Env, String};
 
-use crate::extensions::metadata::{decimals, name, set_metadata, symbol};
+use crate::{
+    extensions::{
+        metadata::{
+            decimals, from_base_units, name, set_metadata, symbol, to_base_units, token_info,
+        },
+        mintable::mint,
+    },
+    storage::total_supply,
+};
 
 #[contract]
 struct MockContract;
@@ -51,3 +59,105 @@ fn metadata_update() {
         assert_eq!(symbol(&e), String::from_str(&e, "UPD"));
     });
 }
+
+#[test]
+fn token_info_matches_individual_getters() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_metadata(&e, 7, String::from_str(&e, "Test Token"), String::from_str(&e, "TEST"));
+        mint(&e, &account, 1000);
+
+        let info = token_info(&e);
+        assert_eq!(info.decimals, decimals(&e));
+        assert_eq!(info.name, name(&e));
+        assert_eq!(info.symbol, symbol(&e));
+        assert_eq!(info.total_supply, total_supply(&e));
+        assert_eq!(info.total_supply, 1000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #205)")]
+fn token_info_with_unset_metadata_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        token_info(&e);
+    });
+}
+
+#[test]
+fn base_units_with_zero_decimals() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_metadata(&e, 0, String::from_str(&e, "Token"), String::from_str(&e, "TKN"));
+
+        assert_eq!(to_base_units(&e, 42, 0), 42);
+        assert_eq!(from_base_units(&e, 42), (42, 0));
+    });
+}
+
+#[test]
+fn base_units_with_seven_decimals() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_metadata(&e, 7, String::from_str(&e, "Token"), String::from_str(&e, "TKN"));
+
+        assert_eq!(to_base_units(&e, 12, 5_000_000), 125_000_000);
+        assert_eq!(from_base_units(&e, 125_000_000), (12, 5_000_000));
+        assert_eq!(to_base_units(&e, 0, 1), 1);
+        assert_eq!(from_base_units(&e, 1), (0, 1));
+        // the fractional part stays positive for negative amounts
+        assert_eq!(to_base_units(&e, -1, 5_000_000), -5_000_000);
+        assert_eq!(from_base_units(&e, -5_000_000), (-1, 5_000_000));
+    });
+}
+
+#[test]
+fn base_units_with_eighteen_decimals() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let unit = 1_000_000_000_000_000_000;
+
+    e.as_contract(&address, || {
+        set_metadata(&e, 18, String::from_str(&e, "Token"), String::from_str(&e, "TKN"));
+
+        assert_eq!(to_base_units(&e, 3, unit / 4), 3 * unit + unit / 4);
+        assert_eq!(from_base_units(&e, 3 * unit + unit / 4), (3, unit / 4));
+        assert_eq!(from_base_units(&e, i128::MAX), (i128::MAX / unit, i128::MAX % unit));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #204)")]
+fn to_base_units_overflow_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_metadata(&e, 18, String::from_str(&e, "Token"), String::from_str(&e, "TKN"));
+
+        to_base_units(&e, i128::MAX / 1_000_000_000_000_000_000 + 1, 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #233)")]
+fn to_base_units_with_invalid_fraction_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_metadata(&e, 7, String::from_str(&e, "Token"), String::from_str(&e, "TKN"));
+
+        to_base_units(&e, 1, 10_000_000);
+    });
+}
@@ -0,0 +1,20 @@
+/// Unlike other extensions, the `mint_rate_limit` extension does not provide a
+/// separate trait. Similarly to the `capped` extension, it modifies the
+/// business logic of the `mint` function: once a rate limit is configured,
+/// [`crate::mintable::mint()`] refuses to mint more than `max_per_window`
+/// tokens within a window of `window_ledgers` ledgers. This bounds the damage a
+/// compromised minter can do by inflating the supply instantly.
+///
+/// This module provides the following helper functions:
+/// - `set_mint_rate_limit`: Configures the maximum amount of tokens that can be
+///   minted per window and the length of the window in ledgers.
+/// - `query_mint_rate_limit`: Returns the current rate limit configuration and
+///   the state of the ongoing window.
+/// - `consume_mint_rate_limit`: Records `amount` in the ongoing window and
+///   panics if the limit is exceeded. It is invoked by `mint()`.
+mod storage;
+pub use self::storage::{
+    consume_mint_rate_limit, query_mint_rate_limit, set_mint_rate_limit, MintRateLimit,
+    MINT_RATE_LIMIT_KEY,
+};
+mod test;
@@ -0,0 +1,111 @@
+use soroban_sdk::{contracttype, panic_with_error, symbol_short, Env, Symbol};
+
+use crate::FungibleTokenError;
+
+/// Storage key
+pub const MINT_RATE_LIMIT_KEY: Symbol = symbol_short!("MINTRATE");
+
+/// Storage container for the mint rate limit configuration together with the
+/// state of the ongoing window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintRateLimit {
+    /// The maximum amount of tokens that can be minted within a window.
+    pub max_per_window: i128,
+    /// The length of a window in ledgers.
+    pub window_ledgers: u32,
+    /// The ledger number at which the ongoing window started.
+    pub window_start: u32,
+    /// The amount of tokens minted so far in the ongoing window.
+    pub minted_in_window: i128,
+}
+
+/// Sets the maximum amount of tokens that can be minted per window of
+/// `window_ledgers` ledgers. A new window starts at the current ledger.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `max_per_window` - The maximum amount of tokens that can be minted within
+///   a window.
+/// * `window_ledgers` - The length of a window in ledgers.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::InvalidMintRateLimit`] - Occurs when
+///   `max_per_window` is negative or `window_ledgers` is `0`.
+///
+/// # Notes
+///
+/// * We recommend using this function in the constructor of your smart
+///   contract.
+/// * Rate limiting is designed to be used in conjunction with the `mintable`
+///   extension.
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function bypasses authorization checks and should only
+/// be used internally or in trusted contexts.
+pub fn set_mint_rate_limit(e: &Env, max_per_window: i128, window_ledgers: u32) {
+    if max_per_window < 0 || window_ledgers == 0 {
+        panic_with_error!(e, FungibleTokenError::InvalidMintRateLimit);
+    }
+    let rate_limit = MintRateLimit {
+        max_per_window,
+        window_ledgers,
+        window_start: e.ledger().sequence(),
+        minted_in_window: 0,
+    };
+    e.storage().instance().set(&MINT_RATE_LIMIT_KEY, &rate_limit);
+}
+
+/// Returns the mint rate limit configuration and the state of the ongoing
+/// window, or `None` if no rate limit is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+pub fn query_mint_rate_limit(e: &Env) -> Option<MintRateLimit> {
+    e.storage().instance().get(&MINT_RATE_LIMIT_KEY)
+}
+
+/// Records `amount` of newly minted tokens in the ongoing window, starting a
+/// new window if the previous one has elapsed. Does nothing if no rate limit
+/// is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `amount` - The amount of tokens to be minted.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::MintRateLimitExceeded`] - Occurs when minting
+///   `amount` would exceed the maximum amount allowed in the ongoing window.
+///
+/// # Notes
+///
+/// This function is called by [`crate::mintable::mint()`], so there is no
+/// need to call it separately when minting through it.
+pub fn consume_mint_rate_limit(e: &Env, amount: i128) {
+    let Some(mut rate_limit) = query_mint_rate_limit(e) else {
+        return;
+    };
+
+    let current_ledger = e.ledger().sequence();
+    // NOTE: can't overflow in practice, but saturate to keep the window open
+    // until the end of the ledger range.
+    if current_ledger >= rate_limit.window_start.saturating_add(rate_limit.window_ledgers) {
+        rate_limit.window_start = current_ledger;
+        rate_limit.minted_in_window = 0;
+    }
+
+    match rate_limit.minted_in_window.checked_add(amount) {
+        Some(minted) if minted <= rate_limit.max_per_window => {
+            rate_limit.minted_in_window = minted;
+        }
+        _ => panic_with_error!(e, FungibleTokenError::MintRateLimitExceeded),
+    }
+
+    e.storage().instance().set(&MINT_RATE_LIMIT_KEY, &rate_limit);
+}
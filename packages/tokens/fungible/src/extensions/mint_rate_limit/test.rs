@@ -0,0 +1,111 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+use crate::{
+    extensions::{
+        mint_rate_limit::{query_mint_rate_limit, set_mint_rate_limit},
+        mintable::mint,
+    },
+    storage::{balance, total_supply},
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn mint_without_rate_limit_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        assert_eq!(query_mint_rate_limit(&e), None);
+
+        mint(&e, &user, 1_000_000);
+        assert_eq!(balance(&e, &user), 1_000_000);
+    });
+}
+
+#[test]
+fn mint_under_rate_limit_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_mint_rate_limit(&e, 1000, 100);
+
+        mint(&e, &user, 400);
+        mint(&e, &user, 600);
+
+        assert_eq!(balance(&e, &user), 1000);
+        assert_eq!(total_supply(&e), 1000);
+        assert_eq!(query_mint_rate_limit(&e).unwrap().minted_in_window, 1000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #210)")]
+fn mint_exceeding_rate_limit_in_one_window_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_mint_rate_limit(&e, 1000, 100);
+
+        mint(&e, &user, 600);
+        e.ledger().set_sequence_number(99);
+        mint(&e, &user, 401);
+    });
+}
+
+#[test]
+fn mint_rate_limit_window_resets() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_mint_rate_limit(&e, 1000, 100);
+
+        mint(&e, &user, 1000);
+
+        e.ledger().set_sequence_number(100);
+        mint(&e, &user, 1000);
+
+        let rate_limit = query_mint_rate_limit(&e).unwrap();
+        assert_eq!(rate_limit.window_start, 100);
+        assert_eq!(rate_limit.minted_in_window, 1000);
+        assert_eq!(total_supply(&e), 2000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #209)")]
+fn set_mint_rate_limit_with_zero_window_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_mint_rate_limit(&e, 1000, 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #209)")]
+fn set_mint_rate_limit_with_negative_max_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_mint_rate_limit(&e, -1, 100);
+    });
+}
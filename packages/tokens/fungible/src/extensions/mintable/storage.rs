@@ -1,6 +1,12 @@
 use soroban_sdk::{Address, Env};
 
-use crate::{extensions::mintable::emit_mint, storage::update};
+use crate::{
+    extensions::{
+        burnable::record_mint, capped::check_max_mint_per_call,
+        mint_rate_limit::consume_mint_rate_limit, mintable::emit_mint,
+    },
+    storage::update,
+};
 
 /// Creates `amount` of tokens and assigns them to `to`. Updates
 /// the total supply accordingly.
@@ -13,7 +19,9 @@ use crate::{extensions::mintable::emit_mint, storage::update};
 ///
 /// # Errors
 ///
-/// refer to [`update`] errors.
+/// * refer to [`check_max_mint_per_call`] errors.
+/// * refer to [`consume_mint_rate_limit`] errors.
+/// * refer to [`update`] errors.
 ///
 /// # Events
 ///
@@ -36,6 +44,9 @@ use crate::{extensions::mintable::emit_mint, storage::update};
 /// admin.require_auth();
 /// ```
 pub fn mint(e: &Env, to: &Address, amount: i128) {
+    check_max_mint_per_call(e, amount);
+    consume_mint_rate_limit(e, amount);
     update(e, None, Some(to), amount);
+    record_mint(e, to);
     emit_mint(e, to, amount);
 }
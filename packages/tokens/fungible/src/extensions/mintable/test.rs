@@ -7,7 +7,7 @@ use stellar_event_assertion::EventAssertion;
 
 use crate::{
     extensions::mintable::storage::mint,
-    storage::{balance, total_supply},
+    storage::{balance, total_supply, StorageKey},
 };
 
 #[contract]
@@ -30,6 +30,23 @@ fn mint_works() {
     });
 }
 
+#[test]
+fn mint_zero_emits_event_without_storage_writes() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+    e.as_contract(&address, || {
+        mint(&e, &account, 0);
+        assert_eq!(total_supply(&e), 0);
+        assert!(!e.storage().persistent().has(&StorageKey::Balance(account.clone())));
+        assert!(!e.storage().instance().has(&StorageKey::TotalSupply));
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_fungible_mint(&account, 0);
+    });
+}
+
 /// Test that confirms the base mint implementation does NOT require
 /// authorization
 ///
@@ -1,4 +1,16 @@
+pub mod atomic_swap;
 pub mod burnable;
 pub mod capped;
+pub mod clawback;
+pub mod exchange_rate;
+pub mod fee_on_transfer;
+pub mod flash_mint;
+pub mod meta_transfer;
 pub mod metadata;
+pub mod mint_rate_limit;
 pub mod mintable;
+pub mod permit;
+pub mod rebase;
+pub mod snapshot;
+pub mod stream;
+pub mod wrapper;
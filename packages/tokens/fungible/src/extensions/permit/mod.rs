@@ -0,0 +1,32 @@
+/// Unlike other extensions, the `permit` extension does not provide a separate
+/// trait. It enables gasless approvals, in the spirit of EIP-2612: the owner
+/// signs an approval off-chain and anyone, typically the spender, submits it
+/// on-chain with `permit`.
+///
+/// The signature is an ed25519 signature from the owner over the payload
+/// returned by `permit_payload`, which binds the contract address, the owner,
+/// the spender, the amount, the expiration ledger of the allowance and the
+/// owner's current nonce. The nonce is passed explicitly to `permit`, which
+/// rejects it unless it is the current one. Every permit consumes the nonce, so
+/// it cannot be replayed.
+///
+/// Only Stellar accounts (`G...` addresses) can sign permits, as their address
+/// is their ed25519 public key.
+///
+/// **IMPORTANT**: Only the master key of an account can sign permits, and the
+/// signers and thresholds configured on the account are ignored. An account
+/// which disabled its master key, or which relies on multisig, keeps being
+/// able to grant allowances with its master key through `permit`.
+///
+/// This module provides the following helper functions:
+/// - `nonce`: Returns the nonce the next permit of an account must be signed
+///   with.
+/// - `permit_payload`: Returns the payload to sign.
+/// - `permit`: Verifies the signature, consumes the nonce and sets the
+///   allowance.
+/// - `revoke_permit`: Consumes a nonce, invalidating the permits signed with it
+///   that haven't been submitted yet.
+mod storage;
+pub use self::storage::{nonce, permit, permit_payload, revoke_permit, PermitStorageKey};
+
+mod test;
@@ -0,0 +1,158 @@
+use soroban_sdk::{
+    contracttype, panic_with_error, symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env,
+};
+use stellar_crypto::signature::{account_public_key, verify_ed25519};
+
+use crate::{emit_approve, set_allowance, FungibleTokenError};
+
+/// Storage keys for the data associated with the permit extension
+#[contracttype]
+pub enum PermitStorageKey {
+    Nonce(Address),
+}
+
+/// Returns the nonce the next permit of `owner` must be signed with.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address signing the permits.
+pub fn nonce(e: &Env, owner: &Address) -> u64 {
+    e.storage().persistent().get(&PermitStorageKey::Nonce(owner.clone())).unwrap_or(0)
+}
+
+/// Returns the payload that must be signed to authorize a permit. It is the
+/// XDR encoding of the tuple `("permit", contract_address, owner, spender,
+/// amount, live_until_ledger, nonce)`. The leading `"permit"` symbol separates
+/// it from the payloads of other signed operations.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+/// * `amount` - The amount of tokens made available to `spender`.
+/// * `live_until_ledger` - The ledger number at which the allowance expires.
+/// * `nonce` - The nonce of `owner`.
+pub fn permit_payload(
+    e: &Env,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    live_until_ledger: u32,
+    nonce: u64,
+) -> Bytes {
+    (
+        symbol_short!("permit"),
+        e.current_contract_address(),
+        owner.clone(),
+        spender.clone(),
+        amount,
+        live_until_ledger,
+        nonce,
+    )
+        .to_xdr(e)
+}
+
+/// Sets the amount of tokens `spender` is allowed to spend on behalf of
+/// `owner`, provided `owner` signed the approval.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+/// * `amount` - The amount of tokens made available to `spender`.
+/// * `live_until_ledger` - The ledger number at which the allowance expires.
+/// * `nonce` - The nonce the permit was signed with, which must be the current
+///   nonce of `owner`.
+/// * `signature` - The ed25519 signature of `owner` over the payload returned
+///   by [`permit_payload`].
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::InvalidNonce`] - When `nonce` is not the current
+///   nonce of `owner`, e.g. because the permit was already used or revoked.
+/// * [`crate::FungibleTokenError::InvalidSigner`] - When `owner` is not a
+///   Stellar account.
+/// * refer to [`set_allowance`] errors.
+///
+/// # Events
+///
+/// * topics - `["approve", owner: Address, spender: Address]`
+/// * data - `[amount: i128, live_until_ledger: u32]`
+///
+/// # Notes
+///
+/// * The function traps if `signature` is not a valid signature of `owner` over
+///   the payload.
+/// * No authorization is required, as the signature authorizes the approval.
+/// * Unlike EIP-2612, the nonce is an argument rather than being read
+///   implicitly, so that a permit signed with a stale nonce fails with an
+///   explicit error instead of an invalid signature.
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: The signature is checked against the master key of `owner`
+/// (see [`stellar_crypto::signature::account_public_key`]), ignoring the
+/// signers and thresholds configured on the account. An account whose master
+/// key has a weight of `0`, or which requires several signers, can still have
+/// allowances granted over its tokens with a signature of its master key
+/// alone. Contracts holding tokens for such accounts must not expose this
+/// function, and rely on `approve` instead.
+pub fn permit(
+    e: &Env,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    live_until_ledger: u32,
+    nonce: u64,
+    signature: &BytesN<64>,
+) {
+    if nonce != self::nonce(e, owner) {
+        panic_with_error!(e, FungibleTokenError::InvalidNonce);
+    }
+    let public_key = account_public_key(e, owner)
+        .unwrap_or_else(|| panic_with_error!(e, FungibleTokenError::InvalidSigner));
+    let payload = permit_payload(e, owner, spender, amount, live_until_ledger, nonce);
+    verify_ed25519(e, &public_key, &payload, signature);
+
+    // NOTE: can't realistically overflow, as every permit must be signed by
+    // `owner`.
+    e.storage().persistent().set(&PermitStorageKey::Nonce(owner.clone()), &(nonce + 1));
+
+    set_allowance(e, owner, spender, amount, live_until_ledger);
+    emit_approve(e, owner, spender, amount, live_until_ledger);
+}
+
+/// Revokes the permits of `owner` signed with `nonce`, before they are
+/// submitted. The nonce of `owner` moves past `nonce`, so that the permits
+/// signed with `nonce` (and with any lower nonce) can no longer be used,
+/// while the permits signed with a higher nonce remain valid.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address that signed the permits.
+/// * `nonce` - The nonce to revoke.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::InvalidNonce`] - When `nonce` has already been
+///   consumed.
+///
+/// # Notes
+///
+/// Authorization for `owner` is required.
+pub fn revoke_permit(e: &Env, owner: &Address, nonce: u64) {
+    owner.require_auth();
+
+    if nonce < self::nonce(e, owner) {
+        panic_with_error!(e, FungibleTokenError::InvalidNonce);
+    }
+
+    let Some(next_nonce) = nonce.checked_add(1) else {
+        panic_with_error!(e, FungibleTokenError::MathOverflow);
+    };
+    e.storage().persistent().set(&PermitStorageKey::Nonce(owner.clone()), &next_nonce);
+}
@@ -0,0 +1,197 @@
+#![cfg(test)]
+
+extern crate std;
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger as _},
+    xdr::{AccountId, PublicKey, ScAddress, Uint256},
+    Address, BytesN, Env, TryFromVal,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    extensions::{
+        mintable::mint,
+        permit::{nonce, permit, permit_payload, revoke_permit},
+    },
+    storage::{allowance, balance, transfer_from},
+};
+
+#[contract]
+struct MockContract;
+
+fn account(e: &Env, signer: &SigningKey) -> Address {
+    let public_key = PublicKey::PublicKeyTypeEd25519(Uint256(signer.verifying_key().to_bytes()));
+    Address::try_from_val(e, &ScAddress::Account(AccountId(public_key))).unwrap()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn sign(
+    e: &Env,
+    address: &Address,
+    signer: &SigningKey,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    live_until_ledger: u32,
+    nonce: u64,
+) -> BytesN<64> {
+    e.as_contract(address, || {
+        let payload = permit_payload(e, owner, spender, amount, live_until_ledger, nonce);
+        let payload: std::vec::Vec<u8> = payload.iter().collect();
+        BytesN::from_array(e, &signer.sign(&payload).to_bytes())
+    })
+}
+
+fn setup(e: &Env) -> (Address, SigningKey, Address) {
+    let address = e.register(MockContract, ());
+    let signer = SigningKey::from_bytes(&[7u8; 32]);
+    let owner = account(e, &signer);
+
+    e.as_contract(&address, || {
+        mint(e, &owner, 100);
+    });
+
+    (address, signer, owner)
+}
+
+#[test]
+fn permit_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (address, signer, owner) = setup(&e);
+    let spender = Address::generate(&e);
+    let signature = sign(&e, &address, &signer, &owner, &spender, 40, 100, 0);
+
+    e.as_contract(&address, || {
+        permit(&e, &owner, &spender, 40, 100, 0, &signature);
+
+        assert_eq!(allowance(&e, &owner, &spender), 40);
+        assert_eq!(nonce(&e, &owner), 1);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_fungible_approve(&owner, &spender, 40, 100);
+    });
+
+    e.as_contract(&address, || {
+        transfer_from(&e, &spender, &owner, &spender, 40);
+        assert_eq!(balance(&e, &spender), 40);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #218)")]
+fn permit_replayed_signature_fails() {
+    let e = Env::default();
+    let (address, signer, owner) = setup(&e);
+    let spender = Address::generate(&e);
+    let signature = sign(&e, &address, &signer, &owner, &spender, 40, 100, 0);
+
+    e.as_contract(&address, || {
+        permit(&e, &owner, &spender, 40, 100, 0, &signature);
+    });
+
+    e.as_contract(&address, || {
+        permit(&e, &owner, &spender, 40, 100, 0, &signature);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #202)")]
+fn permit_with_expired_live_until_ledger_fails() {
+    let e = Env::default();
+    let (address, signer, owner) = setup(&e);
+    let spender = Address::generate(&e);
+    e.ledger().set_sequence_number(200);
+    let signature = sign(&e, &address, &signer, &owner, &spender, 40, 100, 0);
+
+    e.as_contract(&address, || {
+        permit(&e, &owner, &spender, 40, 100, 0, &signature);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #219)")]
+fn permit_from_contract_fails() {
+    let e = Env::default();
+    let (address, signer, _) = setup(&e);
+    let spender = Address::generate(&e);
+    let signature = sign(&e, &address, &signer, &address, &spender, 40, 100, 0);
+
+    e.as_contract(&address, || {
+        permit(&e, &address, &spender, 40, 100, 0, &signature);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #218)")]
+fn permit_with_revoked_nonce_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (address, signer, owner) = setup(&e);
+    let spender = Address::generate(&e);
+    let signature = sign(&e, &address, &signer, &owner, &spender, 40, 100, 0);
+
+    e.as_contract(&address, || {
+        revoke_permit(&e, &owner, 0);
+        assert_eq!(nonce(&e, &owner), 1);
+    });
+
+    e.as_contract(&address, || {
+        permit(&e, &owner, &spender, 40, 100, 0, &signature);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #218)")]
+fn permit_with_future_nonce_fails() {
+    let e = Env::default();
+    let (address, signer, owner) = setup(&e);
+    let spender = Address::generate(&e);
+    let signature = sign(&e, &address, &signer, &owner, &spender, 40, 100, 1);
+
+    e.as_contract(&address, || {
+        permit(&e, &owner, &spender, 40, 100, 1, &signature);
+    });
+}
+
+#[test]
+fn permit_with_unrelated_nonce_works_after_revocation() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (address, signer, owner) = setup(&e);
+    let spender = Address::generate(&e);
+    let signature = sign(&e, &address, &signer, &owner, &spender, 40, 100, 1);
+
+    e.as_contract(&address, || {
+        revoke_permit(&e, &owner, 0);
+    });
+
+    e.as_contract(&address, || {
+        permit(&e, &owner, &spender, 40, 100, 1, &signature);
+
+        assert_eq!(allowance(&e, &owner, &spender), 40);
+        assert_eq!(nonce(&e, &owner), 2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #218)")]
+fn revoke_consumed_nonce_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (address, signer, owner) = setup(&e);
+    let spender = Address::generate(&e);
+    let signature = sign(&e, &address, &signer, &owner, &spender, 40, 100, 0);
+
+    e.as_contract(&address, || {
+        permit(&e, &owner, &spender, 40, 100, 0, &signature);
+    });
+
+    e.as_contract(&address, || {
+        revoke_permit(&e, &owner, 0);
+    });
+}
@@ -0,0 +1,73 @@
+/// Unlike other extensions, the `rebase` extension does not provide a separate
+/// trait. It turns the token into an elastic supply token, whose total supply
+/// can be adjusted to a target (e.g. read from an oracle) without rewriting the
+/// balance of every holder.
+///
+/// Once the extension is used, the balances and the total supply kept by the
+/// base module are interpreted as *shares*. The rebased total supply acts as a
+/// global scaling factor: the balance of an account is its share of the
+/// rebased total supply, so rebasing preserves the relative balances of all
+/// holders.
+///
+/// This module provides the following helper functions:
+/// - `rebase`: Sets the total supply to a target, scaling all the balances.
+/// - `total_supply`: Returns the rebased total supply.
+/// - `balance`: Returns the rebased balance of an account.
+/// - `transfer`, `transfer_from`, `mint` and `burn`: Counterparts of the base
+///   functions taking amounts in rebased units.
+/// - `burn_and_reflect`: Burns part of the tokens of an account and
+///   redistributes another part to all the holders, pro-rata.
+///
+/// Conversions between rebased units and shares always round in favor of the
+/// holders not taking part in an operation: minted shares are rounded down and
+/// burned shares up, and the rebased total supply moves by the value of the
+/// shares actually minted or burned.
+///
+/// **IMPORTANT**: When using this extension, the base functions operating on
+/// amounts (`crate::balance()`, `crate::transfer()`, `crate::mintable::mint()`,
+/// etc.) must NOT be exposed, as they operate on shares.
+mod storage;
+use soroban_sdk::{symbol_short, Address, Env};
+
+pub use self::storage::{
+    balance, burn, burn_and_reflect, mint, rebase, total_supply, transfer, transfer_from,
+    REBASED_SUPPLY_KEY,
+};
+
+mod test;
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating a rebase of the total supply.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `previous_supply` - The total supply before the rebase.
+/// * `target_supply` - The total supply after the rebase.
+///
+/// # Events
+///
+/// * topics - `["rebase"]`
+/// * data - `[previous_supply: i128, target_supply: i128]`
+pub fn emit_rebase(e: &Env, previous_supply: i128, target_supply: i128) {
+    let topics = (symbol_short!("rebase"),);
+    e.events().publish(topics, (previous_supply, target_supply))
+}
+
+/// Emits an event indicating tokens were redistributed to all the holders.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The account whose tokens were redistributed.
+/// * `amount` - The amount of redistributed tokens.
+///
+/// # Events
+///
+/// * topics - `["reflect", from: Address]`
+/// * data - `[amount: i128]`
+pub fn emit_reflect(e: &Env, from: &Address, amount: i128) {
+    let topics = (symbol_short!("reflect"), from);
+    e.events().publish(topics, amount)
+}
@@ -0,0 +1,363 @@
+use soroban_sdk::{panic_with_error, symbol_short, Address, Env, Symbol};
+
+use crate::{
+    burnable::{check_burn_cooldown, emit_burn, record_mint},
+    capped::check_max_mint_per_call,
+    emit_transfer, math,
+    mint_rate_limit::consume_mint_rate_limit,
+    mintable::emit_mint,
+    rebase::{emit_rebase, emit_reflect},
+    spend_allowance, update, FungibleTokenError,
+};
+
+/// Storage key
+pub const REBASED_SUPPLY_KEY: Symbol = symbol_short!("REBASED");
+
+/// Returns the rebased total supply. Defaults to the total number of shares if
+/// no rebase happened yet.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+pub fn total_supply(e: &Env) -> i128 {
+    e.storage().instance().get(&REBASED_SUPPLY_KEY).unwrap_or_else(|| crate::total_supply(e))
+}
+
+/// Returns the rebased balance of `account`, i.e. its share of the rebased
+/// total supply.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `account` - The address for which the balance is being queried.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::MathOverflow`] - When the rebased balance can't be
+///   computed without overflowing.
+pub fn balance(e: &Env, account: &Address) -> i128 {
+    to_amount(e, crate::balance(e, account))
+}
+
+/// Sets the total supply to `target_supply`, scaling all the balances
+/// proportionally.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `target_supply` - The new total supply.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::InvalidRebase`] - When `target_supply` is not
+///   positive or when there is no token in circulation.
+///
+/// # Events
+///
+/// * topics - `["rebase"]`
+/// * data - `[previous_supply: i128, target_supply: i128]`
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts (e.g. the supply oracle)
+/// can execute this function.
+pub fn rebase(e: &Env, target_supply: i128) {
+    if target_supply <= 0 || crate::total_supply(e) == 0 {
+        panic_with_error!(e, FungibleTokenError::InvalidRebase);
+    }
+
+    let previous_supply = total_supply(e);
+    e.storage().instance().set(&REBASED_SUPPLY_KEY, &target_supply);
+    emit_rebase(e, previous_supply, target_supply);
+}
+
+/// Transfers `amount` of tokens, in rebased units, from `from` to `to`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `amount` - The amount of tokens to be transferred.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::MathOverflow`] - When `amount` can't be converted to
+///   shares without overflowing.
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["transfer", from: Address, to: Address]`
+/// * data - `[amount: i128]`
+///
+/// # Notes
+///
+/// * Authorization for `from` is required.
+/// * `amount` is converted to shares rounding down, so the recipient might
+///   receive slightly less than `amount` because of the rounding.
+pub fn transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
+    from.require_auth();
+    update(e, Some(from), Some(to), to_shares(e, amount));
+    emit_transfer(e, from, to, amount);
+}
+
+/// Transfers `amount` of tokens, in rebased units, from `from` to `to` using
+/// the allowance mechanism. Allowances are expressed in rebased units too.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `spender` - The address authorizing the transfer, and having its allowance
+///   consumed during the transfer.
+/// * `from` - The address holding the tokens which will be transferred.
+/// * `to` - The address receiving the transferred tokens.
+/// * `amount` - The amount of tokens to be transferred.
+///
+/// # Errors
+///
+/// * refer to [`spend_allowance`] errors.
+/// * refer to [`transfer`] errors.
+///
+/// # Events
+///
+/// * topics - `["transfer", from: Address, to: Address]`
+/// * data - `[amount: i128]`
+///
+/// # Notes
+///
+/// Authorization for `spender` is required.
+pub fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, amount: i128) {
+    spender.require_auth();
+    spend_allowance(e, from, spender, amount);
+    update(e, Some(from), Some(to), to_shares(e, amount));
+    emit_transfer(e, from, to, amount);
+}
+
+/// Creates `amount` of tokens, in rebased units, and assigns them to `to`.
+/// Updates the rebased total supply accordingly.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `to` - The address receiving the new tokens.
+/// * `amount` - The amount of tokens to mint.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::MathOverflow`] - When the total supply overflows.
+/// * refer to [`check_max_mint_per_call`] errors.
+/// * refer to [`consume_mint_rate_limit`] errors.
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["mint", to: Address]`
+/// * data - `[amount: i128]`
+///
+/// # Notes
+///
+/// * `amount` is converted to shares rounding down, and the rebased total
+///   supply grows by the value of the minted shares, rounding up, so that the
+///   balances of the other holders never decrease. The minted amount, reported
+///   in the event, might then be slightly less than `amount`.
+/// * The maximum mint per call, the mint rate limit and the burn cooldown apply
+///   as with [`crate::mintable::mint()`], in rebased units.
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute minting
+/// operations.
+pub fn mint(e: &Env, to: &Address, amount: i128) {
+    check_max_mint_per_call(e, amount);
+    consume_mint_rate_limit(e, amount);
+
+    let shares = to_shares(e, amount);
+    let minted = to_amount_ceil(e, shares);
+    let supply = e.storage().instance().get::<_, i128>(&REBASED_SUPPLY_KEY);
+    update(e, None, Some(to), shares);
+
+    if let Some(supply) = supply {
+        e.storage().instance().set(&REBASED_SUPPLY_KEY, &math::add(e, supply, minted));
+    }
+    record_mint(e, to);
+    emit_mint(e, to, minted);
+}
+
+/// Destroys `amount` of tokens, in rebased units, from `from`. Updates the
+/// rebased total supply accordingly.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `from` - The account whose tokens are destroyed.
+/// * `amount` - The amount of tokens to burn.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::InsufficientBalance`] - When `amount` exceeds the
+///   rebased balance of `from`.
+/// * refer to [`check_burn_cooldown`] errors.
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["burn", from: Address]`
+/// * data - `[amount: i128]`
+///
+/// # Notes
+///
+/// * Authorization for `from` is required.
+/// * `amount` is converted to shares rounding up, and the rebased total supply
+///   shrinks by the value of the burned shares, rounding down, so that the
+///   balances of the other holders never decrease. The burned amount, reported
+///   in the event, might then be slightly more than `amount`.
+/// * Burning the whole rebased balance of `from` burns all of its shares, so
+///   that no share worth less than a token is left behind.
+pub fn burn(e: &Env, from: &Address, amount: i128) {
+    from.require_auth();
+    check_burn_cooldown(e, from);
+    let balance = balance(e, from);
+    if amount > balance {
+        panic_with_error!(e, FungibleTokenError::InsufficientBalance);
+    }
+
+    let shares = to_burned_shares(e, from, amount, balance);
+    let burned = to_amount(e, shares);
+    let supply = e.storage().instance().get::<_, i128>(&REBASED_SUPPLY_KEY);
+    update(e, Some(from), None, shares);
+
+    if let Some(supply) = supply {
+        e.storage().instance().set(&REBASED_SUPPLY_KEY, &math::sub(e, supply, burned));
+    }
+    emit_burn(e, from, burned);
+}
+
+/// Destroys `burn_amount` of tokens, in rebased units, from `from` and
+/// redistributes `reflect_amount` of its tokens to all the holders, pro-rata
+/// to their balances.
+///
+/// The reflection burns the shares of `from` matching `reflect_amount` while
+/// leaving the rebased total supply untouched, so that the value of every
+/// remaining share grows accordingly.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `from` - The account whose tokens are burned and reflected.
+/// * `burn_amount` - The amount of tokens to burn.
+/// * `reflect_amount` - The amount of tokens to redistribute.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::LessThanZero`] - When `burn_amount` or
+///   `reflect_amount` is negative.
+/// * [`FungibleTokenError::InsufficientBalance`] - When `burn_amount +
+///   reflect_amount` exceeds the rebased balance of `from`.
+/// * [`FungibleTokenError::InvalidRebase`] - When no share would be left to
+///   reflect the tokens to.
+/// * refer to [`check_burn_cooldown`] errors.
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["burn", from: Address]`
+/// * data - `[burn_amount: i128]`
+///
+/// * topics - `["reflect", from: Address]`
+/// * data - `[reflect_amount: i128]`
+///
+/// # Notes
+///
+/// * Authorization for `from` is required.
+/// * `from` takes part in the redistribution with its remaining balance.
+/// * `burn_amount + reflect_amount` is converted to shares at once, rounding
+///   up, and the rebased total supply shrinks by the value of these shares,
+///   rounding down, minus `reflect_amount`. The burned amount, reported in the
+///   event, might then be slightly more than `burn_amount`.
+/// * As with [`burn`], using up the whole rebased balance of `from` burns all
+///   of its shares.
+pub fn burn_and_reflect(e: &Env, from: &Address, burn_amount: i128, reflect_amount: i128) {
+    from.require_auth();
+    check_burn_cooldown(e, from);
+    if burn_amount < 0 || reflect_amount < 0 {
+        panic_with_error!(e, FungibleTokenError::LessThanZero);
+    }
+    let amount = math::add(e, burn_amount, reflect_amount);
+    let balance = balance(e, from);
+    if amount > balance {
+        panic_with_error!(e, FungibleTokenError::InsufficientBalance);
+    }
+
+    let supply = total_supply(e);
+    let shares = to_burned_shares(e, from, amount, balance);
+    if shares == crate::total_supply(e) && reflect_amount > 0 {
+        panic_with_error!(e, FungibleTokenError::InvalidRebase);
+    }
+    let burned = math::sub(e, to_amount(e, shares), reflect_amount);
+
+    update(e, Some(from), None, shares);
+    e.storage().instance().set(&REBASED_SUPPLY_KEY, &math::sub(e, supply, burned));
+
+    emit_burn(e, from, burned);
+    emit_reflect(e, from, reflect_amount);
+}
+
+// ################## INTERNAL HELPERS ##################
+
+/// Converts `shares` to rebased units, rounding down.
+fn to_amount(e: &Env, shares: i128) -> i128 {
+    scale(e, shares, |supply, total_shares| (supply, total_shares), math::mul_div)
+}
+
+/// Converts `shares` to rebased units, rounding up.
+fn to_amount_ceil(e: &Env, shares: i128) -> i128 {
+    scale(e, shares, |supply, total_shares| (supply, total_shares), math::mul_div_ceil)
+}
+
+/// Converts `amount` in rebased units to shares, rounding down.
+fn to_shares(e: &Env, amount: i128) -> i128 {
+    scale(e, amount, |supply, total_shares| (total_shares, supply), math::mul_div)
+}
+
+/// Converts `amount` in rebased units to shares, rounding up.
+fn to_shares_ceil(e: &Env, amount: i128) -> i128 {
+    scale(e, amount, |supply, total_shares| (total_shares, supply), math::mul_div_ceil)
+}
+
+/// Converts `amount` in rebased units, burned out of the rebased `balance` of
+/// `from`, to shares, rounding up. The whole balance maps to all the shares of
+/// `from`, as rounding it up could leave a share behind otherwise.
+fn to_burned_shares(e: &Env, from: &Address, amount: i128, balance: i128) -> i128 {
+    if amount == balance {
+        crate::balance(e, from)
+    } else {
+        to_shares_ceil(e, amount)
+    }
+}
+
+/// Multiplies `value` by the ratio returned by `ratio`, given the rebased
+/// supply and the total number of shares, with `mul_div` setting the rounding.
+/// Returns `value` unchanged when no rebase happened or when there is nothing
+/// to scale against.
+fn scale(
+    e: &Env,
+    value: i128,
+    ratio: impl Fn(i128, i128) -> (i128, i128),
+    mul_div: fn(&Env, i128, i128, i128) -> i128,
+) -> i128 {
+    let total_shares = crate::total_supply(e);
+    let supply = match e.storage().instance().get::<_, i128>(&REBASED_SUPPLY_KEY) {
+        Some(supply) if supply > 0 && total_shares > 0 => supply,
+        _ => return value,
+    };
+
+    let (numerator, denominator) = ratio(supply, total_shares);
+    mul_div(e, value, numerator, denominator)
+}
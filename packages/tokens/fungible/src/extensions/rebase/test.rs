@@ -0,0 +1,358 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+
+use crate::{
+    extensions::rebase::{balance, burn, burn_and_reflect, mint, rebase, total_supply, transfer},
+    test::Prng,
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn rebase_up_preserves_relative_balances() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &alice, 100);
+        mint(&e, &bob, 300);
+        assert_eq!(total_supply(&e), 400);
+
+        rebase(&e, 800);
+
+        assert_eq!(total_supply(&e), 800);
+        assert_eq!(balance(&e, &alice), 200);
+        assert_eq!(balance(&e, &bob), 600);
+        // shares are left untouched
+        assert_eq!(crate::balance(&e, &alice), 100);
+        assert_eq!(crate::balance(&e, &bob), 300);
+    });
+}
+
+#[test]
+fn rebase_down_preserves_relative_balances() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &alice, 100);
+        mint(&e, &bob, 300);
+
+        rebase(&e, 200);
+
+        assert_eq!(total_supply(&e), 200);
+        assert_eq!(balance(&e, &alice), 50);
+        assert_eq!(balance(&e, &bob), 150);
+    });
+}
+
+#[test]
+fn transfer_mint_and_burn_after_rebase_work() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &alice, 100);
+        mint(&e, &bob, 300);
+        rebase(&e, 800);
+    });
+
+    e.as_contract(&address, || {
+        transfer(&e, &alice, &bob, 50);
+        assert_eq!(balance(&e, &alice), 150);
+        assert_eq!(balance(&e, &bob), 650);
+        assert_eq!(total_supply(&e), 800);
+
+        mint(&e, &alice, 200);
+        assert_eq!(balance(&e, &alice), 350);
+        assert_eq!(total_supply(&e), 1000);
+    });
+
+    e.as_contract(&address, || {
+        burn(&e, &bob, 650);
+        assert_eq!(balance(&e, &bob), 0);
+        assert_eq!(balance(&e, &alice), 350);
+        assert_eq!(total_supply(&e), 350);
+    });
+}
+
+#[test]
+fn burn_below_share_value_burns_a_whole_share() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &alice, 100);
+        mint(&e, &bob, 100);
+        // every share is worth 10 tokens
+        rebase(&e, 2_000);
+    });
+
+    e.as_contract(&address, || {
+        // burning less than a share still burns a whole one, instead of
+        // shrinking the supply at the expense of the other holders
+        burn(&e, &alice, 1);
+        assert_eq!(crate::balance(&e, &alice), 99);
+        assert_eq!(balance(&e, &alice), 990);
+        assert_eq!(balance(&e, &bob), 1_000);
+        assert_eq!(total_supply(&e), 1_990);
+    });
+}
+
+#[test]
+fn burn_whole_balance_burns_all_shares() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &alice, 3);
+        mint(&e, &bob, 7);
+        // every share is worth half a token
+        rebase(&e, 5);
+    });
+
+    e.as_contract(&address, || {
+        // the 3 shares of alice are worth 1.5 tokens, reported as 1, which
+        // converts back to only 2 shares
+        assert_eq!(balance(&e, &alice), 1);
+        burn(&e, &alice, 1);
+        assert_eq!(crate::balance(&e, &alice), 0);
+        assert_eq!(balance(&e, &alice), 0);
+        assert_eq!(crate::balance(&e, &bob), 7);
+        assert_eq!(crate::total_supply(&e), 7);
+    });
+}
+
+#[test]
+fn mint_below_share_value_mints_nothing() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &alice, 100);
+        rebase(&e, 1_000);
+
+        mint(&e, &bob, 9);
+        assert_eq!(balance(&e, &bob), 0);
+        assert_eq!(balance(&e, &alice), 1_000);
+        assert_eq!(total_supply(&e), 1_000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #222)")]
+fn mint_over_max_per_call_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        crate::capped::set_max_mint_per_call(&e, 100);
+        mint(&e, &alice, 101);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #220)")]
+fn burn_within_cooldown_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        crate::burnable::set_burn_cooldown(&e, 10);
+        mint(&e, &alice, 100);
+    });
+
+    e.as_contract(&address, || {
+        burn(&e, &alice, 10);
+    });
+}
+
+#[test]
+fn random_operations_never_dilute_other_holders() {
+    const STEPS: u32 = 100;
+
+    for seed in [1, 42, 0xdead_beef] {
+        let e = Env::default();
+        e.mock_all_auths();
+        let address = e.register(MockContract, ());
+        let accounts: std::vec::Vec<Address> = (0..4).map(|_| Address::generate(&e)).collect();
+        let mut prng = Prng(seed);
+
+        for step in 0..STEPS {
+            let from = prng.below(accounts.len() as u64) as usize;
+            let to = prng.below(accounts.len() as u64) as usize;
+            let op = prng.below(5);
+
+            e.as_contract(&address, || {
+                let before: std::vec::Vec<i128> =
+                    accounts.iter().map(|account| balance(&e, account)).collect();
+                let shares = crate::total_supply(&e);
+
+                match op {
+                    0 => {
+                        let amount = prng.below(10_000) as i128;
+                        mint(&e, &accounts[to], amount);
+                        assert!(balance(&e, &accounts[to]) - before[to] <= amount);
+                    }
+                    1 => {
+                        let amount = prng.below(before[from] as u64 + 1) as i128;
+                        transfer(&e, &accounts[from], &accounts[to], amount);
+                    }
+                    2 => {
+                        let amount = prng.below(before[from] as u64 + 1) as i128;
+                        burn(&e, &accounts[from], amount);
+                        assert!(before[from] - balance(&e, &accounts[from]) >= amount);
+                    }
+                    3 => {
+                        let amount = prng.below(before[from] as u64 + 1) as i128;
+                        let burn_amount = prng.below(amount as u64 + 1) as i128;
+                        let reflect_amount = amount - burn_amount;
+                        // reflecting needs other shares to reflect the tokens to
+                        if reflect_amount == 0 || crate::balance(&e, &accounts[from]) < shares {
+                            let supply = total_supply(&e);
+                            burn_and_reflect(&e, &accounts[from], burn_amount, reflect_amount);
+                            assert!(supply - total_supply(&e) >= burn_amount);
+                        }
+                    }
+                    _ if shares > 0 => rebase(&e, prng.below(100_000) as i128 + 1),
+                    _ => {}
+                }
+
+                let after: std::vec::Vec<i128> =
+                    accounts.iter().map(|account| balance(&e, account)).collect();
+                // the balances of the accounts not taking part in a mint, a
+                // transfer or a burn never decrease
+                if op < 4 {
+                    for (i, (before, after)) in before.iter().zip(after.iter()).enumerate() {
+                        if i != from && i != to {
+                            assert!(after >= before, "seed {seed}, step {step}");
+                        }
+                    }
+                }
+
+                // the supply covers the balances, up to the rounding of each one
+                let sum: i128 = after.iter().sum();
+                let supply = total_supply(&e);
+                assert!(sum <= supply, "seed {seed}, step {step}");
+                assert!(supply - sum < accounts.len() as i128, "seed {seed}, step {step}");
+            });
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #214)")]
+fn rebase_to_zero_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &alice, 100);
+        rebase(&e, 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #214)")]
+fn rebase_without_supply_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        rebase(&e, 100);
+    });
+}
+
+#[test]
+fn burn_and_reflect_redistributes_pro_rata() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let carol = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &alice, 400);
+        mint(&e, &bob, 200);
+        mint(&e, &carol, 200);
+    });
+
+    e.as_contract(&address, || {
+        // alice burns 100 and reflects 100 of her 400 tokens
+        burn_and_reflect(&e, &alice, 100, 100);
+
+        // the supply only decreases by the burned amount
+        assert_eq!(total_supply(&e), 700);
+        // the 100 reflected tokens are shared among the 600 remaining, so every
+        // balance grows by a sixth
+        assert_eq!(balance(&e, &alice), 233);
+        assert_eq!(balance(&e, &bob), 233);
+        assert_eq!(balance(&e, &carol), 233);
+    });
+}
+
+#[test]
+fn burn_and_reflect_after_rebase_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &alice, 100);
+        mint(&e, &bob, 300);
+        rebase(&e, 800);
+    });
+
+    e.as_contract(&address, || {
+        burn_and_reflect(&e, &alice, 100, 50);
+
+        assert_eq!(total_supply(&e), 700);
+        assert_eq!(balance(&e, &alice), 53);
+        assert_eq!(balance(&e, &bob), 646);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #200)")]
+fn burn_and_reflect_exceeding_balance_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &alice, 100);
+        mint(&e, &bob, 100);
+    });
+
+    e.as_contract(&address, || {
+        burn_and_reflect(&e, &alice, 60, 50);
+    });
+}
@@ -0,0 +1,43 @@
+/// Unlike other extensions, the `snapshot` extension does not provide a
+/// separate trait. It records the balances and the total supply at the time of
+/// each snapshot, for use-cases such as governance or dividends, which need to
+/// look up balances at a past point in time.
+///
+/// Nothing is copied when a snapshot is taken. Instead, the base
+/// [`crate::update`] checkpoints the prior value of every balance (and of the
+/// total supply) it is about to modify, the first time it does so after a
+/// snapshot. Values that weren't modified since a snapshot are read from the
+/// current state.
+///
+/// This module provides the following helper functions:
+/// - `snapshot`: Takes a new snapshot and returns its id.
+/// - `current_snapshot_id`: Returns the id of the latest snapshot.
+/// - `balance_at`: Returns the balance of an account at a snapshot.
+/// - `total_supply_at`: Returns the total supply at a snapshot.
+mod storage;
+use soroban_sdk::{symbol_short, Env};
+
+pub(crate) use self::storage::checkpoint;
+pub use self::storage::{
+    balance_at, current_snapshot_id, snapshot, total_supply_at, SnapshotStorageKey, SNAPSHOT_ID_KEY,
+};
+
+mod test;
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating a snapshot was taken.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `snapshot_id` - The id of the snapshot.
+///
+/// # Events
+///
+/// * topics - `["snapshot"]`
+/// * data - `[snapshot_id: u32]`
+pub fn emit_snapshot(e: &Env, snapshot_id: u32) {
+    let topics = (symbol_short!("snapshot"),);
+    e.events().publish(topics, snapshot_id)
+}
@@ -0,0 +1,157 @@
+use soroban_sdk::{contracttype, panic_with_error, symbol_short, Address, Env, Symbol, Vec};
+use stellar_constants::{BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD};
+
+use crate::{extensions::snapshot::emit_snapshot, FungibleTokenError};
+
+/// Storage key for the id of the latest snapshot
+pub const SNAPSHOT_ID_KEY: Symbol = symbol_short!("SNAP_ID");
+
+/// Storage keys for the data associated with the snapshot extension. Each
+/// entry is an append-only list of `(snapshot_id, value)` checkpoints, sorted
+/// by `snapshot_id`, where `value` is the value at the time of `snapshot_id`.
+#[contracttype]
+pub enum SnapshotStorageKey {
+    BalanceCheckpoints(Address),
+    TotalSupplyCheckpoints,
+}
+
+/// Returns the id of the latest snapshot, or `0` if no snapshot was taken.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+pub fn current_snapshot_id(e: &Env) -> u32 {
+    e.storage().instance().get(&SNAPSHOT_ID_KEY).unwrap_or(0)
+}
+
+/// Takes a snapshot of the balances and the total supply, and returns its id.
+/// Snapshot ids start at `1`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+///
+/// # Events
+///
+/// * topics - `["snapshot"]`
+/// * data - `[snapshot_id: u32]`
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute this function.
+pub fn snapshot(e: &Env) -> u32 {
+    // NOTE: can't realistically overflow, as it would take more than
+    // `u32::MAX` invocations.
+    let snapshot_id = current_snapshot_id(e) + 1;
+    e.storage().instance().set(&SNAPSHOT_ID_KEY, &snapshot_id);
+    emit_snapshot(e, snapshot_id);
+    snapshot_id
+}
+
+/// Returns the balance of `account` at the time of `snapshot_id`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address for which the balance is being queried.
+/// * `snapshot_id` - The id of the snapshot.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::SnapshotNotFound`] - When no snapshot with
+///   `snapshot_id` was taken.
+pub fn balance_at(e: &Env, account: &Address, snapshot_id: u32) -> i128 {
+    ensure_snapshot_exists(e, snapshot_id);
+    let key = SnapshotStorageKey::BalanceCheckpoints(account.clone());
+    let checkpoints = e.storage().persistent().get(&key).inspect(|_| {
+        e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+    });
+    lookup(checkpoints, snapshot_id).unwrap_or_else(|| crate::balance(e, account))
+}
+
+/// Returns the total supply at the time of `snapshot_id`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `snapshot_id` - The id of the snapshot.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::SnapshotNotFound`] - When no snapshot with
+///   `snapshot_id` was taken.
+pub fn total_supply_at(e: &Env, snapshot_id: u32) -> i128 {
+    ensure_snapshot_exists(e, snapshot_id);
+    let key = SnapshotStorageKey::TotalSupplyCheckpoints;
+    let checkpoints = e.storage().persistent().get(&key).inspect(|_| {
+        e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+    });
+    lookup(checkpoints, snapshot_id).unwrap_or_else(|| crate::total_supply(e))
+}
+
+/// Checkpoints the current balances of `from` and `to`, and the current total
+/// supply when minting or burning, before [`crate::update`] modifies them.
+/// Does nothing if no snapshot was taken.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens, `None` when minting.
+/// * `to` - The address receiving the tokens, `None` when burning.
+pub(crate) fn checkpoint(e: &Env, from: Option<&Address>, to: Option<&Address>) {
+    let snapshot_id = current_snapshot_id(e);
+    if snapshot_id == 0 {
+        return;
+    }
+
+    for account in [from, to].into_iter().flatten() {
+        let key = SnapshotStorageKey::BalanceCheckpoints(account.clone());
+        write_checkpoint(e, &key, snapshot_id, crate::balance(e, account));
+    }
+    if from.is_none() || to.is_none() {
+        let key = SnapshotStorageKey::TotalSupplyCheckpoints;
+        write_checkpoint(e, &key, snapshot_id, crate::total_supply(e));
+    }
+}
+
+// ################## INTERNAL HELPERS ##################
+
+/// Panics if no snapshot with `snapshot_id` was taken.
+fn ensure_snapshot_exists(e: &Env, snapshot_id: u32) {
+    if snapshot_id == 0 || snapshot_id > current_snapshot_id(e) {
+        panic_with_error!(e, FungibleTokenError::SnapshotNotFound);
+    }
+}
+
+/// Appends `(snapshot_id, value)` to the checkpoints stored under `key`,
+/// unless a checkpoint was already written for `snapshot_id`: only the value
+/// prior to the first update following a snapshot matters.
+fn write_checkpoint(e: &Env, key: &SnapshotStorageKey, snapshot_id: u32, value: i128) {
+    let mut checkpoints: Vec<(u32, i128)> =
+        e.storage().persistent().get(key).unwrap_or_else(|| Vec::new(e));
+    if checkpoints.last().is_some_and(|(last_id, _)| last_id >= snapshot_id) {
+        return;
+    }
+    checkpoints.push_back((snapshot_id, value));
+    e.storage().persistent().set(key, &checkpoints);
+}
+
+/// Returns the value of the first checkpoint at or after `snapshot_id`, which
+/// is the value at the time of `snapshot_id`, or `None` if the value wasn't
+/// modified since. Binary searches the sorted checkpoints.
+fn lookup(checkpoints: Option<Vec<(u32, i128)>>, snapshot_id: u32) -> Option<i128> {
+    let checkpoints = checkpoints?;
+    let (mut low, mut high) = (0, checkpoints.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if checkpoints.get_unchecked(mid).0 < snapshot_id {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    checkpoints.get(low).map(|(_, value)| value)
+}
@@ -0,0 +1,110 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    extensions::{
+        burnable::burn,
+        mintable::mint,
+        snapshot::{balance_at, current_snapshot_id, snapshot, total_supply_at},
+    },
+    storage::{balance, total_supply, transfer},
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn snapshot_without_activity_returns_zero() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        assert_eq!(current_snapshot_id(&e), 0);
+        let snapshot_id = snapshot(&e);
+        assert_eq!(snapshot_id, 1);
+
+        assert_eq!(balance_at(&e, &account, snapshot_id), 0);
+        assert_eq!(total_supply_at(&e, snapshot_id), 0);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+    });
+}
+
+#[test]
+fn balances_are_checkpointed_across_snapshots() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &alice, 100);
+        assert_eq!(snapshot(&e), 1);
+        transfer(&e, &alice, &bob, 30);
+    });
+
+    e.as_contract(&address, || {
+        transfer(&e, &alice, &bob, 10);
+        let (first, second) = (1, snapshot(&e));
+        // untouched between the second and the third snapshot
+        let third = snapshot(&e);
+
+        mint(&e, &bob, 50);
+        let fourth = snapshot(&e);
+
+        assert_eq!(balance_at(&e, &alice, first), 100);
+        assert_eq!(balance_at(&e, &bob, first), 0);
+        assert_eq!(total_supply_at(&e, first), 100);
+
+        assert_eq!(balance_at(&e, &alice, second), 60);
+        assert_eq!(balance_at(&e, &bob, second), 40);
+        assert_eq!(balance_at(&e, &bob, third), 40);
+        assert_eq!(total_supply_at(&e, third), 100);
+
+        assert_eq!(balance_at(&e, &alice, fourth), 60);
+        assert_eq!(balance_at(&e, &bob, fourth), 90);
+        assert_eq!(total_supply_at(&e, fourth), 150);
+    });
+}
+
+#[test]
+fn burn_is_checkpointed() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 100);
+        assert_eq!(snapshot(&e), 1);
+    });
+
+    e.as_contract(&address, || {
+        burn(&e, &account, 40);
+
+        assert_eq!(balance(&e, &account), 60);
+        assert_eq!(total_supply(&e), 60);
+        assert_eq!(balance_at(&e, &account, 1), 100);
+        assert_eq!(total_supply_at(&e, 1), 100);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #216)")]
+fn balance_at_future_snapshot_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        snapshot(&e);
+        balance_at(&e, &account, 2);
+    });
+}
@@ -0,0 +1,72 @@
+/// Unlike other extensions, the `stream` extension does not provide a separate
+/// trait. It streams tokens continuously from a sender to a recipient, e.g.
+/// for payroll or grants: the tokens are escrowed by the contract when the
+/// stream is created and vest linearly between the `start` and `end` ledgers.
+///
+/// This module provides the following helper functions:
+/// - `create_stream`: Escrows `total` tokens of the sender and returns the
+///   identifier of the new stream.
+/// - `stream`: Returns the details of a stream.
+/// - `vested_amount`: Returns the amount of tokens of a stream vested so far.
+/// - `withdraw_from_stream`: Releases the vested tokens not yet withdrawn to
+///   the recipient.
+/// - `cancel_stream`: Releases the vested tokens not yet withdrawn to the
+///   recipient and refunds the unvested ones to the sender.
+mod storage;
+use soroban_sdk::{symbol_short, Address, Env};
+
+pub use self::storage::{
+    cancel_stream, create_stream, stream, vested_amount, withdraw_from_stream, Stream,
+    StreamStorageKey,
+};
+
+mod test;
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating a stream has been created.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `stream_id` - The identifier of the stream.
+/// * `sender` - The address streaming the tokens.
+/// * `recipient` - The address receiving the tokens.
+/// * `total` - The amount of tokens streamed.
+/// * `start` - The ledger number at which the tokens start vesting.
+/// * `end` - The ledger number at which all the tokens are vested.
+///
+/// # Events
+///
+/// * topics - `["stream", sender: Address, recipient: Address]`
+/// * data - `[stream_id: u32, total: i128, start: u32, end: u32]`
+pub fn emit_stream_created(
+    e: &Env,
+    stream_id: u32,
+    sender: &Address,
+    recipient: &Address,
+    total: i128,
+    start: u32,
+    end: u32,
+) {
+    let topics = (symbol_short!("stream"), sender, recipient);
+    e.events().publish(topics, (stream_id, total, start, end))
+}
+
+/// Emits an event indicating a stream has been cancelled.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `stream_id` - The identifier of the stream.
+/// * `recipient_amount` - The amount of tokens released to the recipient.
+/// * `refund` - The amount of tokens refunded to the sender.
+///
+/// # Events
+///
+/// * topics - `["cancel", stream_id: u32]`
+/// * data - `[recipient_amount: i128, refund: i128]`
+pub fn emit_stream_cancelled(e: &Env, stream_id: u32, recipient_amount: i128, refund: i128) {
+    let topics = (symbol_short!("cancel"), stream_id);
+    e.events().publish(topics, (recipient_amount, refund))
+}
@@ -0,0 +1,217 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+use stellar_constants::{BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD};
+
+use crate::{
+    extensions::stream::{emit_stream_cancelled, emit_stream_created},
+    math,
+    storage::update,
+    FungibleTokenError,
+};
+
+/// Storage container for a stream of tokens.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Stream {
+    pub sender: Address,
+    pub recipient: Address,
+    pub total: i128,
+    pub start: u32,
+    pub end: u32,
+    pub withdrawn: i128,
+}
+
+/// Storage keys for the data associated with the `stream` extension.
+#[contracttype]
+pub enum StreamStorageKey {
+    Counter,
+    Stream(u32),
+}
+
+/// Returns the details of the stream `stream_id`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `stream_id` - The identifier of the stream.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::StreamNotFound`] - When the stream doesn't exist, or
+///   has been cancelled.
+pub fn stream(e: &Env, stream_id: u32) -> Stream {
+    let key = StreamStorageKey::Stream(stream_id);
+    let Some(stream) = e.storage().persistent().get(&key) else {
+        panic_with_error!(e, FungibleTokenError::StreamNotFound);
+    };
+    e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+    stream
+}
+
+/// Returns the amount of tokens of `stream` vested at the current ledger,
+/// including the ones already withdrawn.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `stream` - The stream.
+pub fn vested_amount(e: &Env, stream: &Stream) -> i128 {
+    let current_ledger = e.ledger().sequence();
+    if current_ledger <= stream.start {
+        0
+    } else if current_ledger >= stream.end {
+        stream.total
+    } else {
+        math::mul_div(
+            e,
+            stream.total,
+            (current_ledger - stream.start) as i128,
+            (stream.end - stream.start) as i128,
+        )
+    }
+}
+
+/// Creates a stream of `total` tokens from `from` to `to`, vesting linearly
+/// between the ledgers `start` and `end`. The tokens are escrowed by the
+/// contract until they are withdrawn or refunded.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `from` - The address streaming the tokens.
+/// * `to` - The address receiving the tokens.
+/// * `total` - The amount of tokens to stream.
+/// * `start` - The ledger number at which the tokens start vesting.
+/// * `end` - The ledger number at which all the tokens are vested.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::InvalidStream`] - When `total` is not positive or
+///   `end` is not greater than `start`.
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["transfer", from: Address, contract: Address]`
+/// * data - `[amount: i128]`
+///
+/// * topics - `["stream", from: Address, to: Address]`
+/// * data - `[stream_id: u32, total: i128, start: u32, end: u32]`
+///
+/// # Notes
+///
+/// * Authorization for `from` is required.
+pub fn create_stream(
+    e: &Env,
+    from: &Address,
+    to: &Address,
+    total: i128,
+    start: u32,
+    end: u32,
+) -> u32 {
+    from.require_auth();
+
+    if total <= 0 || end <= start {
+        panic_with_error!(e, FungibleTokenError::InvalidStream);
+    }
+
+    let contract = e.current_contract_address();
+    update(e, Some(from), Some(&contract), total);
+    crate::emit_transfer(e, from, &contract, total);
+
+    let stream_id: u32 = e.storage().instance().get(&StreamStorageKey::Counter).unwrap_or(0);
+    let Some(next_id) = stream_id.checked_add(1) else {
+        panic_with_error!(e, FungibleTokenError::MathOverflow);
+    };
+    e.storage().instance().set(&StreamStorageKey::Counter, &next_id);
+
+    let stream =
+        Stream { sender: from.clone(), recipient: to.clone(), total, start, end, withdrawn: 0 };
+    e.storage().persistent().set(&StreamStorageKey::Stream(stream_id), &stream);
+
+    emit_stream_created(e, stream_id, from, to, total, start, end);
+    stream_id
+}
+
+/// Releases the tokens of the stream `stream_id` vested and not yet
+/// withdrawn to its recipient, and returns their amount.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `stream_id` - The identifier of the stream.
+///
+/// # Errors
+///
+/// * refer to [`stream`] errors.
+///
+/// # Events
+///
+/// * topics - `["transfer", contract: Address, recipient: Address]`
+/// * data - `[amount: i128]`
+///
+/// # Notes
+///
+/// * Authorization for the recipient of the stream is required.
+pub fn withdraw_from_stream(e: &Env, stream_id: u32) -> i128 {
+    let mut stream = stream(e, stream_id);
+    stream.recipient.require_auth();
+
+    let amount = vested_amount(e, &stream) - stream.withdrawn;
+    release(e, &stream.recipient, amount);
+
+    stream.withdrawn += amount;
+    e.storage().persistent().set(&StreamStorageKey::Stream(stream_id), &stream);
+
+    amount
+}
+
+/// Cancels the stream `stream_id`: the tokens vested and not yet withdrawn
+/// are released to the recipient, and the unvested ones are refunded to the
+/// sender.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `stream_id` - The identifier of the stream.
+///
+/// # Errors
+///
+/// * refer to [`stream`] errors.
+///
+/// # Events
+///
+/// * topics - `["transfer", contract: Address, recipient: Address]`
+/// * data - `[amount: i128]`
+///
+/// * topics - `["transfer", contract: Address, sender: Address]`
+/// * data - `[amount: i128]`
+///
+/// * topics - `["cancel", stream_id: u32]`
+/// * data - `[recipient_amount: i128, refund: i128]`
+///
+/// # Notes
+///
+/// * Authorization for the sender of the stream is required.
+pub fn cancel_stream(e: &Env, stream_id: u32) {
+    let stream = stream(e, stream_id);
+    stream.sender.require_auth();
+
+    let vested = vested_amount(e, &stream);
+    let recipient_amount = vested - stream.withdrawn;
+    let refund = stream.total - vested;
+
+    release(e, &stream.recipient, recipient_amount);
+    release(e, &stream.sender, refund);
+    e.storage().persistent().remove(&StreamStorageKey::Stream(stream_id));
+
+    emit_stream_cancelled(e, stream_id, recipient_amount, refund);
+}
+
+/// Transfers `amount` of escrowed tokens to `to`, if any.
+fn release(e: &Env, to: &Address, amount: i128) {
+    if amount > 0 {
+        let contract = e.current_contract_address();
+        update(e, Some(&contract), Some(to), amount);
+        crate::emit_transfer(e, &contract, to, amount);
+    }
+}
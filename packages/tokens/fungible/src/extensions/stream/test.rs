@@ -0,0 +1,141 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger as _},
+    Address, Env,
+};
+
+use crate::{
+    extensions::{
+        mintable::mint,
+        stream::{cancel_stream, create_stream, stream, vested_amount, withdraw_from_stream},
+    },
+    storage::balance,
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn withdraw_mid_stream_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let sender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    let stream_id = e.as_contract(&address, || {
+        mint(&e, &sender, 1000);
+        let stream_id = create_stream(&e, &sender, &recipient, 1000, 100, 200);
+        assert_eq!(balance(&e, &sender), 0);
+        assert_eq!(balance(&e, &address), 1000);
+        assert_eq!(vested_amount(&e, &stream(&e, stream_id)), 0);
+        stream_id
+    });
+
+    e.ledger().set_sequence_number(150);
+    e.as_contract(&address, || {
+        assert_eq!(withdraw_from_stream(&e, stream_id), 500);
+        assert_eq!(balance(&e, &recipient), 500);
+        assert_eq!(stream(&e, stream_id).withdrawn, 500);
+    });
+
+    // nothing more to withdraw within the same ledger
+    e.as_contract(&address, || {
+        assert_eq!(withdraw_from_stream(&e, stream_id), 0);
+    });
+
+    e.ledger().set_sequence_number(175);
+    e.as_contract(&address, || {
+        assert_eq!(withdraw_from_stream(&e, stream_id), 250);
+        assert_eq!(balance(&e, &recipient), 750);
+        assert_eq!(balance(&e, &address), 250);
+    });
+}
+
+#[test]
+fn withdraw_after_end_releases_everything() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let sender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    let stream_id = e.as_contract(&address, || {
+        mint(&e, &sender, 1000);
+        create_stream(&e, &sender, &recipient, 1000, 100, 200)
+    });
+
+    e.ledger().set_sequence_number(300);
+    e.as_contract(&address, || {
+        assert_eq!(withdraw_from_stream(&e, stream_id), 1000);
+        assert_eq!(balance(&e, &recipient), 1000);
+        assert_eq!(balance(&e, &address), 0);
+    });
+}
+
+#[test]
+fn cancel_stream_splits_funds() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let sender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    let stream_id = e.as_contract(&address, || {
+        mint(&e, &sender, 1000);
+        create_stream(&e, &sender, &recipient, 1000, 100, 200)
+    });
+
+    e.ledger().set_sequence_number(120);
+    e.as_contract(&address, || {
+        assert_eq!(withdraw_from_stream(&e, stream_id), 200);
+    });
+
+    e.ledger().set_sequence_number(160);
+    e.as_contract(&address, || {
+        cancel_stream(&e, stream_id);
+        // 600 vested, of which 200 were already withdrawn
+        assert_eq!(balance(&e, &recipient), 600);
+        assert_eq!(balance(&e, &sender), 400);
+        assert_eq!(balance(&e, &address), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #229)")]
+fn withdraw_from_cancelled_stream_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let sender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    let stream_id = e.as_contract(&address, || {
+        mint(&e, &sender, 1000);
+        create_stream(&e, &sender, &recipient, 1000, 100, 200)
+    });
+
+    e.as_contract(&address, || cancel_stream(&e, stream_id));
+    e.as_contract(&address, || {
+        withdraw_from_stream(&e, stream_id);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #228)")]
+fn create_stream_ending_before_start_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let sender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &sender, 1000);
+        create_stream(&e, &sender, &recipient, 1000, 200, 200);
+    });
+}
@@ -0,0 +1,21 @@
+/// Unlike other extensions, the `wrapper` extension does not provide a
+/// separate trait for the token. It turns the token into a 1:1 wrapper of an
+/// underlying token, e.g. a Stellar Asset Contract (SAC), held in custody by
+/// the contract.
+///
+/// This module provides the following helper functions:
+/// - `set_underlying`: Sets the underlying token, meant to be called from the
+///   constructor.
+/// - `underlying`: Returns the underlying token.
+/// - `deposit`: Pulls `amount` of the underlying token from `from` and mints
+///   the same amount of the wrapper to `from`.
+/// - `withdraw`: Burns `amount` of the wrapper from `to` and sends the same
+///   amount of the underlying token back to `to`.
+///
+/// The total supply of the wrapper is thus always backed by the balance of the
+/// underlying token held by the contract.
+mod storage;
+
+pub use self::storage::{deposit, set_underlying, underlying, withdraw, UNDERLYING_KEY};
+
+mod test;
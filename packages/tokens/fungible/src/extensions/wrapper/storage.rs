@@ -0,0 +1,106 @@
+use soroban_sdk::{panic_with_error, symbol_short, token, Address, Env, Symbol};
+
+use crate::{
+    extensions::{burnable::burn, mintable::mint},
+    FungibleTokenError,
+};
+
+/// Storage key for the address of the underlying token
+pub const UNDERLYING_KEY: Symbol = symbol_short!("UNDERLYNG");
+
+/// Returns the address of the underlying token.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::UnderlyingNotSet`] - Occurs when the underlying
+///   token has not been set.
+pub fn underlying(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&UNDERLYING_KEY)
+        .unwrap_or_else(|| panic_with_error!(e, FungibleTokenError::UnderlyingNotSet))
+}
+
+/// Sets the underlying token wrapped by this token.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `underlying` - The address of the underlying token.
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is meant to be called once, from the constructor. Changing the
+/// underlying token afterwards would leave the wrapper backed by the balance
+/// of the previous one.
+pub fn set_underlying(e: &Env, underlying: &Address) {
+    e.storage().instance().set(&UNDERLYING_KEY, underlying);
+}
+
+/// Deposits `amount` of the underlying token from `from` into the contract and
+/// mints the same amount of the wrapper to `from`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `from` - The account depositing the underlying token.
+/// * `amount` - The amount of tokens to deposit.
+///
+/// # Errors
+///
+/// * refer to [`underlying`] errors.
+/// * refer to [`mint`] errors.
+/// * refer to the `transfer` errors of the underlying token.
+///
+/// # Events
+///
+/// * topics - `["mint", to: Address]`
+/// * data - `[amount: i128]`
+///
+/// # Notes
+///
+/// Authorization for `from` is required.
+pub fn deposit(e: &Env, from: &Address, amount: i128) {
+    from.require_auth();
+
+    let underlying = underlying(e);
+    token::TokenClient::new(e, &underlying).transfer(from, &e.current_contract_address(), &amount);
+
+    mint(e, from, amount);
+}
+
+/// Burns `amount` of the wrapper from `to` and sends the same amount of the
+/// underlying token back to `to`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `to` - The account withdrawing the underlying token.
+/// * `amount` - The amount of tokens to withdraw.
+///
+/// # Errors
+///
+/// * refer to [`underlying`] errors.
+/// * refer to [`burn`] errors.
+///
+/// # Events
+///
+/// * topics - `["burn", from: Address]`
+/// * data - `[amount: i128]`
+///
+/// # Notes
+///
+/// Authorization for `to` is required.
+pub fn withdraw(e: &Env, to: &Address, amount: i128) {
+    let underlying = underlying(e);
+
+    burn(e, to, amount);
+
+    token::TokenClient::new(e, &underlying).transfer(&e.current_contract_address(), to, &amount);
+}
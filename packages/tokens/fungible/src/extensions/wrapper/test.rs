@@ -0,0 +1,122 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract, contractimpl, testutils::Address as _, token::StellarAssetClient, Address, Env,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    extensions::wrapper::{deposit, set_underlying, underlying, withdraw},
+    storage::{balance, total_supply},
+};
+
+#[contract]
+struct Wrapper;
+
+#[contractimpl]
+impl Wrapper {
+    pub fn __constructor(e: &Env, underlying: Address) {
+        set_underlying(e, &underlying);
+    }
+
+    pub fn deposit(e: &Env, from: Address, amount: i128) {
+        deposit(e, &from, amount);
+    }
+
+    pub fn withdraw(e: &Env, to: Address, amount: i128) {
+        withdraw(e, &to, amount);
+    }
+}
+
+#[contract]
+struct MockContract;
+
+fn setup(e: &Env, holder: &Address, amount: i128) -> (Address, Address) {
+    let admin = Address::generate(e);
+    let asset = e.register_stellar_asset_contract_v2(admin).address();
+    StellarAssetClient::new(e, &asset).mint(holder, &amount);
+    let wrapper = e.register(Wrapper, (asset.clone(),));
+    (wrapper, asset)
+}
+
+#[test]
+fn deposit_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let holder = Address::generate(&e);
+    let (wrapper, asset) = setup(&e, &holder, 1000);
+    let client = WrapperClient::new(&e, &wrapper);
+    let asset_client = StellarAssetClient::new(&e, &asset);
+
+    client.deposit(&holder, &400);
+
+    let event_assert = EventAssertion::new(&e, wrapper.clone());
+    event_assert.assert_fungible_mint(&holder, 400);
+
+    assert_eq!(asset_client.balance(&holder), 600);
+    assert_eq!(asset_client.balance(&wrapper), 400);
+    e.as_contract(&wrapper, || {
+        assert_eq!(underlying(&e), asset);
+        assert_eq!(balance(&e, &holder), 400);
+        assert_eq!(total_supply(&e), 400);
+    });
+}
+
+#[test]
+fn deposit_and_withdraw_round_trip() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let holder = Address::generate(&e);
+    let (wrapper, asset) = setup(&e, &holder, 1000);
+    let client = WrapperClient::new(&e, &wrapper);
+    let asset_client = StellarAssetClient::new(&e, &asset);
+
+    client.deposit(&holder, &1000);
+    client.withdraw(&holder, &300);
+
+    let event_assert = EventAssertion::new(&e, wrapper.clone());
+    event_assert.assert_fungible_burn(&holder, 300);
+
+    e.as_contract(&wrapper, || {
+        assert_eq!(balance(&e, &holder), 700);
+        assert_eq!(total_supply(&e), 700);
+    });
+    assert_eq!(asset_client.balance(&holder), 300);
+    // the wrapper supply is fully backed
+    assert_eq!(asset_client.balance(&wrapper), 700);
+
+    client.withdraw(&holder, &700);
+
+    e.as_contract(&wrapper, || {
+        assert_eq!(balance(&e, &holder), 0);
+        assert_eq!(total_supply(&e), 0);
+    });
+    assert_eq!(asset_client.balance(&holder), 1000);
+    assert_eq!(asset_client.balance(&wrapper), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #200)")]
+fn withdraw_more_than_deposited_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let holder = Address::generate(&e);
+    let (wrapper, _) = setup(&e, &holder, 1000);
+    let client = WrapperClient::new(&e, &wrapper);
+
+    client.deposit(&holder, &100);
+    client.withdraw(&holder, &101);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #232)")]
+fn underlying_not_set_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        underlying(&e);
+    });
+}
@@ -1,4 +1,6 @@
-use soroban_sdk::{contracterror, symbol_short, Address, Env, String};
+use soroban_sdk::{
+    contractclient, contracterror, symbol_short, Address, Bytes, BytesN, Env, String, Symbol,
+};
 
 /// Vanilla Fungible Token Trait
 ///
@@ -151,6 +153,57 @@ pub trait FungibleToken {
     }
 }
 
+/// Interface of the contracts deciding whether a conditional allowance can be
+/// spent (see [`crate::set_conditional_allowance`]).
+#[contractclient(name = "AllowancePredicateClient")]
+pub trait AllowancePredicate {
+    /// Called by the token before `spender` spends `amount` of the allowance
+    /// granted by `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `spender` - The address spending the allowance.
+    /// * `amount` - The amount of tokens to be spent.
+    ///
+    /// Returns `true` to allow the spend, `false` to deny it.
+    fn check(e: Env, owner: Address, spender: Address, amount: i128) -> bool;
+}
+
+/// Interface of the contracts receiving tokens through
+/// [`crate::transfer_and_call`], which mirrors the `onTransferReceived` hook of
+/// ERC-1363.
+#[contractclient(name = "FungibleReceiverClient")]
+pub trait FungibleReceiver {
+    /// Handles the receipt of tokens. Called on the recipient after the tokens
+    /// have been transferred to it.
+    ///
+    /// Returns `true` to confirm the tokens have been accepted. Any other
+    /// outcome, including a failure of the call, reverts the transfer.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `operator` - The address which initiated the transfer.
+    /// * `from` - The previous holder of the tokens.
+    /// * `amount` - The amount of tokens received.
+    /// * `data` - Additional data with no specified format, forwarded as is.
+    ///
+    /// # Notes
+    ///
+    /// Anyone can invoke this function. A receiver crediting the received
+    /// tokens should call `require_auth()` on the address of the expected
+    /// token, which only succeeds when the token is the direct caller.
+    fn on_token_received(
+        e: Env,
+        operator: Address,
+        from: Address,
+        amount: i128,
+        data: Bytes,
+    ) -> bool;
+}
+
 // ################## ERRORS ##################
 
 #[contracterror]
@@ -179,6 +232,77 @@ pub enum FungibleTokenError {
     InvalidCap = 207,
     /// Indicates the Cap was not set.
     CapNotSet = 208,
+    /// Indicates the supplied mint rate limit is not valid.
+    InvalidMintRateLimit = 209,
+    /// Indicates that the operation would have exceeded the amount of tokens
+    /// that can be minted within the current window.
+    MintRateLimitExceeded = 210,
+    /// Indicates an allowance greater than the ceiling configured by the
+    /// owner for the spender.
+    AllowanceExceedsCeiling = 211,
+    /// Indicates the recipient has been designated as an invalid recipient.
+    InvalidRecipient = 212,
+    /// Indicates that the lengths of the supplied vectors don't match.
+    LengthMismatch = 213,
+    /// Indicates the supplied rebase target is not valid, or there is no token
+    /// in circulation to rebase.
+    InvalidRebase = 214,
+    /// Indicates an attempt to claw back tokens from an account that is not
+    /// enabled for clawback.
+    ClawbackNotEnabled = 215,
+    /// Indicates a snapshot that doesn't exist.
+    SnapshotNotFound = 216,
+    /// Indicates a signed operation submitted after its deadline.
+    SignatureExpired = 217,
+    /// Indicates a signed operation using a nonce other than the current one.
+    InvalidNonce = 218,
+    /// Indicates a signed operation for an address that can't sign it.
+    InvalidSigner = 219,
+    /// Indicates an attempt to burn tokens before the end of the cooldown
+    /// following the last mint to the account.
+    BurnCooldownActive = 220,
+    /// Indicates a fee rate above 100%.
+    InvalidFee = 221,
+    /// Indicates a single mint exceeding the maximum amount mintable per call.
+    ExceededMaxMintPerCall = 222,
+    /// Indicates an attempt to spend a frozen allowance.
+    AllowanceFrozen = 223,
+    /// Indicates an allowance expiring later than the configured maximum
+    /// approval duration.
+    ApprovalDurationExceeded = 224,
+    /// Indicates a flash loan that was not accepted or not repaid by the
+    /// receiver.
+    FlashLoanNotRepaid = 226,
+    /// Indicates a flash loan greater than the maximum amount that can be
+    /// borrowed.
+    ExceededMaxFlashLoan = 227,
+    /// Indicates a stream with a non-positive amount, or ending before it
+    /// starts.
+    InvalidStream = 228,
+    /// Indicates a stream that doesn't exist.
+    StreamNotFound = 229,
+    /// Indicates an attempt to unlock more tokens than the locked balance of
+    /// an account.
+    InsufficientLockedBalance = 230,
+    /// Indicates a conditional allowance whose predicate denied the spend.
+    AllowanceConditionNotMet = 231,
+    /// Indicates access to the underlying token of a wrapper before it is
+    /// set.
+    UnderlyingNotSet = 232,
+    /// Indicates a fractional part which is negative or exceeds the token
+    /// decimals.
+    InvalidFraction = 233,
+    /// Indicates access to the exchange rate provider before it is set.
+    ExchangeRateProviderNotSet = 234,
+    /// Indicates an exchange rate which is not positive.
+    InvalidExchangeRate = 235,
+    /// Indicates a recipient of [`crate::transfer_and_call`] which didn't
+    /// confirm the receipt of the tokens.
+    TransferRejected = 236,
+    /// Indicates an owner tracking too many spenders for allowance snapshots.
+    TooManySpenders = 237,
+    /// Indicates a burn cooldown longer than the maximum TTL of the network.
+    InvalidBurnCooldown = 238,
 }
 
 // ################## EVENTS ##################
@@ -196,9 +320,85 @@ pub enum FungibleTokenError {
 ///
 /// * topics - `["transfer", from: Address, to: Address]`
 /// * data - `[amount: i128]`
+///
+/// When the `transfer_sequence` feature is enabled:
+///
+/// * data - `[amount: i128, sequence: u64]`
+///
+/// When the `detailed_events` feature is enabled, the data additionally
+/// carries the balances of `from` and `to` after the transfer, before the
+/// sequence number if any:
+///
+/// * data - `[amount: i128, from_balance: i128, to_balance: i128]`
 pub fn emit_transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
     let topics = (symbol_short!("transfer"), from, to);
-    e.events().publish(topics, amount)
+    #[cfg(all(not(feature = "transfer_sequence"), not(feature = "detailed_events")))]
+    e.events().publish(topics, amount);
+    #[cfg(all(feature = "transfer_sequence", not(feature = "detailed_events")))]
+    e.events().publish(topics, (amount, crate::transfer_sequence(e)));
+    #[cfg(all(not(feature = "transfer_sequence"), feature = "detailed_events"))]
+    e.events().publish(topics, (amount, crate::balance(e, from), crate::balance(e, to)));
+    #[cfg(all(feature = "transfer_sequence", feature = "detailed_events"))]
+    e.events().publish(
+        topics,
+        (amount, crate::balance(e, from), crate::balance(e, to), crate::transfer_sequence(e)),
+    );
+}
+
+/// Emits an event indicating a transfer of tokens carrying a commitment to a
+/// memo, to be revealed later.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `amount` - The amount of tokens to be transferred.
+/// * `commitment` - The hash commitment attached to the transfer.
+///
+/// # Events
+///
+/// * topics - `["transfer", from: Address, to: Address]`
+/// * data - `[amount: i128, commitment: BytesN<32>]`
+///
+/// When the `transfer_sequence` feature is enabled:
+///
+/// * data - `[amount: i128, commitment: BytesN<32>, sequence: u64]`
+///
+/// When the `detailed_events` feature is enabled, the data additionally
+/// carries the balances of `from` and `to` after the transfer, right after
+/// `amount` as in [`emit_transfer`]:
+///
+/// * data - `[amount: i128, from_balance: i128, to_balance: i128, commitment:
+///   BytesN<32>]`
+pub fn emit_transfer_with_commitment(
+    e: &Env,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    commitment: &BytesN<32>,
+) {
+    let topics = (symbol_short!("transfer"), from, to);
+    #[cfg(all(not(feature = "transfer_sequence"), not(feature = "detailed_events")))]
+    e.events().publish(topics, (amount, commitment.clone()));
+    #[cfg(all(feature = "transfer_sequence", not(feature = "detailed_events")))]
+    e.events().publish(topics, (amount, commitment.clone(), crate::transfer_sequence(e)));
+    #[cfg(all(not(feature = "transfer_sequence"), feature = "detailed_events"))]
+    e.events().publish(
+        topics,
+        (amount, crate::balance(e, from), crate::balance(e, to), commitment.clone()),
+    );
+    #[cfg(all(feature = "transfer_sequence", feature = "detailed_events"))]
+    e.events().publish(
+        topics,
+        (
+            amount,
+            crate::balance(e, from),
+            crate::balance(e, to),
+            commitment.clone(),
+            crate::transfer_sequence(e),
+        ),
+    );
 }
 
 /// Emits an event indicating an allowance was set.
@@ -225,3 +425,28 @@ pub fn emit_approve(
     let topics = (symbol_short!("approve"), owner, spender);
     e.events().publish(topics, (amount, live_until_ledger))
 }
+
+/// Emits an event indicating an allowance was found expired and removed.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address whose allowance expired.
+/// * `amount` - The amount of tokens that was left unspent.
+/// * `live_until_ledger` - The ledger number at which the allowance expired.
+///
+/// # Events
+///
+/// * topics - `["allowance_expired", owner: Address, spender: Address]`
+/// * data - `[amount: i128, live_until_ledger: u32]`
+pub fn emit_allowance_expired(
+    e: &Env,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    live_until_ledger: u32,
+) {
+    let topics = (Symbol::new(e, "allowance_expired"), owner, spender);
+    e.events().publish(topics, (amount, live_until_ledger))
+}
@@ -39,6 +39,31 @@
 //! - Burnable: Enables token holders to destroy their tokens, reducing the
 //!   total supply.
 //! - Capped: Enables the contract to set a maximum limit on the total supply.
+//! - Atomic Swap: Settles over-the-counter deals between two parties, both legs
+//!   of the deal being executed atomically.
+//! - Clawback: Allows taking back the tokens of the accounts enabled for
+//!   clawback.
+//! - Exchange Rate: Turns the token into an interest-bearing token whose
+//!   balances grow with an exchange rate reported by a provider contract.
+//! - Fee On Transfer: Skims a fee from every transfer, accrued until it is
+//!   withdrawn.
+//! - Flash Mint: Lends freshly minted tokens that must be returned, plus a fee,
+//!   within the same invocation. Not ERC-3156: the borrowed tokens can't be
+//!   used, as the token can't be re-entered from the callback.
+//! - Meta Transfer: Enables gasless transfers signed by the holder and
+//!   submitted by a relayer.
+//! - Mint Rate Limit: Bounds the amount of tokens that can be minted within a
+//!   window of ledgers.
+//! - Permit: Enables gasless approvals signed by the owner and submitted by
+//!   anyone, typically the spender.
+//! - Rebase: Turns the token into an elastic supply token whose total supply
+//!   can be adjusted while preserving the relative balances of all holders.
+//! - Snapshot: Records the balances and the total supply at given points in
+//!   time, e.g. for governance or dividends.
+//! - Stream: Streams escrowed tokens from a sender to a recipient, vesting
+//!   linearly over a range of ledgers.
+//! - Wrapper: Wraps an underlying token 1:1, e.g. a Stellar Asset Contract,
+//!   through deposits and withdrawals.
 //!
 //! ## Compatibility and Compliance
 //!
@@ -62,23 +87,64 @@
 //! - **Composable Design**: The modular structure encourages developers to
 //!   extend functionality by combining provided primitives or creating custom
 //!   extensions.
+//! - **Zero amounts**: Transfers, mints and burns of `0` tokens are accepted.
+//!   They don't modify any balance nor the total supply, but they emit their
+//!   events like any other successful operation.
 //! - **TTL management**: This library handles the TTL of only `temporary` and
 //!   `persistent` storage entries declared by the library. The `instance` TTL
 //!   management is left to the implementor due to flexibility. The library
 //!   exposes the sane default values for extending the TTL:
 //!   `INSTANCE_TTL_THRESHOLD` and `INSTANCE_EXTEND_AMOUNT`.
+//! - **Locked balances**: Tokens locked with [`lock_balance`] stay in the
+//!   balance of their holder but can't be transferred nor burned until they are
+//!   unlocked, see [`spendable_balance`].
+//! - **Transfer and call**: [`transfer_and_call`] notifies contract recipients
+//!   implementing [`FungibleReceiver`] of the tokens they receive, and reverts
+//!   unless they confirm the receipt.
+//! - **Pausing**: With the `pausable` feature, [`update`] performs a pause
+//!   check, so that every transfer, burn and mint panics while the contract is
+//!   paused, whichever entry point it comes from. Contracts pick the
+//!   [`PausableBase`] marker to make it explicit. Without the feature, no pause
+//!   check (hence no storage read) is performed.
+//! - **Checked math**: The [`math`] module exposes the checked arithmetic used
+//!   by the token and its extensions, panicking with
+//!   [`FungibleTokenError::MathOverflow`], for custom extensions to reuse.
 #![no_std]
 
 mod extensions;
 mod fungible;
 mod impl_token_interface_macro;
+pub mod math;
+mod overrides;
 mod storage;
 
-pub use extensions::{burnable, capped, metadata, mintable};
-pub use fungible::{emit_approve, emit_transfer, FungibleToken, FungibleTokenError};
+pub use extensions::{
+    atomic_swap, burnable, capped, clawback, exchange_rate, fee_on_transfer, flash_mint,
+    meta_transfer, metadata, mint_rate_limit, mintable, permit, rebase, snapshot, stream, wrapper,
+};
+pub use fungible::{
+    emit_allowance_expired, emit_approve, emit_transfer, emit_transfer_with_commitment,
+    AllowancePredicate, AllowancePredicateClient, FungibleReceiver, FungibleReceiverClient,
+    FungibleToken, FungibleTokenError,
+};
+pub use overrides::*;
+#[cfg(feature = "transfer_sequence")]
+pub use storage::transfer_sequence;
 pub use storage::{
-    allowance, allowance_data, approve, balance, set_allowance, spend_allowance, total_supply,
-    transfer, transfer_from, update, AllowanceData, AllowanceKey, StorageKey,
+    allowance, allowance_at, allowance_ceiling, allowance_data, allowance_live_until,
+    allowance_tag, approve, approve_batch, approve_tracked, approve_with_tag, balance,
+    decaying_allowance, decrease_allowance, freeze_allowance, increase_allowance,
+    is_allowance_frozen, lock_balance, locked_balance, max_approval_duration, reconcile_supply,
+    remove_auto_renew_allowance, remove_conditional_allowance, remove_decaying_allowance,
+    set_allowance, set_allowance_ceiling, set_allowance_with_tag, set_auto_renew_allowance,
+    set_conditional_allowance, set_decaying_allowance, set_max_approval_duration,
+    snapshot_allowances, spend_allowance, spend_auto_renew_allowance, spend_conditional_allowance,
+    spend_decaying_allowance, spend_freezable_allowance, spendable_balance, total_supply, transfer,
+    transfer_and_call, transfer_batch, transfer_from, transfer_then, transfer_with_commitment,
+    transfer_with_relayer_fee, unfreeze_allowance, unlock_balance, update, AllowanceData,
+    AllowanceKey, AllowanceSnapshot, AutoRenewData, DecayData, StorageKey, MAX_TRACKED_SPENDERS,
 };
+#[cfg(feature = "invalid_recipient")]
+pub use storage::{is_invalid_recipient, remove_invalid_recipient, set_invalid_recipient};
 
 mod test;
@@ -0,0 +1,101 @@
+//! Checked arithmetic for token amounts.
+//!
+//! These helpers wrap the checked `i128` operations and panic with
+//! [`FungibleTokenError::MathOverflow`] instead of returning an `Option`, so
+//! that the token math reads as plain arithmetic. They are used across the
+//! token and its extensions, and can be reused by custom extensions as well.
+use soroban_sdk::{panic_with_error, Env};
+
+use crate::FungibleTokenError;
+
+/// Returns `a + b`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `a` - The first operand.
+/// * `b` - The second operand.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::MathOverflow`] - When the addition overflows.
+pub fn add(e: &Env, a: i128, b: i128) -> i128 {
+    a.checked_add(b).unwrap_or_else(|| panic_with_error!(e, FungibleTokenError::MathOverflow))
+}
+
+/// Returns `a - b`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `a` - The first operand.
+/// * `b` - The second operand.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::MathOverflow`] - When the subtraction overflows.
+pub fn sub(e: &Env, a: i128, b: i128) -> i128 {
+    a.checked_sub(b).unwrap_or_else(|| panic_with_error!(e, FungibleTokenError::MathOverflow))
+}
+
+/// Returns `a * b`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `a` - The first factor.
+/// * `b` - The second factor.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::MathOverflow`] - When the multiplication overflows.
+pub fn mul(e: &Env, a: i128, b: i128) -> i128 {
+    a.checked_mul(b).unwrap_or_else(|| panic_with_error!(e, FungibleTokenError::MathOverflow))
+}
+
+/// Returns `a * b / denom`, rounding towards zero.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `a` - The first factor.
+/// * `b` - The second factor.
+/// * `denom` - The divisor.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::MathOverflow`] - When the multiplication overflows
+///   or `denom` is `0`.
+pub fn mul_div(e: &Env, a: i128, b: i128, denom: i128) -> i128 {
+    a.checked_mul(b)
+        .and_then(|product| product.checked_div(denom))
+        .unwrap_or_else(|| panic_with_error!(e, FungibleTokenError::MathOverflow))
+}
+
+/// Returns `a * b / denom`, rounding up. `a`, `b` and `denom` are expected to
+/// be positive.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `a` - The first factor.
+/// * `b` - The second factor.
+/// * `denom` - The divisor.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::MathOverflow`] - When the multiplication overflows
+///   or `denom` is `0`.
+pub fn mul_div_ceil(e: &Env, a: i128, b: i128, denom: i128) -> i128 {
+    let product = mul(e, a, b);
+    let quotient = product
+        .checked_div(denom)
+        .unwrap_or_else(|| panic_with_error!(e, FungibleTokenError::MathOverflow));
+    if product % denom == 0 {
+        quotient
+    } else {
+        add(e, quotient, 1)
+    }
+}
+
+mod test;
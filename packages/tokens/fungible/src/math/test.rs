@@ -0,0 +1,115 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, Env};
+
+use crate::math::{add, mul, mul_div, mul_div_ceil, sub};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn add_and_sub_work() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        assert_eq!(add(&e, 40, 2), 42);
+        assert_eq!(sub(&e, 40, 42), -2);
+        assert_eq!(add(&e, i128::MAX - 1, 1), i128::MAX);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #204)")]
+fn add_overflow_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        add(&e, i128::MAX, 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #204)")]
+fn sub_underflow_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        sub(&e, i128::MIN, 1);
+    });
+}
+
+#[test]
+fn mul_div_rounds_towards_zero() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        assert_eq!(mul_div(&e, 100, 3, 4), 75);
+        assert_eq!(mul_div(&e, 10, 1, 3), 3);
+        assert_eq!(mul_div(&e, 2, 1, 3), 0);
+        assert_eq!(mul_div(&e, -10, 1, 3), -3);
+    });
+}
+
+#[test]
+fn mul_div_ceil_rounds_up() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        assert_eq!(mul_div_ceil(&e, 100, 3, 4), 75);
+        assert_eq!(mul_div_ceil(&e, 10, 1, 3), 4);
+        assert_eq!(mul_div_ceil(&e, 2, 1, 3), 1);
+        assert_eq!(mul_div_ceil(&e, 0, 1, 3), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #204)")]
+fn mul_div_ceil_by_zero_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        mul_div_ceil(&e, 1, 1, 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #204)")]
+fn mul_div_overflow_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        mul_div(&e, i128::MAX, 2, 4);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #204)")]
+fn mul_div_by_zero_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        mul_div(&e, 1, 1, 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #204)")]
+fn mul_overflow_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        assert_eq!(mul(&e, 6, 7), 42);
+        mul(&e, i128::MAX, 2);
+    });
+}
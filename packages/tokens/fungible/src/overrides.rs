@@ -0,0 +1,68 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{burnable, mintable};
+
+/// Based on the integration, some default behavior of the state-changing
+/// entry points of a fungible token might have to be overridden. This is a
+/// helper trait that allows us this override mechanism that favors the DevX.
+///
+/// The way to provide different default implementations for different
+/// integrations is by implementing the trait for different types (unit
+/// structs). The end-developer picks one of them and routes the methods of
+/// [`crate::FungibleToken`], [`crate::burnable::FungibleBurnable`] and
+/// [`crate::mintable::FungibleMintable`] through it.
+///
+/// Example:
+///
+/// ```rust
+/// type Token = PausableBase;
+///
+/// impl FungibleToken for ExampleContract {
+///     fn transfer(e: &Env, from: Address, to: Address, amount: i128) {
+///         Token::transfer(e, &from, &to, amount);
+///     }
+///
+///     /* and so on */
+/// }
+/// ```
+pub trait ContractOverrides {
+    fn transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
+        crate::transfer(e, from, to, amount);
+    }
+
+    fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, amount: i128) {
+        crate::transfer_from(e, spender, from, to, amount);
+    }
+
+    fn burn(e: &Env, from: &Address, amount: i128) {
+        burnable::burn(e, from, amount);
+    }
+
+    fn burn_from(e: &Env, spender: &Address, from: &Address, amount: i128) {
+        burnable::burn_from(e, spender, from, amount);
+    }
+
+    fn mint(e: &Env, to: &Address, amount: i128) {
+        mintable::mint(e, to, amount);
+    }
+}
+
+/// Default marker type
+pub struct Base;
+
+// No override required for the `Base` contract type.
+impl ContractOverrides for Base {}
+
+/// Marker type of the pausable tokens. With the `pausable` feature, the pause
+/// check is performed by [`crate::update`], which every balance change goes
+/// through, so that transfers, burns and mints coming from any entry point
+/// (including the extensions, e.g. flash loans or meta-transfers) panic while
+/// the contract is paused. Therefore, no method has to be overridden.
+///
+/// Approvals are not guarded, so that holders can still revoke them while the
+/// contract is paused.
+#[cfg(feature = "pausable")]
+pub struct PausableBase;
+
+#[cfg(feature = "pausable")]
+impl ContractOverrides for PausableBase {}
@@ -1,7 +1,16 @@
-use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+use soroban_sdk::{
+    contracttype, panic_with_error, token, Address, Bytes, BytesN, Env, Map, Symbol, Vec,
+};
 use stellar_constants::{BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD};
 
-use crate::fungible::{emit_approve, emit_transfer, FungibleTokenError};
+use crate::{
+    extensions::snapshot,
+    fungible::{
+        emit_approve, emit_transfer, emit_transfer_with_commitment, AllowancePredicateClient,
+        FungibleReceiverClient, FungibleTokenError,
+    },
+    math,
+};
 
 /// Storage key that maps to [`AllowanceData`]
 #[contracttype]
@@ -10,12 +19,43 @@ pub struct AllowanceKey {
     pub spender: Address,
 }
 
-/// Storage container for the amount of tokens for which an allowance is granted
-/// and the ledger number at which this allowance expires.
+/// Storage container for the amount of tokens for which an allowance is
+/// granted, the ledger number at which this allowance expires and an optional
+/// tag describing the purpose of the allowance.
 #[contracttype]
 pub struct AllowanceData {
     pub amount: i128,
     pub live_until_ledger: u32,
+    pub tag: Option<Symbol>,
+}
+
+/// Storage container for the configuration of an allowance that renews itself
+/// once fully consumed (see [`set_auto_renew_allowance`]).
+#[contracttype]
+pub struct AutoRenewData {
+    pub amount: i128,
+    pub window: u32,
+}
+
+/// Storage container for the schedule of an allowance that linearly decreases
+/// from `initial` to `0` between the ledgers `start` and `end` (see
+/// [`set_decaying_allowance`]).
+#[contracttype]
+pub struct DecayData {
+    pub initial: i128,
+    pub start: u32,
+    pub end: u32,
+}
+
+/// The maximum number of spenders tracked per owner by [`approve_tracked`].
+pub const MAX_TRACKED_SPENDERS: u32 = 20;
+
+/// Storage container for the allowances granted by `owner`, as recorded by
+/// [`snapshot_allowances`].
+#[contracttype]
+pub struct AllowanceSnapshot {
+    pub owner: Address,
+    pub allowances: Map<Address /* spender */, i128 /* amount */>,
 }
 
 /// Storage keys for the data associated with `FungibleToken`
@@ -24,6 +64,18 @@ pub enum StorageKey {
     TotalSupply,
     Balance(Address),
     Allowance(AllowanceKey),
+    AllowanceCeiling(AllowanceKey),
+    AutoRenewAllowance(AllowanceKey),
+    DecayingAllowance(AllowanceKey),
+    ConditionalAllowance(AllowanceKey),
+    TransferSequence,
+    InvalidRecipient(Address),
+    Spenders(Address),
+    AllowanceSnapshotCounter,
+    AllowanceSnapshot(u32),
+    FrozenAllowance(AllowanceKey),
+    MaxApprovalDuration,
+    LockedBalance(Address),
 }
 
 // ################## QUERY STATE ##################
@@ -55,6 +107,35 @@ pub fn balance(e: &Env, account: &Address) -> i128 {
     }
 }
 
+/// Returns the amount of tokens of `account` that are locked, and can't be
+/// moved until they are unlocked. Defaults to `0`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `account` - The address for which the locked balance is being queried.
+pub fn locked_balance(e: &Env, account: &Address) -> i128 {
+    let key = StorageKey::LockedBalance(account.clone());
+    if let Some(locked) = e.storage().persistent().get::<_, i128>(&key) {
+        e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+        locked
+    } else {
+        0
+    }
+}
+
+/// Returns the amount of tokens `account` can transfer or burn, that is its
+/// balance minus its locked balance.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `account` - The address for which the spendable balance is being queried.
+pub fn spendable_balance(e: &Env, account: &Address) -> i128 {
+    // NOTE: can't underflow, as the locked balance never exceeds the balance.
+    balance(e, account) - locked_balance(e, account)
+}
+
 /// Returns the amount of tokens a `spender` is allowed to spend on behalf of an
 /// `owner` and the ledger number at which this allowance expires. Both values
 /// default to `0`.
@@ -72,10 +153,31 @@ pub fn balance(e: &Env, account: &Address) -> i128 {
 /// allowance should be treated as `0`.
 pub fn allowance_data(e: &Env, owner: &Address, spender: &Address) -> AllowanceData {
     let key = AllowanceKey { owner: owner.clone(), spender: spender.clone() };
-    e.storage()
-        .temporary()
-        .get(&StorageKey::Allowance(key))
-        .unwrap_or(AllowanceData { amount: 0, live_until_ledger: 0 })
+    e.storage().temporary().get(&StorageKey::Allowance(key)).unwrap_or(AllowanceData {
+        amount: 0,
+        live_until_ledger: 0,
+        tag: None,
+    })
+}
+
+/// Returns the ledger number at which the allowance `owner` granted to
+/// `spender` expires, or `0` if no allowance entry exists. Unlike
+/// [`allowance`], the value is returned even once the allowance has expired,
+/// which tells an expired allowance apart from one that was never granted.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+///
+/// # Notes
+///
+/// Expired entries are only kept until their TTL runs out, or, when the
+/// `allowance_expired_event` feature is enabled, until an approval or a spend
+/// finds them expired.
+pub fn allowance_live_until(e: &Env, owner: &Address, spender: &Address) -> u32 {
+    allowance_data(e, owner, spender).live_until_ledger
 }
 
 /// Returns the amount of tokens a `spender` is allowed to spend on behalf of an
@@ -89,8 +191,10 @@ pub fn allowance_data(e: &Env, owner: &Address, spender: &Address) -> AllowanceD
 ///
 /// # Notes
 ///
-/// An allowance entry where `live_until_ledger` is less than the current
-/// ledger number is treated as an allowance with amount `0`.
+/// * An allowance entry where `live_until_ledger` is less than the current
+///   ledger number is treated as an allowance with amount `0`.
+/// * The decay of a decaying allowance is not applied, see
+///   [`decaying_allowance`].
 pub fn allowance(e: &Env, owner: &Address, spender: &Address) -> i128 {
     let allowance = allowance_data(e, owner, spender);
 
@@ -101,6 +205,174 @@ pub fn allowance(e: &Env, owner: &Address, spender: &Address) -> i128 {
     allowance.amount
 }
 
+/// Returns the amount of tokens a `spender` is allowed to spend on behalf of an
+/// `owner`, capped by the decayed value of the decaying allowance `owner`
+/// granted to `spender`, if any (see [`set_decaying_allowance`]).
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+///
+/// # Notes
+///
+/// Meant to back the `allowance` entry point of the contracts granting
+/// decaying allowances, in place of [`allowance`].
+pub fn decaying_allowance(e: &Env, owner: &Address, spender: &Address) -> i128 {
+    let amount = allowance(e, owner, spender);
+    decayed_allowance(e, owner, spender, amount)
+}
+
+/// Returns the tag attached to the allowance a `spender` has been granted on
+/// behalf of an `owner`, if any.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+///
+/// # Notes
+///
+/// Allowances set without a tag, as well as expired allowances, return
+/// `None`.
+pub fn allowance_tag(e: &Env, owner: &Address, spender: &Address) -> Option<Symbol> {
+    let allowance = allowance_data(e, owner, spender);
+
+    if allowance.live_until_ledger < e.ledger().sequence() {
+        return None;
+    }
+
+    allowance.tag
+}
+
+/// Returns the maximum allowance `owner` accepts to grant to `spender`, or
+/// `None` if no ceiling has been configured.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+pub fn allowance_ceiling(e: &Env, owner: &Address, spender: &Address) -> Option<i128> {
+    let key = StorageKey::AllowanceCeiling(AllowanceKey {
+        owner: owner.clone(),
+        spender: spender.clone(),
+    });
+    e.storage().persistent().get::<_, i128>(&key).inspect(|_| {
+        e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+    })
+}
+
+/// Returns `true` if `account` has been designated as an invalid recipient,
+/// meaning that no tokens can be transferred or minted to it.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address to check.
+#[cfg(feature = "invalid_recipient")]
+pub fn is_invalid_recipient(e: &Env, account: &Address) -> bool {
+    let key = StorageKey::InvalidRecipient(account.clone());
+    e.storage().persistent().get::<_, bool>(&key).is_some_and(|_| {
+        e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+        true
+    })
+}
+
+/// Returns the maximum number of ledgers an allowance can be granted for, or
+/// `None` if no maximum has been configured, in which case allowances are only
+/// bounded by the maximum TTL.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+pub fn max_approval_duration(e: &Env) -> Option<u32> {
+    e.storage().instance().get(&StorageKey::MaxApprovalDuration)
+}
+
+/// Returns `true` if the allowance `owner` granted to `spender` is frozen,
+/// meaning that it can't be spent.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+pub fn is_allowance_frozen(e: &Env, owner: &Address, spender: &Address) -> bool {
+    let key = StorageKey::FrozenAllowance(AllowanceKey {
+        owner: owner.clone(),
+        spender: spender.clone(),
+    });
+    e.storage().persistent().get::<_, bool>(&key).is_some_and(|_| {
+        e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+        true
+    })
+}
+
+/// Returns `true` if the balances of `accounts` sum up to the total supply,
+/// and `false` otherwise. Meant for invariant checks, when the caller can
+/// enumerate all the token holders.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `accounts` - The distinct addresses holding the tokens.
+///
+/// # Notes
+///
+/// An account listed more than once is counted more than once.
+pub fn reconcile_supply(e: &Env, accounts: Vec<Address>) -> bool {
+    let sum = accounts.iter().try_fold(0i128, |sum, account| sum.checked_add(balance(e, &account)));
+
+    sum == Some(total_supply(e))
+}
+
+/// Returns the number of balance updates (transfers, mints and burns) that
+/// have occurred so far. The value is included in the `transfer` event data
+/// and can be used as a strict per-contract ordering key.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+#[cfg(feature = "transfer_sequence")]
+pub fn transfer_sequence(e: &Env) -> u64 {
+    e.storage().instance().get(&StorageKey::TransferSequence).unwrap_or(0)
+}
+
+/// Returns the allowance of `spender` over the tokens of `owner` as recorded by
+/// the snapshot `snapshot_id`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `snapshot_id` - The identifier returned by [`snapshot_allowances`].
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::SnapshotNotFound`] - When no snapshot exists for
+///   `snapshot_id`.
+///
+/// # Notes
+///
+/// Returns `0` if `spender` had no allowance at the time of the snapshot, or
+/// if the snapshot was taken for another owner.
+pub fn allowance_at(e: &Env, snapshot_id: u32, owner: &Address, spender: &Address) -> i128 {
+    let key = StorageKey::AllowanceSnapshot(snapshot_id);
+    let Some(snapshot) = e.storage().persistent().get::<_, AllowanceSnapshot>(&key) else {
+        panic_with_error!(e, FungibleTokenError::SnapshotNotFound);
+    };
+    e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+
+    if snapshot.owner != *owner {
+        return 0;
+    }
+    snapshot.allowances.get(spender.clone()).unwrap_or(0)
+}
+
 // ################## CHANGE STATE ##################
 
 /// Sets the amount of tokens a `spender` is allowed to spend on behalf of an
@@ -137,8 +409,8 @@ pub fn approve(e: &Env, owner: &Address, spender: &Address, amount: i128, live_u
 }
 
 /// Sets the amount of tokens a `spender` is allowed to spend on behalf of an
-/// `owner`. Overrides any existing allowance set between `spender` and `owner`.
-/// Doesn't handle authorization, nor event emission.
+/// `owner`, like [`approve`], and tracks `spender` so that the allowance is
+/// recorded by [`snapshot_allowances`].
 ///
 /// # Arguments
 ///
@@ -150,139 +422,1507 @@ pub fn approve(e: &Env, owner: &Address, spender: &Address, amount: i128, live_u
 ///
 /// # Errors
 ///
-/// * [`FungibleTokenError::InvalidLiveUntilLedger`] - Occurs when attempting to
-///   set `live_until_ledger` that is 1) greater than the maximum allowed or 2)
-///   less than the current ledger number and `amount` is greater than `0`.
-/// * [`FungibleTokenError::LessThanZero`] - Occurs when `amount < 0`.
+/// * [`FungibleTokenError::TooManySpenders`] - When `owner` already has
+///   [`MAX_TRACKED_SPENDERS`] tracked spenders with a live allowance.
+/// * refer to [`set_allowance`] errors.
+///
+/// # Events
+///
+/// * topics - `["approve", from: Address, spender: Address]`
+/// * data - `[amount: i128, live_until_ledger: u32]`
 ///
 /// # Notes
 ///
-/// * This function does not enforce authorization. Ensure that authorization is
-///   handled at a higher level.
-/// * Allowance is implicitly timebound by the maximum allowed storage TTL value
-///   which is a network parameter, i.e. one cannot set an allowance for a
-///   longer period. This behavior closely mirrors the functioning of the
-///   "Stellar Asset Contract" implementation for consistency reasons.
-pub fn set_allowance(
+/// * Authorization for `owner` is required.
+/// * Tracking is opt-in, so that plain approvals don't pay for it: the
+///   allowances granted through the other functions are not recorded by
+///   [`snapshot_allowances`].
+pub fn approve_tracked(
     e: &Env,
     owner: &Address,
     spender: &Address,
     amount: i128,
     live_until_ledger: u32,
 ) {
-    if amount < 0 {
-        panic_with_error!(e, FungibleTokenError::LessThanZero);
-    }
-
-    let current_ledger = e.ledger().sequence();
-
-    if live_until_ledger > e.ledger().max_live_until_ledger()
-        || (amount > 0 && live_until_ledger < current_ledger)
-    {
-        panic_with_error!(e, FungibleTokenError::InvalidLiveUntilLedger);
-    }
-
-    let key =
-        StorageKey::Allowance(AllowanceKey { owner: owner.clone(), spender: spender.clone() });
-    let allowance = AllowanceData { amount, live_until_ledger };
-
-    e.storage().temporary().set(&key, &allowance);
-
+    owner.require_auth();
+    set_allowance(e, owner, spender, amount, live_until_ledger);
     if amount > 0 {
-        // NOTE: cannot revert because of the check above;
-        // NOTE: 1 is not added to `live_for` as in the SAC implementation which
-        // is a bug tracked in https://github.com/stellar/rs-soroban-env/issues/1519
-        let live_for = live_until_ledger - current_ledger;
-
-        e.storage().temporary().extend_ttl(&key, live_for, live_for);
+        track_spender(e, owner, spender);
     }
+    emit_approve(e, owner, spender, amount, live_until_ledger);
 }
 
-/// Deducts the amount of tokens a `spender` is allowed to spend on behalf of an
-/// `owner`.
+/// Sets a standing allowance of `amount` tokens for `spender` over the tokens
+/// of `owner`, valid for `window` ledgers. Each time the allowance is fully
+/// consumed by [`spend_allowance`], it is renewed to `amount` for another
+/// `window` ledgers, enabling subscription-like pulls. If the window lapses
+/// without the allowance being fully consumed, it expires as usual.
 ///
 /// # Arguments
 ///
 /// * `e` - Access to Soroban environment.
 /// * `owner` - The address holding the tokens.
 /// * `spender` - The address authorized to spend the tokens.
-/// * `amount` - The amount of tokens to be deducted from `spender`s allowance.
+/// * `amount` - The amount of tokens made available to `spender` per window.
+/// * `window` - The number of ledgers each allowance is valid for.
 ///
 /// # Errors
 ///
-/// * [`FungibleTokenError::InsufficientAllowance`] - When attempting to
-///   transfer more tokens than `spender` current allowance.
-/// * [`FungibleTokenError::LessThanZero`] - Occurs when `amount < 0`.
-/// * also refer to [`set_allowance`] errors.
+/// * refer to [`set_allowance`] errors.
+///
+/// # Events
+///
+/// * topics - `["approve", from: Address, spender: Address]`
+/// * data - `[amount: i128, live_until_ledger: u32]`
 ///
 /// # Notes
 ///
-/// This function does not enforce authorization. Ensure that authorization
-/// is handled at a higher level.
-pub fn spend_allowance(e: &Env, owner: &Address, spender: &Address, amount: i128) {
-    if amount < 0 {
-        panic_with_error!(e, FungibleTokenError::LessThanZero)
-    }
+/// * Authorization for `owner` is required.
+/// * The allowance is only renewed when spent through
+///   [`spend_auto_renew_allowance`].
+/// * The renewals go on until [`remove_auto_renew_allowance`] is called, which
+///   the `approve` entry point of the contract is expected to do, so that an
+///   explicit approval overrides the standing allowance.
+pub fn set_auto_renew_allowance(
+    e: &Env,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    window: u32,
+) {
+    owner.require_auth();
 
-    let allowance = allowance_data(e, owner, spender);
+    let live_until_ledger = e.ledger().sequence().saturating_add(window);
+    set_allowance(e, owner, spender, amount, live_until_ledger);
 
-    if allowance.amount < amount {
-        panic_with_error!(e, FungibleTokenError::InsufficientAllowance);
-    }
+    let key = StorageKey::AutoRenewAllowance(AllowanceKey {
+        owner: owner.clone(),
+        spender: spender.clone(),
+    });
+    e.storage().persistent().set(&key, &AutoRenewData { amount, window });
 
-    if amount > 0 {
-        set_allowance(e, owner, spender, allowance.amount - amount, allowance.live_until_ledger);
-    }
+    emit_approve(e, owner, spender, amount, live_until_ledger);
 }
 
-/// Transfers `amount` of tokens from `from` to `to`.
+/// Sets an allowance that linearly decreases from `initial` at the ledger
+/// `start` to `0` at the ledger `end`, e.g. for streaming-payment-like
+/// approvals. Before `start`, the full `initial` amount is available.
 ///
 /// # Arguments
 ///
 /// * `e` - Access to Soroban environment.
-/// * `from` - The address holding the tokens.
-/// * `to` - The address receiving the transferred tokens.
-/// * `amount` - The amount of tokens to be transferred.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+/// * `initial` - The amount of tokens made available to `spender` at `start`.
+/// * `start` - The ledger number at which the allowance starts decaying.
+/// * `end` - The ledger number at which the allowance is fully decayed.
 ///
 /// # Errors
 ///
-/// * refer to [`update`] errors.
+/// * [`FungibleTokenError::InvalidLiveUntilLedger`] - Occurs when `end` is not
+///   greater than `start`.
+/// * refer to [`set_allowance`] errors.
 ///
 /// # Events
 ///
-/// * topics - `["transfer", from: Address, to: Address]`
-/// * data - `[amount: i128]`
+/// * topics - `["approve", from: Address, spender: Address]`
+/// * data - `[amount: i128, live_until_ledger: u32]`
 ///
 /// # Notes
 ///
-/// Authorization for `from` is required.
-pub fn transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
-    from.require_auth();
-    update(e, Some(from), Some(to), amount);
-    emit_transfer(e, from, to, amount);
+/// * Authorization for `owner` is required.
+/// * The allowance is capped by the decayed value, which means spending doesn't
+///   lower the cap: the spender can use up to the decayed value at any time,
+///   within the limit of what is left of `initial`.
+/// * The decay only applies when the allowance is read through
+///   [`decaying_allowance`] and spent through [`spend_decaying_allowance`].
+/// * The decay goes on until [`remove_decaying_allowance`] is called, which the
+///   `approve` entry point of the contract is expected to do, so that an
+///   explicit approval overrides the decaying allowance.
+pub fn set_decaying_allowance(
+    e: &Env,
+    owner: &Address,
+    spender: &Address,
+    initial: i128,
+    start: u32,
+    end: u32,
+) {
+    owner.require_auth();
+
+    if end <= start {
+        panic_with_error!(e, FungibleTokenError::InvalidLiveUntilLedger);
+    }
+
+    set_allowance(e, owner, spender, initial, end);
+
+    let key = StorageKey::DecayingAllowance(AllowanceKey {
+        owner: owner.clone(),
+        spender: spender.clone(),
+    });
+    e.storage().persistent().set(&key, &DecayData { initial, start, end });
+
+    emit_approve(e, owner, spender, initial, end);
 }
 
-/// Transfers `amount` of tokens from `from` to `to` using the
-/// allowance mechanism. `amount` is then deducted from `spender`s allowance.
+/// Sets an allowance that can only be spent when `predicate` approves the
+/// spend. On every spend, [`spend_conditional_allowance`] invokes
+/// [`crate::AllowancePredicate::check`] on `predicate`, which makes it possible
+/// to tie allowances to arbitrary on-chain conditions (e.g. an oracle price, a
+/// KYC registry, or a time window).
 ///
 /// # Arguments
 ///
 /// * `e` - Access to Soroban environment.
-/// * `spender` - The address authorizing the transfer, and having its allowance
-///   consumed during the transfer.
-/// * `from` - The address holding the tokens which will be transferred.
-/// * `to` - The address receiving the transferred tokens.
-/// * `amount` - The amount of tokens to be transferred.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+/// * `amount` - The amount of tokens made available to `spender`.
+/// * `predicate` - The contract implementing [`crate::AllowancePredicate`].
 ///
 /// # Errors
 ///
-/// * refer to [`spend_allowance`] errors.
-/// * refer to [`update`] errors.
+/// * refer to [`set_allowance`] errors.
 ///
 /// # Events
 ///
-/// * topics - `["transfer", from: Address, to: Address]`
-/// * data - `[amount: i128]`
+/// * topics - `["approve", from: Address, spender: Address]`
+/// * data - `[amount: i128, live_until_ledger: u32]`
+///
+/// # Notes
+///
+/// * Authorization for `owner` is required.
+/// * The allowance lives as long as possible, i.e. until the maximum ledger
+///   allowed for temporary entries. Expiration, if any, is expected to be
+///   enforced by `predicate`.
+/// * The condition stays until [`remove_conditional_allowance`] is called,
+///   which the `approve` entry point of the contract is expected to do, so that
+///   an explicit approval overrides the conditional allowance.
+pub fn set_conditional_allowance(
+    e: &Env,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    predicate: &Address,
+) {
+    owner.require_auth();
+
+    let live_until_ledger = e.ledger().max_live_until_ledger();
+    set_allowance(e, owner, spender, amount, live_until_ledger);
+
+    let key = StorageKey::ConditionalAllowance(AllowanceKey {
+        owner: owner.clone(),
+        spender: spender.clone(),
+    });
+    e.storage().persistent().set(&key, predicate);
+
+    emit_approve(e, owner, spender, amount, live_until_ledger);
+}
+
+/// Sets the amounts of tokens multiple `spenders` are allowed to spend on
+/// behalf of an `owner`, in a single call. Overrides any existing allowance
+/// set between each spender and `owner`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spenders` - The addresses authorized to spend the tokens.
+/// * `amounts` - The amounts of tokens made available to each spender, in the
+///   same order as `spenders`.
+/// * `live_until_ledger` - The ledger number at which the allowances expire.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::LengthMismatch`] - Occurs when `spenders` and
+///   `amounts` have different lengths.
+/// * refer to [`set_allowance`] errors.
+///
+/// # Events
+///
+/// Emits one event per spender:
+/// * topics - `["approve", from: Address, spender: Address]`
+/// * data - `[amount: i128, live_until_ledger: u32]`
+///
+/// # Notes
+///
+/// * Authorization for `owner` is required, once for the whole batch.
+/// * An amount of `0` clears the existing allowance of the spender.
+pub fn approve_batch(
+    e: &Env,
+    owner: &Address,
+    spenders: Vec<Address>,
+    amounts: Vec<i128>,
+    live_until_ledger: u32,
+) {
+    owner.require_auth();
+    if spenders.len() != amounts.len() {
+        panic_with_error!(e, FungibleTokenError::LengthMismatch);
+    }
+
+    for (spender, amount) in spenders.iter().zip(amounts.iter()) {
+        set_allowance(e, owner, &spender, amount, live_until_ledger);
+        emit_approve(e, owner, &spender, amount, live_until_ledger);
+    }
+}
+
+/// Sets the amount of tokens a `spender` is allowed to spend on behalf of an
+/// `owner` and tags the allowance with a spending category. Overrides any
+/// existing allowance set between `spender` and `owner`, including its tag.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+/// * `amount` - The amount of tokens made available to `spender`.
+/// * `live_until_ledger` - The ledger number at which the allowance expires.
+/// * `tag` - The spending category attached to the allowance.
+///
+/// # Errors
+///
+/// * refer to [`set_allowance`] errors.
+///
+/// # Events
+///
+/// * topics - `["approve", from: Address, spender: Address]`
+/// * data - `[amount: i128, live_until_ledger: u32]`
+///
+/// # Notes
+///
+/// * Authorization for `owner` is required.
+/// * The tag is kept as long as the allowance is partially spent and can be
+///   queried with [`allowance_tag`].
+pub fn approve_with_tag(
+    e: &Env,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    live_until_ledger: u32,
+    tag: &Symbol,
+) {
+    owner.require_auth();
+    set_allowance_with_tag(e, owner, spender, amount, live_until_ledger, Some(tag.clone()));
+    emit_approve(e, owner, spender, amount, live_until_ledger);
+}
+
+/// Increases the amount of tokens a `spender` is allowed to spend on behalf of
+/// an `owner` by `added`, and sets its expiry to `live_until_ledger`. Unlike
+/// [`approve`], the change is relative to the current allowance, so a spender
+/// front-running it can't spend both the old and the new allowance.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+/// * `added` - The amount of tokens added to the allowance.
+/// * `live_until_ledger` - The ledger number at which the allowance expires.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::LessThanZero`] - Occurs when `added < 0`.
+/// * [`FungibleTokenError::MathOverflow`] - Occurs when the resulting allowance
+///   overflows.
+/// * refer to [`set_allowance_with_tag`] errors.
+///
+/// # Events
+///
+/// * topics - `["approve", from: Address, spender: Address]`
+/// * data - `[amount: i128, live_until_ledger: u32]`
+///
+/// # Notes
+///
+/// * Authorization for `owner` is required.
+/// * An expired allowance counts as `0`, so increasing it grants exactly
+///   `added`.
+/// * The tag of the allowance is preserved, while any standing, decaying or
+///   conditional allowance is overridden, as with [`approve`].
+pub fn increase_allowance(
+    e: &Env,
+    owner: &Address,
+    spender: &Address,
+    added: i128,
+    live_until_ledger: u32,
+) {
+    owner.require_auth();
+
+    if added < 0 {
+        panic_with_error!(e, FungibleTokenError::LessThanZero);
+    }
+
+    let tag = allowance_tag(e, owner, spender);
+    let amount = allowance(e, owner, spender)
+        .checked_add(added)
+        .unwrap_or_else(|| panic_with_error!(e, FungibleTokenError::MathOverflow));
+
+    set_allowance_with_tag(e, owner, spender, amount, live_until_ledger, tag);
+    emit_approve(e, owner, spender, amount, live_until_ledger);
+}
+
+/// Decreases the amount of tokens a `spender` is allowed to spend on behalf of
+/// an `owner` by `subtracted`, saturating at `0`. The expiry of the allowance
+/// is left unchanged.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+/// * `subtracted` - The amount of tokens removed from the allowance.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::LessThanZero`] - Occurs when `subtracted < 0`.
+///
+/// # Events
+///
+/// * topics - `["approve", from: Address, spender: Address]`
+/// * data - `[amount: i128, live_until_ledger: u32]`
+///
+/// # Notes
+///
+/// * Authorization for `owner` is required.
+/// * The ceiling configured by `owner` is not checked, so that an allowance can
+///   always be decreased, and the tag as well as any standing, decaying or
+///   conditional allowance are kept.
+pub fn decrease_allowance(e: &Env, owner: &Address, spender: &Address, subtracted: i128) {
+    owner.require_auth();
+
+    if subtracted < 0 {
+        panic_with_error!(e, FungibleTokenError::LessThanZero);
+    }
+
+    let AllowanceData { live_until_ledger, tag, .. } = allowance_data(e, owner, spender);
+    let amount = allowance(e, owner, spender).saturating_sub(subtracted).max(0);
+
+    write_allowance(e, owner, spender, amount, live_until_ledger, tag);
+    emit_approve(e, owner, spender, amount, live_until_ledger);
+}
+
+/// Sets the maximum allowance `owner` accepts to grant to `spender`. Any
+/// subsequent attempt to set an allowance above `ceiling` is rejected.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+/// * `ceiling` - The maximum allowance that can be granted to `spender`.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::LessThanZero`] - Occurs when `ceiling < 0`.
+///
+/// # Notes
+///
+/// * Authorization for `owner` is required.
+/// * The ceiling doesn't affect the allowance already granted, which can still
+///   be spent.
+pub fn set_allowance_ceiling(e: &Env, owner: &Address, spender: &Address, ceiling: i128) {
+    owner.require_auth();
+
+    if ceiling < 0 {
+        panic_with_error!(e, FungibleTokenError::LessThanZero);
+    }
+
+    let key = StorageKey::AllowanceCeiling(AllowanceKey {
+        owner: owner.clone(),
+        spender: spender.clone(),
+    });
+    e.storage().persistent().set(&key, &ceiling);
+}
+
+/// Sets the amount of tokens a `spender` is allowed to spend on behalf of an
+/// `owner`. Overrides any existing allowance set between `spender` and `owner`.
+/// Doesn't handle authorization, nor event emission.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+/// * `amount` - The amount of tokens made available to `spender`.
+/// * `live_until_ledger` - The ledger number at which the allowance expires.
+///
+/// # Errors
+///
+/// * refer to [`set_allowance_with_tag`] errors.
+///
+/// # Notes
+///
+/// * This function does not enforce authorization. Ensure that authorization is
+///   handled at a higher level.
+/// * Allowance is implicitly timebound by the maximum allowed storage TTL value
+///   which is a network parameter, i.e. one cannot set an allowance for a
+///   longer period. This behavior closely mirrors the functioning of the
+///   "Stellar Asset Contract" implementation for consistency reasons.
+pub fn set_allowance(
+    e: &Env,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    live_until_ledger: u32,
+) {
+    set_allowance_with_tag(e, owner, spender, amount, live_until_ledger, None);
+}
+
+/// Sets the amount of tokens a `spender` is allowed to spend on behalf of an
+/// `owner` together with an optional `tag`. Overrides any existing allowance
+/// set between `spender` and `owner`. Doesn't handle authorization, nor event
+/// emission.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+/// * `amount` - The amount of tokens made available to `spender`.
+/// * `live_until_ledger` - The ledger number at which the allowance expires.
+/// * `tag` - The optional spending category attached to the allowance.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::AllowanceExceedsCeiling`] - Occurs when `amount` is
+///   greater than the ceiling configured by `owner` for `spender`.
+/// * [`FungibleTokenError::ApprovalDurationExceeded`] - Occurs when `amount` is
+///   greater than `0` and `live_until_ledger` is later than the maximum
+///   approval duration set with [`set_max_approval_duration`].
+/// * [`FungibleTokenError::InvalidLiveUntilLedger`] - Occurs when attempting to
+///   set `live_until_ledger` that is 1) greater than the maximum allowed or 2)
+///   less than the current ledger number and `amount` is greater than `0`.
+/// * [`FungibleTokenError::LessThanZero`] - Occurs when `amount < 0`.
+///
+/// # Events
+///
+/// With the `allowance_expired_event` feature only, when the overridden
+/// allowance has expired, which removes it:
+/// * topics - `["allowance_expired", owner: Address, spender: Address]`
+/// * data - `[amount: i128, live_until_ledger: u32]`
+///
+/// # Notes
+///
+/// This function does not enforce authorization. Ensure that authorization is
+/// handled at a higher level.
+pub fn set_allowance_with_tag(
+    e: &Env,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    live_until_ledger: u32,
+    tag: Option<Symbol>,
+) {
+    if let Some(ceiling) = allowance_ceiling(e, owner, spender) {
+        if amount > ceiling {
+            panic_with_error!(e, FungibleTokenError::AllowanceExceedsCeiling);
+        }
+    }
+
+    if let Some(max_duration) = max_approval_duration(e) {
+        if amount > 0 && live_until_ledger > e.ledger().sequence().saturating_add(max_duration) {
+            panic_with_error!(e, FungibleTokenError::ApprovalDurationExceeded);
+        }
+    }
+
+    #[cfg(feature = "allowance_expired_event")]
+    prune_expired_allowance(e, owner, spender, &allowance_data(e, owner, spender));
+
+    write_allowance(e, owner, spender, amount, live_until_ledger, tag);
+}
+
+/// Stops the renewals of the standing allowance `owner` granted to `spender`
+/// (see [`set_auto_renew_allowance`]), if any. The current allowance is left
+/// untouched.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute this function.
+pub fn remove_auto_renew_allowance(e: &Env, owner: &Address, spender: &Address) {
+    let key = StorageKey::AutoRenewAllowance(AllowanceKey {
+        owner: owner.clone(),
+        spender: spender.clone(),
+    });
+    e.storage().persistent().remove(&key);
+}
+
+/// Stops the decay of the decaying allowance `owner` granted to `spender`
+/// (see [`set_decaying_allowance`]), if any. The current allowance is left
+/// untouched.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute this function.
+pub fn remove_decaying_allowance(e: &Env, owner: &Address, spender: &Address) {
+    let key = StorageKey::DecayingAllowance(AllowanceKey {
+        owner: owner.clone(),
+        spender: spender.clone(),
+    });
+    e.storage().persistent().remove(&key);
+}
+
+/// Removes the condition of the conditional allowance `owner` granted to
+/// `spender` (see [`set_conditional_allowance`]), if any. The current
+/// allowance is left untouched.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute this function.
+pub fn remove_conditional_allowance(e: &Env, owner: &Address, spender: &Address) {
+    let key = StorageKey::ConditionalAllowance(AllowanceKey {
+        owner: owner.clone(),
+        spender: spender.clone(),
+    });
+    e.storage().persistent().remove(&key);
+}
+
+/// Stores the allowance after validating `amount` and `live_until_ledger`,
+/// without checking the ceiling configured by the owner. Used when deducting
+/// from an existing allowance, which must remain possible even if the ceiling
+/// has been lowered in the meantime.
+fn write_allowance(
+    e: &Env,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    live_until_ledger: u32,
+    tag: Option<Symbol>,
+) {
+    if amount < 0 {
+        panic_with_error!(e, FungibleTokenError::LessThanZero);
+    }
+
+    let current_ledger = e.ledger().sequence();
+
+    if live_until_ledger > e.ledger().max_live_until_ledger()
+        || (amount > 0 && live_until_ledger < current_ledger)
+    {
+        panic_with_error!(e, FungibleTokenError::InvalidLiveUntilLedger);
+    }
+
+    let key =
+        StorageKey::Allowance(AllowanceKey { owner: owner.clone(), spender: spender.clone() });
+    let allowance = AllowanceData { amount, live_until_ledger, tag };
+
+    e.storage().temporary().set(&key, &allowance);
+
+    if amount > 0 {
+        // NOTE: cannot revert because of the check above;
+        // NOTE: 1 is not added to `live_for` as in the SAC implementation which
+        // is a bug tracked in https://github.com/stellar/rs-soroban-env/issues/1519
+        let live_for = live_until_ledger - current_ledger;
+
+        e.storage().temporary().extend_ttl(&key, live_for, live_for);
+    }
+}
+
+/// Invokes the predicate of the conditional allowance `owner` granted to
+/// `spender`, if any (see [`set_conditional_allowance`]), and reverts if it
+/// denies spending `amount`. A predicate that fails reverts the spend as well.
+fn check_allowance_condition(e: &Env, owner: &Address, spender: &Address, amount: i128) {
+    let key = StorageKey::ConditionalAllowance(AllowanceKey {
+        owner: owner.clone(),
+        spender: spender.clone(),
+    });
+    let Some(predicate) = e.storage().persistent().get::<_, Address>(&key) else {
+        return;
+    };
+
+    if !AllowancePredicateClient::new(e, &predicate).check(owner, spender, &amount) {
+        panic_with_error!(e, FungibleTokenError::AllowanceConditionNotMet);
+    }
+}
+
+/// Caps `amount` with the decayed value of the decaying allowance `owner`
+/// granted to `spender`, if any (see [`set_decaying_allowance`]).
+fn decayed_allowance(e: &Env, owner: &Address, spender: &Address, amount: i128) -> i128 {
+    let key = StorageKey::DecayingAllowance(AllowanceKey {
+        owner: owner.clone(),
+        spender: spender.clone(),
+    });
+    let Some(DecayData { initial, start, end }) = e.storage().persistent().get(&key) else {
+        return amount;
+    };
+
+    let current_ledger = e.ledger().sequence();
+    let decayed = if current_ledger <= start {
+        initial
+    } else if current_ledger >= end {
+        0
+    } else {
+        math::mul_div(e, initial, (end - current_ledger) as i128, (end - start) as i128)
+    };
+    amount.min(decayed)
+}
+
+/// Adds `spender` to the spenders of `owner` tracked for
+/// [`snapshot_allowances`], unless it is already tracked. When the list is
+/// full, the spenders whose allowance dropped to zero or expired are dropped
+/// first.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::TooManySpenders`] - When `owner` already has
+///   [`MAX_TRACKED_SPENDERS`] tracked spenders with a live allowance.
+fn track_spender(e: &Env, owner: &Address, spender: &Address) {
+    let key = StorageKey::Spenders(owner.clone());
+    let mut spenders: Vec<Address> =
+        e.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(e));
+    if spenders.contains(spender) {
+        return;
+    }
+
+    if spenders.len() >= MAX_TRACKED_SPENDERS {
+        let mut active_spenders = Vec::new(e);
+        for tracked in spenders.iter() {
+            if allowance(e, owner, &tracked) > 0 {
+                active_spenders.push_back(tracked);
+            }
+        }
+        if active_spenders.len() >= MAX_TRACKED_SPENDERS {
+            panic_with_error!(e, FungibleTokenError::TooManySpenders);
+        }
+        spenders = active_spenders;
+    }
+
+    spenders.push_back(spender.clone());
+    e.storage().persistent().set(&key, &spenders);
+}
+
+/// Records the current allowances granted by `owner` under a new snapshot
+/// identifier, which can then be queried with [`allowance_at`].
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::MathOverflow`] - When no snapshot identifier is
+///   left.
+///
+/// # Notes
+///
+/// * Only the spenders approved with [`approve_tracked`] are recorded, and only
+///   while their allowance is non-zero and unexpired. Spenders whose allowance
+///   dropped to zero or expired are forgotten, so that they are no longer
+///   tracked until they are approved again.
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute this function.
+pub fn snapshot_allowances(e: &Env, owner: &Address) -> u32 {
+    let counter_key = StorageKey::AllowanceSnapshotCounter;
+    let snapshot_id: u32 = e.storage().instance().get(&counter_key).unwrap_or(0);
+    let Some(next_id) = snapshot_id.checked_add(1) else {
+        panic_with_error!(e, FungibleTokenError::MathOverflow);
+    };
+
+    let spenders_key = StorageKey::Spenders(owner.clone());
+    let spenders: Vec<Address> =
+        e.storage().persistent().get(&spenders_key).unwrap_or_else(|| Vec::new(e));
+
+    let mut allowances = Map::new(e);
+    let mut active_spenders = Vec::new(e);
+    for spender in spenders.iter() {
+        let amount = allowance(e, owner, &spender);
+        if amount > 0 {
+            allowances.set(spender.clone(), amount);
+            active_spenders.push_back(spender);
+        }
+    }
+
+    if active_spenders.is_empty() {
+        e.storage().persistent().remove(&spenders_key);
+    } else if active_spenders.len() != spenders.len() {
+        e.storage().persistent().set(&spenders_key, &active_spenders);
+    }
+
+    let snapshot = AllowanceSnapshot { owner: owner.clone(), allowances };
+    e.storage().persistent().set(&StorageKey::AllowanceSnapshot(snapshot_id), &snapshot);
+    e.storage().instance().set(&counter_key, &next_id);
+
+    snapshot_id
+}
+
+/// Deducts the amount of tokens a `spender` is allowed to spend on behalf of an
+/// `owner`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+/// * `amount` - The amount of tokens to be deducted from `spender`s allowance.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::InsufficientAllowance`] - When attempting to
+///   transfer more tokens than `spender` current allowance.
+/// * [`FungibleTokenError::LessThanZero`] - Occurs when `amount < 0`.
+/// * also refer to [`set_allowance`] errors.
+///
+/// # Events
+///
+/// With the `allowance_expired_event` feature only, when the allowance has
+/// expired, which removes it:
+/// * topics - `["allowance_expired", owner: Address, spender: Address]`
+/// * data - `[amount: i128, live_until_ledger: u32]`
+///
+/// # Notes
+///
+/// * This function does not enforce authorization. Ensure that authorization is
+///   handled at a higher level.
+/// * With the `allowance_expired_event` feature, an expired allowance counts as
+///   `0`, so the event is only kept by spends of a zero `amount`, as any other
+///   spend of an expired allowance reverts.
+/// * Frozen, standing, decaying and conditional allowances are spent like any
+///   other allowance. Use [`spend_freezable_allowance`],
+///   [`spend_auto_renew_allowance`], [`spend_decaying_allowance`] or
+///   [`spend_conditional_allowance`] respectively to enforce them.
+pub fn spend_allowance(e: &Env, owner: &Address, spender: &Address, amount: i128) {
+    let allowance = allowance_data(e, owner, spender);
+    let available = allowance.amount;
+    deduct_allowance(e, owner, spender, allowance, available, amount);
+}
+
+/// Deducts the amount of tokens a `spender` is allowed to spend on behalf of an
+/// `owner`, like [`spend_allowance`], unless the allowance is frozen (see
+/// [`freeze_allowance`]).
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+/// * `amount` - The amount of tokens to be deducted from `spender`s allowance.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::AllowanceFrozen`] - When the allowance is frozen.
+/// * refer to [`spend_allowance`] errors.
+///
+/// # Notes
+///
+/// This function does not enforce authorization. Ensure that authorization
+/// is handled at a higher level.
+pub fn spend_freezable_allowance(e: &Env, owner: &Address, spender: &Address, amount: i128) {
+    if is_allowance_frozen(e, owner, spender) {
+        panic_with_error!(e, FungibleTokenError::AllowanceFrozen);
+    }
+
+    spend_allowance(e, owner, spender, amount);
+}
+
+/// Deducts the amount of tokens a `spender` is allowed to spend on behalf of an
+/// `owner`, like [`spend_allowance`], and renews the standing allowance `owner`
+/// granted to `spender` (see [`set_auto_renew_allowance`]) when it is fully
+/// consumed.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+/// * `amount` - The amount of tokens to be deducted from `spender`s allowance.
+///
+/// # Errors
+///
+/// * refer to [`spend_allowance`] errors.
+///
+/// # Events
+///
+/// When the standing allowance is fully consumed and renewed:
+///
+/// * topics - `["approve", from: Address, spender: Address]`
+/// * data - `[amount: i128, live_until_ledger: u32]`
+///
+/// # Notes
+///
+/// This function does not enforce authorization. Ensure that authorization
+/// is handled at a higher level.
+pub fn spend_auto_renew_allowance(e: &Env, owner: &Address, spender: &Address, amount: i128) {
+    let allowance = allowance_data(e, owner, spender);
+
+    if amount > 0 && allowance.amount == amount {
+        let key = StorageKey::AutoRenewAllowance(AllowanceKey {
+            owner: owner.clone(),
+            spender: spender.clone(),
+        });
+        if let Some(AutoRenewData { amount, window }) = e.storage().persistent().get(&key) {
+            // the renewed allowance can't outlive the maximum TTL
+            let live_until_ledger = e
+                .ledger()
+                .sequence()
+                .saturating_add(window)
+                .min(e.ledger().max_live_until_ledger());
+            write_allowance(e, owner, spender, amount, live_until_ledger, allowance.tag);
+            emit_approve(e, owner, spender, amount, live_until_ledger);
+            return;
+        }
+    }
+
+    let available = allowance.amount;
+    deduct_allowance(e, owner, spender, allowance, available, amount);
+}
+
+/// Deducts the amount of tokens a `spender` is allowed to spend on behalf of an
+/// `owner`, like [`spend_allowance`], within the limit of the decayed value of
+/// the decaying allowance `owner` granted to `spender` (see
+/// [`set_decaying_allowance`]), if any.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+/// * `amount` - The amount of tokens to be deducted from `spender`s allowance.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::InsufficientAllowance`] - When `amount` is greater
+///   than the decayed allowance, see [`decaying_allowance`].
+/// * refer to [`spend_allowance`] errors.
+///
+/// # Notes
+///
+/// This function does not enforce authorization. Ensure that authorization
+/// is handled at a higher level.
+pub fn spend_decaying_allowance(e: &Env, owner: &Address, spender: &Address, amount: i128) {
+    let allowance = allowance_data(e, owner, spender);
+    let available = decayed_allowance(e, owner, spender, allowance.amount);
+    deduct_allowance(e, owner, spender, allowance, available, amount);
+}
+
+/// Deducts the amount of tokens a `spender` is allowed to spend on behalf of an
+/// `owner`, like [`spend_allowance`], once the predicate of the conditional
+/// allowance `owner` granted to `spender` (see [`set_conditional_allowance`]),
+/// if any, approves the spend.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+/// * `amount` - The amount of tokens to be deducted from `spender`s allowance.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::AllowanceConditionNotMet`] - When the predicate
+///   denies the spend.
+/// * refer to [`spend_allowance`] errors.
+/// * any error raised by the predicate, which reverts the spend as well.
+///
+/// # Notes
+///
+/// This function does not enforce authorization. Ensure that authorization
+/// is handled at a higher level.
+pub fn spend_conditional_allowance(e: &Env, owner: &Address, spender: &Address, amount: i128) {
+    spend_allowance(e, owner, spender, amount);
+    // a denial reverts the deduction above along with the whole invocation
+    check_allowance_condition(e, owner, spender, amount);
+}
+
+/// Checks that `amount` is a valid spend of the `available` part of
+/// `allowance`, and deducts it.
+fn deduct_allowance(
+    e: &Env,
+    owner: &Address,
+    spender: &Address,
+    allowance: AllowanceData,
+    available: i128,
+    amount: i128,
+) {
+    if amount < 0 {
+        panic_with_error!(e, FungibleTokenError::LessThanZero)
+    }
+
+    #[cfg(feature = "allowance_expired_event")]
+    let available =
+        if prune_expired_allowance(e, owner, spender, &allowance) { 0 } else { available };
+
+    if available < amount {
+        panic_with_error!(e, FungibleTokenError::InsufficientAllowance);
+    }
+
+    if amount == 0 {
+        return;
+    }
+
+    let remaining = allowance.amount - amount;
+    write_allowance(e, owner, spender, remaining, allowance.live_until_ledger, allowance.tag);
+}
+
+/// Removes the allowance `owner` granted to `spender` if it has expired with
+/// a non-zero amount left, and emits an event, so that indexers learn about
+/// the expiry. Returns `true` if the allowance was removed.
+///
+/// Only called on the approval and spend paths, so that reading an allowance
+/// never changes the state. Since allowance entries live in `temporary`
+/// storage, the expiry is only detected as long as the entry has not been
+/// evicted yet, i.e. when its TTL outlives `live_until_ledger`.
+///
+/// # Events
+///
+/// * topics - `["allowance_expired", owner: Address, spender: Address]`
+/// * data - `[amount: i128, live_until_ledger: u32]`
+#[cfg(feature = "allowance_expired_event")]
+fn prune_expired_allowance(
+    e: &Env,
+    owner: &Address,
+    spender: &Address,
+    allowance: &AllowanceData,
+) -> bool {
+    // a non-zero amount means the entry exists, as missing entries default to 0
+    if allowance.amount == 0 || allowance.live_until_ledger >= e.ledger().sequence() {
+        return false;
+    }
+
+    let key = AllowanceKey { owner: owner.clone(), spender: spender.clone() };
+    e.storage().temporary().remove(&StorageKey::Allowance(key));
+    crate::emit_allowance_expired(e, owner, spender, allowance.amount, allowance.live_until_ledger);
+    true
+}
+
+/// Sets the maximum number of ledgers an allowance can be granted for. Any
+/// subsequent allowance expiring later than `ledgers` after the current
+/// ledger is rejected by [`set_allowance_with_tag`].
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `ledgers` - The maximum duration of an allowance, in ledgers.
+///
+/// # Notes
+///
+/// Existing allowances are left untouched.
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute this function.
+pub fn set_max_approval_duration(e: &Env, ledgers: u32) {
+    e.storage().instance().set(&StorageKey::MaxApprovalDuration, &ledgers);
+}
+
+/// Freezes the allowance `owner` granted to `spender`: any attempt to spend it
+/// is rejected by [`spend_freezable_allowance`], while `owner` can still
+/// transfer its tokens directly. The allowance itself is left untouched, and
+/// can still be changed by `owner`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts (e.g. a compliance
+/// officer) can execute this function.
+pub fn freeze_allowance(e: &Env, owner: &Address, spender: &Address) {
+    let key = StorageKey::FrozenAllowance(AllowanceKey {
+        owner: owner.clone(),
+        spender: spender.clone(),
+    });
+    e.storage().persistent().set(&key, &true);
+}
+
+/// Unfreezes the allowance `owner` granted to `spender`, if frozen.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `spender` - The address authorized to spend the tokens.
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute this function.
+pub fn unfreeze_allowance(e: &Env, owner: &Address, spender: &Address) {
+    let key = StorageKey::FrozenAllowance(AllowanceKey {
+        owner: owner.clone(),
+        spender: spender.clone(),
+    });
+    e.storage().persistent().remove(&key);
+}
+
+/// Locks `amount` tokens of `account`. Locked tokens still count towards the
+/// balance of `account`, but they can't be transferred nor burned until they
+/// are unlocked with [`unlock_balance`].
+///
+/// This is meant to be used as a sub-ledger for staking, escrow or vesting,
+/// keeping the tokens with their holder rather than in custody.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address whose tokens are locked.
+/// * `amount` - The amount of tokens to lock.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::LessThanZero`] - When `amount < 0`.
+/// * [`FungibleTokenError::InsufficientBalance`] - When `amount` exceeds the
+///   spendable balance of `account`.
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute this function.
+pub fn lock_balance(e: &Env, account: &Address, amount: i128) {
+    if amount < 0 {
+        panic_with_error!(e, FungibleTokenError::LessThanZero);
+    }
+    if spendable_balance(e, account) < amount {
+        panic_with_error!(e, FungibleTokenError::InsufficientBalance);
+    }
+    // NOTE: can't overflow because the locked balance is at most the balance.
+    let locked = locked_balance(e, account) + amount;
+    e.storage().persistent().set(&StorageKey::LockedBalance(account.clone()), &locked);
+}
+
+/// Unlocks `amount` of the tokens previously locked for `account` with
+/// [`lock_balance`], making them spendable again.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address whose tokens are unlocked.
+/// * `amount` - The amount of tokens to unlock.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::LessThanZero`] - When `amount < 0`.
+/// * [`FungibleTokenError::InsufficientLockedBalance`] - When `amount` exceeds
+///   the locked balance of `account`.
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute this function.
+pub fn unlock_balance(e: &Env, account: &Address, amount: i128) {
+    if amount < 0 {
+        panic_with_error!(e, FungibleTokenError::LessThanZero);
+    }
+    let locked = locked_balance(e, account);
+    if locked < amount {
+        panic_with_error!(e, FungibleTokenError::InsufficientLockedBalance);
+    }
+    let key = StorageKey::LockedBalance(account.clone());
+    if locked == amount {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, &(locked - amount));
+    }
+}
+
+/// Designates `account` as an invalid recipient. Any subsequent transfer or
+/// mint to `account` is rejected by [`update`].
+///
+/// This can be used to guard against tokens being sent to addresses that can't
+/// make use of them, such as the token contract itself or a known burn sink.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address to designate as an invalid recipient.
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute this function.
+#[cfg(feature = "invalid_recipient")]
+pub fn set_invalid_recipient(e: &Env, account: &Address) {
+    e.storage().persistent().set(&StorageKey::InvalidRecipient(account.clone()), &true);
+}
+
+/// Removes the invalid recipient designation of `account`, if any.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The address to designate as a valid recipient again.
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute this function.
+#[cfg(feature = "invalid_recipient")]
+pub fn remove_invalid_recipient(e: &Env, account: &Address) {
+    e.storage().persistent().remove(&StorageKey::InvalidRecipient(account.clone()));
+}
+
+/// Transfers `amount` of tokens from `from` to `to`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `amount` - The amount of tokens to be transferred.
+///
+/// # Errors
+///
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["transfer", from: Address, to: Address]`
+/// * data - `[amount: i128]`
+///
+/// # Notes
+///
+/// Authorization for `from` is required.
+pub fn transfer(e: &Env, from: &Address, to: &Address, amount: i128) {
+    from.require_auth();
+    update(e, Some(from), Some(to), amount);
+    emit_transfer(e, from, to, amount);
+}
+
+/// Transfers each amount of `amounts` from `from` to the recipient at the
+/// same index in `recipients`. The balance of `from` is read and written only
+/// once for the whole batch, unless the `detailed_events` feature is enabled,
+/// in which case it is written after each transfer so that every event carries
+/// the balances right after its own transfer.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `recipients` - The addresses receiving the transferred tokens.
+/// * `amounts` - The amounts of tokens to be transferred to each recipient, in
+///   the same order as `recipients`.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::LengthMismatch`] - Occurs when `recipients` and
+///   `amounts` have different lengths.
+/// * [`FungibleTokenError::LessThanZero`] - When any of `amounts` is negative.
+/// * [`FungibleTokenError::InvalidRecipient`] - When any of `recipients` has
+///   been designated as an invalid recipient, with the `invalid_recipient`
+///   feature enabled.
+/// * [`FungibleTokenError::InsufficientBalance`] - When the sum of `amounts`
+///   exceeds the spendable balance of `from`, see [`spendable_balance`].
+/// * [`FungibleTokenError::MathOverflow`] - When the sum of `amounts`
+///   overflows.
+///
+/// # Events
+///
+/// Emits one event per recipient:
+/// * topics - `["transfer", from: Address, to: Address]`
+/// * data - `[amount: i128]`
+///
+/// With the `detailed_events` feature, the balances are the ones after each
+/// transfer, see [`emit_transfer`]:
+/// * data - `[amount: i128, from_balance: i128, to_balance: i128]`
+///
+/// # Notes
+///
+/// * Authorization for `from` is required, once for the whole batch.
+/// * All the amounts and recipients are validated before any state change.
+pub fn transfer_batch(e: &Env, from: &Address, recipients: Vec<Address>, amounts: Vec<i128>) {
+    // the balances are written directly rather than through `update`
+    #[cfg(feature = "pausable")]
+    stellar_pausable::when_not_paused(e);
+
+    from.require_auth();
+    if recipients.len() != amounts.len() {
+        panic_with_error!(e, FungibleTokenError::LengthMismatch);
+    }
+
+    let mut total: i128 = 0;
+    for amount in amounts.iter() {
+        if amount < 0 {
+            panic_with_error!(e, FungibleTokenError::LessThanZero);
+        }
+        total = math::add(e, total, amount);
+    }
+    #[cfg(feature = "invalid_recipient")]
+    for to in recipients.iter() {
+        if is_invalid_recipient(e, &to) {
+            panic_with_error!(e, FungibleTokenError::InvalidRecipient);
+        }
+    }
+
+    if spendable_balance(e, from) < total {
+        panic_with_error!(e, FungibleTokenError::InsufficientBalance);
+    }
+    let mut from_balance = balance(e, from);
+
+    for to in recipients.iter() {
+        snapshot::checkpoint(e, Some(from), Some(&to));
+    }
+
+    for (to, amount) in recipients.iter().zip(amounts.iter()) {
+        #[cfg(feature = "transfer_sequence")]
+        increment_transfer_sequence(e);
+
+        if amount > 0 {
+            // NOTE: can't underflow because of the check above.
+            from_balance -= amount;
+            if to == *from {
+                from_balance += amount;
+            } else {
+                // NOTE: can't overflow because balance + amount is at most
+                // total_supply.
+                let to_balance = balance(e, &to) + amount;
+                e.storage().persistent().set(&StorageKey::Balance(to.clone()), &to_balance);
+            }
+            // the event reads the balances back from storage
+            #[cfg(feature = "detailed_events")]
+            e.storage().persistent().set(&StorageKey::Balance(from.clone()), &from_balance);
+        }
+        emit_transfer(e, from, &to, amount);
+    }
+
+    #[cfg(not(feature = "detailed_events"))]
+    if total > 0 {
+        e.storage().persistent().set(&StorageKey::Balance(from.clone()), &from_balance);
+    }
+}
+
+/// Transfers `amount` of tokens from `from` to `to`, and pays a flat fee of
+/// `fee_amount` in another token, `fee_token`, from `from` to `relayer`. Meant
+/// for relayed transfers, where the relayer submits the transaction on behalf
+/// of `from` and gets compensated in the token of its choice.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens and paying the fee.
+/// * `to` - The address receiving the transferred tokens.
+/// * `amount` - The amount of tokens to be transferred.
+/// * `fee_token` - The address of the token contract the fee is paid in.
+/// * `fee_amount` - The amount of `fee_token` paid to `relayer`.
+/// * `relayer` - The address receiving the fee.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::LessThanZero`] - When `fee_amount < 0`.
+/// * refer to [`update`] errors.
+/// * any error raised by `fee_token` when transferring the fee, e.g. when the
+///   `fee_token` balance of `from` is insufficient.
+///
+/// # Events
+///
+/// * topics - `["transfer", from: Address, to: Address]`
+/// * data - `[amount: i128]`
+///
+/// The fee transfer emits its own events from `fee_token`.
+///
+/// # Notes
+///
+/// * Authorization for `from` is required, covering the fee transfer too.
+/// * Both legs succeed or fail together, as any failure reverts the whole
+///   invocation.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_with_relayer_fee(
+    e: &Env,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    fee_token: &Address,
+    fee_amount: i128,
+    relayer: &Address,
+) {
+    if fee_amount < 0 {
+        panic_with_error!(e, FungibleTokenError::LessThanZero);
+    }
+
+    transfer(e, from, to, amount);
+
+    if fee_amount > 0 {
+        token::Client::new(e, fee_token).transfer(from, relayer, &fee_amount);
+    }
+}
+
+/// Transfers `amount` of tokens from `from` to `to`, attaching a hash
+/// commitment to a memo instead of the memo itself. The commitment is carried
+/// by the transfer event, so that the memo can be revealed and checked
+/// against it later, off-chain.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `amount` - The amount of tokens to be transferred.
+/// * `commitment` - The hash commitment to the memo.
+///
+/// # Errors
+///
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["transfer", from: Address, to: Address]`
+/// * data - `[amount: i128, commitment: BytesN<32>]`
+///
+/// With the `detailed_events` feature, see
+/// [`emit_transfer_with_commitment`]:
+/// * data - `[amount: i128, from_balance: i128, to_balance: i128, commitment:
+///   BytesN<32>]`
+///
+/// # Notes
+///
+/// * Authorization for `from` is required.
+/// * The commitment is neither stored nor interpreted by the contract. How it
+///   is computed (e.g. the hash of the memo and a salt) is up to the parties.
+pub fn transfer_with_commitment(
+    e: &Env,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    commitment: &BytesN<32>,
+) {
+    from.require_auth();
+    update(e, Some(from), Some(to), amount);
+    emit_transfer_with_commitment(e, from, to, amount, commitment);
+}
+
+/// Transfers `amount` of tokens from `from` to the contract `to`, then invokes
+/// [`crate::FungibleReceiver::on_token_received`] on `to`, e.g. to let a
+/// router act on the received tokens within the same transaction.
+///
+/// Unlike [`transfer_and_call`], the value returned by `to` is ignored and
+/// its failures are propagated as they are.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The contract receiving the transferred tokens.
+/// * `amount` - The amount of tokens to be transferred.
+/// * `data` - Additional data forwarded to the recipient.
+///
+/// # Errors
+///
+/// * refer to [`update`] errors.
+/// * refer to the errors of `on_token_received` on `to`.
+///
+/// # Events
+///
+/// * topics - `["transfer", from: Address, to: Address]`
+/// * data - `[amount: i128]`
+///
+/// # Notes
+///
+/// * Authorization for `from` is required.
+/// * Only `to` is invoked, through a fixed interface. Invoking a contract and
+///   function chosen by the caller would let it act with the authorization of
+///   the token contract itself.
+/// * If the callback reverts, the whole invocation reverts, including the
+///   transfer.
+pub fn transfer_then(e: &Env, from: &Address, to: &Address, amount: i128, data: Bytes) {
+    transfer(e, from, to, amount);
+    FungibleReceiverClient::new(e, to).on_token_received(from, from, &amount, &data);
+}
+
+/// Transfers `amount` of tokens from `from` to the contract `to`, then invokes
+/// [`crate::FungibleReceiver::on_token_received`] on `to` and ensures the
+/// receipt is confirmed, so that the tokens can't get stuck in a contract
+/// unaware of them.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The contract receiving the transferred tokens.
+/// * `amount` - The amount of tokens to be transferred.
+/// * `data` - Additional data forwarded to the recipient.
+///
+/// # Errors
+///
+/// * [`FungibleTokenError::TransferRejected`] - If `to` doesn't return `true`,
+///   or fails to handle the call, e.g. because it is not a contract.
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["transfer", from: Address, to: Address]`
+/// * data - `[amount: i128]`
+///
+/// # Notes
+///
+/// * Authorization for `from` is required.
+/// * The receiver can't call back into this contract from its callback, as the
+///   host forbids re-entering a contract that is on the call stack, so no
+///   reentrancy guard is needed.
+pub fn transfer_and_call(e: &Env, from: &Address, to: &Address, amount: i128, data: Bytes) {
+    transfer(e, from, to, amount);
+
+    let client = FungibleReceiverClient::new(e, to);
+    if !matches!(client.try_on_token_received(from, from, &amount, &data), Ok(Ok(true))) {
+        panic_with_error!(e, FungibleTokenError::TransferRejected);
+    }
+}
+
+/// Transfers `amount` of tokens from `from` to `to` using the
+/// allowance mechanism. `amount` is then deducted from `spender`s allowance.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `spender` - The address authorizing the transfer, and having its allowance
+///   consumed during the transfer.
+/// * `from` - The address holding the tokens which will be transferred.
+/// * `to` - The address receiving the transferred tokens.
+/// * `amount` - The amount of tokens to be transferred.
+///
+/// # Errors
+///
+/// * refer to [`spend_allowance`] errors.
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["transfer", from: Address, to: Address]`
+/// * data - `[amount: i128]`
 ///
 /// # Notes
 ///
@@ -308,32 +1948,64 @@ pub fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, a
 /// # Errors
 ///
 /// * [`FungibleTokenError::InsufficientBalance`] - When attempting to transfer
-///   more tokens than `from` current balance.
+///   more tokens than `from` current spendable balance, i.e. its balance minus
+///   its locked balance (see [`lock_balance`]).
 /// * [`FungibleTokenError::LessThanZero`] - When `amount < 0`.
 /// * [`FungibleTokenError::MathOverflow`] - When `total_supply` overflows.
+/// * [`FungibleTokenError::InvalidRecipient`] - When `to` has been designated
+///   as an invalid recipient, with the `invalid_recipient` feature enabled.
+/// * refer to [`stellar_pausable::when_not_paused`] errors, when the `pausable`
+///   feature is enabled.
 ///
 /// # Notes
 ///
-/// This function does not enforce authorization. Ensure that authorization
-/// is handled at a higher level.
+/// * This function does not enforce authorization. Ensure that authorization is
+///   handled at a higher level.
+/// * When the `pausable` feature is enabled, every balance change panics while
+///   the contract is paused, whichever entry point it comes from.
+/// * When the `transfer_sequence` feature is enabled, every call increments the
+///   sequence number returned by `transfer_sequence`.
+/// * Once a snapshot has been taken (see [`crate::snapshot::snapshot`]), the
+///   prior balances and total supply are checkpointed before being modified.
+/// * A zero `amount` is accepted and doesn't touch balances nor the total
+///   supply. The calling functions (`transfer`, `mint`, `burn`, etc.) still
+///   emit their events, so that every successful call is observable.
 pub fn update(e: &Env, from: Option<&Address>, to: Option<&Address>, amount: i128) {
+    #[cfg(feature = "pausable")]
+    stellar_pausable::when_not_paused(e);
+
     if amount < 0 {
         panic_with_error!(e, FungibleTokenError::LessThanZero);
     }
+    #[cfg(feature = "invalid_recipient")]
+    if let Some(account) = to {
+        if is_invalid_recipient(e, account) {
+            panic_with_error!(e, FungibleTokenError::InvalidRecipient);
+        }
+    }
+
+    #[cfg(feature = "transfer_sequence")]
+    increment_transfer_sequence(e);
+
+    // zero-amount updates leave balances and total supply unchanged, so there
+    // is nothing to write
+    if amount == 0 {
+        return;
+    }
+
+    snapshot::checkpoint(e, from, to);
+
     if let Some(account) = from {
-        let mut from_balance = balance(e, account);
-        if from_balance < amount {
+        if spendable_balance(e, account) < amount {
             panic_with_error!(e, FungibleTokenError::InsufficientBalance);
         }
+        let mut from_balance = balance(e, account);
         // NOTE: can't underflow because of the check above.
         from_balance -= amount;
         e.storage().persistent().set(&StorageKey::Balance(account.clone()), &from_balance);
     } else {
         // `from` is None, so we're minting tokens.
-        let total_supply = total_supply(e);
-        let Some(new_total_supply) = total_supply.checked_add(amount) else {
-            panic_with_error!(e, FungibleTokenError::MathOverflow);
-        };
+        let new_total_supply = math::add(e, total_supply(e), amount);
         e.storage().instance().set(&StorageKey::TotalSupply, &new_total_supply);
     }
 
@@ -350,3 +2022,12 @@ pub fn update(e: &Env, from: Option<&Address>, to: Option<&Address>, amount: i12
         e.storage().instance().set(&StorageKey::TotalSupply, &total_supply);
     }
 }
+
+/// Increments the sequence number returned by [`transfer_sequence`].
+#[cfg(feature = "transfer_sequence")]
+fn increment_transfer_sequence(e: &Env) {
+    // NOTE: can't realistically overflow, as it would take more than
+    // `u64::MAX` invocations.
+    let sequence = transfer_sequence(e) + 1;
+    e.storage().instance().set(&StorageKey::TransferSequence, &sequence);
+}
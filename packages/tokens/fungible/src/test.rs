@@ -4,21 +4,34 @@ extern crate std;
 
 #[allow(unused_imports)]
 use soroban_sdk::{
-    contract, symbol_short,
+    contract, contractimpl, symbol_short,
     testutils::{
         storage::{Instance, Persistent},
         Address as _, AuthorizedFunction, Events, Ledger,
     },
-    vec, Address, Env, IntoVal,
+    token, vec, Address, Bytes, BytesN, Env, FromVal, IntoVal, Symbol, Val, Vec,
 };
 use stellar_constants::{BALANCE_EXTEND_AMOUNT, INSTANCE_EXTEND_AMOUNT, INSTANCE_TTL_THRESHOLD};
 use stellar_event_assertion::EventAssertion;
 
+#[cfg(feature = "invalid_recipient")]
+use crate::storage::{is_invalid_recipient, remove_invalid_recipient, set_invalid_recipient};
 use crate::{
     extensions::mintable::mint,
+    fungible::{FungibleReceiver, FungibleTokenError},
     storage::{
-        allowance, approve, balance, set_allowance, spend_allowance, total_supply, transfer,
-        transfer_from, update, StorageKey,
+        allowance, allowance_at, allowance_ceiling, allowance_live_until, allowance_tag, approve,
+        approve_batch, approve_tracked, approve_with_tag, balance, decaying_allowance,
+        decrease_allowance, freeze_allowance, increase_allowance, is_allowance_frozen,
+        lock_balance, locked_balance, max_approval_duration, reconcile_supply,
+        remove_auto_renew_allowance, remove_conditional_allowance, remove_decaying_allowance,
+        set_allowance, set_allowance_ceiling, set_auto_renew_allowance, set_conditional_allowance,
+        set_decaying_allowance, set_max_approval_duration, snapshot_allowances, spend_allowance,
+        spend_auto_renew_allowance, spend_conditional_allowance, spend_decaying_allowance,
+        spend_freezable_allowance, spendable_balance, total_supply, transfer, transfer_and_call,
+        transfer_batch, transfer_from, transfer_then, transfer_with_commitment,
+        transfer_with_relayer_fee, unfreeze_allowance, unlock_balance, update, StorageKey,
+        MAX_TRACKED_SPENDERS,
     },
 };
 
@@ -116,6 +129,97 @@ fn approve_handles_expiry() {
     });
 }
 
+#[test]
+fn increase_allowance_from_zero_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        increase_allowance(&e, &owner, &spender, 30, 1000);
+        assert_eq!(allowance(&e, &owner, &spender), 30);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_fungible_approve(&owner, &spender, 30, 1000);
+    });
+
+    e.as_contract(&address, || {
+        increase_allowance(&e, &owner, &spender, 20, 2000);
+        assert_eq!(allowance(&e, &owner, &spender), 50);
+        assert_eq!(allowance_live_until(&e, &owner, &spender), 2000);
+    });
+}
+
+#[test]
+fn decrease_allowance_below_zero_clamps() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        approve(&e, &owner, &spender, 50, 1000);
+    });
+
+    e.as_contract(&address, || {
+        decrease_allowance(&e, &owner, &spender, 20);
+        assert_eq!(allowance(&e, &owner, &spender), 30);
+        assert_eq!(allowance_live_until(&e, &owner, &spender), 1000);
+    });
+
+    e.as_contract(&address, || {
+        decrease_allowance(&e, &owner, &spender, 100);
+        assert_eq!(allowance(&e, &owner, &spender), 0);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_fungible_approve(&owner, &spender, 0, 1000);
+    });
+}
+
+#[test]
+fn increase_expired_allowance_starts_from_zero() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        approve(&e, &owner, &spender, 50, 10);
+    });
+
+    e.ledger().set_sequence_number(11);
+    e.as_contract(&address, || {
+        // decreasing an expired allowance leaves it at zero
+        decrease_allowance(&e, &owner, &spender, 10);
+        assert_eq!(allowance(&e, &owner, &spender), 0);
+    });
+
+    e.as_contract(&address, || {
+        increase_allowance(&e, &owner, &spender, 20, 100);
+        assert_eq!(allowance(&e, &owner, &spender), 20);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #203)")]
+fn decrease_allowance_with_neg_amount_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        decrease_allowance(&e, &owner, &spender, -1);
+    });
+}
+
 #[test]
 fn spend_allowance_reduces_amount() {
     let e = Env::default();
@@ -135,8 +239,7 @@ fn spend_allowance_reduces_amount() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #201)")]
-fn spend_allowance_insufficient_allowance_fails() {
+fn approve_with_tag_round_trips() {
     let e = Env::default();
     e.mock_all_auths();
     let address = e.register(MockContract, ());
@@ -144,291 +247,1853 @@ fn spend_allowance_insufficient_allowance_fails() {
     let spender = Address::generate(&e);
 
     e.as_contract(&address, || {
-        approve(&e, &owner, &spender, 10, 1000);
+        approve_with_tag(&e, &owner, &spender, 50, 1000, &symbol_short!("groceries"));
+        assert_eq!(allowance(&e, &owner, &spender), 50);
+        assert_eq!(allowance_tag(&e, &owner, &spender), Some(symbol_short!("groceries")));
+
+        // the tag survives partial spending
         spend_allowance(&e, &owner, &spender, 20);
+        assert_eq!(allowance(&e, &owner, &spender), 30);
+        assert_eq!(allowance_tag(&e, &owner, &spender), Some(symbol_short!("groceries")));
+    });
+
+    e.as_contract(&address, || {
+        // a plain approval overrides the tag
+        approve(&e, &owner, &spender, 50, 1000);
+        assert_eq!(allowance_tag(&e, &owner, &spender), None);
     });
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #203)")]
-fn spend_allowance_invalid_amount_fails() {
+fn approve_batch_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender1 = Address::generate(&e);
+    let spender2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        approve_batch(
+            &e,
+            &owner,
+            vec![&e, spender1.clone(), spender2.clone()],
+            vec![&e, 50, 70],
+            1000,
+        );
+        assert_eq!(allowance(&e, &owner, &spender1), 50);
+        assert_eq!(allowance(&e, &owner, &spender2), 70);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #213)")]
+fn approve_batch_with_length_mismatch_panics() {
     let e = Env::default();
+    e.mock_all_auths();
     let address = e.register(MockContract, ());
     let owner = Address::generate(&e);
     let spender = Address::generate(&e);
 
     e.as_contract(&address, || {
-        spend_allowance(&e, &owner, &spender, -1);
+        approve_batch(&e, &owner, vec![&e, spender], vec![&e, 50, 70], 1000);
     });
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #202)")]
-fn set_allowance_with_expired_ledger_fails() {
+fn approve_batch_with_zero_amounts_clears_allowances() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender1 = Address::generate(&e);
+    let spender2 = Address::generate(&e);
+    let spenders = vec![&e, spender1.clone(), spender2.clone()];
+
+    e.as_contract(&address, || {
+        approve_batch(&e, &owner, spenders.clone(), vec![&e, 50, 70], 1000);
+    });
+
+    e.as_contract(&address, || {
+        approve_batch(&e, &owner, spenders.clone(), vec![&e, 0, 0], 0);
+        assert_eq!(allowance(&e, &owner, &spender1), 0);
+        assert_eq!(allowance(&e, &owner, &spender2), 0);
+    });
+}
+
+#[test]
+fn snapshot_allowances_preserves_original_values() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender1 = Address::generate(&e);
+    let spender2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        approve_tracked(&e, &owner, &spender1, 50, 1000);
+    });
+
+    e.as_contract(&address, || {
+        approve_tracked(&e, &owner, &spender2, 70, 1000);
+    });
+
+    e.as_contract(&address, || {
+        let snapshot_id = snapshot_allowances(&e, &owner);
+
+        set_allowance(&e, &owner, &spender1, 10, 1000);
+        set_allowance(&e, &owner, &spender2, 0, 0);
+        assert_eq!(allowance(&e, &owner, &spender1), 10);
+
+        assert_eq!(allowance_at(&e, snapshot_id, &owner, &spender1), 50);
+        assert_eq!(allowance_at(&e, snapshot_id, &owner, &spender2), 70);
+
+        let next_snapshot_id = snapshot_allowances(&e, &owner);
+        assert_ne!(next_snapshot_id, snapshot_id);
+        assert_eq!(allowance_at(&e, next_snapshot_id, &owner, &spender1), 10);
+        assert_eq!(allowance_at(&e, next_snapshot_id, &owner, &spender2), 0);
+
+        // the snapshot only covers the allowances of `owner`
+        assert_eq!(allowance_at(&e, snapshot_id, &spender1, &spender2), 0);
+    });
+}
+
+#[test]
+fn snapshot_allowances_skips_untracked_approvals() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        approve(&e, &owner, &spender, 50, 1000);
+    });
+
+    e.as_contract(&address, || {
+        let snapshot_id = snapshot_allowances(&e, &owner);
+        assert_eq!(allowance_at(&e, snapshot_id, &owner, &spender), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #237)")]
+fn approve_tracked_too_many_spenders_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    for _ in 0..=MAX_TRACKED_SPENDERS {
+        let spender = Address::generate(&e);
+        e.as_contract(&address, || {
+            approve_tracked(&e, &owner, &spender, 50, 1000);
+        });
+    }
+}
+
+#[test]
+fn approve_tracked_replaces_revoked_spenders() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spenders: std::vec::Vec<Address> =
+        (0..MAX_TRACKED_SPENDERS).map(|_| Address::generate(&e)).collect();
+
+    for spender in &spenders {
+        e.as_contract(&address, || {
+            approve_tracked(&e, &owner, spender, 50, 1000);
+        });
+    }
+
+    e.as_contract(&address, || {
+        approve(&e, &owner, &spenders[0], 0, 0);
+    });
+
+    let spender = Address::generate(&e);
+    e.as_contract(&address, || {
+        approve_tracked(&e, &owner, &spender, 70, 1000);
+    });
+
+    e.as_contract(&address, || {
+        let snapshot_id = snapshot_allowances(&e, &owner);
+        assert_eq!(allowance_at(&e, snapshot_id, &owner, &spender), 70);
+        assert_eq!(allowance_at(&e, snapshot_id, &owner, &spenders[1]), 50);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #216)")]
+fn allowance_at_unknown_snapshot_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        allowance_at(&e, 0, &owner, &spender);
+    });
+}
+
+#[test]
+fn auto_renew_allowance_renews_when_consumed() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &owner, 1000);
+        set_auto_renew_allowance(&e, &owner, &spender, 100, 50);
+        assert_eq!(allowance(&e, &owner, &spender), 100);
+    });
+
+    e.ledger().set_sequence_number(30);
+
+    e.as_contract(&address, || {
+        // partial consumption doesn't renew the allowance
+        spend_auto_renew_allowance(&e, &owner, &spender, 40);
+        update(&e, Some(&owner), Some(&recipient), 40);
+        assert_eq!(allowance(&e, &owner, &spender), 60);
+    });
+
+    e.as_contract(&address, || {
+        spend_auto_renew_allowance(&e, &owner, &spender, 60);
+        update(&e, Some(&owner), Some(&recipient), 60);
+        assert_eq!(allowance(&e, &owner, &spender), 100);
+    });
+
+    // the renewed allowance lives for a new window
+    e.ledger().set_sequence_number(70);
+
+    e.as_contract(&address, || {
+        assert_eq!(allowance(&e, &owner, &spender), 100);
+        spend_auto_renew_allowance(&e, &owner, &spender, 100);
+        update(&e, Some(&owner), Some(&recipient), 100);
+        assert_eq!(balance(&e, &recipient), 200);
+    });
+}
+
+#[test]
+fn auto_renew_allowance_expires_when_window_lapses() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_auto_renew_allowance(&e, &owner, &spender, 100, 50);
+    });
+
+    e.ledger().set_sequence_number(51);
+
+    e.as_contract(&address, || {
+        assert_eq!(allowance(&e, &owner, &spender), 0);
+    });
+}
+
+#[test]
+fn remove_auto_renew_allowance_stops_renewals() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &owner, 1000);
+        set_auto_renew_allowance(&e, &owner, &spender, 100, 50);
+    });
+
+    e.as_contract(&address, || {
+        approve(&e, &owner, &spender, 30, 50);
+        remove_auto_renew_allowance(&e, &owner, &spender);
+    });
+
+    e.as_contract(&address, || {
+        spend_auto_renew_allowance(&e, &owner, &spender, 30);
+        update(&e, Some(&owner), Some(&recipient), 30);
+        assert_eq!(allowance(&e, &owner, &spender), 0);
+    });
+}
+
+#[test]
+fn decaying_allowance_decreases_linearly() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.ledger().set_sequence_number(10);
+    e.as_contract(&address, || {
+        set_decaying_allowance(&e, &owner, &spender, 1000, 100, 200);
+        // full amount before the decay starts
+        assert_eq!(decaying_allowance(&e, &owner, &spender), 1000);
+    });
+
+    e.ledger().set_sequence_number(100);
+    e.as_contract(&address, || assert_eq!(decaying_allowance(&e, &owner, &spender), 1000));
+
+    e.ledger().set_sequence_number(125);
+    e.as_contract(&address, || assert_eq!(decaying_allowance(&e, &owner, &spender), 750));
+
+    e.ledger().set_sequence_number(150);
+    e.as_contract(&address, || assert_eq!(decaying_allowance(&e, &owner, &spender), 500));
+
+    // fully decayed
+    e.ledger().set_sequence_number(200);
+    e.as_contract(&address, || assert_eq!(decaying_allowance(&e, &owner, &spender), 0));
+}
+
+#[test]
+fn spend_decaying_allowance_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &owner, 1000);
+        set_decaying_allowance(&e, &owner, &spender, 1000, 100, 200);
+    });
+
+    e.ledger().set_sequence_number(150);
+    e.as_contract(&address, || {
+        spend_decaying_allowance(&e, &owner, &spender, 500);
+        update(&e, Some(&owner), Some(&recipient), 500);
+        assert_eq!(balance(&e, &recipient), 500);
+        assert_eq!(decaying_allowance(&e, &owner, &spender), 500);
+    });
+
+    // the remaining amount keeps being capped by the decayed value
+    e.ledger().set_sequence_number(180);
+    e.as_contract(&address, || assert_eq!(decaying_allowance(&e, &owner, &spender), 200));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #201)")]
+fn spend_decayed_allowance_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_decaying_allowance(&e, &owner, &spender, 1000, 100, 200);
+    });
+
+    e.ledger().set_sequence_number(150);
+    e.as_contract(&address, || {
+        spend_decaying_allowance(&e, &owner, &spender, 501);
+    });
+}
+
+#[contract]
+struct CapPredicate;
+
+#[contractimpl]
+impl CapPredicate {
+    pub fn check(_e: &Env, _owner: Address, _spender: Address, amount: i128) -> bool {
+        amount <= 100
+    }
+}
+
+#[contract]
+struct FailingPredicate;
+
+#[contractimpl]
+impl FailingPredicate {
+    pub fn check(_e: &Env, _owner: Address, _spender: Address, _amount: i128) -> bool {
+        panic!("predicate failed");
+    }
+}
+
+#[test]
+fn spend_conditional_allowance_allowed_by_predicate() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let predicate = e.register(CapPredicate, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &owner, 1000);
+        set_conditional_allowance(&e, &owner, &spender, 500, &predicate);
+        assert_eq!(allowance(&e, &owner, &spender), 500);
+    });
+
+    e.as_contract(&address, || {
+        spend_conditional_allowance(&e, &owner, &spender, 100);
+        update(&e, Some(&owner), Some(&recipient), 100);
+        assert_eq!(allowance(&e, &owner, &spender), 400);
+        assert_eq!(balance(&e, &recipient), 100);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #231)")]
+fn spend_conditional_allowance_denied_by_predicate() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let predicate = e.register(CapPredicate, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_conditional_allowance(&e, &owner, &spender, 500, &predicate);
+    });
+
+    e.as_contract(&address, || {
+        spend_conditional_allowance(&e, &owner, &spender, 101);
+    });
+}
+
+#[test]
+#[should_panic(expected = "predicate failed")]
+fn spend_conditional_allowance_with_failing_predicate() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let predicate = e.register(FailingPredicate, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_conditional_allowance(&e, &owner, &spender, 500, &predicate);
+        spend_conditional_allowance(&e, &owner, &spender, 50);
+    });
+}
+
+#[test]
+fn remove_conditional_allowance_removes_condition() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let predicate = e.register(FailingPredicate, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_conditional_allowance(&e, &owner, &spender, 500, &predicate);
+    });
+
+    e.as_contract(&address, || {
+        approve(&e, &owner, &spender, 200, 1000);
+        remove_conditional_allowance(&e, &owner, &spender);
+    });
+
+    e.as_contract(&address, || {
+        spend_conditional_allowance(&e, &owner, &spender, 50);
+        assert_eq!(allowance(&e, &owner, &spender), 150);
+    });
+}
+
+#[test]
+fn lock_balance_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 100);
+        lock_balance(&e, &account, 60);
+
+        assert_eq!(balance(&e, &account), 100);
+        assert_eq!(locked_balance(&e, &account), 60);
+        assert_eq!(spendable_balance(&e, &account), 40);
+    });
+
+    e.as_contract(&address, || {
+        transfer(&e, &account, &recipient, 40);
+        assert_eq!(balance(&e, &account), 60);
+        assert_eq!(spendable_balance(&e, &account), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #200)")]
+fn transfer_locked_balance_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 100);
+        lock_balance(&e, &account, 60);
+    });
+
+    e.as_contract(&address, || {
+        transfer(&e, &account, &recipient, 41);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #200)")]
+fn lock_more_than_spendable_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 100);
+        lock_balance(&e, &account, 60);
+        lock_balance(&e, &account, 41);
+    });
+}
+
+#[test]
+fn unlock_balance_restores_spendability() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 100);
+        lock_balance(&e, &account, 60);
+        unlock_balance(&e, &account, 60);
+
+        assert_eq!(locked_balance(&e, &account), 0);
+        assert_eq!(spendable_balance(&e, &account), 100);
+        assert!(!e.storage().persistent().has(&StorageKey::LockedBalance(account.clone())));
+    });
+
+    e.as_contract(&address, || {
+        transfer(&e, &account, &recipient, 100);
+        assert_eq!(balance(&e, &recipient), 100);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #230)")]
+fn unlock_more_than_locked_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 100);
+        lock_balance(&e, &account, 60);
+        unlock_balance(&e, &account, 61);
+    });
+}
+
+#[test]
+fn remove_decaying_allowance_stops_decay() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_decaying_allowance(&e, &owner, &spender, 1000, 100, 200);
+    });
+
+    e.as_contract(&address, || {
+        approve(&e, &owner, &spender, 1000, 300);
+        remove_decaying_allowance(&e, &owner, &spender);
+    });
+
+    e.ledger().set_sequence_number(150);
+    e.as_contract(&address, || assert_eq!(decaying_allowance(&e, &owner, &spender), 1000));
+}
+
+#[test]
+fn spend_allowance_ignores_decay() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_decaying_allowance(&e, &owner, &spender, 1000, 100, 200);
+    });
+
+    e.ledger().set_sequence_number(150);
+    e.as_contract(&address, || {
+        assert_eq!(decaying_allowance(&e, &owner, &spender), 500);
+        spend_allowance(&e, &owner, &spender, 800);
+        assert_eq!(allowance(&e, &owner, &spender), 200);
+    });
+}
+
+#[test]
+fn untagged_allowance_has_no_tag() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        assert_eq!(allowance_tag(&e, &owner, &spender), None);
+
+        approve(&e, &owner, &spender, 50, 1000);
+        assert_eq!(allowance_tag(&e, &owner, &spender), None);
+    });
+}
+
+#[test]
+fn approve_under_and_at_ceiling_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        assert_eq!(allowance_ceiling(&e, &owner, &spender), None);
+        set_allowance_ceiling(&e, &owner, &spender, 100);
+        assert_eq!(allowance_ceiling(&e, &owner, &spender), Some(100));
+    });
+
+    e.as_contract(&address, || {
+        approve(&e, &owner, &spender, 50, 1000);
+        assert_eq!(allowance(&e, &owner, &spender), 50);
+    });
+
+    e.as_contract(&address, || {
+        approve(&e, &owner, &spender, 100, 1000);
+        assert_eq!(allowance(&e, &owner, &spender), 100);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #211)")]
+fn approve_above_ceiling_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_allowance_ceiling(&e, &owner, &spender, 100);
+    });
+
+    e.as_contract(&address, || {
+        approve(&e, &owner, &spender, 101, 1000);
+    });
+}
+
+#[test]
+fn spend_allowance_after_ceiling_lowered_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        approve(&e, &owner, &spender, 100, 1000);
+    });
+
+    e.as_contract(&address, || {
+        set_allowance_ceiling(&e, &owner, &spender, 10);
+        spend_allowance(&e, &owner, &spender, 20);
+        assert_eq!(allowance(&e, &owner, &spender), 80);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #201)")]
+fn spend_allowance_insufficient_allowance_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        approve(&e, &owner, &spender, 10, 1000);
+        spend_allowance(&e, &owner, &spender, 20);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #203)")]
+fn spend_allowance_invalid_amount_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        spend_allowance(&e, &owner, &spender, -1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #202)")]
+fn set_allowance_with_expired_ledger_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        e.ledger().set_sequence_number(10);
+        set_allowance(&e, &owner, &spender, 50, 5);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #202)")]
+fn set_allowance_with_greater_than_max_ledger_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let ttl = e.storage().max_ttl() + 1;
+        set_allowance(&e, &owner, &spender, 50, ttl);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #203)")]
+fn set_allowance_with_neg_amount_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_allowance(&e, &owner, &spender, -1, 5);
+    });
+}
+
+#[test]
+fn set_allowance_with_zero_amount() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let owner2 = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_allowance(&e, &owner, &spender, 0, 5);
+        let allowance_val = allowance(&e, &owner, &spender);
+        assert_eq!(allowance_val, 0);
+
+        // should pass for a past ledger
+        e.ledger().set_sequence_number(10);
+        set_allowance(&e, &owner2, &spender, 0, 5);
+        let allowance_val = allowance(&e, &owner2, &spender);
+        assert_eq!(allowance_val, 0);
+    });
+}
+
+#[test]
+fn transfer_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 100);
+        transfer(&e, &from, &recipient, 50);
+        assert_eq!(balance(&e, &from), 50);
+        assert_eq!(balance(&e, &recipient), 50);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+        event_assert.assert_fungible_mint(&from, 100);
+        event_assert.assert_fungible_transfer(&from, &recipient, 50);
+    });
+}
+
+#[cfg(feature = "detailed_events")]
+#[test]
+fn transfer_event_carries_balances() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 100);
+        mint(&e, &recipient, 5);
+        transfer(&e, &from, &recipient, 30);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_fungible_transfer_detailed(&from, &recipient, 30, 70, 35);
+    });
+}
+
+#[cfg(feature = "detailed_events")]
+#[test]
+fn transfer_batch_events_carry_balances_after_each_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let recipient1 = Address::generate(&e);
+    let recipient2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 100);
+        mint(&e, &recipient2, 5);
+    });
+
+    e.as_contract(&address, || {
+        let recipients = vec![&e, recipient1.clone(), from.clone(), recipient2.clone()];
+        transfer_batch(&e, &from, recipients, vec![&e, 30, 10, 20]);
+        assert_eq!(balance(&e, &from), 50);
+
+        let events = e.events().all();
+        assert_eq!(events.len(), 3);
+        // the sequence number, if any, comes after the balances
+        let balances = |i: u32| {
+            let data: Vec<Val> = events.get_unchecked(i).2.into_val(&e);
+            let get = |j| i128::from_val(&e, &data.get_unchecked(j));
+            (get(0), get(1), get(2))
+        };
+        let data = Vec::from_array(&e, [balances(0), balances(1), balances(2)]);
+        assert_eq!(data, vec![&e, (30, 70, 30), (10, 70, 70), (20, 50, 25)]);
+    });
+}
+
+#[test]
+fn allowance_live_until_survives_expiry() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        assert_eq!(allowance_live_until(&e, &owner, &spender), 0);
+        approve(&e, &owner, &spender, 50, 5);
+        assert_eq!(allowance_live_until(&e, &owner, &spender), 5);
+    });
+
+    e.ledger().set_sequence_number(6);
+
+    e.as_contract(&address, || {
+        assert_eq!(allowance_live_until(&e, &owner, &spender), 5);
+        assert_eq!(allowance(&e, &owner, &spender), 0);
+    });
+}
+
+#[test]
+fn transfer_with_commitment_carries_commitment() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let commitment = BytesN::from_array(&e, &[7; 32]);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 100);
+    });
+
+    e.as_contract(&address, || {
+        transfer_with_commitment(&e, &from, &recipient, 50, &commitment);
+        assert_eq!(balance(&e, &from), 50);
+        assert_eq!(balance(&e, &recipient), 50);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        let (_, data) = event_assert.assert_event_from(&address, "transfer");
+        let data: Vec<Val> = data.into_val(&e);
+        assert_eq!(i128::from_val(&e, &data.get_unchecked(0)), 50);
+        #[cfg(not(feature = "detailed_events"))]
+        assert_eq!(BytesN::<32>::from_val(&e, &data.get_unchecked(1)), commitment);
+        #[cfg(feature = "detailed_events")]
+        {
+            // same balances as a plain transfer, before the commitment
+            assert_eq!(i128::from_val(&e, &data.get_unchecked(1)), 50);
+            assert_eq!(i128::from_val(&e, &data.get_unchecked(2)), 50);
+            assert_eq!(BytesN::<32>::from_val(&e, &data.get_unchecked(3)), commitment);
+        }
+    });
+
+    // plain transfers are unaffected
+    e.as_contract(&address, || {
+        transfer(&e, &from, &recipient, 10);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_fungible_transfer(&from, &recipient, 10);
+    });
+}
+
+#[cfg(feature = "allowance_expired_event")]
+#[test]
+fn allowance_expired_event_fires_once_on_spend() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        // the entry outlives the allowance, as the minimum TTL of temporary
+        // entries is longer than 5 ledgers
+        set_allowance(&e, &owner, &spender, 50, 5);
+    });
+
+    e.ledger().set_sequence_number(6);
+
+    let key = || {
+        StorageKey::Allowance(crate::AllowanceKey {
+            owner: owner.clone(),
+            spender: spender.clone(),
+        })
+    };
+
+    e.as_contract(&address, || {
+        // reading the allowance has no side effects
+        assert_eq!(allowance(&e, &owner, &spender), 0);
+        assert_eq!(e.events().all().len(), 0);
+        assert!(e.storage().temporary().has(&key()));
+    });
+
+    e.as_contract(&address, || {
+        spend_allowance(&e, &owner, &spender, 0);
+
+        let events = e.events().all();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events,
+            vec![
+                &e,
+                (
+                    address.clone(),
+                    vec![
+                        &e,
+                        Symbol::new(&e, "allowance_expired").into_val(&e),
+                        owner.into_val(&e),
+                        spender.into_val(&e)
+                    ],
+                    (50i128, 5u32).into_val(&e)
+                )
+            ]
+        );
+
+        assert!(!e.storage().temporary().has(&key()));
+    });
+
+    e.as_contract(&address, || {
+        spend_allowance(&e, &owner, &spender, 0);
+        assert_eq!(e.events().all().len(), 0);
+    });
+}
+
+#[cfg(feature = "allowance_expired_event")]
+#[test]
+fn allowance_expired_event_fires_on_approve() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_allowance(&e, &owner, &spender, 50, 5);
+    });
+
+    e.ledger().set_sequence_number(6);
+
+    e.as_contract(&address, || {
+        approve(&e, &owner, &spender, 20, 100);
+
+        let events = e.events().all();
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            events.slice(0..1),
+            vec![
+                &e,
+                (
+                    address.clone(),
+                    vec![
+                        &e,
+                        Symbol::new(&e, "allowance_expired").into_val(&e),
+                        owner.into_val(&e),
+                        spender.into_val(&e)
+                    ],
+                    (50i128, 5u32).into_val(&e)
+                )
+            ]
+        );
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_fungible_approve(&owner, &spender, 20, 100);
+        assert_eq!(allowance(&e, &owner, &spender), 20);
+    });
+}
+
+#[cfg(not(any(feature = "transfer_sequence", feature = "detailed_events")))]
+#[test]
+fn transfer_event_has_no_sequence() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 100);
+        transfer(&e, &from, &recipient, 50);
+
+        let events = e.events().all();
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            events.slice(events.len() - 1..),
+            vec![
+                &e,
+                (
+                    address.clone(),
+                    vec![
+                        &e,
+                        symbol_short!("transfer").into_val(&e),
+                        from.into_val(&e),
+                        recipient.into_val(&e)
+                    ],
+                    50i128.into_val(&e)
+                )
+            ]
+        );
+    });
+}
+
+#[cfg(all(feature = "transfer_sequence", not(feature = "detailed_events")))]
+#[test]
+fn transfer_sequence_increments() {
+    use crate::storage::transfer_sequence;
+
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        assert_eq!(transfer_sequence(&e), 0);
+
+        mint(&e, &from, 100);
+        assert_eq!(transfer_sequence(&e), 1);
+
+        transfer(&e, &from, &recipient, 50);
+        assert_eq!(transfer_sequence(&e), 2);
+
+        let events = e.events().all();
+        assert_eq!(
+            events.slice(events.len() - 1..),
+            vec![
+                &e,
+                (
+                    address.clone(),
+                    vec![
+                        &e,
+                        symbol_short!("transfer").into_val(&e),
+                        from.into_val(&e),
+                        recipient.into_val(&e)
+                    ],
+                    (50i128, 2u64).into_val(&e)
+                )
+            ]
+        );
+    });
+
+    e.as_contract(&address, || {
+        mint(&e, &recipient, 10);
+        transfer(&e, &recipient, &from, 60);
+        assert_eq!(transfer_sequence(&e), 4);
+
+        let events = e.events().all();
+        assert_eq!(
+            events.slice(events.len() - 1..),
+            vec![
+                &e,
+                (
+                    address.clone(),
+                    vec![
+                        &e,
+                        symbol_short!("transfer").into_val(&e),
+                        recipient.into_val(&e),
+                        from.into_val(&e)
+                    ],
+                    (60i128, 4u64).into_val(&e)
+                )
+            ]
+        );
+    });
+}
+
+#[test]
+fn transfer_zero_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        transfer(&e, &from, &recipient, 0);
+        assert_eq!(balance(&e, &from), 0);
+        assert_eq!(balance(&e, &recipient), 0);
+        assert!(!e.storage().persistent().has(&StorageKey::Balance(from.clone())));
+        assert!(!e.storage().persistent().has(&StorageKey::Balance(recipient.clone())));
+
+        let events = e.events().all();
+        assert_eq!(events.len(), 1);
+    });
+}
+
+#[test]
+fn extend_balance_ttl_thru_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 100);
+
+        let key = StorageKey::Balance(from.clone());
+
+        let ttl = e.storage().persistent().get_ttl(&key);
+        e.ledger().with_mut(|l| {
+            l.sequence_number += ttl;
+        });
+        transfer(&e, &from, &recipient, 50);
+        let ttl = e.storage().persistent().get_ttl(&key);
+        assert_eq!(ttl, BALANCE_EXTEND_AMOUNT);
+    });
+}
+
+#[test]
+fn approve_and_transfer_from() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &owner, 100);
+        approve(&e, &owner, &spender, 50, 1000);
+
+        let allowance_val = allowance(&e, &owner, &spender);
+        assert_eq!(allowance_val, 50);
+
+        transfer_from(&e, &spender, &owner, &recipient, 30);
+        assert_eq!(balance(&e, &owner), 70);
+        assert_eq!(balance(&e, &recipient), 30);
+
+        let updated_allowance = allowance(&e, &owner, &spender);
+        assert_eq!(updated_allowance, 20);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(3);
+        event_assert.assert_fungible_mint(&owner, 100);
+        event_assert.assert_fungible_approve(&owner, &spender, 50, 1000);
+        event_assert.assert_fungible_transfer(&owner, &recipient, 30);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #200)")]
+fn transfer_insufficient_balance_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 50);
+        transfer(&e, &from, &recipient, 100);
+    });
+}
+
+#[test]
+fn transfer_batch_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let recipient1 = Address::generate(&e);
+    let recipient2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 100);
+    });
+
+    e.as_contract(&address, || {
+        transfer_batch(
+            &e,
+            &from,
+            vec![&e, recipient1.clone(), recipient2.clone(), recipient1.clone()],
+            vec![&e, 30, 20, 5],
+        );
+        assert_eq!(balance(&e, &from), 45);
+        assert_eq!(balance(&e, &recipient1), 35);
+        assert_eq!(balance(&e, &recipient2), 20);
+        assert_eq!(total_supply(&e), 100);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(3);
+        event_assert.assert_fungible_transfer(&from, &recipient1, 30);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #213)")]
+fn transfer_batch_with_length_mismatch_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 100);
+        transfer_batch(&e, &from, vec![&e, recipient], vec![&e, 30, 20]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #203)")]
+fn transfer_batch_with_negative_amount_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let recipient1 = Address::generate(&e);
+    let recipient2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 100);
+        transfer_batch(&e, &from, vec![&e, recipient1, recipient2], vec![&e, 30, -20]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #200)")]
+fn transfer_batch_insufficient_balance_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let recipient1 = Address::generate(&e);
+    let recipient2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 100);
+        transfer_batch(&e, &from, vec![&e, recipient1, recipient2], vec![&e, 60, 41]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #200)")]
+fn transfer_batch_locked_balance_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let recipient1 = Address::generate(&e);
+    let recipient2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 100);
+        lock_balance(&e, &from, 60);
+    });
+
+    e.as_contract(&address, || {
+        // only 40 tokens are spendable, the batch dips into the locked ones
+        transfer_batch(&e, &from, vec![&e, recipient1, recipient2], vec![&e, 20, 21]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #201)")]
+fn transfer_from_insufficient_allowance_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &owner, 100);
+        approve(&e, &owner, &spender, 30, 1000);
+        transfer_from(&e, &spender, &owner, &recipient, 50);
+    });
+}
+
+#[test]
+fn update_transfers_between_accounts() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 100);
+        update(&e, Some(&from), Some(&to), 50);
+        assert_eq!(balance(&e, &from), 50);
+        assert_eq!(balance(&e, &to), 50);
+    });
+}
+
+#[test]
+fn update_mints_tokens() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        update(&e, None, Some(&to), 100);
+        assert_eq!(balance(&e, &to), 100);
+        assert_eq!(total_supply(&e), 100);
+    });
+}
+
+#[test]
+fn update_burns_tokens() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 100);
+        update(&e, Some(&from), None, 50);
+        assert_eq!(balance(&e, &from), 50);
+        assert_eq!(total_supply(&e), 50);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #203)")]
+fn update_with_invalid_amount_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        update(&e, Some(&from), Some(&to), -1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #204)")]
+fn update_overflow_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, i128::MAX);
+        update(&e, None, Some(&account), 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #200)")]
+fn update_with_insufficient_balance_panics() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 50);
+        update(&e, Some(&from), Some(&to), 100);
+    });
+}
+
+#[cfg(feature = "invalid_recipient")]
+#[test]
+fn transfer_to_valid_recipient_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 100);
+        set_invalid_recipient(&e, &address);
+        assert!(is_invalid_recipient(&e, &address));
+        assert!(!is_invalid_recipient(&e, &recipient));
+    });
+
+    e.as_contract(&address, || {
+        transfer(&e, &from, &recipient, 50);
+        assert_eq!(balance(&e, &recipient), 50);
+    });
+}
+
+#[cfg(feature = "invalid_recipient")]
+#[test]
+#[should_panic(expected = "Error(Contract, #212)")]
+fn transfer_to_invalid_recipient_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 100);
+        set_invalid_recipient(&e, &address);
+    });
+
+    e.as_contract(&address, || {
+        transfer(&e, &from, &address, 50);
+    });
+}
+
+#[cfg(feature = "invalid_recipient")]
+#[test]
+fn remove_invalid_recipient_works() {
     let e = Env::default();
     let address = e.register(MockContract, ());
-    let owner = Address::generate(&e);
-    let spender = Address::generate(&e);
+    let account = Address::generate(&e);
 
     e.as_contract(&address, || {
-        e.ledger().set_sequence_number(10);
-        set_allowance(&e, &owner, &spender, 50, 5);
+        set_invalid_recipient(&e, &account);
+        remove_invalid_recipient(&e, &account);
+        assert!(!is_invalid_recipient(&e, &account));
+
+        mint(&e, &account, 100);
+        assert_eq!(balance(&e, &account), 100);
     });
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #202)")]
-fn set_allowance_with_greater_than_max_ledger_fails() {
+#[should_panic(expected = "Error(Contract, #223)")]
+fn spend_frozen_allowance_panics() {
     let e = Env::default();
+    e.mock_all_auths();
     let address = e.register(MockContract, ());
     let owner = Address::generate(&e);
     let spender = Address::generate(&e);
 
     e.as_contract(&address, || {
-        let ttl = e.storage().max_ttl() + 1;
-        set_allowance(&e, &owner, &spender, 50, ttl);
+        mint(&e, &owner, 100);
+        approve(&e, &owner, &spender, 50, 1000);
+        freeze_allowance(&e, &owner, &spender);
+        assert!(is_allowance_frozen(&e, &owner, &spender));
+    });
+
+    e.as_contract(&address, || {
+        spend_freezable_allowance(&e, &owner, &spender, 10);
     });
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #203)")]
-fn set_allowance_with_neg_amount_fails() {
+fn frozen_allowance_leaves_direct_transfers_working() {
     let e = Env::default();
+    e.mock_all_auths();
     let address = e.register(MockContract, ());
     let owner = Address::generate(&e);
     let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
 
     e.as_contract(&address, || {
-        set_allowance(&e, &owner, &spender, -1, 5);
+        mint(&e, &owner, 100);
+        approve(&e, &owner, &spender, 50, 1000);
+        freeze_allowance(&e, &owner, &spender);
+    });
+
+    e.as_contract(&address, || {
+        transfer(&e, &owner, &recipient, 30);
+        assert_eq!(balance(&e, &recipient), 30);
+        // the allowance is kept while frozen
+        assert_eq!(allowance(&e, &owner, &spender), 50);
+    });
+
+    e.as_contract(&address, || {
+        unfreeze_allowance(&e, &owner, &spender);
+        assert!(!is_allowance_frozen(&e, &owner, &spender));
+        spend_freezable_allowance(&e, &owner, &spender, 20);
+        update(&e, Some(&owner), Some(&recipient), 20);
+        assert_eq!(balance(&e, &recipient), 50);
+        assert_eq!(allowance(&e, &owner, &spender), 30);
     });
 }
 
 #[test]
-fn set_allowance_with_zero_amount() {
+fn approve_within_max_approval_duration_works() {
     let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(100);
     let address = e.register(MockContract, ());
     let owner = Address::generate(&e);
-    let owner2 = Address::generate(&e);
     let spender = Address::generate(&e);
 
     e.as_contract(&address, || {
-        set_allowance(&e, &owner, &spender, 0, 5);
-        let allowance_val = allowance(&e, &owner, &spender);
-        assert_eq!(allowance_val, 0);
+        set_max_approval_duration(&e, 500);
+        assert_eq!(max_approval_duration(&e), Some(500));
+    });
 
-        // should pass for a past ledger
-        e.ledger().set_sequence_number(10);
-        set_allowance(&e, &owner2, &spender, 0, 5);
-        let allowance_val = allowance(&e, &owner2, &spender);
-        assert_eq!(allowance_val, 0);
+    e.as_contract(&address, || {
+        approve(&e, &owner, &spender, 50, 600);
+        assert_eq!(allowance(&e, &owner, &spender), 50);
     });
 }
 
 #[test]
-fn transfer_works() {
+#[should_panic(expected = "Error(Contract, #224)")]
+fn approve_exceeding_max_approval_duration_fails() {
     let e = Env::default();
     e.mock_all_auths();
+    e.ledger().set_sequence_number(100);
     let address = e.register(MockContract, ());
-    let from = Address::generate(&e);
-    let recipient = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
 
     e.as_contract(&address, || {
-        mint(&e, &from, 100);
-        transfer(&e, &from, &recipient, 50);
-        assert_eq!(balance(&e, &from), 50);
-        assert_eq!(balance(&e, &recipient), 50);
+        set_max_approval_duration(&e, 500);
+    });
 
-        let event_assert = EventAssertion::new(&e, address.clone());
-        event_assert.assert_event_count(2);
-        event_assert.assert_fungible_mint(&from, 100);
-        event_assert.assert_fungible_transfer(&from, &recipient, 50);
+    e.as_contract(&address, || {
+        approve(&e, &owner, &spender, 50, 601);
     });
 }
 
 #[test]
-fn transfer_zero_works() {
+fn approve_without_max_approval_duration_is_unlimited() {
     let e = Env::default();
     e.mock_all_auths();
     let address = e.register(MockContract, ());
-    let from = Address::generate(&e);
-    let recipient = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
 
     e.as_contract(&address, || {
-        transfer(&e, &from, &recipient, 0);
-        assert_eq!(balance(&e, &from), 0);
-        assert_eq!(balance(&e, &recipient), 0);
-
-        let events = e.events().all();
-        assert_eq!(events.len(), 1);
+        assert_eq!(max_approval_duration(&e), None);
+        let live_until_ledger = e.ledger().max_live_until_ledger();
+        approve(&e, &owner, &spender, 50, live_until_ledger);
+        assert_eq!(allowance(&e, &owner, &spender), 50);
     });
 }
 
 #[test]
-fn extend_balance_ttl_thru_transfer() {
+fn reconcile_supply_holds_after_updates() {
     let e = Env::default();
     e.mock_all_auths();
     let address = e.register(MockContract, ());
-    let from = Address::generate(&e);
-    let recipient = Address::generate(&e);
+    let account1 = Address::generate(&e);
+    let account2 = Address::generate(&e);
+    let accounts = vec![&e, account1.clone(), account2.clone()];
 
     e.as_contract(&address, || {
-        mint(&e, &from, 100);
+        assert!(reconcile_supply(&e, accounts.clone()));
 
-        let key = StorageKey::Balance(from.clone());
+        mint(&e, &account1, 100);
+        mint(&e, &account2, 50);
+        assert!(reconcile_supply(&e, accounts.clone()));
 
-        let ttl = e.storage().persistent().get_ttl(&key);
-        e.ledger().with_mut(|l| {
-            l.sequence_number += ttl;
-        });
-        transfer(&e, &from, &recipient, 50);
-        let ttl = e.storage().persistent().get_ttl(&key);
-        assert_eq!(ttl, BALANCE_EXTEND_AMOUNT);
+        update(&e, Some(&account1), Some(&account2), 30);
+        assert!(reconcile_supply(&e, accounts.clone()));
+
+        update(&e, Some(&account2), None, 60);
+        assert!(reconcile_supply(&e, accounts.clone()));
+        assert_eq!(total_supply(&e), 90);
     });
 }
 
 #[test]
-fn approve_and_transfer_from() {
+fn reconcile_supply_detects_missing_holders() {
     let e = Env::default();
-    e.mock_all_auths();
     let address = e.register(MockContract, ());
-    let owner = Address::generate(&e);
-    let spender = Address::generate(&e);
-    let recipient = Address::generate(&e);
+    let account1 = Address::generate(&e);
+    let account2 = Address::generate(&e);
 
     e.as_contract(&address, || {
-        mint(&e, &owner, 100);
-        approve(&e, &owner, &spender, 50, 1000);
-
-        let allowance_val = allowance(&e, &owner, &spender);
-        assert_eq!(allowance_val, 50);
+        mint(&e, &account1, 100);
+        mint(&e, &account2, 50);
 
-        transfer_from(&e, &spender, &owner, &recipient, 30);
-        assert_eq!(balance(&e, &owner), 70);
-        assert_eq!(balance(&e, &recipient), 30);
+        assert!(!reconcile_supply(&e, vec![&e, account1.clone()]));
+        // counting a holder twice doesn't reconcile either
+        assert!(!reconcile_supply(&e, vec![&e, account1.clone(), account1]));
+    });
+}
 
-        let updated_allowance = allowance(&e, &owner, &spender);
-        assert_eq!(updated_allowance, 20);
+#[contract]
+struct RelayedToken;
+
+#[contractimpl]
+impl RelayedToken {
+    #[allow(clippy::too_many_arguments)]
+    pub fn transfer_with_relayer_fee(
+        e: &Env,
+        from: Address,
+        to: Address,
+        amount: i128,
+        fee_token: Address,
+        fee_amount: i128,
+        relayer: Address,
+    ) {
+        transfer_with_relayer_fee(e, &from, &to, amount, &fee_token, fee_amount, &relayer);
+    }
+}
 
-        let event_assert = EventAssertion::new(&e, address.clone());
-        event_assert.assert_event_count(3);
-        event_assert.assert_fungible_mint(&owner, 100);
-        event_assert.assert_fungible_approve(&owner, &spender, 50, 1000);
-        event_assert.assert_fungible_transfer(&owner, &recipient, 30);
-    });
+fn create_fee_token(e: &Env, holder: &Address, amount: i128) -> Address {
+    let admin = Address::generate(e);
+    let fee_token = e.register_stellar_asset_contract_v2(admin).address();
+    token::StellarAssetClient::new(e, &fee_token).mint(holder, &amount);
+    fee_token
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #200)")]
-fn transfer_insufficient_balance_fails() {
+fn transfer_with_relayer_fee_works() {
     let e = Env::default();
     e.mock_all_auths();
-    let address = e.register(MockContract, ());
+    let address = e.register(RelayedToken, ());
+    let client = RelayedTokenClient::new(&e, &address);
     let from = Address::generate(&e);
-    let recipient = Address::generate(&e);
+    let to = Address::generate(&e);
+    let relayer = Address::generate(&e);
+    let fee_token = create_fee_token(&e, &from, 10);
+
+    e.as_contract(&address, || mint(&e, &from, 100));
+
+    client.transfer_with_relayer_fee(&from, &to, &60, &fee_token, &3, &relayer);
 
     e.as_contract(&address, || {
-        mint(&e, &from, 50);
-        transfer(&e, &from, &recipient, 100);
+        assert_eq!(balance(&e, &from), 40);
+        assert_eq!(balance(&e, &to), 60);
     });
+    let fee_client = token::Client::new(&e, &fee_token);
+    assert_eq!(fee_client.balance(&from), 7);
+    assert_eq!(fee_client.balance(&relayer), 3);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #201)")]
-fn transfer_from_insufficient_allowance_fails() {
+fn transfer_with_insufficient_relayer_fee_reverts() {
     let e = Env::default();
     e.mock_all_auths();
-    let address = e.register(MockContract, ());
-    let owner = Address::generate(&e);
-    let spender = Address::generate(&e);
-    let recipient = Address::generate(&e);
+    let address = e.register(RelayedToken, ());
+    let client = RelayedTokenClient::new(&e, &address);
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+    let relayer = Address::generate(&e);
+    let fee_token = create_fee_token(&e, &from, 2);
+
+    e.as_contract(&address, || mint(&e, &from, 100));
+
+    let result = client.try_transfer_with_relayer_fee(&from, &to, &60, &fee_token, &3, &relayer);
+    assert!(result.is_err());
 
+    // the transfer leg is reverted along with the fee leg
     e.as_contract(&address, || {
-        mint(&e, &owner, 100);
-        approve(&e, &owner, &spender, 30, 1000);
-        transfer_from(&e, &spender, &owner, &recipient, 50);
+        assert_eq!(balance(&e, &from), 100);
+        assert_eq!(balance(&e, &to), 0);
     });
+    let fee_client = token::Client::new(&e, &fee_token);
+    assert_eq!(fee_client.balance(&from), 2);
+    assert_eq!(fee_client.balance(&relayer), 0);
+}
+
+#[contract]
+struct ChainedToken;
+
+#[contractimpl]
+impl ChainedToken {
+    pub fn transfer_and_call(e: &Env, from: Address, to: Address, amount: i128, data: Bytes) {
+        transfer_and_call(e, &from, &to, amount, data);
+    }
+
+    pub fn transfer_then(e: &Env, from: Address, to: Address, amount: i128, data: Bytes) {
+        transfer_then(e, &from, &to, amount, data);
+    }
+}
+
+#[contract]
+struct Router;
+
+#[contractimpl]
+impl Router {
+    pub fn on_transfer(e: &Env, amount: i128) {
+        e.storage().instance().set(&symbol_short!("received"), &amount);
+    }
+}
+
+#[contract]
+struct RevertingReceiver;
+
+#[contractimpl]
+impl FungibleReceiver for RevertingReceiver {
+    fn on_token_received(
+        _e: Env,
+        _operator: Address,
+        _from: Address,
+        _amount: i128,
+        _data: Bytes,
+    ) -> bool {
+        panic!("rejected");
+    }
 }
 
 #[test]
-fn update_transfers_between_accounts() {
+fn transfer_then_invokes_recipient() {
     let e = Env::default();
-    let address = e.register(MockContract, ());
+    e.mock_all_auths();
+    let address = e.register(ChainedToken, ());
+    let client = ChainedTokenClient::new(&e, &address);
+    let receiver = e.register(TokenReceiver, ());
     let from = Address::generate(&e);
-    let to = Address::generate(&e);
+
+    e.as_contract(&address, || mint(&e, &from, 200));
+
+    // the value returned by the recipient is ignored
+    client.transfer_then(&from, &receiver, &150, &Bytes::new(&e));
 
     e.as_contract(&address, || {
-        mint(&e, &from, 100);
-        update(&e, Some(&from), Some(&to), 50);
         assert_eq!(balance(&e, &from), 50);
-        assert_eq!(balance(&e, &to), 50);
+        assert_eq!(balance(&e, &receiver), 150);
+    });
+    e.as_contract(&receiver, || {
+        assert_eq!(e.storage().instance().get(&symbol_short!("received")), Some(150i128));
     });
 }
 
 #[test]
-fn update_mints_tokens() {
+fn transfer_then_reverting_callback_rolls_back_transfer() {
     let e = Env::default();
-    let address = e.register(MockContract, ());
-    let to = Address::generate(&e);
+    e.mock_all_auths();
+    let address = e.register(ChainedToken, ());
+    let client = ChainedTokenClient::new(&e, &address);
+    let receiver = e.register(RevertingReceiver, ());
+    let from = Address::generate(&e);
+
+    e.as_contract(&address, || mint(&e, &from, 100));
+
+    let result = client.try_transfer_then(&from, &receiver, &60, &Bytes::new(&e));
+    assert!(result.is_err());
 
     e.as_contract(&address, || {
-        update(&e, None, Some(&to), 100);
-        assert_eq!(balance(&e, &to), 100);
-        assert_eq!(total_supply(&e), 100);
+        assert_eq!(balance(&e, &from), 100);
+        assert_eq!(balance(&e, &receiver), 0);
     });
 }
 
 #[test]
-fn update_burns_tokens() {
+fn transfer_then_to_non_receiver_fails() {
     let e = Env::default();
-    let address = e.register(MockContract, ());
+    e.mock_all_auths();
+    let address = e.register(ChainedToken, ());
+    let client = ChainedTokenClient::new(&e, &address);
+    let router = e.register(Router, ());
     let from = Address::generate(&e);
 
+    e.as_contract(&address, || mint(&e, &from, 100));
+
+    let result = client.try_transfer_then(&from, &router, &60, &Bytes::new(&e));
+    assert!(result.is_err());
+
     e.as_contract(&address, || {
-        mint(&e, &from, 100);
-        update(&e, Some(&from), None, 50);
-        assert_eq!(balance(&e, &from), 50);
-        assert_eq!(total_supply(&e), 50);
+        assert_eq!(balance(&e, &from), 100);
+        assert_eq!(balance(&e, &router), 0);
     });
 }
 
+#[contract]
+struct TokenReceiver;
+
+#[contractimpl]
+impl FungibleReceiver for TokenReceiver {
+    fn on_token_received(
+        e: Env,
+        _operator: Address,
+        _from: Address,
+        amount: i128,
+        _data: Bytes,
+    ) -> bool {
+        e.storage().instance().set(&symbol_short!("received"), &amount);
+        amount <= 100
+    }
+}
+
 #[test]
-#[should_panic(expected = "Error(Contract, #203)")]
-fn update_with_invalid_amount_panics() {
+fn transfer_and_call_notifies_receiver() {
     let e = Env::default();
-    let address = e.register(MockContract, ());
+    e.mock_all_auths();
+    let address = e.register(ChainedToken, ());
+    let client = ChainedTokenClient::new(&e, &address);
+    let receiver = e.register(TokenReceiver, ());
     let from = Address::generate(&e);
-    let to = Address::generate(&e);
+
+    e.as_contract(&address, || mint(&e, &from, 200));
+
+    client.transfer_and_call(&from, &receiver, &60, &Bytes::new(&e));
 
     e.as_contract(&address, || {
-        update(&e, Some(&from), Some(&to), -1);
+        assert_eq!(balance(&e, &from), 140);
+        assert_eq!(balance(&e, &receiver), 60);
+    });
+    e.as_contract(&receiver, || {
+        assert_eq!(e.storage().instance().get(&symbol_short!("received")), Some(60i128));
     });
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #204)")]
-fn update_overflow_panics() {
+fn transfer_and_call_rejected_rolls_back_transfer() {
     let e = Env::default();
-    let address = e.register(MockContract, ());
-    let account = Address::generate(&e);
+    e.mock_all_auths();
+    let address = e.register(ChainedToken, ());
+    let client = ChainedTokenClient::new(&e, &address);
+    let receiver = e.register(TokenReceiver, ());
+    let from = Address::generate(&e);
+
+    e.as_contract(&address, || mint(&e, &from, 200));
+
+    let result = client.try_transfer_and_call(&from, &receiver, &150, &Bytes::new(&e));
+    assert_eq!(result, Err(Ok(FungibleTokenError::TransferRejected.into())));
 
     e.as_contract(&address, || {
-        mint(&e, &account, i128::MAX);
-        update(&e, None, Some(&account), 1);
+        assert_eq!(balance(&e, &from), 200);
+        assert_eq!(balance(&e, &receiver), 0);
     });
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #200)")]
-fn update_with_insufficient_balance_panics() {
+fn transfer_and_call_to_non_receiver_fails() {
     let e = Env::default();
-    let address = e.register(MockContract, ());
+    e.mock_all_auths();
+    let address = e.register(ChainedToken, ());
+    let client = ChainedTokenClient::new(&e, &address);
+    let router = e.register(Router, ());
     let from = Address::generate(&e);
-    let to = Address::generate(&e);
 
-    e.as_contract(&address, || {
-        mint(&e, &from, 50);
-        update(&e, Some(&from), Some(&to), 100);
-    });
+    e.as_contract(&address, || mint(&e, &from, 200));
+
+    let result = client.try_transfer_and_call(&from, &router, &60, &Bytes::new(&e));
+    assert_eq!(result, Err(Ok(FungibleTokenError::TransferRejected.into())));
 }
 
 // Authorization Tests
@@ -630,3 +2295,259 @@ fn burn_from_requires_auth() {
     //     ))
     // );
 }
+
+/// Minimal xorshift generator, so that the random sequences of operations are
+/// reproducible from their seed.
+pub(crate) struct Prng(pub(crate) u64);
+
+impl Prng {
+    pub(crate) fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a number in `[0, bound)`.
+    pub(crate) fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+}
+
+#[test]
+fn random_operations_keep_balances_consistent_with_total_supply() {
+    const STEPS: u32 = 100;
+
+    for seed in [1, 42, 0xdead_beef] {
+        let e = Env::default();
+        let address = e.register(MockContract, ());
+        let accounts: std::vec::Vec<Address> = (0..4).map(|_| Address::generate(&e)).collect();
+        let mut prng = Prng(seed);
+
+        for step in 0..STEPS {
+            e.as_contract(&address, || {
+                let from = &accounts[prng.below(accounts.len() as u64) as usize];
+                let to = &accounts[prng.below(accounts.len() as u64) as usize];
+                let from_balance = balance(&e, from) as u64;
+
+                // amounts never exceed the balance, so that every operation succeeds
+                match prng.below(3) {
+                    0 => update(&e, None, Some(to), prng.below(1_000) as i128),
+                    1 => update(&e, Some(from), Some(to), prng.below(from_balance + 1) as i128),
+                    _ => update(&e, Some(from), None, prng.below(from_balance + 1) as i128),
+                }
+
+                let sum: i128 = accounts.iter().map(|account| balance(&e, account)).sum();
+                assert_eq!(sum, total_supply(&e), "seed {seed}, step {step}");
+            });
+        }
+    }
+}
+
+#[cfg(feature = "pausable")]
+#[test]
+fn pausable_base_works_while_unpaused() {
+    use crate::{ContractOverrides, PausableBase};
+
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        PausableBase::mint(&e, &account, 100);
+        PausableBase::transfer(&e, &account, &recipient, 40);
+        assert_eq!(balance(&e, &recipient), 40);
+    });
+
+    e.as_contract(&address, || {
+        PausableBase::burn(&e, &account, 10);
+        assert_eq!(balance(&e, &account), 50);
+        assert_eq!(total_supply(&e), 90);
+    });
+}
+
+#[cfg(feature = "pausable")]
+#[test]
+#[should_panic(expected = "Error(Contract, #100)")]
+fn pausable_base_transfer_while_paused_fails() {
+    use crate::{ContractOverrides, PausableBase};
+
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 100);
+        stellar_pausable::pause(&e, &account);
+    });
+
+    e.as_contract(&address, || {
+        PausableBase::transfer(&e, &account, &recipient, 40);
+    });
+}
+
+#[cfg(feature = "pausable")]
+#[test]
+#[should_panic(expected = "Error(Contract, #100)")]
+fn pausable_base_mint_while_paused_fails() {
+    use crate::{ContractOverrides, PausableBase};
+
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        stellar_pausable::pause(&e, &account);
+        PausableBase::mint(&e, &account, 100);
+    });
+}
+
+#[cfg(feature = "pausable")]
+mod paused {
+    use ed25519_dalek::{Signer, SigningKey};
+    use soroban_sdk::{
+        contract, contractimpl,
+        testutils::Address as _,
+        vec,
+        xdr::{AccountId, PublicKey, ScAddress, Uint256},
+        Address, Bytes, BytesN, Env, TryFromVal, Vec,
+    };
+
+    use crate::{
+        burnable, clawback, flash_mint, meta_transfer, mintable, rebase, storage, wrapper,
+    };
+
+    #[contract]
+    struct PausedToken;
+
+    #[contractimpl]
+    impl PausedToken {
+        pub fn transfer(e: &Env, from: Address, to: Address, amount: i128) {
+            storage::transfer(e, &from, &to, amount);
+        }
+
+        pub fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, amount: i128) {
+            storage::transfer_from(e, &spender, &from, &to, amount);
+        }
+
+        pub fn transfer_batch(
+            e: &Env,
+            from: Address,
+            recipients: Vec<Address>,
+            amounts: Vec<i128>,
+        ) {
+            storage::transfer_batch(e, &from, recipients, amounts);
+        }
+
+        pub fn transfer_then(e: &Env, from: Address, to: Address, amount: i128) {
+            storage::transfer_then(e, &from, &to, amount, Bytes::new(e));
+        }
+
+        pub fn transfer_and_call(e: &Env, from: Address, to: Address, amount: i128) {
+            storage::transfer_and_call(e, &from, &to, amount, Bytes::new(e));
+        }
+
+        pub fn transfer_with_commitment(e: &Env, from: Address, to: Address, amount: i128) {
+            storage::transfer_with_commitment(
+                e,
+                &from,
+                &to,
+                amount,
+                &BytesN::from_array(e, &[0; 32]),
+            );
+        }
+
+        pub fn transfer_with_relayer_fee(e: &Env, from: Address, to: Address, amount: i128) {
+            let relayer = to.clone();
+            storage::transfer_with_relayer_fee(e, &from, &to, amount, &to, 0, &relayer);
+        }
+
+        pub fn meta_transfer(
+            e: &Env,
+            from: Address,
+            to: Address,
+            amount: i128,
+            signature: BytesN<64>,
+        ) {
+            meta_transfer::meta_transfer(e, &from, &to, amount, 0, 100, &signature);
+        }
+
+        pub fn mint(e: &Env, to: Address, amount: i128) {
+            mintable::mint(e, &to, amount);
+        }
+
+        pub fn burn(e: &Env, from: Address, amount: i128) {
+            burnable::burn(e, &from, amount);
+        }
+
+        pub fn burn_from(e: &Env, spender: Address, from: Address, amount: i128) {
+            burnable::burn_from(e, &spender, &from, amount);
+        }
+
+        pub fn flash_loan(e: &Env, receiver: Address, amount: i128) {
+            flash_mint::flash_loan(e, &receiver, amount, Bytes::new(e));
+        }
+
+        pub fn withdraw(e: &Env, to: Address, amount: i128) {
+            wrapper::withdraw(e, &to, amount);
+        }
+
+        pub fn rebase_transfer(e: &Env, from: Address, to: Address, amount: i128) {
+            rebase::transfer(e, &from, &to, amount);
+        }
+
+        pub fn clawback(e: &Env, from: Address, amount: i128) {
+            clawback::clawback(e, &from, amount);
+        }
+    }
+
+    #[test]
+    fn every_entry_point_while_paused_fails() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let address = e.register(PausedToken, ());
+        let client = PausedTokenClient::new(&e, &address);
+
+        let signer = SigningKey::from_bytes(&[7u8; 32]);
+        let public_key =
+            PublicKey::PublicKeyTypeEd25519(Uint256(signer.verifying_key().to_bytes()));
+        let from = Address::try_from_val(&e, &ScAddress::Account(AccountId(public_key))).unwrap();
+        let to = Address::generate(&e);
+
+        e.as_contract(&address, || {
+            mintable::mint(&e, &from, 100);
+            storage::approve(&e, &from, &to, 100, 1000);
+            clawback::enable_clawback(&e, &from);
+            wrapper::set_underlying(&e, &Address::generate(&e));
+        });
+        e.as_contract(&address, || stellar_pausable::pause(&e, &from));
+
+        let signature = e.as_contract(&address, || {
+            let payload = meta_transfer::meta_transfer_payload(&e, &from, &to, 10, 0, 100);
+            let payload: super::std::vec::Vec<u8> = payload.iter().collect();
+            BytesN::from_array(&e, &signer.sign(&payload).to_bytes())
+        });
+
+        let paused = Err(Ok(stellar_pausable::PausableError::EnforcedPause.into()));
+        assert_eq!(client.try_transfer(&from, &to, &10), paused);
+        assert_eq!(client.try_transfer_from(&to, &from, &to, &10), paused);
+        assert_eq!(client.try_transfer_batch(&from, &vec![&e, to.clone()], &vec![&e, 10]), paused);
+        assert_eq!(client.try_transfer_then(&from, &to, &10), paused);
+        assert_eq!(client.try_transfer_and_call(&from, &to, &10), paused);
+        assert_eq!(client.try_transfer_with_commitment(&from, &to, &10), paused);
+        assert_eq!(client.try_transfer_with_relayer_fee(&from, &to, &10), paused);
+        assert_eq!(client.try_meta_transfer(&from, &to, &10, &signature), paused);
+        assert_eq!(client.try_mint(&to, &10), paused);
+        assert_eq!(client.try_burn(&from, &10), paused);
+        assert_eq!(client.try_burn_from(&to, &from, &10), paused);
+        assert_eq!(client.try_flash_loan(&to, &10), paused);
+        assert_eq!(client.try_withdraw(&from, &10), paused);
+        assert_eq!(client.try_rebase_transfer(&from, &to, &10), paused);
+        assert_eq!(client.try_clawback(&from, &10), paused);
+    }
+}
@@ -1,10 +1,33 @@
 mod storage;
-use crate::{Base, NonFungibleToken, TokenId};
+pub use storage::{DepositData, StorageKey};
+
+use crate::{Base, ContractOverrides, NonFungibleToken, TokenId};
 
 mod test;
 
 use soroban_sdk::{symbol_short, Address, Env};
 
+/// Trait for the contract types keeping the ownership of tokens the same way
+/// as [`Base`], which makes them compatible with [`NonFungibleBurnable`].
+///
+/// Similarly to [`ContractOverrides`], the contract types of the extensions
+/// that need to act on burns (e.g. [`crate::lockable::Lockable`]) override
+/// `burn` and `burn_from`, so that [`Base`] doesn't pay for them.
+pub trait BurnableContractType: ContractOverrides {
+    fn burn(e: &Env, from: &Address, token_id: TokenId) {
+        Base::burn(e, from, token_id);
+    }
+
+    fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: TokenId) {
+        Base::burn_from(e, spender, from, token_id);
+    }
+}
+
+impl BurnableContractType for Base {}
+
+#[cfg(feature = "pausable")]
+impl BurnableContractType for crate::PausableBase {}
+
 /// Burnable Trait for Non-Fungible Token
 ///
 /// The `NonFungibleBurnable` trait extends the `NonFungibleToken` trait to
@@ -34,7 +57,7 @@ use soroban_sdk::{symbol_short, Address, Env};
 ///     /* and the macro will generate all the missing default implementations for you */
 /// }
 /// ```
-pub trait NonFungibleBurnable: NonFungibleToken<ContractType = Base> {
+pub trait NonFungibleBurnable: NonFungibleToken<ContractType: BurnableContractType> {
     /// Destroys the `token_id` from `account`.
     ///
     /// # Arguments
@@ -1,6 +1,23 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{contracttype, panic_with_error, token, Address, Env};
+use stellar_constants::{TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD};
 
-use crate::{extensions::burnable::emit_burn, Base, TokenId};
+use crate::{extensions::burnable::emit_burn, Base, NonFungibleTokenError, TokenId};
+
+/// Storage container for the deposit locked when minting a token.
+#[contracttype]
+pub struct DepositData {
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Storage keys for the data associated with the deposits and the burn quota
+/// of the `Burnable` extension.
+#[contracttype]
+pub enum StorageKey {
+    Deposit(TokenId),
+    BurnQuota,
+    LedgerBurns(u32),
+}
 
 // `Burnable` extension is build for the `Base` contract type.
 impl Base {
@@ -24,7 +41,10 @@ impl Base {
     ///
     /// # Notes
     ///
-    /// Authorization for `from` is required.
+    /// * Authorization for `from` is required.
+    /// * The burn quota, the deposits and the per-token data of the other
+    ///   extensions (e.g. URIs or royalties) are not handled here, see
+    ///   [`Base::consume_burn_quota`] and [`Base::check_no_deposit`].
     pub fn burn(e: &Env, from: &Address, token_id: TokenId) {
         from.require_auth();
         Base::update(e, Some(from), None, token_id);
@@ -54,11 +74,218 @@ impl Base {
     ///
     /// # Notes
     ///
-    /// Authorization for `spender` is required.
+    /// * Authorization for `spender` is required.
+    /// * Refer to the notes of [`Base::burn`].
     pub fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: TokenId) {
         spender.require_auth();
         Base::check_spender_approval(e, spender, from, token_id);
         Base::update(e, Some(from), None, token_id);
         emit_burn(e, from, token_id);
     }
+
+    /// Returns the deposit locked for `token_id` when it was minted, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn deposit(e: &Env, token_id: TokenId) -> Option<DepositData> {
+        let key = StorageKey::Deposit(token_id);
+        e.storage().persistent().get::<_, DepositData>(&key).inspect(|_| {
+            e.storage().persistent().extend_ttl(&key, TOKEN_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT);
+        })
+    }
+
+    /// Panics if `token_id` holds a deposit, so that it isn't burned without
+    /// settling the deposit, which would leave it recorded for a token that
+    /// no longer exists. Meant to be called by the `burn` and `burn_from` of
+    /// the contracts minting with [`Base::mint_with_deposit`].
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::DepositNotRefunded`] - When `token_id` holds
+    ///   a deposit.
+    pub fn check_no_deposit(e: &Env, token_id: TokenId) {
+        if e.storage().persistent().has(&StorageKey::Deposit(token_id)) {
+            panic_with_error!(e, NonFungibleTokenError::DepositNotRefunded);
+        }
+    }
+
+    /// Creates a token with the provided `token_id`, assigns it to `to`, and
+    /// collects a deposit of `deposit_amount` units of `deposit_token` from
+    /// `to`. The deposit is held by the contract until the token is burned
+    /// with [`Base::burn_with_refund`]. [`Base::burn`] and [`Base::burn_from`]
+    /// are not aware of deposits, so the contract is expected to guard them
+    /// with [`Base::check_no_deposit`].
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the new token and paying the deposit.
+    /// * `token_id` - The token_id of the new token.
+    /// * `deposit_token` - The address of the token contract used for the
+    ///   deposit.
+    /// * `deposit_amount` - The amount of `deposit_token` to collect.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// Similarly to [`Base::mint`], it is the responsibility of the implementer
+    /// to establish appropriate access controls and to ensure the uniqueness
+    /// of `token_id`. Authorization for `to` is required by `deposit_token`
+    /// to transfer the deposit.
+    pub fn mint_with_deposit(
+        e: &Env,
+        to: &Address,
+        token_id: TokenId,
+        deposit_token: &Address,
+        deposit_amount: i128,
+    ) {
+        token::Client::new(e, deposit_token).transfer(
+            to,
+            &e.current_contract_address(),
+            &deposit_amount,
+        );
+
+        Base::mint(e, to, token_id);
+
+        let deposit = DepositData { token: deposit_token.clone(), amount: deposit_amount };
+        e.storage().persistent().set(&StorageKey::Deposit(token_id), &deposit);
+    }
+
+    /// Destroys the `token_id` from `from`, ensuring ownership checks, and
+    /// refunds `refund_amount` of `refund_token` out of the deposit locked
+    /// when minting the token.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The account whose token is destroyed.
+    /// * `token_id` - The token to burn.
+    /// * `refund_token` - The address of the token contract of the deposit.
+    /// * `refund_amount` - The amount of `refund_token` refunded to `from`.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::RefundExceedsDeposit`] - When `refund_amount`
+    ///   is negative or greater than the deposit recorded in `refund_token` for
+    ///   `token_id`.
+    /// * refer to [`update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `from` is required.
+    /// * The deposit follows the token, so the refund goes to the owner at the
+    ///   time of burning.
+    /// * The deposit record is removed, so any part of it that is not refunded
+    ///   stays with the contract.
+    /// * Refer to the notes of [`Base::burn`].
+    pub fn burn_with_refund(
+        e: &Env,
+        from: &Address,
+        token_id: TokenId,
+        refund_token: &Address,
+        refund_amount: i128,
+    ) {
+        from.require_auth();
+
+        let refundable = match Base::deposit(e, token_id) {
+            Some(deposit) if deposit.token == *refund_token => deposit.amount,
+            _ => 0,
+        };
+        if refund_amount < 0 || refund_amount > refundable {
+            panic_with_error!(e, NonFungibleTokenError::RefundExceedsDeposit);
+        }
+
+        Base::update(e, Some(from), None, token_id);
+        e.storage().persistent().remove(&StorageKey::Deposit(token_id));
+        emit_burn(e, from, token_id);
+
+        if refund_amount > 0 {
+            token::Client::new(e, refund_token).transfer(
+                &e.current_contract_address(),
+                from,
+                &refund_amount,
+            );
+        }
+    }
+
+    /// Returns the maximum number of tokens that can be burned within a
+    /// single ledger, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn burn_quota(e: &Env) -> Option<u32> {
+        e.storage().instance().get(&StorageKey::BurnQuota)
+    }
+
+    /// Sets the maximum number of tokens that can be burned within a single
+    /// ledger, limiting the damage of mass burns during an exploit. The quota
+    /// is enforced by [`Base::consume_burn_quota`], which the burn entry
+    /// points of the contract are expected to call.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `max_per_ledger` - The maximum number of tokens burned per ledger.
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can execute
+    /// this function.
+    pub fn set_burn_quota(e: &Env, max_per_ledger: u32) {
+        e.storage().instance().set(&StorageKey::BurnQuota, &max_per_ledger);
+    }
+
+    /// Counts a burn against the quota of the current ledger (see
+    /// [`Base::set_burn_quota`]). Does nothing if no quota is set. Meant to be
+    /// called by every burn entry point of the contract, similarly to
+    /// [`crate::capped::check_cap`] for mints.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::BurnQuotaExceeded`] - When the burn quota of
+    ///   the current ledger is exhausted.
+    pub fn consume_burn_quota(e: &Env) {
+        let Some(quota) = Base::burn_quota(e) else {
+            return;
+        };
+
+        // NOTE: the count of a ledger is irrelevant in the following ones, so
+        // it is kept in temporary storage and left to expire.
+        let key = StorageKey::LedgerBurns(e.ledger().sequence());
+        let burns: u32 = e.storage().temporary().get(&key).unwrap_or(0);
+        if burns >= quota {
+            panic_with_error!(e, NonFungibleTokenError::BurnQuotaExceeded);
+        }
+        e.storage().temporary().set(&key, &(burns + 1));
+    }
 }
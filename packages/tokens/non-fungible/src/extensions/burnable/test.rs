@@ -2,7 +2,11 @@
 
 extern crate std;
 
-use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger as _},
+    token, Address, Env,
+};
 use stellar_event_assertion::EventAssertion;
 
 use crate::Base;
@@ -148,3 +152,238 @@ fn burn_with_non_existent_token_panics() {
         Base::burn(&e, &owner, non_existent_token_id);
     });
 }
+
+fn create_deposit_token(e: &Env, holder: &Address, amount: i128) -> Address {
+    let admin = Address::generate(e);
+    let deposit_token = e.register_stellar_asset_contract_v2(admin).address();
+    token::StellarAssetClient::new(e, &deposit_token).mint(holder, &amount);
+    deposit_token
+}
+
+#[test]
+fn mint_with_deposit_works() {
+    let e = Env::default();
+    e.mock_all_auths_allowing_non_root_auth();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let deposit_token = create_deposit_token(&e, &owner, 1000);
+
+    e.as_contract(&address, || {
+        Base::mint_with_deposit(&e, &owner, 1, &deposit_token, 100);
+
+        assert_eq!(Base::owner_of(&e, 1), owner);
+        let deposit = Base::deposit(&e, 1).unwrap();
+        assert_eq!(deposit.token, deposit_token);
+        assert_eq!(deposit.amount, 100);
+    });
+
+    let deposit_client = token::Client::new(&e, &deposit_token);
+    assert_eq!(deposit_client.balance(&owner), 900);
+    assert_eq!(deposit_client.balance(&address), 100);
+}
+
+#[test]
+fn burn_with_refund_works() {
+    let e = Env::default();
+    e.mock_all_auths_allowing_non_root_auth();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let deposit_token = create_deposit_token(&e, &owner, 1000);
+
+    e.as_contract(&address, || {
+        Base::mint_with_deposit(&e, &owner, 1, &deposit_token, 100);
+    });
+
+    e.as_contract(&address, || {
+        Base::burn_with_refund(&e, &owner, 1, &deposit_token, 100);
+
+        assert_eq!(Base::balance(&e, &owner), 0);
+        assert!(Base::deposit(&e, 1).is_none());
+    });
+
+    let deposit_client = token::Client::new(&e, &deposit_token);
+    assert_eq!(deposit_client.balance(&owner), 1000);
+    assert_eq!(deposit_client.balance(&address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #316)")]
+fn burn_with_refund_exceeding_deposit_fails() {
+    let e = Env::default();
+    e.mock_all_auths_allowing_non_root_auth();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let deposit_token = create_deposit_token(&e, &owner, 1000);
+
+    e.as_contract(&address, || {
+        Base::mint_with_deposit(&e, &owner, 1, &deposit_token, 100);
+    });
+
+    e.as_contract(&address, || {
+        Base::burn_with_refund(&e, &owner, 1, &deposit_token, 101);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #316)")]
+fn burn_with_refund_without_deposit_fails() {
+    let e = Env::default();
+    e.mock_all_auths_allowing_non_root_auth();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let deposit_token = create_deposit_token(&e, &owner, 1000);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::burn_with_refund(&e, &owner, token_id, &deposit_token, 1);
+    });
+}
+
+#[test]
+fn total_minted_is_unaffected_by_burns() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        assert_eq!(Base::total_minted(&e), 0);
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::sequential_mint(&e, &owner);
+        assert_eq!(Base::total_minted(&e), 2);
+
+        Base::burn(&e, &owner, token_id);
+        assert_eq!(Base::total_minted(&e), 2);
+    });
+}
+
+#[cfg(feature = "countable_supply")]
+#[test]
+fn total_supply_decreases_on_burn() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::mint(&e, &owner, 42);
+        assert_eq!(Base::total_supply(&e), 2);
+
+        Base::burn(&e, &owner, token_id);
+        assert_eq!(Base::total_supply(&e), 1);
+        assert_eq!(Base::total_minted(&e), 1);
+    });
+}
+
+#[test]
+fn burn_up_to_quota_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        assert_eq!(Base::burn_quota(&e), None);
+        Base::set_burn_quota(&e, 2);
+        assert_eq!(Base::burn_quota(&e), Some(2));
+
+        let alice_token = Base::sequential_mint(&e, &alice);
+        let bob_token = Base::sequential_mint(&e, &bob);
+
+        Base::consume_burn_quota(&e);
+        Base::burn(&e, &alice, alice_token);
+        Base::consume_burn_quota(&e);
+        Base::burn(&e, &bob, bob_token);
+
+        assert_eq!(Base::balance(&e, &alice), 0);
+        assert_eq!(Base::balance(&e, &bob), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #327)")]
+fn burn_exceeding_quota_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_burn_quota(&e, 1);
+
+        let alice_token = Base::sequential_mint(&e, &alice);
+        let bob_token = Base::sequential_mint(&e, &bob);
+
+        Base::consume_burn_quota(&e);
+        Base::burn(&e, &alice, alice_token);
+        Base::consume_burn_quota(&e);
+        Base::burn(&e, &bob, bob_token);
+    });
+}
+
+#[test]
+fn burn_quota_resets_in_next_ledger() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_burn_quota(&e, 1);
+        Base::sequential_mint(&e, &owner);
+        Base::sequential_mint(&e, &owner);
+        Base::consume_burn_quota(&e);
+        Base::burn(&e, &owner, 0);
+    });
+
+    e.ledger().with_mut(|l| l.sequence_number += 1);
+
+    e.as_contract(&address, || {
+        Base::consume_burn_quota(&e);
+        Base::burn(&e, &owner, 1);
+        assert_eq!(Base::balance(&e, &owner), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #340)")]
+fn burn_of_token_with_deposit_fails() {
+    let e = Env::default();
+    e.mock_all_auths_allowing_non_root_auth();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let deposit_token = create_deposit_token(&e, &owner, 1000);
+
+    e.as_contract(&address, || {
+        Base::mint_with_deposit(&e, &owner, 1, &deposit_token, 100);
+    });
+
+    e.as_contract(&address, || {
+        Base::check_no_deposit(&e, 1);
+        Base::burn(&e, &owner, 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #340)")]
+fn burn_from_of_token_with_deposit_fails() {
+    let e = Env::default();
+    e.mock_all_auths_allowing_non_root_auth();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let deposit_token = create_deposit_token(&e, &owner, 1000);
+
+    e.as_contract(&address, || {
+        Base::mint_with_deposit(&e, &owner, 1, &deposit_token, 100);
+        Base::approve(&e, &owner, &spender, 1, 1000);
+    });
+
+    e.as_contract(&address, || {
+        Base::check_no_deposit(&e, 1);
+        Base::burn_from(&e, &spender, &owner, 1);
+    });
+}
@@ -0,0 +1,21 @@
+//! # Capped Extension for Non-Fungible Token
+//!
+//! Sets a ceiling on the number of tokens in circulation. Like its fungible
+//! counterpart, the extension doesn't provide a separate trait: it is meant to
+//! be used inside the `mint` function of the contract, by calling
+//! [`check_cap`] before `Base::sequential_mint` (or any other minting
+//! function).
+//!
+//! The supply is read from [`crate::enumerable::Enumerable::total_supply`]
+//! when the `Enumerable` extension is used. Otherwise, it is the number of
+//! token IDs issued by the sequential counter, which also accounts for the
+//! burned tokens: burning doesn't free room under the cap.
+//!
+//! This module provides the following helper functions:
+//! - `set_cap`: Sets the maximum token supply.
+//! - `query_cap`: Returns the maximum token supply.
+//! - `check_cap`: Panics if minting `amount` tokens would exceed the cap.
+mod storage;
+pub use self::storage::{check_cap, query_cap, set_cap, CAP_KEY};
+
+mod test;
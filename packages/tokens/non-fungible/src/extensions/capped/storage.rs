@@ -0,0 +1,68 @@
+use soroban_sdk::{panic_with_error, symbol_short, Env, Symbol};
+
+use crate::{enumerable, sequential, Balance, NonFungibleTokenError, TokenId};
+
+/// Storage key
+pub const CAP_KEY: Symbol = symbol_short!("CAP");
+
+/// Sets the maximum supply of tokens.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `cap` - The maximum supply of tokens.
+///
+/// # Notes
+///
+/// * We recommend using this function in the constructor of your smart
+///   contract.
+pub fn set_cap(e: &Env, cap: TokenId) {
+    e.storage().instance().set(&CAP_KEY, &cap);
+}
+
+/// Returns the maximum supply of tokens.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * [`NonFungibleTokenError::CapNotSet`] - Occurs when the cap has not been
+///   set.
+pub fn query_cap(e: &Env) -> TokenId {
+    e.storage()
+        .instance()
+        .get(&CAP_KEY)
+        .unwrap_or_else(|| panic_with_error!(e, NonFungibleTokenError::CapNotSet))
+}
+
+/// Panics if minting `amount` new tokens would exceed the maximum supply.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `amount` - The number of tokens to be minted.
+///
+/// # Errors
+///
+/// * [`NonFungibleTokenError::CapNotSet`] - Occurs when the cap has not been
+///   set.
+/// * [`NonFungibleTokenError::ExceededCap`] - Occurs when the new tokens would
+///   exceed the cap.
+///
+/// # Notes
+///
+/// Must be called inside the `mint` function of the contract, before
+/// `Base::sequential_mint` (or any other minting function).
+pub fn check_cap(e: &Env, amount: Balance) {
+    let cap = query_cap(e);
+    let supply: Balance = e
+        .storage()
+        .instance()
+        .get(&enumerable::storage::StorageKey::TotalSupply)
+        .unwrap_or_else(|| sequential::next_token_id(e) - sequential::token_id_start(e));
+    if supply.checked_add(amount).is_none_or(|new_supply| new_supply > cap) {
+        panic_with_error!(e, NonFungibleTokenError::ExceededCap);
+    }
+}
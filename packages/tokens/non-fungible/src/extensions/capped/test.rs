@@ -0,0 +1,125 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+
+use crate::{
+    capped::{check_cap, query_cap, set_cap},
+    enumerable::Enumerable,
+    Base,
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn test_mint_under_cap() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_cap(&e, 10);
+
+        check_cap(&e, 1);
+        Base::sequential_mint(&e, &user);
+
+        assert_eq!(Base::balance(&e, &user), 1);
+    });
+}
+
+#[test]
+fn test_mint_exact_cap() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_cap(&e, 2);
+
+        check_cap(&e, 1);
+        Base::sequential_mint(&e, &user);
+        check_cap(&e, 1);
+        Base::sequential_mint(&e, &user);
+
+        assert_eq!(Base::balance(&e, &user), 2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #325)")]
+fn test_mint_exceeds_cap() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_cap(&e, 2);
+
+        check_cap(&e, 2);
+        Base::sequential_mint_batch(&e, &user, 2);
+
+        check_cap(&e, 1); // This should panic
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #325)")]
+fn test_burn_doesnt_free_cap_without_enumerable() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_cap(&e, 1);
+
+        check_cap(&e, 1);
+        let token_id = Base::sequential_mint(&e, &user);
+        Base::burn(&e, &user, token_id);
+
+        check_cap(&e, 1); // This should panic
+    });
+}
+
+#[test]
+fn test_enumerable_total_supply_is_used() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&contract_address, || {
+        set_cap(&e, 1);
+
+        check_cap(&e, 1);
+        let token_id = Enumerable::sequential_mint(&e, &user);
+        Enumerable::sequential_burn(&e, &user, token_id);
+
+        // the burned token no longer counts towards the cap
+        check_cap(&e, 1);
+    });
+}
+
+#[test]
+fn test_query_cap() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+
+    e.as_contract(&contract_address, || {
+        set_cap(&e, 1000);
+        assert_eq!(query_cap(&e), 1000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #326)")]
+fn test_query_cap_not_set() {
+    let e = Env::default();
+    let contract_address = e.register(MockContract, ());
+
+    e.as_contract(&contract_address, || {
+        query_cap(&e);
+    });
+}
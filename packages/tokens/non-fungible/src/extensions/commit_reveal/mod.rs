@@ -0,0 +1,46 @@
+//! # Commit-Reveal Minting Extension for Non-Fungible Token
+//!
+//! Fair drops are exposed to mint sniping: as soon as a mint transaction is
+//! visible, anybody can front-run it. With a commit-reveal mint, the minter
+//! first submits an opaque commitment with [`crate::Base::commit_mint`], and
+//! only reveals its intent with [`crate::Base::reveal_mint`] once a minimum
+//! number of ledgers has passed.
+//!
+//! The commitment is the SHA-256 hash returned by
+//! [`crate::Base::mint_commitment`], which binds the contract address, the
+//! recipient and a secret nonce. Front-running the reveal is pointless, as the
+//! token can only be minted to the committed recipient.
+//!
+//! ## Usage
+//!
+//! The minimum delay between a commitment and its reveal should be configured
+//! with [`crate::Base::set_reveal_delay`], ideally in the constructor. It
+//! defaults to `0`, i.e. a commitment can be revealed in the same ledger.
+//!
+//! Neither [`crate::Base::commit_mint`] nor [`crate::Base::reveal_mint`]
+//! handle authorization, so the implementor is expected to gate them as
+//! required by the drop (e.g. charging a price on commit, or limiting the
+//! commitments per account).
+mod storage;
+use soroban_sdk::{BytesN, Env, Symbol};
+pub use storage::StorageKey;
+
+mod test;
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating a mint commitment has been recorded.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `commitment` - The hash committing to the mint.
+///
+/// # Events
+///
+/// * topics - `["mint_commit", commitment: BytesN<32>]`
+/// * data - `[]`
+pub fn emit_mint_commit(e: &Env, commitment: &BytesN<32>) {
+    let topics = (Symbol::new(e, "mint_commit"), commitment);
+    e.events().publish(topics, ())
+}
@@ -0,0 +1,149 @@
+use soroban_sdk::{contracttype, panic_with_error, xdr::ToXdr, Address, BytesN, Env};
+use stellar_constants::{TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD};
+
+use crate::{commit_reveal::emit_mint_commit, Base, NonFungibleTokenError, TokenId};
+
+/// Storage keys for the data associated with the commit-reveal extension.
+#[contracttype]
+pub enum StorageKey {
+    RevealDelay,
+    Commitment(BytesN<32>),
+}
+
+impl Base {
+    // ################## QUERY STATE ##################
+
+    /// Returns the minimum number of ledgers between a mint commitment and
+    /// its reveal. Defaults to `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn reveal_delay(e: &Env) -> u32 {
+        e.storage().instance().get(&StorageKey::RevealDelay).unwrap_or(0)
+    }
+
+    /// Returns the commitment to mint a token to `to`, i.e. the SHA-256 hash
+    /// of the XDR encoding of the tuple `(contract_address, to, nonce)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the token.
+    /// * `nonce` - A secret chosen by the minter.
+    pub fn mint_commitment(e: &Env, to: &Address, nonce: &BytesN<32>) -> BytesN<32> {
+        let payload = (e.current_contract_address(), to.clone(), nonce.clone()).to_xdr(e);
+        e.crypto().sha256(&payload).into()
+    }
+
+    /// Returns the ledger at which `commitment` was recorded, or `None` if it
+    /// doesn't exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `commitment` - The hash committing to the mint.
+    pub fn committed_at(e: &Env, commitment: &BytesN<32>) -> Option<u32> {
+        let key = StorageKey::Commitment(commitment.clone());
+        e.storage().persistent().get::<_, u32>(&key).inspect(|_| {
+            e.storage().persistent().extend_ttl(&key, TOKEN_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT);
+        })
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Sets the minimum number of ledgers between a mint commitment and its
+    /// reveal.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `ledgers` - The minimum delay, in ledgers.
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used internally or in trusted contexts. We recommend calling it
+    /// in the constructor of your smart contract.
+    pub fn set_reveal_delay(e: &Env, ledgers: u32) {
+        e.storage().instance().set(&StorageKey::RevealDelay, &ledgers);
+    }
+
+    /// Records `commitment`, to be revealed with [`Base::reveal_mint`] once
+    /// the reveal delay has passed.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `commitment` - The hash committing to the mint, as returned by
+    ///   [`Base::mint_commitment`].
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::CommitmentAlreadyExists`] - If `commitment`
+    ///   has already been recorded.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint_commit", commitment: BytesN<32>]`
+    /// * data - `[]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can commit to
+    /// minting operations.
+    pub fn commit_mint(e: &Env, commitment: &BytesN<32>) {
+        let key = StorageKey::Commitment(commitment.clone());
+        // NOTE: an existing commitment is never overwritten, otherwise anyone
+        // could postpone its reveal by committing it again.
+        if e.storage().persistent().has(&key) {
+            panic_with_error!(e, NonFungibleTokenError::CommitmentAlreadyExists);
+        }
+
+        e.storage().persistent().set(&key, &e.ledger().sequence());
+        emit_mint_commit(e, commitment);
+    }
+
+    /// Mints the next token to `to`, provided the matching commitment was
+    /// recorded at least [`Base::reveal_delay`] ledgers ago. Returns the
+    /// identifier of the minted token.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the token.
+    /// * `nonce` - The secret nonce used to compute the commitment.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::CommitmentNotFound`] - If no commitment
+    ///   matches `to` and `nonce`.
+    /// * [`NonFungibleTokenError::RevealTooEarly`] - If the reveal delay has
+    ///   not passed yet.
+    /// * refer to [`Base::sequential_mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// The commitment is consumed, so it can't be revealed twice.
+    pub fn reveal_mint(e: &Env, to: &Address, nonce: &BytesN<32>) -> TokenId {
+        let commitment = Base::mint_commitment(e, to, nonce);
+        let Some(committed_at) = Base::committed_at(e, &commitment) else {
+            panic_with_error!(e, NonFungibleTokenError::CommitmentNotFound);
+        };
+
+        if e.ledger().sequence() < committed_at.saturating_add(Base::reveal_delay(e)) {
+            panic_with_error!(e, NonFungibleTokenError::RevealTooEarly);
+        }
+
+        e.storage().persistent().remove(&StorageKey::Commitment(commitment));
+        Base::sequential_mint(e, to)
+    }
+}
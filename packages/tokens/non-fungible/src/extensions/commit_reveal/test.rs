@@ -0,0 +1,141 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger as _},
+    Address, BytesN, Env,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::Base;
+
+#[contract]
+struct MockContract;
+
+fn setup(e: &Env, delay: u32) -> Address {
+    let address = e.register(MockContract, ());
+    e.as_contract(&address, || {
+        Base::set_reveal_delay(e, delay);
+    });
+    address
+}
+
+#[test]
+fn commit_and_reveal_works() {
+    let e = Env::default();
+    let address = setup(&e, 10);
+    let to = Address::generate(&e);
+    let nonce = BytesN::from_array(&e, &[1u8; 32]);
+
+    e.ledger().set_sequence_number(100);
+    let commitment = e.as_contract(&address, || {
+        let commitment = Base::mint_commitment(&e, &to, &nonce);
+        Base::commit_mint(&e, &commitment);
+
+        assert_eq!(Base::committed_at(&e, &commitment), Some(100));
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        commitment
+    });
+
+    e.ledger().set_sequence_number(110);
+    e.as_contract(&address, || {
+        let token_id = Base::reveal_mint(&e, &to, &nonce);
+
+        assert_eq!(Base::owner_of(&e, token_id), to);
+        assert_eq!(Base::committed_at(&e, &commitment), None);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_non_fungible_mint(&to, token_id);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #334)")]
+fn reveal_too_early_fails() {
+    let e = Env::default();
+    let address = setup(&e, 10);
+    let to = Address::generate(&e);
+    let nonce = BytesN::from_array(&e, &[1u8; 32]);
+
+    e.ledger().set_sequence_number(100);
+    e.as_contract(&address, || {
+        Base::commit_mint(&e, &Base::mint_commitment(&e, &to, &nonce));
+    });
+
+    e.ledger().set_sequence_number(109);
+    e.as_contract(&address, || {
+        Base::reveal_mint(&e, &to, &nonce);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #333)")]
+fn reveal_with_wrong_nonce_fails() {
+    let e = Env::default();
+    let address = setup(&e, 10);
+    let to = Address::generate(&e);
+
+    e.ledger().set_sequence_number(100);
+    e.as_contract(&address, || {
+        let nonce = BytesN::from_array(&e, &[1u8; 32]);
+        Base::commit_mint(&e, &Base::mint_commitment(&e, &to, &nonce));
+    });
+
+    e.ledger().set_sequence_number(110);
+    e.as_contract(&address, || {
+        Base::reveal_mint(&e, &to, &BytesN::from_array(&e, &[2u8; 32]));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #333)")]
+fn reveal_to_other_recipient_fails() {
+    let e = Env::default();
+    let address = setup(&e, 10);
+    let to = Address::generate(&e);
+    let sniper = Address::generate(&e);
+    let nonce = BytesN::from_array(&e, &[1u8; 32]);
+
+    e.ledger().set_sequence_number(100);
+    e.as_contract(&address, || {
+        Base::commit_mint(&e, &Base::mint_commitment(&e, &to, &nonce));
+    });
+
+    e.ledger().set_sequence_number(110);
+    e.as_contract(&address, || {
+        Base::reveal_mint(&e, &sniper, &nonce);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #333)")]
+fn reveal_twice_fails() {
+    let e = Env::default();
+    let address = setup(&e, 0);
+    let to = Address::generate(&e);
+    let nonce = BytesN::from_array(&e, &[1u8; 32]);
+
+    e.as_contract(&address, || {
+        Base::commit_mint(&e, &Base::mint_commitment(&e, &to, &nonce));
+        Base::reveal_mint(&e, &to, &nonce);
+        Base::reveal_mint(&e, &to, &nonce);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #332)")]
+fn commit_twice_fails() {
+    let e = Env::default();
+    let address = setup(&e, 10);
+    let to = Address::generate(&e);
+    let nonce = BytesN::from_array(&e, &[1u8; 32]);
+
+    e.as_contract(&address, || {
+        let commitment = Base::mint_commitment(&e, &to, &nonce);
+        Base::commit_mint(&e, &commitment);
+        Base::commit_mint(&e, &commitment);
+    });
+}
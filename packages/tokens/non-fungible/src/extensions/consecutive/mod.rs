@@ -13,7 +13,10 @@
 //! - **Minting**: `batch_mint` stores the owner only for the first token ID in
 //!   the batch.
 //! - **owner_of**: Walks backwards from the token ID to find the closest
-//!   recorded owner.
+//!   recorded owner. Token IDs with a recorded owner are marked in a bitmap
+//!   (see [`storage::OWNER_WRITES`]), so a single storage read covers 64 token
+//!   IDs during the walk. Owner records written before the bitmap existed (see
+//!   [`storage::OWNER_WRITES_FROM`]) are found by a plain backward scan.
 //! - **Transfer**: Stores the new owner for the token ID and re-stores the old
 //!   owner at `token_id + 1` if needed, to preserve correct inference for later
 //!   tokens.
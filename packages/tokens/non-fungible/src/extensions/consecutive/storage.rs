@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, panic_with_error, Address, Env, String};
+use soroban_sdk::{contracttype, panic_with_error, symbol_short, Address, Env, String, Symbol};
 use stellar_constants::{
     OWNER_EXTEND_AMOUNT, OWNER_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD,
 };
@@ -11,6 +11,15 @@ use crate::{
     Balance, Base, ContractOverrides, NonFungibleTokenError, TokenId,
 };
 
+/// Identifies the bitmap marking the token IDs that have an explicit owner
+/// record, allowing `owner_of` to skip large gaps of inferred ownership.
+pub const OWNER_WRITES: Symbol = symbol_short!("OWNERS");
+
+/// Identifies the first token ID from which every owner record is marked in
+/// the [`OWNER_WRITES`] bitmap. Owner records of the lower token IDs may
+/// predate the bitmap, e.g. after a contract upgrade.
+pub const OWNER_WRITES_FROM: Symbol = symbol_short!("OWNERSFR");
+
 pub struct Consecutive;
 
 impl ContractOverrides for Consecutive {
@@ -71,24 +80,52 @@ impl Consecutive {
             e.storage().persistent().extend_ttl(&key, TOKEN_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT);
         }
 
-        if token_id >= max || is_burned {
+        if token_id >= max || token_id < sequential::token_id_start(e) || is_burned {
+            panic_with_error!(&e, NonFungibleTokenError::NonExistentToken);
+        }
+
+        // the owner is often recorded for the token itself, e.g. after a transfer
+        if let Some(owner) = Consecutive::read_owner(e, token_id) {
+            return owner;
+        }
+
+        // otherwise, the ownership bitmap points to the closest recorded owner
+        if let Some(index) = owner_bit(token_id) {
+            let found = stellar_bitmap::find_last_set(e, &OWNER_WRITES, index);
+            if let Some(found) = found {
+                stellar_bitmap::extend_ttl(
+                    e,
+                    &OWNER_WRITES,
+                    found,
+                    OWNER_TTL_THRESHOLD,
+                    OWNER_EXTEND_AMOUNT,
+                );
+            }
+
+            // owner records written before the bitmap existed are not marked,
+            // so the IDs between the closest marked one and the first tracked
+            // one are scanned backwards
+            let lower = found.map_or(sequential::token_id_start(e), |id| TokenId::from(id) + 1);
+            let legacy_end = token_id.min(Consecutive::owner_writes_from(e));
+            if let Some(owner) =
+                (lower..legacy_end).rev().find_map(|id| Consecutive::read_owner(e, id))
+            {
+                return owner;
+            }
+
+            if let Some(owner) = found.and_then(|id| Consecutive::read_owner(e, TokenId::from(id)))
+            {
+                return owner;
+            }
             panic_with_error!(&e, NonFungibleTokenError::NonExistentToken);
         }
 
-        (0..=token_id)
+        // token IDs out of the range of the bitmap fall back to a backward scan
+        (sequential::token_id_start(e)..token_id)
             .rev()
-            .map(StorageKey::Owner)
             // after the Protocol 23 upgrade, storage read cost is marginal,
             // making the consecutive storage reads justifiable
-            .find_map(|key| {
-                e.storage().persistent().get::<_, Address>(&key).inspect(|_| {
-                    e.storage().persistent().extend_ttl(
-                        &key,
-                        OWNER_TTL_THRESHOLD,
-                        OWNER_EXTEND_AMOUNT,
-                    );
-                })
-            })
+            .find_map(|id| Consecutive::read_owner(e, id))
             .unwrap_or_else(|| panic_with_error!(&e, NonFungibleTokenError::NonExistentToken))
     }
 
@@ -105,8 +142,92 @@ impl Consecutive {
     /// * refer to [`base_uri`] errors.
     pub fn token_uri(e: &Env, token_id: TokenId) -> String {
         let _ = Consecutive::owner_of(e, token_id);
-        let base_uri = Base::base_uri(e);
-        Base::compose_uri_for_token(e, base_uri, token_id)
+
+        if let Some(uri) = Base::stored_token_uri(e, token_id) {
+            return uri;
+        }
+
+        match Base::uri_template(e) {
+            Some(template) => Base::compose_uri_from_template(e, template, token_id),
+            None => Base::compose_uri_for_token(e, Base::base_uri(e), token_id),
+        }
+    }
+
+    /// Returns the lowest and the highest IDs of the tokens that have been
+    /// minted and not burned, or `None` if there is no such token.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Notes
+    ///
+    /// The burned tokens are scanned from both ends of the minted range, so
+    /// the number of storage reads grows with the number of burned tokens at
+    /// the ends of the range.
+    pub fn id_bounds(e: &Env) -> Option<(TokenId, TokenId)> {
+        let max = sequential::next_token_id(e);
+        let is_burned =
+            |token_id: &TokenId| e.storage().persistent().has(&StorageKey::BurnedToken(*token_id));
+
+        let first = (sequential::token_id_start(e)..max).find(|token_id| !is_burned(token_id))?;
+        // `first` is not burned, so the backward scan stops at `first` at the latest
+        let last = (first..max).rev().find(|token_id| !is_burned(token_id))?;
+
+        Some((first, last))
+    }
+
+    /// Recomputes the balance of `owner` by scanning the ownership records of
+    /// the first `scan_limit` token IDs, and returns the recomputed balance.
+    /// When the scan covers all the minted tokens, the result is compared
+    /// against the balance stored for `owner`.
+    ///
+    /// Because ownership is backfilled lazily, this helper is meant for
+    /// debugging and for asserting invariants in tests.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address whose balance is recomputed.
+    /// * `scan_limit` - The maximum number of token IDs to scan, starting from
+    ///   the first sequential token ID (see [`sequential::token_id_start`]).
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::BalanceMismatch`] - Occurs if all the minted
+    ///   tokens were scanned and the recomputed balance differs from the stored
+    ///   one.
+    ///
+    /// # Notes
+    ///
+    /// The number of storage reads grows linearly with `scan_limit`, so this
+    /// function is not meant to be exposed as a contract entry-point.
+    pub fn verify_balance(e: &Env, owner: &Address, scan_limit: TokenId) -> Balance {
+        let start = sequential::token_id_start(e);
+        let max = sequential::next_token_id(e);
+        let end = start.saturating_add(scan_limit).min(max);
+
+        let mut current_owner: Option<Address> = None;
+        let mut balance: Balance = 0;
+        for token_id in start..end {
+            if let Some(token_owner) =
+                e.storage().persistent().get::<_, Address>(&StorageKey::Owner(token_id))
+            {
+                current_owner = Some(token_owner);
+            } else if e.storage().persistent().has(&StorageKey::BurnedToken(token_id)) {
+                continue;
+            }
+
+            if current_owner.as_ref() == Some(owner) {
+                balance += 1;
+            }
+        }
+
+        if end == max && balance != Base::balance(e, owner) {
+            panic_with_error!(e, NonFungibleTokenError::BalanceMismatch);
+        }
+
+        balance
     }
 
     // ################## CHANGE STATE ##################
@@ -150,9 +271,16 @@ impl Consecutive {
     pub fn batch_mint(e: &Env, to: &Address, amount: Balance) -> TokenId {
         let first_id = sequential::increment_token_id(e, amount);
 
-        e.storage().persistent().set(&StorageKey::Owner(first_id), &to);
+        // the owner records of the tokens minted from now on are all marked
+        if !e.storage().instance().has(&OWNER_WRITES_FROM) {
+            e.storage().instance().set(&OWNER_WRITES_FROM, &first_id);
+        }
+
+        Consecutive::write_owner(e, to, first_id);
 
         Base::increase_balance(e, to, amount);
+        #[cfg(feature = "countable_supply")]
+        Base::increase_supply(e, amount);
 
         let last_id = first_id + amount - 1;
         emit_consecutive_mint(e, to, first_id, last_id);
@@ -372,20 +500,31 @@ impl Consecutive {
             // Set the next token to prev owner
             Consecutive::set_owner_for(e, from_address, token_id + 1);
         } else {
-            // nothing to do for the `None` case, since we don't track
-            // `total_supply`
+            // Minting: `from` is None. The supply is only tracked with the
+            // `countable_supply` feature.
+            #[cfg(feature = "countable_supply")]
+            Base::increase_supply(e, 1);
         }
 
+        #[cfg(feature = "previous_owner")]
+        Base::record_previous_owner(e, from, to, token_id);
+
         if let Some(to_address) = to {
             Base::increase_balance(e, to_address, 1);
 
             // Set the new owner
-            e.storage().persistent().set(&StorageKey::Owner(token_id), to_address);
+            Consecutive::write_owner(e, to_address, token_id);
         } else {
             // Burning: `to` is None
             e.storage().persistent().remove(&StorageKey::Owner(token_id));
+            if let Some(index) = owner_bit(token_id) {
+                stellar_bitmap::unset(e, &OWNER_WRITES, index);
+            }
 
             e.storage().persistent().set(&StorageKey::BurnedToken(token_id), &true);
+
+            #[cfg(feature = "countable_supply")]
+            Base::decrease_supply(e, 1);
         }
     }
 
@@ -423,8 +562,42 @@ impl Consecutive {
             );
         }
 
-        if token_id < max && !has_owner && !is_burned {
-            e.storage().persistent().set(&StorageKey::Owner(token_id), to);
+        if token_id < max && token_id >= sequential::token_id_start(e) && !has_owner && !is_burned {
+            Consecutive::write_owner(e, to, token_id);
         }
     }
+
+    /// Reads the owner recorded for `token_id`, if any, extending its TTL.
+    fn read_owner(e: &Env, token_id: TokenId) -> Option<Address> {
+        let key = StorageKey::Owner(token_id);
+        e.storage().persistent().get::<_, Address>(&key).inspect(|_| {
+            e.storage().persistent().extend_ttl(&key, OWNER_TTL_THRESHOLD, OWNER_EXTEND_AMOUNT);
+        })
+    }
+
+    /// Returns the first token ID from which every owner record is marked in
+    /// the ownership bitmap. If no batch was minted since the bitmap exists,
+    /// every minted token ID may have an unmarked owner record.
+    fn owner_writes_from(e: &Env) -> TokenId {
+        e.storage()
+            .instance()
+            .get(&OWNER_WRITES_FROM)
+            .unwrap_or_else(|| sequential::next_token_id(e))
+    }
+
+    /// Records `to` as the owner of `token_id`, and marks it in the ownership
+    /// bitmap.
+    fn write_owner(e: &Env, to: &Address, token_id: TokenId) {
+        e.storage().persistent().set(&StorageKey::Owner(token_id), to);
+        if let Some(index) = owner_bit(token_id) {
+            stellar_bitmap::set(e, &OWNER_WRITES, index);
+        }
+    }
+}
+
+/// Returns the position of `token_id` in the ownership bitmap, or `None` if
+/// `token_id` is out of the range covered by the bitmap.
+#[allow(clippy::useless_conversion, clippy::unnecessary_fallible_conversions)]
+fn owner_bit(token_id: TokenId) -> Option<u32> {
+    u32::try_from(token_id).ok()
 }
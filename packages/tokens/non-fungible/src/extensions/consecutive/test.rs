@@ -2,12 +2,15 @@
 
 extern crate std;
 
-use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+use soroban_sdk::{contract, testutils::Address as _, Address, Env, String};
 use stellar_event_assertion::EventAssertion;
 
 use crate::{
-    consecutive::{storage::StorageKey, Consecutive},
-    sequential::next_token_id,
+    consecutive::{
+        storage::{StorageKey, OWNER_WRITES, OWNER_WRITES_FROM},
+        Consecutive,
+    },
+    sequential::{increment_token_id, next_token_id, set_token_id_start},
     Base,
 };
 
@@ -256,3 +259,330 @@ fn consecutive_set_owner_for_works() {
         assert_eq!(owner, None);
     });
 }
+
+#[test]
+fn consecutive_verify_balance_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &user1, 5); // 0,1,2,3,4
+        Consecutive::batch_mint(&e, &user2, 3); // 5,6,7
+        Consecutive::batch_mint(&e, &user1, 2); // 8,9
+    });
+
+    e.as_contract(&address, || {
+        Consecutive::transfer(&e, &user1, &user3, 2);
+        Consecutive::transfer(&e, &user2, &user3, 7);
+    });
+
+    e.as_contract(&address, || {
+        Consecutive::burn(&e, &user1, 3);
+        Consecutive::burn(&e, &user3, 7);
+    });
+
+    e.as_contract(&address, || {
+        Consecutive::transfer(&e, &user1, &user2, 9);
+    });
+
+    e.as_contract(&address, || {
+        assert_eq!(Consecutive::verify_balance(&e, &user1, 10), 4); // 0,1,4,8
+        assert_eq!(Consecutive::verify_balance(&e, &user2, 10), 3); // 5,6,9
+        assert_eq!(Consecutive::verify_balance(&e, &user3, 10), 1); // 2
+
+        // a partial scan only counts the tokens within the limit
+        assert_eq!(Consecutive::verify_balance(&e, &user1, 3), 2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #313)")]
+fn consecutive_verify_balance_mismatch_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 5);
+        Base::increase_balance(&e, &owner, 1);
+
+        Consecutive::verify_balance(&e, &owner, 5);
+    });
+}
+
+#[test]
+fn consecutive_owner_of_with_sparse_owners_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 300);
+        Consecutive::transfer(&e, &owner, &recipient, 70);
+    });
+
+    e.as_contract(&address, || {
+        Consecutive::burn(&e, &owner, 150);
+
+        assert_eq!(Consecutive::owner_of(&e, 0), owner);
+        assert_eq!(Consecutive::owner_of(&e, 69), owner);
+        assert_eq!(Consecutive::owner_of(&e, 70), recipient);
+        assert_eq!(Consecutive::owner_of(&e, 71), owner);
+        assert_eq!(Consecutive::owner_of(&e, 149), owner);
+        assert_eq!(Consecutive::owner_of(&e, 151), owner);
+        assert_eq!(Consecutive::owner_of(&e, 299), owner);
+
+        // ownership records: 0, 70, 71 and 151
+        for id in [0, 70, 71, 151] {
+            assert!(stellar_bitmap::get(&e, &OWNER_WRITES, id));
+        }
+        assert!(!stellar_bitmap::get(&e, &OWNER_WRITES, 150));
+    });
+}
+
+/// Returns the CPU instructions consumed by `owner_of(token_id)`.
+fn owner_of_cost(e: &Env, token_id: crate::TokenId) -> u64 {
+    e.cost_estimate().budget().reset_default();
+    Consecutive::owner_of(e, token_id);
+    e.cost_estimate().budget().cpu_instruction_cost()
+}
+
+#[test]
+fn consecutive_owner_of_reduces_scan_reads() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 1000);
+
+        // a backward scan would read the 1000 owner records, whereas the bitmap
+        // requires 16 chunk reads (999 / 64 + 1) on top of the owner reads
+        let direct = owner_of_cost(&e, 0);
+        let inferred = owner_of_cost(&e, 999);
+        assert!(inferred < direct * 20);
+    });
+}
+
+#[test]
+fn consecutive_owner_of_finds_owners_recorded_before_bitmap() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let new_owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        // owner records written by a version without the bitmap
+        increment_token_id(&e, 10);
+        e.storage().persistent().set(&StorageKey::Owner(0), &owner);
+        e.storage().persistent().set(&StorageKey::Owner(5), &recipient);
+        Base::increase_balance(&e, &owner, 5);
+        Base::increase_balance(&e, &recipient, 5);
+
+        assert_eq!(Consecutive::owner_of(&e, 4), owner);
+        assert_eq!(Consecutive::owner_of(&e, 9), recipient);
+
+        Consecutive::batch_mint(&e, &new_owner, 10);
+        assert_eq!(e.storage().instance().get(&OWNER_WRITES_FROM), Some::<crate::TokenId>(10));
+        assert_eq!(Consecutive::owner_of(&e, 9), recipient);
+        assert_eq!(Consecutive::owner_of(&e, 19), new_owner);
+    });
+
+    e.as_contract(&address, || Consecutive::transfer(&e, &owner, &new_owner, 2));
+
+    e.as_contract(&address, || {
+        assert_eq!(Consecutive::owner_of(&e, 1), owner);
+        assert_eq!(Consecutive::owner_of(&e, 2), new_owner);
+        assert_eq!(Consecutive::owner_of(&e, 3), owner);
+        assert_eq!(Consecutive::owner_of(&e, 4), owner);
+        assert_eq!(Consecutive::owner_of(&e, 9), recipient);
+    });
+}
+
+#[cfg(feature = "token_u64")]
+#[test]
+#[should_panic(expected = "Error(Contract, #300)")]
+fn consecutive_owner_of_beyond_bitmap_respects_token_id_start() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    let owner = Address::generate(&e);
+    let first_id = crate::TokenId::from(u32::MAX) + 10;
+
+    e.as_contract(&address, || {
+        set_token_id_start(&e, first_id);
+        Consecutive::batch_mint(&e, &owner, 3);
+        assert_eq!(Consecutive::owner_of(&e, first_id + 2), owner);
+
+        // without any owner record, the scan stops at `token_id_start` instead
+        // of walking down to `0`
+        e.storage().persistent().remove(&StorageKey::Owner(first_id));
+        Consecutive::owner_of(&e, first_id + 2);
+    });
+}
+
+#[test]
+fn consecutive_id_bounds_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        assert_eq!(Consecutive::id_bounds(&e), None);
+
+        Consecutive::batch_mint(&e, &owner, 10);
+        assert_eq!(Consecutive::id_bounds(&e), Some((0, 9)));
+    });
+
+    e.as_contract(&address, || Consecutive::burn(&e, &owner, 0));
+    e.as_contract(&address, || Consecutive::burn(&e, &owner, 1));
+    e.as_contract(&address, || Consecutive::burn(&e, &owner, 9));
+
+    e.as_contract(&address, || {
+        assert_eq!(Consecutive::id_bounds(&e), Some((2, 8)));
+    });
+}
+
+#[test]
+fn consecutive_batch_mint_respects_token_id_start() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_token_id_start(&e, 1);
+        Consecutive::batch_mint(&e, &owner, 10);
+
+        assert_eq!(Consecutive::id_bounds(&e), Some((1, 10)));
+        assert_eq!(Consecutive::owner_of(&e, 10), owner);
+        assert_eq!(Consecutive::verify_balance(&e, &owner, 10), 10);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #300)")]
+fn consecutive_owner_of_before_token_id_start_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_token_id_start(&e, 1);
+        Consecutive::batch_mint(&e, &owner, 10);
+
+        Consecutive::owner_of(&e, 0);
+    });
+}
+
+#[test]
+fn consecutive_id_bounds_fully_burned_is_none() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 3);
+    });
+
+    for token_id in 0..3 {
+        e.as_contract(&address, || Consecutive::burn(&e, &owner, token_id));
+    }
+
+    e.as_contract(&address, || {
+        assert_eq!(Consecutive::id_bounds(&e), None);
+    });
+}
+
+#[cfg(feature = "token_u128")]
+#[test]
+fn consecutive_with_wide_token_ids_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let first_id = crate::TokenId::from(u64::MAX) - 1;
+
+    e.as_contract(&address, || {
+        e.storage().instance().set(&crate::sequential::StorageKey::TokenIdCounter, &first_id);
+
+        let last_id = Consecutive::batch_mint(&e, &owner, 4);
+        assert_eq!(last_id, first_id + 3);
+        assert_eq!(Base::balance(&e, &owner), 4);
+        assert_eq!(Consecutive::owner_of(&e, last_id), owner);
+    });
+
+    e.as_contract(&address, || {
+        Consecutive::transfer(&e, &owner, &recipient, first_id + 1);
+
+        assert_eq!(Consecutive::owner_of(&e, first_id), owner);
+        assert_eq!(Consecutive::owner_of(&e, first_id + 1), recipient);
+        assert_eq!(Consecutive::owner_of(&e, first_id + 2), owner);
+        assert_eq!(Consecutive::owner_of(&e, first_id + 3), owner);
+    });
+}
+
+#[test]
+fn consecutive_token_uri_prefers_explicit_uri() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_metadata(
+            &e,
+            String::from_str(&e, "https://smth.com/"),
+            String::from_str(&e, "My NFT collection"),
+            String::from_str(&e, "NFT"),
+        );
+        Consecutive::batch_mint(&e, &owner, 10);
+        Base::set_token_uri(&e, 3, String::from_str(&e, "ipfs://bafy-three"));
+
+        assert_eq!(Consecutive::token_uri(&e, 3), String::from_str(&e, "ipfs://bafy-three"));
+        assert_eq!(Consecutive::token_uri(&e, 4), String::from_str(&e, "https://smth.com/4"));
+    });
+}
+
+#[cfg(feature = "countable_supply")]
+#[test]
+fn consecutive_total_supply_tracks_batch_mints_and_burns() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 10);
+        assert_eq!(Base::total_supply(&e), 10);
+    });
+
+    e.as_contract(&address, || {
+        Consecutive::transfer(&e, &owner, &recipient, 3);
+        assert_eq!(Base::total_supply(&e), 10);
+    });
+
+    e.as_contract(&address, || {
+        Consecutive::burn(&e, &owner, 5);
+        assert_eq!(Base::total_supply(&e), 9);
+    });
+}
@@ -3,7 +3,7 @@ pub mod storage;
 mod test;
 
 use soroban_sdk::{Address, Env};
-pub use storage::Enumerable;
+pub use storage::{Enumerable, MintResult};
 
 use crate::{Balance, NonFungibleToken, TokenId};
 
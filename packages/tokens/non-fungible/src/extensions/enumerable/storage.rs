@@ -1,10 +1,11 @@
-use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, String};
 use stellar_constants::{
     OWNER_EXTEND_AMOUNT, OWNER_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD,
 };
 
 use crate::{
-    non_fungible::emit_mint, Balance, Base, ContractOverrides, NonFungibleTokenError, TokenId,
+    non_fungible::emit_mint, Balance, Base, CollectionInfo, ContractOverrides,
+    NonFungibleTokenError, TokenId,
 };
 
 pub struct Enumerable;
@@ -25,6 +26,16 @@ pub struct OwnerTokensKey {
     pub index: TokenId,
 }
 
+/// Bundles everything known about a freshly minted token, so that callers
+/// don't have to query it afterwards.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintResult {
+    pub token_id: TokenId,
+    pub token_uri: String,
+    pub new_total_supply: Balance,
+}
+
 /// Storage keys for the data associated with `FungibleToken`
 #[contracttype]
 pub enum StorageKey {
@@ -47,6 +58,23 @@ impl Enumerable {
         e.storage().instance().get(&StorageKey::TotalSupply).unwrap_or(0)
     }
 
+    /// Returns the collection name, symbol, base URI and total supply in a
+    /// single struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::get_metadata`] errors.
+    pub fn collection_info(e: &Env) -> CollectionInfo {
+        CollectionInfo {
+            total_supply: Some(Enumerable::total_supply(e)),
+            ..Base::collection_info(e)
+        }
+    }
+
     /// Returns the `token_id` owned by `owner` at a given `index` in the
     /// owner's local list. Use along with
     /// [`crate::NonFungibleToken::balance()`] to enumerate all of `owner`'s
@@ -141,6 +169,39 @@ impl Enumerable {
         token_id
     }
 
+    /// Creates a token with the next available `token_id` and assigns it to
+    /// `to`. Returns the `token_id`, the URI and the new total supply in a
+    /// single [`MintResult`].
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the new token.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Enumerable::sequential_mint`] errors.
+    /// * refer to [`Base::token_uri`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// The metadata of the collection must be set beforehand, as the URI of
+    /// the token is composed while minting.
+    pub fn sequential_mint_full(e: &Env, to: &Address) -> MintResult {
+        let token_id = Enumerable::sequential_mint(e, to);
+
+        MintResult {
+            token_id,
+            token_uri: Base::token_uri(e, token_id),
+            new_total_supply: Enumerable::total_supply(e),
+        }
+    }
+
     /// Creates a token with the provided `token_id` and assigns it to `to`.
     ///
     /// # Arguments
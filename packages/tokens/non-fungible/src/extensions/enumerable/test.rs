@@ -2,10 +2,10 @@
 
 extern crate std;
 
-use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+use soroban_sdk::{contract, testutils::Address as _, Address, Bytes, Env, String};
 use stellar_event_assertion::EventAssertion;
 
-use crate::{extensions::enumerable::Enumerable, Base, StorageKey, TokenId};
+use crate::{extensions::enumerable::Enumerable, Base, ContractOverrides, StorageKey, TokenId};
 
 #[contract]
 struct MockContract;
@@ -19,17 +19,14 @@ fn test_total_supply() {
 
     e.as_contract(&address, || {
         let token_id1 = Enumerable::sequential_mint(&e, &owner);
-        let _token_id2 = Enumerable::sequential_mint(&e, &owner);
+        let token_id2 = Enumerable::sequential_mint(&e, &owner);
 
         assert_eq!(Enumerable::total_supply(&e), 2);
 
         let event_assert = EventAssertion::new(&e, address.clone());
         event_assert.assert_event_count(2);
-        event_assert.assert_non_fungible_mint(&owner, token_id1);
-
-        // TODO: below fails because the same event is read by the
-        // `event_assert`, not the next one. event_assert.
-        // assert_non_fungible_mint(&owner, token_id2);
+        event_assert.assert_non_fungible_mint_at(0, &owner, token_id1);
+        event_assert.assert_non_fungible_mint_at(1, &owner, token_id2);
     });
 }
 
@@ -287,3 +284,123 @@ fn test_enumerable_transfer_from() {
         assert_eq!(Enumerable::total_supply(&e), 1);
     });
 }
+
+#[test]
+fn test_enumerable_safe_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient =
+        Address::from_str(&e, "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF");
+
+    e.as_contract(&address, || {
+        let token_id1 = Enumerable::sequential_mint(&e, &owner);
+        let token_id2 = Enumerable::sequential_mint(&e, &owner);
+        Enumerable::safe_transfer(&e, &owner, &recipient, token_id1, Bytes::new(&e));
+
+        assert_eq!(Enumerable::get_owner_token_id(&e, &owner, 0), token_id2);
+        assert_eq!(Enumerable::get_owner_token_id(&e, &recipient, 0), token_id1);
+        assert_eq!(Enumerable::total_supply(&e), 2);
+    });
+}
+
+#[test]
+fn test_enumerable_safe_transfer_from() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient =
+        Address::from_str(&e, "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF");
+
+    e.as_contract(&address, || {
+        let token_id1 = Enumerable::sequential_mint(&e, &owner);
+        let token_id2 = Enumerable::sequential_mint(&e, &owner);
+        Base::approve(&e, &owner, &spender, token_id1, 1000);
+        Enumerable::safe_transfer_from(&e, &spender, &owner, &recipient, token_id1, Bytes::new(&e));
+
+        assert_eq!(Enumerable::get_owner_token_id(&e, &owner, 0), token_id2);
+        assert_eq!(Enumerable::get_owner_token_id(&e, &recipient, 0), token_id1);
+    });
+}
+
+#[cfg(feature = "token_u128")]
+#[test]
+fn test_enumeration_with_wide_token_ids() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let token_id1 = TokenId::from(u64::MAX) + 1;
+    let token_id2 = TokenId::MAX - 1;
+
+    e.as_contract(&address, || {
+        Enumerable::non_sequential_mint(&e, &owner, token_id1);
+        Enumerable::non_sequential_mint(&e, &owner, token_id2);
+
+        assert_eq!(Enumerable::total_supply(&e), 2);
+        assert_eq!(Enumerable::get_token_id(&e, 0), token_id1);
+        assert_eq!(Enumerable::get_token_id(&e, 1), token_id2);
+        assert_eq!(Enumerable::get_owner_token_id(&e, &owner, 1), token_id2);
+    });
+
+    e.as_contract(&address, || {
+        Enumerable::non_sequential_burn(&e, &owner, token_id1);
+
+        assert_eq!(Enumerable::total_supply(&e), 1);
+        assert_eq!(Enumerable::get_token_id(&e, 0), token_id2);
+        assert_eq!(Enumerable::get_owner_token_id(&e, &owner, 0), token_id2);
+    });
+}
+
+#[test]
+fn test_collection_info() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_metadata(
+            &e,
+            String::from_str(&e, "https://smth.com/"),
+            String::from_str(&e, "My Token"),
+            String::from_str(&e, "TKN"),
+        );
+        Enumerable::sequential_mint(&e, &owner);
+        Enumerable::sequential_mint(&e, &owner);
+
+        let info = Enumerable::collection_info(&e);
+        assert_eq!(info.name, Base::name(&e));
+        assert_eq!(info.symbol, Base::symbol(&e));
+        assert_eq!(info.base_uri, Some(Base::base_uri(&e)));
+        assert_eq!(info.total_supply, Some(Enumerable::total_supply(&e)));
+        assert_eq!(info.total_supply, Some(2));
+    });
+}
+
+#[test]
+fn test_sequential_mint_full() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_metadata(
+            &e,
+            String::from_str(&e, "https://smth.com/"),
+            String::from_str(&e, "My Token"),
+            String::from_str(&e, "TKN"),
+        );
+        Enumerable::sequential_mint(&e, &owner);
+
+        let result = Enumerable::sequential_mint_full(&e, &owner);
+        assert_eq!(result.token_id, Enumerable::get_owner_token_id(&e, &owner, 1));
+        assert_eq!(result.token_uri, Base::token_uri(&e, result.token_id));
+        assert_eq!(result.new_total_supply, Enumerable::total_supply(&e));
+        assert_eq!(result.new_total_supply, 2);
+    });
+}
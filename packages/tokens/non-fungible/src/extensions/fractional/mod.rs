@@ -0,0 +1,66 @@
+//! # Fractional Extension for Non-Fungible Token
+//!
+//! Allows an NFT to be co-owned through fractional shares backed by a
+//! fungible token. [`crate::Base::fractionalize`] takes the token into the
+//! custody of the contract and mints the shares to the depositor, who can then
+//! trade them freely. Whoever gathers all the shares can redeem the token with
+//! [`crate::Base::redeem`], which burns the shares and releases the token.
+//!
+//! ## Usage
+//!
+//! The share token must expose the Stellar Asset Contract admin interface
+//! (`mint`) with this contract as admin, as well as the SEP-41 `burn`, e.g. a
+//! Stellar Asset Contract whose admin has been set to this contract. A
+//! dedicated share token is expected per fractionalized token, as the shares
+//! of different tokens would otherwise be interchangeable.
+mod storage;
+use soroban_sdk::{Address, Env, Symbol};
+pub use storage::{FractionData, StorageKey};
+
+use crate::TokenId;
+
+mod test;
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating a token has been fractionalized.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token_id` - The identifier of the token.
+/// * `depositor` - The address that deposited the token.
+/// * `share_token` - The fungible token representing the shares.
+/// * `shares` - The number of shares minted to `depositor`.
+///
+/// # Events
+///
+/// * topics - `["fractionalize", token_id: TokenId]`
+/// * data - `[depositor: Address, share_token: Address, shares: i128]`
+pub fn emit_fractionalize(
+    e: &Env,
+    token_id: TokenId,
+    depositor: &Address,
+    share_token: &Address,
+    shares: i128,
+) {
+    let topics = (Symbol::new(e, "fractionalize"), token_id);
+    e.events().publish(topics, (depositor, share_token, shares))
+}
+
+/// Emits an event indicating a fractionalized token has been redeemed.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token_id` - The identifier of the token.
+/// * `holder` - The address that redeemed the token.
+///
+/// # Events
+///
+/// * topics - `["redeem", token_id: TokenId]`
+/// * data - `[holder: Address]`
+pub fn emit_redeem(e: &Env, token_id: TokenId, holder: &Address) {
+    let topics = (Symbol::new(e, "redeem"), token_id);
+    e.events().publish(topics, holder)
+}
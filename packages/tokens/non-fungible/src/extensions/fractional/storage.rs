@@ -0,0 +1,142 @@
+use soroban_sdk::{contracttype, panic_with_error, token, Address, Env};
+use stellar_constants::{TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD};
+
+use crate::{
+    emit_transfer,
+    fractional::{emit_fractionalize, emit_redeem},
+    Base, NonFungibleTokenError, TokenId,
+};
+
+/// Storage container for the shares backing a fractionalized token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FractionData {
+    pub share_token: Address,
+    pub shares: i128,
+}
+
+/// Storage keys for the data associated with the fractional extension.
+#[contracttype]
+pub enum StorageKey {
+    Fraction(TokenId),
+}
+
+impl Base {
+    /// Returns the shares backing `token_id`, or `None` if the token is not
+    /// fractionalized.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn fraction(e: &Env, token_id: TokenId) -> Option<FractionData> {
+        let key = StorageKey::Fraction(token_id);
+        e.storage().persistent().get::<_, FractionData>(&key).inspect(|_| {
+            e.storage().persistent().extend_ttl(&key, TOKEN_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT);
+        })
+    }
+
+    /// Takes `token_id` into the custody of the contract and mints `shares`
+    /// of `share_token` to its owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The owner of the token.
+    /// * `token_id` - The identifier of the token.
+    /// * `shares` - The number of shares to mint.
+    /// * `share_token` - The fungible token representing the shares.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidAmount`] - If `shares` is not
+    ///   positive.
+    /// * refer to [`Base::update`] errors.
+    /// * refer to the `mint` errors of `share_token`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", owner: Address, contract: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// * topics - `["fractionalize", token_id: TokenId]`
+    /// * data - `[depositor: Address, share_token: Address, shares: i128]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `owner` is required.
+    pub fn fractionalize(
+        e: &Env,
+        owner: &Address,
+        token_id: TokenId,
+        shares: i128,
+        share_token: &Address,
+    ) {
+        owner.require_auth();
+
+        if shares <= 0 {
+            panic_with_error!(e, NonFungibleTokenError::InvalidAmount);
+        }
+
+        let contract = e.current_contract_address();
+        Base::update(e, Some(owner), Some(&contract), token_id);
+        emit_transfer(e, owner, &contract, token_id);
+
+        token::StellarAssetClient::new(e, share_token).mint(owner, &shares);
+
+        let fraction = FractionData { share_token: share_token.clone(), shares };
+        e.storage().persistent().set(&StorageKey::Fraction(token_id), &fraction);
+
+        emit_fractionalize(e, token_id, owner, share_token, shares);
+    }
+
+    /// Burns all the shares of `token_id` from `holder` and releases the
+    /// token to `holder`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `holder` - The address holding all the shares.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::NotFractionalized`] - If the token is not
+    ///   fractionalized.
+    /// * [`NonFungibleTokenError::InsufficientShares`] - If `holder` doesn't
+    ///   hold all the shares.
+    /// * refer to the `burn` errors of the share token.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", contract: Address, holder: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// * topics - `["redeem", token_id: TokenId]`
+    /// * data - `[holder: Address]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `holder` is required, including by the share token
+    ///   to burn the shares.
+    pub fn redeem(e: &Env, holder: &Address, token_id: TokenId) {
+        holder.require_auth();
+
+        let Some(fraction) = Base::fraction(e, token_id) else {
+            panic_with_error!(e, NonFungibleTokenError::NotFractionalized);
+        };
+
+        let share_client = token::Client::new(e, &fraction.share_token);
+        if share_client.balance(holder) < fraction.shares {
+            panic_with_error!(e, NonFungibleTokenError::InsufficientShares);
+        }
+        share_client.burn(holder, &fraction.shares);
+        e.storage().persistent().remove(&StorageKey::Fraction(token_id));
+
+        let contract = e.current_contract_address();
+        Base::update(e, Some(&contract), Some(holder), token_id);
+        emit_transfer(e, &contract, holder, token_id);
+
+        emit_redeem(e, token_id, holder);
+    }
+}
@@ -0,0 +1,104 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, token, Address, Env};
+
+use crate::{fractional::FractionData, Base, TokenId};
+
+#[contract]
+struct MockContract;
+
+#[contractimpl]
+impl MockContract {
+    pub fn fractionalize(
+        e: &Env,
+        owner: Address,
+        token_id: TokenId,
+        shares: i128,
+        share_token: Address,
+    ) {
+        Base::fractionalize(e, &owner, token_id, shares, &share_token);
+    }
+
+    pub fn redeem(e: &Env, holder: Address, token_id: TokenId) {
+        Base::redeem(e, &holder, token_id);
+    }
+}
+
+fn setup(e: &Env) -> (Address, MockContractClient<'_>, Address, Address) {
+    e.mock_all_auths_allowing_non_root_auth();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(e, &address);
+    // the NFT contract administers the share token, so that it can mint shares
+    let share_token = e.register_stellar_asset_contract_v2(address.clone()).address();
+    let owner = Address::generate(e);
+
+    e.as_contract(&address, || {
+        Base::mint(e, &owner, 1);
+    });
+
+    (address, client, share_token, owner)
+}
+
+#[test]
+fn fractionalize_works() {
+    let e = Env::default();
+    let (address, client, share_token, owner) = setup(&e);
+
+    client.fractionalize(&owner, &1, &1000, &share_token);
+
+    assert_eq!(token::Client::new(&e, &share_token).balance(&owner), 1000);
+    e.as_contract(&address, || {
+        assert_eq!(Base::owner_of(&e, 1), address);
+        assert_eq!(
+            Base::fraction(&e, 1),
+            Some(FractionData { share_token: share_token.clone(), shares: 1000 })
+        );
+    });
+}
+
+#[test]
+fn redeem_by_partial_holder_fails() {
+    let e = Env::default();
+    let (address, client, share_token, owner) = setup(&e);
+    let buyer = Address::generate(&e);
+
+    client.fractionalize(&owner, &1, &1000, &share_token);
+    token::Client::new(&e, &share_token).transfer(&owner, &buyer, &400);
+
+    assert!(client.try_redeem(&owner, &1).is_err());
+    assert!(client.try_redeem(&buyer, &1).is_err());
+
+    e.as_contract(&address, || {
+        assert_eq!(Base::owner_of(&e, 1), address);
+    });
+}
+
+#[test]
+fn redeem_by_full_holder_unlocks_token() {
+    let e = Env::default();
+    let (address, client, share_token, owner) = setup(&e);
+    let buyer = Address::generate(&e);
+    let share_client = token::Client::new(&e, &share_token);
+
+    client.fractionalize(&owner, &1, &1000, &share_token);
+    share_client.transfer(&owner, &buyer, &1000);
+
+    client.redeem(&buyer, &1);
+
+    assert_eq!(share_client.balance(&buyer), 0);
+    e.as_contract(&address, || {
+        assert_eq!(Base::owner_of(&e, 1), buyer);
+        assert_eq!(Base::fraction(&e, 1), None);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #329)")]
+fn redeem_not_fractionalized_token_fails() {
+    let e = Env::default();
+    let (_address, client, _share_token, owner) = setup(&e);
+
+    client.redeem(&owner, &1);
+}
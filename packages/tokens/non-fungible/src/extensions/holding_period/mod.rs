@@ -0,0 +1,24 @@
+//! # Holding Period Extension for Non-Fungible Token
+//!
+//! Collections discouraging flipping can require their tokens to be held for
+//! a minimum number of ledgers before they can be resold. The ledger at which
+//! each token was last acquired is recorded, and transfers revert with
+//! [`crate::NonFungibleTokenError::HoldingPeriodActive`] until the period set
+//! with [`crate::holding_period::HoldingPeriod::set_min_holding_period`]
+//! elapsed.
+//!
+//! ## Usage
+//!
+//! A contract opts in by setting `type ContractType = HoldingPeriod`, and
+//! mints its tokens with [`crate::holding_period::HoldingPeriod::mint`] or
+//! [`crate::holding_period::HoldingPeriod::sequential_mint`]. Mints are never
+//! subject to the holding period, but they start it: a freshly minted token
+//! can only be transferred once the period elapsed.
+//!
+//! Tokens without a recorded acquisition, e.g. the ones minted with
+//! [`crate::Base::mint`] directly, are not restricted until their first
+//! transfer through `HoldingPeriod`.
+pub mod storage;
+pub use storage::{HoldingPeriod, StorageKey};
+
+mod test;
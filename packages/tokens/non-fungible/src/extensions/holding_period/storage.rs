@@ -0,0 +1,227 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+use stellar_constants::{TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD};
+
+use crate::{Base, ContractOverrides, NonFungibleTokenError, TokenId};
+
+pub struct HoldingPeriod;
+
+impl ContractOverrides for HoldingPeriod {
+    fn transfer(e: &Env, from: &Address, to: &Address, token_id: TokenId) {
+        HoldingPeriod::transfer(e, from, to, token_id);
+    }
+
+    fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, token_id: TokenId) {
+        HoldingPeriod::transfer_from(e, spender, from, to, token_id);
+    }
+}
+
+/// Storage keys for the data associated with the holding period extension.
+#[contracttype]
+pub enum StorageKey {
+    MinHoldingPeriod,
+    AcquiredAt(TokenId),
+}
+
+impl HoldingPeriod {
+    // ################## QUERY STATE ##################
+
+    /// Returns the minimum number of ledgers a token must be held before it
+    /// can be transferred. Defaults to `0`, i.e. no holding period.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn min_holding_period(e: &Env) -> u32 {
+        e.storage().instance().get(&StorageKey::MinHoldingPeriod).unwrap_or(0)
+    }
+
+    /// Returns the ledger at which `token_id` was last acquired, or `None` if
+    /// no acquisition was recorded for it.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn acquired_at(e: &Env, token_id: TokenId) -> Option<u32> {
+        let key = StorageKey::AcquiredAt(token_id);
+        e.storage().persistent().get::<_, u32>(&key).inspect(|_| {
+            e.storage().persistent().extend_ttl(&key, TOKEN_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT);
+        })
+    }
+
+    /// Ensures the minimum holding period of `token_id` elapsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::HoldingPeriodActive`] - If the token was
+    ///   acquired less than [`HoldingPeriod::min_holding_period`] ledgers ago.
+    pub fn check_holding_period(e: &Env, token_id: TokenId) {
+        let Some(acquired_at) = HoldingPeriod::acquired_at(e, token_id) else {
+            return;
+        };
+        let unlocked_at = acquired_at.saturating_add(HoldingPeriod::min_holding_period(e));
+        if e.ledger().sequence() < unlocked_at {
+            panic_with_error!(e, NonFungibleTokenError::HoldingPeriodActive);
+        }
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Sets the minimum number of ledgers a token must be held before it can
+    /// be transferred. The new period applies to the tokens already held as
+    /// well.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `ledgers` - The minimum holding period, in ledgers.
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can change the
+    /// holding period.
+    pub fn set_min_holding_period(e: &Env, ledgers: u32) {
+        e.storage().instance().set(&StorageKey::MinHoldingPeriod, &ledgers);
+    }
+
+    /// Transfers a non-fungible token from `from` to `to`, provided its
+    /// minimum holding period elapsed, and restarts the holding period for
+    /// `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `token_id` - The identifier of the token being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`HoldingPeriod::check_holding_period`] errors.
+    /// * refer to [`Base::transfer`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `from` is required.
+    pub fn transfer(e: &Env, from: &Address, to: &Address, token_id: TokenId) {
+        HoldingPeriod::check_holding_period(e, token_id);
+        Base::transfer(e, from, to, token_id);
+        HoldingPeriod::record_acquisition(e, token_id);
+    }
+
+    /// Transfers a non-fungible token using `spender`s approval, provided its
+    /// minimum holding period elapsed, and restarts the holding period for
+    /// `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address attempting to transfer the token.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_id` - The identifier of the token being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`HoldingPeriod::check_holding_period`] errors.
+    /// * refer to [`Base::transfer_from`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `spender` is required.
+    pub fn transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_id: TokenId,
+    ) {
+        HoldingPeriod::check_holding_period(e, token_id);
+        Base::transfer_from(e, spender, from, to, token_id);
+        HoldingPeriod::record_acquisition(e, token_id);
+    }
+
+    /// Creates a token with the provided `token_id`, assigns it to `to` and
+    /// starts its holding period.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the new token.
+    /// * `token_id` - The token_id of the new token.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can execute
+    /// minting operations. Like [`Base::mint`], this function does NOT
+    /// verify whether the provided `token_id` already exists.
+    pub fn mint(e: &Env, to: &Address, token_id: TokenId) {
+        Base::mint(e, to, token_id);
+        HoldingPeriod::record_acquisition(e, token_id);
+    }
+
+    /// Creates a token with the next available `token_id`, assigns it to `to`
+    /// and starts its holding period. Returns the `token_id` of the new token.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the new token.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::sequential_mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can execute
+    /// minting operations.
+    pub fn sequential_mint(e: &Env, to: &Address) -> TokenId {
+        let token_id = Base::sequential_mint(e, to);
+        HoldingPeriod::record_acquisition(e, token_id);
+        token_id
+    }
+
+    /// Records the current ledger as the acquisition ledger of `token_id`.
+    fn record_acquisition(e: &Env, token_id: TokenId) {
+        e.storage().persistent().set(&StorageKey::AcquiredAt(token_id), &e.ledger().sequence());
+    }
+}
@@ -0,0 +1,180 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger as _},
+    Address, Bytes, Env,
+};
+
+use crate::{holding_period::HoldingPeriod, Base, ContractOverrides};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn mint_records_acquisition() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.ledger().set_sequence_number(100);
+    e.as_contract(&address, || {
+        assert_eq!(HoldingPeriod::min_holding_period(&e), 0);
+
+        HoldingPeriod::set_min_holding_period(&e, 50);
+        assert_eq!(HoldingPeriod::min_holding_period(&e), 50);
+
+        // minting is not subject to the holding period
+        let token_id = HoldingPeriod::sequential_mint(&e, &owner);
+        assert_eq!(Base::owner_of(&e, token_id), owner);
+        assert_eq!(HoldingPeriod::acquired_at(&e, token_id), Some(100));
+
+        HoldingPeriod::mint(&e, &owner, 42);
+        assert_eq!(HoldingPeriod::acquired_at(&e, 42), Some(100));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #336)")]
+fn transfer_of_freshly_minted_token_before_period_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.ledger().set_sequence_number(100);
+    let token_id = e.as_contract(&address, || {
+        HoldingPeriod::set_min_holding_period(&e, 50);
+        HoldingPeriod::sequential_mint(&e, &owner)
+    });
+
+    e.ledger().set_sequence_number(149);
+    e.as_contract(&address, || {
+        HoldingPeriod::transfer(&e, &owner, &recipient, token_id);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #336)")]
+fn safe_transfer_before_period_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.ledger().set_sequence_number(100);
+    let token_id = e.as_contract(&address, || {
+        HoldingPeriod::set_min_holding_period(&e, 50);
+        HoldingPeriod::sequential_mint(&e, &owner)
+    });
+
+    e.ledger().set_sequence_number(149);
+    e.as_contract(&address, || {
+        HoldingPeriod::safe_transfer(&e, &owner, &recipient, token_id, Bytes::new(&e));
+    });
+}
+
+#[test]
+fn transfer_of_freshly_minted_token_after_period_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.ledger().set_sequence_number(100);
+    let token_id = e.as_contract(&address, || {
+        HoldingPeriod::set_min_holding_period(&e, 50);
+        HoldingPeriod::sequential_mint(&e, &owner)
+    });
+
+    e.ledger().set_sequence_number(150);
+    e.as_contract(&address, || {
+        HoldingPeriod::transfer(&e, &owner, &recipient, token_id);
+        assert_eq!(Base::owner_of(&e, token_id), recipient);
+        assert_eq!(HoldingPeriod::acquired_at(&e, token_id), Some(150));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #336)")]
+fn resale_before_period_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.ledger().set_sequence_number(100);
+    let token_id = e.as_contract(&address, || {
+        HoldingPeriod::set_min_holding_period(&e, 50);
+        HoldingPeriod::sequential_mint(&e, &owner)
+    });
+
+    e.ledger().set_sequence_number(200);
+    e.as_contract(&address, || {
+        HoldingPeriod::transfer(&e, &owner, &buyer, token_id);
+        Base::approve(&e, &buyer, &spender, token_id, 1000);
+    });
+
+    // the purchase restarted the holding period
+    e.ledger().set_sequence_number(220);
+    e.as_contract(&address, || {
+        HoldingPeriod::transfer_from(&e, &spender, &buyer, &recipient, token_id);
+    });
+}
+
+#[test]
+fn resale_after_period_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.ledger().set_sequence_number(100);
+    let token_id = e.as_contract(&address, || {
+        HoldingPeriod::set_min_holding_period(&e, 50);
+        HoldingPeriod::sequential_mint(&e, &owner)
+    });
+
+    e.ledger().set_sequence_number(200);
+    e.as_contract(&address, || {
+        HoldingPeriod::transfer(&e, &owner, &buyer, token_id);
+        Base::approve(&e, &buyer, &spender, token_id, 1000);
+    });
+
+    e.ledger().set_sequence_number(250);
+    e.as_contract(&address, || {
+        HoldingPeriod::transfer_from(&e, &spender, &buyer, &recipient, token_id);
+        assert_eq!(Base::owner_of(&e, token_id), recipient);
+        assert_eq!(HoldingPeriod::acquired_at(&e, token_id), Some(250));
+    });
+}
+
+#[test]
+fn transfer_without_recorded_acquisition_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        HoldingPeriod::set_min_holding_period(&e, 50);
+        let token_id = Base::sequential_mint(&e, &owner);
+        assert_eq!(HoldingPeriod::acquired_at(&e, token_id), None);
+
+        HoldingPeriod::transfer(&e, &owner, &recipient, token_id);
+        assert_eq!(Base::owner_of(&e, token_id), recipient);
+        assert_eq!(HoldingPeriod::acquired_at(&e, token_id), Some(e.ledger().sequence()));
+    });
+}
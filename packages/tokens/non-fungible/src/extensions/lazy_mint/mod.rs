@@ -0,0 +1,27 @@
+//! # Lazy Minting Extension for Non-Fungible Token
+//!
+//! Marketplaces commonly support lazy minting: instead of minting upfront, the
+//! creator signs a mint voucher off-chain and the token is only minted on its
+//! first sale, when the voucher is submitted on-chain.
+//!
+//! The voucher is an ed25519 signature from the configured signer over the
+//! payload returned by [`crate::Base::lazy_mint_payload`], which binds the
+//! contract address, the recipient, the `token_id`, its URI and a deadline
+//! ledger after which the voucher can no longer be redeemed. A voucher is
+//! marked as redeemed once used, so it cannot be replayed, even after the
+//! token is burned.
+//!
+//! ## Usage
+//!
+//! The signer's public key must be configured with
+//! [`crate::Base::set_lazy_mint_signer`], ideally in the constructor. Then,
+//! [`crate::Base::lazy_mint`] can be exposed as an entry-point: it doesn't
+//! require any further authorization, as the signature authorizes the mint.
+//!
+//! Lazy minting is meant for the `Base` contract type only, as it doesn't do
+//! the bookkeeping of `Enumerable` or `Consecutive`. The signed `token_id`s
+//! must not overlap with the ones handed out by sequential minting.
+mod storage;
+pub use storage::StorageKey;
+
+mod test;
@@ -0,0 +1,143 @@
+use soroban_sdk::{
+    contracttype, panic_with_error, xdr::ToXdr, Address, Bytes, BytesN, Env, String,
+};
+
+use crate::{Base, NonFungibleTokenError, StorageKey as BaseStorageKey, TokenId};
+
+/// Storage keys for the data associated with the lazy minting extension.
+#[contracttype]
+pub enum StorageKey {
+    Signer,
+    /// Marks a redeemed voucher, by the hash of its signed payload.
+    Redeemed(BytesN<32>),
+}
+
+impl Base {
+    /// Returns the ed25519 public key whose signatures authorize lazy mints.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::LazyMintSignerNotSet`] - If no signer has
+    ///   been configured.
+    pub fn lazy_mint_signer(e: &Env) -> BytesN<32> {
+        e.storage()
+            .instance()
+            .get(&StorageKey::Signer)
+            .unwrap_or_else(|| panic_with_error!(e, NonFungibleTokenError::LazyMintSignerNotSet))
+    }
+
+    /// Returns the payload that must be signed to authorize a lazy mint. It is
+    /// the XDR encoding of the tuple `(contract_address, to, token_id, uri,
+    /// deadline)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the token.
+    /// * `token_id` - The identifier of the token to mint.
+    /// * `uri` - The URI of the token.
+    /// * `deadline` - The last ledger number at which the mint can happen.
+    pub fn lazy_mint_payload(
+        e: &Env,
+        to: &Address,
+        token_id: TokenId,
+        uri: &String,
+        deadline: u32,
+    ) -> Bytes {
+        (e.current_contract_address(), to.clone(), token_id, uri.clone(), deadline).to_xdr(e)
+    }
+
+    /// Sets the ed25519 public key whose signatures authorize lazy mints.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `public_key` - The ed25519 public key of the creator.
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used internally or in trusted contexts. We recommend calling it
+    /// in the constructor of your smart contract.
+    pub fn set_lazy_mint_signer(e: &Env, public_key: &BytesN<32>) {
+        e.storage().instance().set(&StorageKey::Signer, public_key);
+    }
+
+    /// Mints `token_id` to `to` and sets its URI, provided the configured
+    /// signer authorized it before `deadline`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the token.
+    /// * `token_id` - The identifier of the token to mint.
+    /// * `uri` - The URI of the token.
+    /// * `deadline` - The last ledger number at which the mint can happen.
+    /// * `signature` - The ed25519 signature of the signer over the payload
+    ///   returned by [`Base::lazy_mint_payload`].
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::LazyMintExpired`] - If the current ledger
+    ///   number is greater than `deadline`.
+    /// * [`NonFungibleTokenError::LazyMintVoucherUsed`] - If the voucher has
+    ///   already been redeemed, even if the token was burned since.
+    /// * [`NonFungibleTokenError::TokenIDInUse`] - If `token_id` has already
+    ///   been minted.
+    /// * refer to [`Base::lazy_mint_signer`] errors.
+    /// * refer to [`Base::mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * The function traps if `signature` is not a valid signature of the
+    ///   signer over the payload.
+    /// * No authorization is required, as the signature authorizes the mint.
+    /// * The token is minted with [`Base::mint`], so this function is meant for
+    ///   the `Base` contract type only: it skips the bookkeeping of
+    ///   `Enumerable` and `Consecutive`. Moreover, [`Base::mint`] doesn't check
+    ///   the sequential counter, so the signed `token_id`s must not overlap
+    ///   with the ones handed out by [`Base::sequential_mint`], e.g. by not
+    ///   using sequential minting in the same collection.
+    pub fn lazy_mint(
+        e: &Env,
+        to: &Address,
+        token_id: TokenId,
+        uri: String,
+        deadline: u32,
+        signature: &BytesN<64>,
+    ) {
+        if e.ledger().sequence() > deadline {
+            panic_with_error!(e, NonFungibleTokenError::LazyMintExpired);
+        }
+
+        if e.storage().persistent().has(&BaseStorageKey::Owner(token_id)) {
+            panic_with_error!(e, NonFungibleTokenError::TokenIDInUse);
+        }
+
+        let signer = Base::lazy_mint_signer(e);
+        let payload = Base::lazy_mint_payload(e, to, token_id, &uri, deadline);
+        let key = StorageKey::Redeemed(e.crypto().sha256(&payload).to_bytes());
+        if e.storage().persistent().has(&key) {
+            panic_with_error!(e, NonFungibleTokenError::LazyMintVoucherUsed);
+        }
+        e.crypto().ed25519_verify(&signer, &payload, signature);
+
+        // the mark is only needed until the deadline, after which the voucher
+        // is rejected anyway
+        e.storage().persistent().set(&key, &());
+        let live_for = deadline - e.ledger().sequence();
+        e.storage().persistent().extend_ttl(&key, live_for, live_for);
+
+        Base::mint(e, to, token_id);
+        Base::set_token_uri(e, token_id, uri);
+    }
+}
@@ -0,0 +1,178 @@
+#![cfg(test)]
+
+extern crate std;
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger as _},
+    Address, Bytes, BytesN, Env, String,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{Base, TokenId};
+
+#[contract]
+struct MockContract;
+
+fn sign(e: &Env, signer: &SigningKey, payload: &Bytes) -> BytesN<64> {
+    let payload: std::vec::Vec<u8> = payload.iter().collect();
+    BytesN::from_array(e, &signer.sign(&payload).to_bytes())
+}
+
+fn setup(e: &Env) -> (Address, SigningKey) {
+    let address = e.register(MockContract, ());
+    let signer = SigningKey::from_bytes(&[7u8; 32]);
+
+    e.as_contract(&address, || {
+        Base::set_metadata(
+            e,
+            String::from_str(e, "https://smth.com/"),
+            String::from_str(e, "My NFT collection"),
+            String::from_str(e, "NFT"),
+        );
+        Base::set_lazy_mint_signer(e, &BytesN::from_array(e, &signer.verifying_key().to_bytes()));
+    });
+
+    (address, signer)
+}
+
+fn voucher(
+    e: &Env,
+    address: &Address,
+    signer: &SigningKey,
+    to: &Address,
+    token_id: TokenId,
+    uri: &String,
+    deadline: u32,
+) -> BytesN<64> {
+    e.as_contract(address, || {
+        let payload = Base::lazy_mint_payload(e, to, token_id, uri, deadline);
+        sign(e, signer, &payload)
+    })
+}
+
+#[test]
+fn lazy_mint_works() {
+    let e = Env::default();
+    let (address, signer) = setup(&e);
+    let buyer = Address::generate(&e);
+    let token_id = 42;
+    let uri =
+        String::from_str(&e, "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi");
+    let signature = voucher(&e, &address, &signer, &buyer, token_id, &uri, 100);
+
+    e.as_contract(&address, || {
+        Base::lazy_mint(&e, &buyer, token_id, uri.clone(), 100, &signature);
+
+        assert_eq!(Base::owner_of(&e, token_id), buyer);
+        assert_eq!(Base::balance(&e, &buyer), 1);
+        assert_eq!(Base::token_uri(&e, token_id), uri);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_non_fungible_mint(&buyer, token_id);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #314)")]
+fn lazy_mint_expired_fails() {
+    let e = Env::default();
+    let (address, signer) = setup(&e);
+    let buyer = Address::generate(&e);
+    let uri = String::from_str(&e, "ipfs://token");
+    let signature = voucher(&e, &address, &signer, &buyer, 1, &uri, 100);
+
+    e.ledger().set_sequence_number(101);
+    e.as_contract(&address, || {
+        Base::lazy_mint(&e, &buyer, 1, uri, 100, &signature);
+    });
+}
+
+#[test]
+#[should_panic]
+fn lazy_mint_forged_signature_fails() {
+    let e = Env::default();
+    let (address, _) = setup(&e);
+    let forger = SigningKey::from_bytes(&[8u8; 32]);
+    let buyer = Address::generate(&e);
+    let uri = String::from_str(&e, "ipfs://token");
+    let signature = voucher(&e, &address, &forger, &buyer, 1, &uri, 100);
+
+    e.as_contract(&address, || {
+        Base::lazy_mint(&e, &buyer, 1, uri, 100, &signature);
+    });
+}
+
+#[test]
+#[should_panic]
+fn lazy_mint_tampered_recipient_fails() {
+    let e = Env::default();
+    let (address, signer) = setup(&e);
+    let buyer = Address::generate(&e);
+    let attacker = Address::generate(&e);
+    let uri = String::from_str(&e, "ipfs://token");
+    let signature = voucher(&e, &address, &signer, &buyer, 1, &uri, 100);
+
+    e.as_contract(&address, || {
+        Base::lazy_mint(&e, &attacker, 1, uri, 100, &signature);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #307)")]
+fn lazy_mint_replay_fails() {
+    let e = Env::default();
+    let (address, signer) = setup(&e);
+    let buyer = Address::generate(&e);
+    let uri = String::from_str(&e, "ipfs://token");
+    let signature = voucher(&e, &address, &signer, &buyer, 1, &uri, 100);
+
+    e.as_contract(&address, || {
+        Base::lazy_mint(&e, &buyer, 1, uri.clone(), 100, &signature);
+        Base::lazy_mint(&e, &buyer, 1, uri, 100, &signature);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #341)")]
+fn lazy_mint_replay_after_burn_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (address, signer) = setup(&e);
+    let buyer = Address::generate(&e);
+    let uri = String::from_str(&e, "ipfs://token");
+    let signature = voucher(&e, &address, &signer, &buyer, 1, &uri, 100);
+
+    e.as_contract(&address, || {
+        Base::lazy_mint(&e, &buyer, 1, uri.clone(), 100, &signature);
+    });
+
+    e.as_contract(&address, || {
+        Base::burn(&e, &buyer, 1);
+    });
+
+    e.as_contract(&address, || {
+        Base::lazy_mint(&e, &buyer, 1, uri, 100, &signature);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #315)")]
+fn lazy_mint_without_signer_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let buyer = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::lazy_mint(
+            &e,
+            &buyer,
+            1,
+            String::from_str(&e, "ipfs://token"),
+            100,
+            &BytesN::from_array(&e, &[0u8; 64]),
+        );
+    });
+}
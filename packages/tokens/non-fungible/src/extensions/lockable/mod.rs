@@ -0,0 +1,77 @@
+//! # Lockable Extension for Non-Fungible Token
+//!
+//! The `lockable` module allows freezing individual tokens. While a token is
+//! locked, it cannot be transferred nor burned, and no new approvals can be
+//! granted for it. Operator approvals (`approve_for_all`) are not bound to a
+//! single token and are therefore never affected by locks: they can be granted
+//! and, most importantly, revoked at any time.
+//!
+//! ## Usage
+//!
+//! The extension doesn't decide who is allowed to lock or unlock a token. The
+//! functions [`Lockable::lock`] and [`Lockable::unlock`] don't handle
+//! authorization, so the implementor is expected to wrap them with the
+//! appropriate checks (e.g. only the owner of the token, an admin or a staking
+//! contract).
+pub mod storage;
+use soroban_sdk::{symbol_short, Env};
+pub use storage::Lockable;
+
+use crate::{NonFungibleToken, TokenId};
+
+/// Lockable Trait for Non-Fungible Token
+///
+/// The `NonFungibleLockable` trait extends the `NonFungibleToken` trait to
+/// expose whether a token is currently locked.
+///
+/// # Notes
+///
+/// `Lockable` overrides `transfer`, `transfer_from` and `approve` of the
+/// base implementation, as well as `burn` and `burn_from` of the `Burnable`
+/// extension. Therefore, this trait is INCOMPATIBLE with the `Enumerable` and
+/// `Consecutive` extensions.
+pub trait NonFungibleLockable: NonFungibleToken<ContractType = Lockable> {
+    /// Returns `true` if `token_id` is locked, `false` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    fn is_locked(e: &Env, token_id: TokenId) -> bool;
+}
+
+mod test;
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating a token has been locked.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `token_id` - The identifier of the locked token.
+///
+/// # Events
+///
+/// * topics - `["lock", token_id: TokenId]`
+/// * data - `[]`
+pub fn emit_lock(e: &Env, token_id: TokenId) {
+    let topics = (symbol_short!("lock"), token_id);
+    e.events().publish(topics, ())
+}
+
+/// Emits an event indicating a token has been unlocked.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `token_id` - The identifier of the unlocked token.
+///
+/// # Events
+///
+/// * topics - `["unlock", token_id: TokenId]`
+/// * data - `[]`
+pub fn emit_unlock(e: &Env, token_id: TokenId) {
+    let topics = (symbol_short!("unlock"), token_id);
+    e.events().publish(topics, ())
+}
@@ -0,0 +1,295 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+use stellar_constants::{TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD};
+
+use crate::{
+    burnable::BurnableContractType,
+    extensions::lockable::{emit_lock, emit_unlock},
+    Base, ContractOverrides, NonFungibleTokenError, TokenId,
+};
+
+pub struct Lockable;
+
+impl ContractOverrides for Lockable {
+    fn transfer(e: &Env, from: &Address, to: &Address, token_id: TokenId) {
+        Lockable::transfer(e, from, to, token_id);
+    }
+
+    fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, token_id: TokenId) {
+        Lockable::transfer_from(e, spender, from, to, token_id);
+    }
+
+    fn approve(
+        e: &Env,
+        approver: &Address,
+        approved: &Address,
+        token_id: TokenId,
+        live_until_ledger: u32,
+    ) {
+        Lockable::approve(e, approver, approved, token_id, live_until_ledger);
+    }
+
+    // NOTE: `approve_for_all` is intentionally not overridden. Operator
+    // approvals span all the tokens of an owner, so granting and revoking them
+    // must remain possible regardless of individual token locks.
+}
+
+impl BurnableContractType for Lockable {
+    fn burn(e: &Env, from: &Address, token_id: TokenId) {
+        Lockable::burn(e, from, token_id);
+    }
+
+    fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: TokenId) {
+        Lockable::burn_from(e, spender, from, token_id);
+    }
+}
+
+/// Storage keys for the data associated with the lockable extension.
+#[contracttype]
+pub enum StorageKey {
+    Locked(TokenId),
+}
+
+impl Lockable {
+    // ################## QUERY STATE ##################
+
+    /// Returns `true` if `token_id` is locked, `false` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn is_locked(e: &Env, token_id: TokenId) -> bool {
+        let key = StorageKey::Locked(token_id);
+        if e.storage().persistent().has(&key) {
+            e.storage().persistent().extend_ttl(&key, TOKEN_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT);
+            true
+        } else {
+            false
+        }
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Locks `token_id`, preventing its transfer and approval until it is
+    /// unlocked.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token to lock.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::owner_of`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["lock", token_id: TokenId]`
+    /// * data - `[]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used internally or in trusted contexts. Ensure that the caller
+    /// is allowed to lock the token before calling it.
+    pub fn lock(e: &Env, token_id: TokenId) {
+        // ensures the token exists
+        Base::owner_of(e, token_id);
+
+        e.storage().persistent().set(&StorageKey::Locked(token_id), &true);
+        emit_lock(e, token_id);
+    }
+
+    /// Unlocks `token_id`. Unlocking a token that is not locked is a no-op,
+    /// but the event is still emitted.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token to unlock.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["unlock", token_id: TokenId]`
+    /// * data - `[]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used internally or in trusted contexts. Ensure that the caller
+    /// is allowed to unlock the token before calling it.
+    pub fn unlock(e: &Env, token_id: TokenId) {
+        e.storage().persistent().remove(&StorageKey::Locked(token_id));
+        emit_unlock(e, token_id);
+    }
+
+    /// Transfers a non-fungible token from `from` to `to`, provided the token
+    /// is not locked.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `token_id` - The identifier of the token being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Lockable::check_not_locked`] errors.
+    /// * refer to [`Base::transfer`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `from` is required.
+    pub fn transfer(e: &Env, from: &Address, to: &Address, token_id: TokenId) {
+        Lockable::check_not_locked(e, token_id);
+        Base::transfer(e, from, to, token_id);
+    }
+
+    /// Transfers a non-fungible token using `spender`s approval, provided the
+    /// token is not locked.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address attempting to transfer the token.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_id` - The identifier of the token being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Lockable::check_not_locked`] errors.
+    /// * refer to [`Base::transfer_from`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `spender` is required.
+    pub fn transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_id: TokenId,
+    ) {
+        Lockable::check_not_locked(e, token_id);
+        Base::transfer_from(e, spender, from, to, token_id);
+    }
+
+    /// Approves an address to transfer a specific token, provided the token is
+    /// not locked.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `approver` - The address of the approver (should be `owner` or
+    ///   `operator`).
+    /// * `approved` - The address receiving the approval.
+    /// * `token_id` - The identifier of the token to be approved.
+    /// * `live_until_ledger` - The ledger number at which the approval expires.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Lockable::check_not_locked`] errors.
+    /// * refer to [`Base::approve`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve", owner: Address, token_id: TokenId]`
+    /// * data - `[approved: Address, live_until_ledger: u32]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `approver` is required.
+    pub fn approve(
+        e: &Env,
+        approver: &Address,
+        approved: &Address,
+        token_id: TokenId,
+        live_until_ledger: u32,
+    ) {
+        Lockable::check_not_locked(e, token_id);
+        Base::approve(e, approver, approved, token_id, live_until_ledger);
+    }
+
+    /// Destroys `token_id` from `from`, provided the token is not locked.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The account whose token is destroyed.
+    /// * `token_id` - The token to burn.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Lockable::check_not_locked`] errors.
+    /// * refer to [`Base::burn`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `from` is required.
+    pub fn burn(e: &Env, from: &Address, token_id: TokenId) {
+        Lockable::check_not_locked(e, token_id);
+        Base::burn(e, from, token_id);
+    }
+
+    /// Destroys `token_id` from `from` using `spender`s approval, provided the
+    /// token is not locked.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The account that is allowed to burn the token on behalf of
+    ///   the owner.
+    /// * `from` - The account whose token is destroyed.
+    /// * `token_id` - The token to burn.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Lockable::check_not_locked`] errors.
+    /// * refer to [`Base::burn_from`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `spender` is required.
+    pub fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: TokenId) {
+        Lockable::check_not_locked(e, token_id);
+        Base::burn_from(e, spender, from, token_id);
+    }
+
+    /// Ensures `token_id` is not locked.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::TokenLocked`] - If the token is locked.
+    pub fn check_not_locked(e: &Env, token_id: TokenId) {
+        if Lockable::is_locked(e, token_id) {
+            panic_with_error!(e, NonFungibleTokenError::TokenLocked);
+        }
+    }
+}
@@ -0,0 +1,208 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger as _},
+    Address, Bytes, Env,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{burnable::BurnableContractType, lockable::Lockable, Base, ContractOverrides};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn lock_and_unlock_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        assert!(!Lockable::is_locked(&e, token_id));
+
+        Lockable::lock(&e, token_id);
+        assert!(Lockable::is_locked(&e, token_id));
+
+        Lockable::unlock(&e, token_id);
+        assert!(!Lockable::is_locked(&e, token_id));
+
+        Lockable::transfer(&e, &owner, &recipient, token_id);
+        assert_eq!(Base::owner_of(&e, token_id), recipient);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        // mint + lock + unlock + transfer
+        event_assert.assert_event_count(4);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #300)")]
+fn lock_nonexistent_token_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Lockable::lock(&e, 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #312)")]
+fn transfer_locked_token_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Lockable::lock(&e, token_id);
+
+        Lockable::transfer(&e, &owner, &recipient, token_id);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #312)")]
+fn transfer_from_locked_token_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::approve_for_all(&e, &owner, &operator, 1000);
+        Lockable::lock(&e, token_id);
+
+        Lockable::transfer_from(&e, &operator, &owner, &recipient, token_id);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #312)")]
+fn safe_transfer_locked_token_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Lockable::lock(&e, token_id);
+
+        Lockable::safe_transfer(&e, &owner, &recipient, token_id, Bytes::new(&e));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #312)")]
+fn safe_transfer_from_locked_token_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::approve_for_all(&e, &owner, &operator, 1000);
+        Lockable::lock(&e, token_id);
+
+        Lockable::safe_transfer_from(&e, &operator, &owner, &recipient, token_id, Bytes::new(&e));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #312)")]
+fn burn_locked_token_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Lockable::lock(&e, token_id);
+
+        Lockable::burn(&e, &owner, token_id);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #312)")]
+fn burn_from_locked_token_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::approve(&e, &owner, &spender, token_id, 1000);
+        Lockable::lock(&e, token_id);
+        token_id
+    });
+
+    e.as_contract(&address, || {
+        <Lockable as BurnableContractType>::burn_from(&e, &spender, &owner, token_id)
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #312)")]
+fn approve_locked_token_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let approved = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Lockable::lock(&e, token_id);
+
+        Lockable::approve(&e, &owner, &approved, token_id, 1000);
+    });
+}
+
+#[test]
+fn revoke_approve_for_all_with_locked_token_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(100);
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::sequential_mint(&e, &owner);
+        Base::approve_for_all(&e, &owner, &operator, 1000);
+        assert!(Base::is_approved_for_all(&e, &owner, &operator));
+    });
+
+    e.as_contract(&address, || {
+        let token_id = 0;
+        Lockable::lock(&e, token_id);
+
+        // revoking operator approvals is always allowed
+        Base::approve_for_all(&e, &owner, &operator, 0);
+        assert!(!Base::is_approved_for_all(&e, &owner, &operator));
+        assert!(Lockable::is_locked(&e, token_id));
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_approve_for_all(&owner, &operator, 0);
+    });
+}
@@ -0,0 +1,41 @@
+//! # Metadata Extension for Non-Fungible Token
+//!
+//! Marketplaces commonly display the traits of a token next to its image.
+//! This extension attaches a map of attributes to each token, e.g.
+//! `background => "blue"`, readable one by one with
+//! [`crate::Base::get_token_attribute`] or at once with
+//! [`crate::Base::token_attributes`].
+//!
+//! The extension doesn't decide who is allowed to set attributes:
+//! [`crate::Base::set_token_attribute`] doesn't handle authorization, so the
+//! implementor is expected to wrap it with the appropriate checks (e.g. only
+//! an admin or the creator of the collection). The attributes of a token are
+//! not cleared by burns: contracts setting them are expected to call
+//! [`crate::Base::remove_token_attributes`] when burning a token.
+mod storage;
+use soroban_sdk::{Env, String, Symbol};
+pub use storage::StorageKey;
+
+use crate::TokenId;
+
+mod test;
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating an attribute of a token has been set.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token_id` - The identifier of the token.
+/// * `key` - The name of the attribute.
+/// * `value` - The new value of the attribute.
+///
+/// # Events
+///
+/// * topics - `["metadata_update", token_id: TokenId]`
+/// * data - `[key: Symbol, value: String]`
+pub fn emit_metadata_update(e: &Env, token_id: TokenId, key: &Symbol, value: &String) {
+    let topics = (Symbol::new(e, "metadata_update"), token_id);
+    e.events().publish(topics, (key, value))
+}
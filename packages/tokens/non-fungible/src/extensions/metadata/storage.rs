@@ -0,0 +1,93 @@
+use soroban_sdk::{contracttype, Env, Map, String, Symbol};
+use stellar_constants::{TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD};
+
+use crate::{metadata::emit_metadata_update, Base, TokenId};
+
+/// Storage keys for the data associated with the metadata extension.
+#[contracttype]
+pub enum StorageKey {
+    TokenAttributes(TokenId),
+}
+
+impl Base {
+    /// Returns the value of the attribute `key` of `token_id`, or `None` if
+    /// the attribute is not set.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `key` - The name of the attribute.
+    pub fn get_token_attribute(e: &Env, token_id: TokenId, key: Symbol) -> Option<String> {
+        Base::token_attributes(e, token_id).get(key)
+    }
+
+    /// Returns all the attributes of `token_id`, which is empty if no
+    /// attribute is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn token_attributes(e: &Env, token_id: TokenId) -> Map<Symbol, String> {
+        let key = StorageKey::TokenAttributes(token_id);
+        if let Some(attributes) = e.storage().persistent().get(&key) {
+            e.storage().persistent().extend_ttl(&key, TOKEN_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT);
+            attributes
+        } else {
+            Map::new(e)
+        }
+    }
+
+    /// Sets the attribute `key` of `token_id` to `value`, overwriting its
+    /// previous value, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `key` - The name of the attribute.
+    /// * `value` - The value of the attribute.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::owner_of`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["metadata_update", token_id: TokenId]`
+    /// * data - `[key: Symbol, value: String]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used internally or in trusted contexts.
+    pub fn set_token_attribute(e: &Env, token_id: TokenId, key: Symbol, value: String) {
+        // ensure the token exists
+        Base::owner_of(e, token_id);
+
+        let mut attributes = Base::token_attributes(e, token_id);
+        attributes.set(key.clone(), value.clone());
+        e.storage().persistent().set(&StorageKey::TokenAttributes(token_id), &attributes);
+
+        emit_metadata_update(e, token_id, &key, &value);
+    }
+
+    /// Low-level function for removing all the attributes of `token_id`,
+    /// without handling authorization. Burns don't clear attributes, so
+    /// contracts setting them are expected to call this function in their
+    /// `burn` and `burn_from`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used internally or in trusted contexts.
+    pub fn remove_token_attributes(e: &Env, token_id: TokenId) {
+        e.storage().persistent().remove(&StorageKey::TokenAttributes(token_id));
+    }
+}
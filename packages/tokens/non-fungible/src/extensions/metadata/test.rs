@@ -0,0 +1,74 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, map, symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_event_assertion::EventAssertion;
+
+use crate::Base;
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn set_token_attributes_and_overwrite() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1);
+        assert_eq!(Base::token_attributes(&e, 1), map![&e]);
+        assert_eq!(Base::get_token_attribute(&e, 1, symbol_short!("color")), None);
+
+        Base::set_token_attribute(&e, 1, symbol_short!("color"), String::from_str(&e, "blue"));
+        Base::set_token_attribute(&e, 1, symbol_short!("eyes"), String::from_str(&e, "green"));
+        Base::set_token_attribute(&e, 1, symbol_short!("color"), String::from_str(&e, "red"));
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(4);
+
+        assert_eq!(
+            Base::get_token_attribute(&e, 1, symbol_short!("color")),
+            Some(String::from_str(&e, "red"))
+        );
+        assert_eq!(
+            Base::token_attributes(&e, 1),
+            map![
+                &e,
+                (symbol_short!("color"), String::from_str(&e, "red")),
+                (symbol_short!("eyes"), String::from_str(&e, "green"))
+            ]
+        );
+        // attributes are per token
+        assert_eq!(Base::token_attributes(&e, 2), map![&e]);
+    });
+}
+
+#[test]
+fn remove_token_attributes_on_burn_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1);
+        Base::set_token_attribute(&e, 1, symbol_short!("color"), String::from_str(&e, "blue"));
+
+        Base::update(&e, Some(&owner), None, 1);
+        Base::remove_token_attributes(&e, 1);
+
+        assert_eq!(Base::token_attributes(&e, 1), map![&e]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #300)")]
+fn set_attribute_of_nonexistent_token_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::set_token_attribute(&e, 1, symbol_short!("color"), String::from_str(&e, "blue"));
+    });
+}
@@ -1,3 +1,15 @@
 pub mod burnable;
+pub mod capped;
+pub mod commit_reveal;
 pub mod consecutive;
 pub mod enumerable;
+pub mod fractional;
+pub mod holding_period;
+pub mod lazy_mint;
+pub mod lockable;
+pub mod metadata;
+pub mod permit;
+pub mod rental;
+pub mod royalty;
+pub mod soulbound;
+pub mod staking;
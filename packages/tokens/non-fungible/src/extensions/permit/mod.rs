@@ -0,0 +1,23 @@
+//! # Permit Extension for Non-Fungible Token
+//!
+//! Allows granting operator approvals without the owner submitting the
+//! transaction: the owner signs an operator permit off-chain, and anyone (e.g.
+//! a relayer or the operator itself) submits it on-chain with
+//! [`crate::Base::permit_for_all`].
+//!
+//! The permit is an ed25519 signature from the owner over the payload returned
+//! by [`crate::Base::permit_for_all_payload`], which binds the contract
+//! address, the owner, the operator, the expiration ledger of the approval and
+//! the owner's current nonce. Every permit consumes the nonce, so a permit
+//! cannot be replayed.
+//!
+//! ## Usage
+//!
+//! Only Stellar accounts (`G...` addresses) can sign permits, as their address
+//! is their ed25519 public key. [`crate::Base::permit_for_all`] can be exposed
+//! as an entry-point: it doesn't require any further authorization, as the
+//! signature authorizes the approval.
+mod storage;
+pub use storage::StorageKey;
+
+mod test;
@@ -0,0 +1,102 @@
+use soroban_sdk::{contracttype, panic_with_error, xdr::ToXdr, Address, Bytes, BytesN, Env};
+use stellar_crypto::signature::{account_public_key, verify_ed25519};
+
+use crate::{Base, NonFungibleTokenError};
+
+/// Storage keys for the data associated with the permit extension.
+#[contracttype]
+pub enum StorageKey {
+    Nonce(Address),
+}
+
+impl Base {
+    /// Returns the nonce the next permit of `owner` must be signed with.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address signing the permits.
+    pub fn permit_nonce(e: &Env, owner: &Address) -> u32 {
+        e.storage().persistent().get(&StorageKey::Nonce(owner.clone())).unwrap_or(0)
+    }
+
+    /// Returns the payload that must be signed to permit an operator approval.
+    /// It is the XDR encoding of the tuple `(contract_address, owner,
+    /// operator, live_until_ledger, nonce)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address granting approval for all their tokens.
+    /// * `operator` - The address being granted or revoked approval.
+    /// * `live_until_ledger` - The ledger number at which the approval expires.
+    /// * `nonce` - The nonce of `owner`.
+    pub fn permit_for_all_payload(
+        e: &Env,
+        owner: &Address,
+        operator: &Address,
+        live_until_ledger: u32,
+        nonce: u32,
+    ) -> Bytes {
+        (e.current_contract_address(), owner.clone(), operator.clone(), live_until_ledger, nonce)
+            .to_xdr(e)
+    }
+
+    /// Sets or removes the operator approval of `operator` over all the tokens
+    /// of `owner`, provided `owner` signed the corresponding permit.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address granting approval for all their tokens.
+    /// * `operator` - The address being granted or revoked approval.
+    /// * `live_until_ledger` - The ledger number at which the approval expires.
+    ///   If `live_until_ledger` is `0`, the approval is revoked.
+    /// * `nonce` - The current nonce of `owner`, see [`Base::permit_nonce`].
+    /// * `signature` - The ed25519 signature of `owner` over the payload
+    ///   returned by [`Base::permit_for_all_payload`].
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidPermitNonce`] - If `nonce` is not the
+    ///   current nonce of `owner`.
+    /// * [`NonFungibleTokenError::InvalidPermitSigner`] - If `owner` is not a
+    ///   Stellar account.
+    /// * refer to [`Base::set_approval_for_all`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve", owner: Address]`
+    /// * data - `[operator: Address, live_until_ledger: u32]`
+    ///
+    /// # Notes
+    ///
+    /// * The function traps if `signature` is not a valid signature of `owner`
+    ///   over the payload.
+    /// * No authorization is required, as the signature authorizes the
+    ///   approval.
+    pub fn permit_for_all(
+        e: &Env,
+        owner: &Address,
+        operator: &Address,
+        live_until_ledger: u32,
+        nonce: u32,
+        signature: &BytesN<64>,
+    ) {
+        let current_nonce = Base::permit_nonce(e, owner);
+        if nonce != current_nonce {
+            panic_with_error!(e, NonFungibleTokenError::InvalidPermitNonce);
+        }
+
+        let public_key = account_public_key(e, owner)
+            .unwrap_or_else(|| panic_with_error!(e, NonFungibleTokenError::InvalidPermitSigner));
+        let payload = Base::permit_for_all_payload(e, owner, operator, live_until_ledger, nonce);
+        verify_ed25519(e, &public_key, &payload, signature);
+
+        // NOTE: can't realistically overflow, as every permit must be signed
+        // by the owner.
+        e.storage().persistent().set(&StorageKey::Nonce(owner.clone()), &(current_nonce + 1));
+
+        Base::set_approval_for_all(e, owner, operator, live_until_ledger);
+    }
+}
@@ -0,0 +1,110 @@
+#![cfg(test)]
+
+extern crate std;
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    contract,
+    testutils::Address as _,
+    xdr::{AccountId, PublicKey, ScAddress, Uint256},
+    Address, BytesN, Env, TryFromVal,
+};
+
+use crate::{ApprovalForAllData, Base, StorageKey};
+
+#[contract]
+struct MockContract;
+
+fn account(e: &Env, signer: &SigningKey) -> Address {
+    let public_key = PublicKey::PublicKeyTypeEd25519(Uint256(signer.verifying_key().to_bytes()));
+    Address::try_from_val(e, &ScAddress::Account(AccountId(public_key))).unwrap()
+}
+
+fn permit(
+    e: &Env,
+    address: &Address,
+    signer: &SigningKey,
+    owner: &Address,
+    operator: &Address,
+    live_until_ledger: u32,
+    nonce: u32,
+) -> BytesN<64> {
+    e.as_contract(address, || {
+        let payload = Base::permit_for_all_payload(e, owner, operator, live_until_ledger, nonce);
+        let payload: std::vec::Vec<u8> = payload.iter().collect();
+        BytesN::from_array(e, &signer.sign(&payload).to_bytes())
+    })
+}
+
+fn is_operator(e: &Env, owner: &Address, operator: &Address) -> bool {
+    e.storage()
+        .temporary()
+        .get::<_, ApprovalForAllData>(&StorageKey::ApprovalForAll(owner.clone()))
+        .is_some_and(|data| data.operators.contains_key(operator.clone()))
+}
+
+#[test]
+fn permit_for_all_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let signer = SigningKey::from_bytes(&[7u8; 32]);
+    let owner = account(&e, &signer);
+    let operator = Address::generate(&e);
+    let signature = permit(&e, &address, &signer, &owner, &operator, 1000, 0);
+
+    e.as_contract(&address, || {
+        Base::permit_for_all(&e, &owner, &operator, 1000, 0, &signature);
+
+        assert!(is_operator(&e, &owner, &operator));
+        assert_eq!(Base::permit_nonce(&e, &owner), 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #318)")]
+fn permit_for_all_with_replayed_nonce_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let signer = SigningKey::from_bytes(&[7u8; 32]);
+    let owner = account(&e, &signer);
+    let operator = Address::generate(&e);
+    let signature = permit(&e, &address, &signer, &owner, &operator, 1000, 0);
+
+    e.as_contract(&address, || {
+        Base::permit_for_all(&e, &owner, &operator, 1000, 0, &signature);
+    });
+
+    e.as_contract(&address, || {
+        Base::permit_for_all(&e, &owner, &operator, 1000, 0, &signature);
+    });
+}
+
+#[test]
+#[should_panic]
+fn permit_for_all_with_forged_signature_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let signer = SigningKey::from_bytes(&[7u8; 32]);
+    let forger = SigningKey::from_bytes(&[9u8; 32]);
+    let owner = account(&e, &signer);
+    let operator = Address::generate(&e);
+    let signature = permit(&e, &address, &forger, &owner, &operator, 1000, 0);
+
+    e.as_contract(&address, || {
+        Base::permit_for_all(&e, &owner, &operator, 1000, 0, &signature);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #319)")]
+fn permit_for_all_from_contract_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let signer = SigningKey::from_bytes(&[7u8; 32]);
+    let operator = Address::generate(&e);
+    let signature = permit(&e, &address, &signer, &address, &operator, 1000, 0);
+
+    e.as_contract(&address, || {
+        Base::permit_for_all(&e, &address, &operator, 1000, 0, &signature);
+    });
+}
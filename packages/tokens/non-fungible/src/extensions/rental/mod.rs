@@ -0,0 +1,79 @@
+//! # Rental Extension for Non-Fungible Token
+//!
+//! Some use cases, e.g. GameFi, need to delegate the usage of a token without
+//! transferring its ownership. Inspired by ERC-4907, this extension assigns a
+//! *user* to a token until a given ledger, after which the user is
+//! automatically considered unset.
+//!
+//! The owner of a token, or an address approved for it, assigns a user with
+//! [`crate::Base::set_user`]. Alternatively, they list the token for rental
+//! with [`crate::Base::set_rental_terms`]: the accepted payment token, the
+//! price per ledger and the maximum duration. Anyone can then rent the token
+//! with [`crate::Base::rent`] on these terms, which pays the owner of the
+//! token and assigns the renter as its user atomically: if the payment fails,
+//! the rental reverts.
+//!
+//! The contract must use [`Rentable`] as its `ContractType`, which clears the
+//! user and the rental terms whenever the token is transferred or burned, so
+//! that a new owner never inherits the rentals granted by the previous one.
+mod storage;
+use soroban_sdk::{Address, Env, Symbol};
+pub use storage::{Rentable, RentalTerms, StorageKey, UserInfo};
+
+use crate::TokenId;
+
+mod test;
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating the user of a token has changed.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token_id` - The identifier of the token.
+/// * `user` - The new user of the token.
+/// * `expires` - The ledger number until which `user` is the user.
+///
+/// # Events
+///
+/// * topics - `["update_user", token_id: TokenId]`
+/// * data - `[user: Address, expires: u32]`
+pub fn emit_update_user(e: &Env, token_id: TokenId, user: &Address, expires: u32) {
+    let topics = (Symbol::new(e, "update_user"), token_id);
+    e.events().publish(topics, (user, expires))
+}
+
+/// Emits an event indicating the rental terms of a token have changed.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token_id` - The identifier of the token.
+/// * `terms` - The new rental terms of the token.
+///
+/// # Events
+///
+/// * topics - `["rental_terms", token_id: TokenId]`
+/// * data - `[payment_token: Address, price_per_ledger: i128, max_duration:
+///   u32]`
+pub fn emit_rental_terms(e: &Env, token_id: TokenId, terms: &RentalTerms) {
+    let topics = (Symbol::new(e, "rental_terms"), token_id);
+    e.events().publish(topics, (&terms.payment_token, terms.price_per_ledger, terms.max_duration))
+}
+
+/// Emits an event indicating the rental terms of a token were cancelled.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token_id` - The identifier of the token.
+///
+/// # Events
+///
+/// * topics - `["rental_cancelled", token_id: TokenId]`
+/// * data - `[]`
+pub fn emit_rental_cancelled(e: &Env, token_id: TokenId) {
+    let topics = (Symbol::new(e, "rental_cancelled"), token_id);
+    e.events().publish(topics, ())
+}
@@ -0,0 +1,488 @@
+use soroban_sdk::{contracttype, panic_with_error, token, Address, Env};
+
+use crate::{
+    burnable::BurnableContractType,
+    rental::{emit_rental_cancelled, emit_rental_terms, emit_update_user},
+    Base, ContractOverrides, NonFungibleTokenError, TokenId,
+};
+
+pub struct Rentable;
+
+impl ContractOverrides for Rentable {
+    fn transfer(e: &Env, from: &Address, to: &Address, token_id: TokenId) {
+        Rentable::transfer(e, from, to, token_id);
+    }
+
+    fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, token_id: TokenId) {
+        Rentable::transfer_from(e, spender, from, to, token_id);
+    }
+}
+
+impl BurnableContractType for Rentable {
+    fn burn(e: &Env, from: &Address, token_id: TokenId) {
+        Rentable::burn(e, from, token_id);
+    }
+
+    fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: TokenId) {
+        Rentable::burn_from(e, spender, from, token_id);
+    }
+}
+
+/// Storage container for the user of a token and the ledger number until
+/// which it is the user.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserInfo {
+    pub user: Address,
+    pub expires: u32,
+    /// Whether `user` paid for the rental through [`Base::rent`], in which
+    /// case it can't be replaced before the rental expires.
+    pub rented: bool,
+}
+
+/// The terms on which a token can be rented, set by its owner.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentalTerms {
+    /// The only token the rent can be paid with.
+    pub payment_token: Address,
+    /// The rent per ledger of rental, in `payment_token`.
+    pub price_per_ledger: i128,
+    /// The maximum number of ledgers a single rental can last.
+    pub max_duration: u32,
+}
+
+/// Storage keys for the data associated with the rental extension.
+#[contracttype]
+pub enum StorageKey {
+    UserInfo(TokenId),
+    RentalTerms(TokenId),
+}
+
+impl Base {
+    /// Returns the user of `token_id`, or `None` if the token has no user or
+    /// if the rental expired.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn user_of(e: &Env, token_id: TokenId) -> Option<Address> {
+        e.storage()
+            .temporary()
+            .get::<_, UserInfo>(&StorageKey::UserInfo(token_id))
+            .filter(|info| info.expires >= e.ledger().sequence())
+            .map(|info| info.user)
+    }
+
+    /// Returns the rental terms of `token_id`, or `None` if the token is not
+    /// listed for rental.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn rental_terms(e: &Env, token_id: TokenId) -> Option<RentalTerms> {
+        e.storage().persistent().get(&StorageKey::RentalTerms(token_id))
+    }
+
+    /// Lists `token_id` for rental on `terms`, replacing its current terms,
+    /// if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `operator` - The address setting the terms, either the owner or an
+    ///   address approved for the token.
+    /// * `token_id` - The identifier of the token.
+    /// * `terms` - The terms on which the token can be rented.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidRentalTerms`] - If the price is
+    ///   negative or the maximum duration is `0`.
+    /// * refer to [`Base::owner_of`] errors.
+    /// * refer to [`Base::check_spender_approval`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["rental_terms", token_id: TokenId]`
+    /// * data - `[payment_token: Address, price_per_ledger: i128, max_duration:
+    ///   u32]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `operator` is required.
+    /// * The terms are cleared when the token is transferred or burned through
+    ///   [`Rentable`].
+    pub fn set_rental_terms(e: &Env, operator: &Address, token_id: TokenId, terms: &RentalTerms) {
+        operator.require_auth();
+
+        let owner = Base::owner_of(e, token_id);
+        Base::check_spender_approval(e, operator, &owner, token_id);
+
+        if terms.price_per_ledger < 0 || terms.max_duration == 0 {
+            panic_with_error!(e, NonFungibleTokenError::InvalidRentalTerms);
+        }
+
+        e.storage().persistent().set(&StorageKey::RentalTerms(token_id), terms);
+        emit_rental_terms(e, token_id, terms);
+    }
+
+    /// Removes `token_id` from rental. The current user, if any, is kept
+    /// until its rental expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `operator` - The address cancelling the terms, either the owner or an
+    ///   address approved for the token.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::RentalNotListed`] - If the token is not
+    ///   listed for rental.
+    /// * refer to [`Base::owner_of`] errors.
+    /// * refer to [`Base::check_spender_approval`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["rental_cancelled", token_id: TokenId]`
+    /// * data - `[]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `operator` is required.
+    pub fn cancel_rental_terms(e: &Env, operator: &Address, token_id: TokenId) {
+        operator.require_auth();
+
+        let owner = Base::owner_of(e, token_id);
+        Base::check_spender_approval(e, operator, &owner, token_id);
+
+        if Base::rental_terms(e, token_id).is_none() {
+            panic_with_error!(e, NonFungibleTokenError::RentalNotListed);
+        }
+
+        Base::remove_rental_terms(e, token_id);
+        emit_rental_cancelled(e, token_id);
+    }
+
+    /// Rents `token_id` to `renter` for `duration` ledgers on the terms set
+    /// by its owner, paying the rent in `payment_token` from `renter` to the
+    /// owner of the token.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `renter` - The address renting the token.
+    /// * `token_id` - The identifier of the token.
+    /// * `duration` - The number of ledgers the rental lasts.
+    /// * `payment_token` - The token the rent is paid with, which must be the
+    ///   one accepted by the terms.
+    /// * `amount` - The maximum rent `renter` agrees to pay. The rent actually
+    ///   paid is the price per ledger times `duration`.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidLiveUntilLedger`] - If `duration`
+    ///   exceeds the maximum TTL of the network, as the rental entry lives in
+    ///   temporary storage.
+    /// * [`NonFungibleTokenError::RentalNotListed`] - If the token is not
+    ///   listed for rental, or not with `payment_token`.
+    /// * [`NonFungibleTokenError::InvalidRentalTerms`] - If `duration` is `0`
+    ///   or exceeds the maximum duration, or if `amount` is lower than the
+    ///   rent.
+    /// * [`NonFungibleTokenError::TokenAlreadyRented`] - If the token has an
+    ///   active user.
+    /// * [`NonFungibleTokenError::MathOverflow`] - If the rent overflows.
+    /// * refer to [`Base::owner_of`] errors.
+    /// * refer to the `transfer` errors of `payment_token`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["update_user", token_id: TokenId]`
+    /// * data - `[user: Address, expires: u32]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `renter` is required, including by `payment_token`
+    ///   to transfer the rent.
+    /// * Until the rental expires, the user can't be replaced with
+    ///   [`Base::set_user`].
+    pub fn rent(
+        e: &Env,
+        renter: &Address,
+        token_id: TokenId,
+        duration: u32,
+        payment_token: &Address,
+        amount: i128,
+    ) {
+        renter.require_auth();
+
+        let owner = Base::owner_of(e, token_id);
+        let terms = match Base::rental_terms(e, token_id) {
+            Some(terms) if terms.payment_token == *payment_token => terms,
+            _ => panic_with_error!(e, NonFungibleTokenError::RentalNotListed),
+        };
+        if duration == 0 || duration > terms.max_duration {
+            panic_with_error!(e, NonFungibleTokenError::InvalidRentalTerms);
+        }
+        let Some(rent) = terms.price_per_ledger.checked_mul(duration as i128) else {
+            panic_with_error!(e, NonFungibleTokenError::MathOverflow);
+        };
+        if amount < rent {
+            panic_with_error!(e, NonFungibleTokenError::InvalidRentalTerms);
+        }
+        if Base::user_of(e, token_id).is_some() {
+            panic_with_error!(e, NonFungibleTokenError::TokenAlreadyRented);
+        }
+
+        token::Client::new(e, payment_token).transfer(renter, &owner, &rent);
+
+        let expires = e.ledger().sequence().saturating_add(duration);
+        Base::set_user_info(e, token_id, renter, expires, true);
+    }
+
+    /// Returns the ledger number until which the current user of `token_id`
+    /// is the user, or `0` if the token has no user or if the rental expired.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn user_expires(e: &Env, token_id: TokenId) -> u32 {
+        e.storage()
+            .temporary()
+            .get::<_, UserInfo>(&StorageKey::UserInfo(token_id))
+            .filter(|info| info.expires >= e.ledger().sequence())
+            .map_or(0, |info| info.expires)
+    }
+
+    /// Sets `user` as the user of `token_id` until the ledger `expires`,
+    /// replacing the current user, if any, unless it paid for an ongoing
+    /// rental.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `operator` - The address setting the user, either the owner or an
+    ///   address approved for the token.
+    /// * `token_id` - The identifier of the token.
+    /// * `user` - The new user of the token.
+    /// * `expires` - The last ledger number at which `user` is the user.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidLiveUntilLedger`] - If `expires` is
+    ///   less than the current ledger number, or beyond the maximum TTL of the
+    ///   network.
+    /// * [`NonFungibleTokenError::TokenAlreadyRented`] - If the current user
+    ///   rented the token with [`Base::rent`] and the rental hasn't expired.
+    /// * refer to [`Base::owner_of`] errors.
+    /// * refer to [`Base::check_spender_approval`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["update_user", token_id: TokenId]`
+    /// * data - `[user: Address, expires: u32]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `operator` is required.
+    /// * The user is cleared when the token is transferred or burned through
+    ///   [`Rentable`].
+    pub fn set_user(e: &Env, operator: &Address, token_id: TokenId, user: &Address, expires: u32) {
+        operator.require_auth();
+
+        let owner = Base::owner_of(e, token_id);
+        Base::check_spender_approval(e, operator, &owner, token_id);
+
+        if expires < e.ledger().sequence() {
+            panic_with_error!(e, NonFungibleTokenError::InvalidLiveUntilLedger);
+        }
+        let current = e.storage().temporary().get::<_, UserInfo>(&StorageKey::UserInfo(token_id));
+        if current.is_some_and(|info| info.rented && info.expires >= e.ledger().sequence()) {
+            panic_with_error!(e, NonFungibleTokenError::TokenAlreadyRented);
+        }
+
+        Base::set_user_info(e, token_id, user, expires, false);
+    }
+
+    /// Low-level function for removing the user of `token_id`, without
+    /// handling authorization. Called by [`Rentable`] when the token
+    /// changes hands.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used internally or in trusted contexts.
+    pub fn remove_user(e: &Env, token_id: TokenId) {
+        e.storage().temporary().remove(&StorageKey::UserInfo(token_id));
+    }
+
+    /// Low-level function for removing the rental terms of `token_id`,
+    /// without handling authorization. Called by [`Rentable`] when the
+    /// token changes hands.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used internally or in trusted contexts.
+    pub fn remove_rental_terms(e: &Env, token_id: TokenId) {
+        e.storage().persistent().remove(&StorageKey::RentalTerms(token_id));
+    }
+
+    /// Stores `user` as the user of `token_id` until the ledger `expires`,
+    /// with the TTL of the entry matching `expires`.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidLiveUntilLedger`] - If `expires` is
+    ///   beyond the maximum TTL of the network.
+    fn set_user_info(e: &Env, token_id: TokenId, user: &Address, expires: u32, rented: bool) {
+        let live_for = expires.saturating_sub(e.ledger().sequence());
+        if live_for > e.storage().max_ttl() {
+            panic_with_error!(e, NonFungibleTokenError::InvalidLiveUntilLedger);
+        }
+
+        let key = StorageKey::UserInfo(token_id);
+        e.storage().temporary().set(&key, &UserInfo { user: user.clone(), expires, rented });
+        e.storage().temporary().extend_ttl(&key, live_for, live_for);
+
+        emit_update_user(e, token_id, user, expires);
+    }
+}
+
+impl Rentable {
+    /// Transfers `token_id` from `from` to `to`, and clears its user and
+    /// rental terms, so that `to` doesn't inherit the rentals granted by
+    /// `from`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `token_id` - The identifier of the token being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::transfer`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `from` is required.
+    pub fn transfer(e: &Env, from: &Address, to: &Address, token_id: TokenId) {
+        Base::transfer(e, from, to, token_id);
+        Rentable::clear_rental(e, token_id);
+    }
+
+    /// Transfers `token_id` from `from` to `to` using `spender`s approval, and
+    /// clears its user and rental terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address attempting to transfer the token.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_id` - The identifier of the token being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::transfer_from`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `spender` is required.
+    pub fn transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_id: TokenId,
+    ) {
+        Base::transfer_from(e, spender, from, to, token_id);
+        Rentable::clear_rental(e, token_id);
+    }
+
+    /// Destroys `token_id` from `from`, and clears its user and rental terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The account whose token is destroyed.
+    /// * `token_id` - The token to burn.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::burn`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `from` is required.
+    pub fn burn(e: &Env, from: &Address, token_id: TokenId) {
+        Base::burn(e, from, token_id);
+        Rentable::clear_rental(e, token_id);
+    }
+
+    /// Destroys `token_id` from `from` using `spender`s approval, and clears
+    /// its user and rental terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The account that is allowed to burn the token on behalf of
+    ///   the owner.
+    /// * `from` - The account whose token is destroyed.
+    /// * `token_id` - The token to burn.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::burn_from`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `spender` is required.
+    pub fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: TokenId) {
+        Base::burn_from(e, spender, from, token_id);
+        Rentable::clear_rental(e, token_id);
+    }
+
+    /// Clears the user and the rental terms of `token_id`.
+    fn clear_rental(e: &Env, token_id: TokenId) {
+        Base::remove_user(e, token_id);
+        Base::remove_rental_terms(e, token_id);
+    }
+}
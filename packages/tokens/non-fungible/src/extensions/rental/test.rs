@@ -0,0 +1,458 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger as _},
+    token, Address, Env,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    burnable::BurnableContractType,
+    rental::{Rentable, RentalTerms},
+    Base, ContractOverrides, NonFungibleTokenError, TokenId,
+};
+
+#[contract]
+struct MockContract;
+
+#[contractimpl]
+impl MockContract {
+    pub fn rent(
+        e: &Env,
+        renter: Address,
+        token_id: TokenId,
+        duration: u32,
+        payment_token: Address,
+        amount: i128,
+    ) {
+        Base::rent(e, &renter, token_id, duration, &payment_token, amount);
+    }
+
+    pub fn set_user(e: &Env, operator: Address, token_id: TokenId, user: Address, expires: u32) {
+        Base::set_user(e, &operator, token_id, &user, expires);
+    }
+
+    pub fn set_rental_terms(e: &Env, operator: Address, token_id: TokenId, terms: RentalTerms) {
+        Base::set_rental_terms(e, &operator, token_id, &terms);
+    }
+
+    pub fn cancel_rental_terms(e: &Env, operator: Address, token_id: TokenId) {
+        Base::cancel_rental_terms(e, &operator, token_id);
+    }
+}
+
+fn create_payment_token(e: &Env, holder: &Address, amount: i128) -> Address {
+    let admin = Address::generate(e);
+    let payment_token = e.register_stellar_asset_contract_v2(admin).address();
+    token::StellarAssetClient::new(e, &payment_token).mint(holder, &amount);
+    payment_token
+}
+
+fn terms(payment_token: &Address, price_per_ledger: i128, max_duration: u32) -> RentalTerms {
+    RentalTerms { payment_token: payment_token.clone(), price_per_ledger, max_duration }
+}
+
+#[test]
+fn rent_pays_owner_and_sets_user() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let owner = Address::generate(&e);
+    let renter = Address::generate(&e);
+    let payment_token = create_payment_token(&e, &renter, 1000);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1);
+        assert_eq!(Base::user_of(&e, 1), None);
+    });
+    client.set_rental_terms(&owner, &1, &terms(&payment_token, 3, 200));
+
+    // only the rent is paid, even if the renter agrees to pay more
+    client.rent(&renter, &1, &100, &payment_token, &400);
+
+    let event_assert = EventAssertion::new(&e, address.clone());
+    event_assert.assert_event_from(&address, "update_user");
+
+    let payment_client = token::Client::new(&e, &payment_token);
+    assert_eq!(payment_client.balance(&renter), 700);
+    assert_eq!(payment_client.balance(&owner), 300);
+
+    e.as_contract(&address, || {
+        assert_eq!(Base::user_of(&e, 1), Some(renter.clone()));
+        // renting doesn't transfer the ownership
+        assert_eq!(Base::owner_of(&e, 1), owner);
+    });
+
+    e.ledger().with_mut(|l| l.sequence_number += 101);
+    e.as_contract(&address, || {
+        assert_eq!(Base::user_of(&e, 1), None);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #322)")]
+fn rent_already_rented_token_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let owner = Address::generate(&e);
+    let renter = Address::generate(&e);
+    let other = Address::generate(&e);
+    let payment_token = create_payment_token(&e, &renter, 1000);
+    token::StellarAssetClient::new(&e, &payment_token).mint(&other, &1000);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1);
+    });
+    client.set_rental_terms(&owner, &1, &terms(&payment_token, 3, 200));
+
+    client.rent(&renter, &1, &100, &payment_token, &300);
+    client.rent(&other, &1, &100, &payment_token, &300);
+}
+
+#[test]
+fn rent_with_failed_payment_reverts() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let owner = Address::generate(&e);
+    let renter = Address::generate(&e);
+    let payment_token = create_payment_token(&e, &renter, 100);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1);
+    });
+    client.set_rental_terms(&owner, &1, &terms(&payment_token, 3, 200));
+
+    assert!(client.try_rent(&renter, &1, &100, &payment_token, &300).is_err());
+
+    e.as_contract(&address, || {
+        assert_eq!(Base::user_of(&e, 1), None);
+    });
+    assert_eq!(token::Client::new(&e, &payment_token).balance(&renter), 100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #338)")]
+fn rent_unlisted_token_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let owner = Address::generate(&e);
+    let renter = Address::generate(&e);
+    let payment_token = create_payment_token(&e, &renter, 1000);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1);
+    });
+
+    client.rent(&renter, &1, &100, &payment_token, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #338)")]
+fn rent_with_unlisted_payment_token_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let owner = Address::generate(&e);
+    let renter = Address::generate(&e);
+    let payment_token = create_payment_token(&e, &renter, 1000);
+    let other_token = create_payment_token(&e, &renter, 1000);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1);
+    });
+    client.set_rental_terms(&owner, &1, &terms(&payment_token, 3, 200));
+
+    client.rent(&renter, &1, &100, &other_token, &300);
+}
+
+#[test]
+fn rent_underpaid_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let owner = Address::generate(&e);
+    let renter = Address::generate(&e);
+    let payment_token = create_payment_token(&e, &renter, 1000);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1);
+    });
+    client.set_rental_terms(&owner, &1, &terms(&payment_token, 3, 200));
+
+    let result = client.try_rent(&renter, &1, &100, &payment_token, &299);
+    assert_eq!(result, Err(Ok(NonFungibleTokenError::InvalidRentalTerms.into())));
+    assert_eq!(token::Client::new(&e, &payment_token).balance(&renter), 1000);
+
+    let result = client.try_rent(&renter, &1, &100, &payment_token, &0);
+    assert_eq!(result, Err(Ok(NonFungibleTokenError::InvalidRentalTerms.into())));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #339)")]
+fn rent_exceeding_max_duration_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let owner = Address::generate(&e);
+    let renter = Address::generate(&e);
+    let payment_token = create_payment_token(&e, &renter, 1000);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1);
+    });
+    client.set_rental_terms(&owner, &1, &terms(&payment_token, 1, 50));
+
+    client.rent(&renter, &1, &51, &payment_token, &51);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #302)")]
+fn set_rental_terms_without_approval_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let owner = Address::generate(&e);
+    let renter = Address::generate(&e);
+    let payment_token = create_payment_token(&e, &renter, 1000);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1);
+    });
+
+    // the renter can't set terms of their own
+    client.set_rental_terms(&renter, &1, &terms(&payment_token, 0, 200));
+}
+
+#[test]
+fn rental_terms_cancelled_and_cleared_on_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let payment_token = create_payment_token(&e, &owner, 0);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1);
+    });
+
+    client.set_rental_terms(&owner, &1, &terms(&payment_token, 3, 200));
+    let event_assert = EventAssertion::new(&e, address.clone());
+    event_assert.assert_event_from(&address, "rental_terms");
+
+    client.cancel_rental_terms(&owner, &1);
+    let event_assert = EventAssertion::new(&e, address.clone());
+    event_assert.assert_event_from(&address, "rental_cancelled");
+
+    client.set_rental_terms(&owner, &1, &terms(&payment_token, 3, 200));
+    e.as_contract(&address, || {
+        assert_eq!(Base::rental_terms(&e, 1), Some(terms(&payment_token, 3, 200)));
+
+        Rentable::transfer(&e, &owner, &recipient, 1);
+
+        // the new owner doesn't inherit the terms of the previous one
+        assert_eq!(Base::rental_terms(&e, 1), None);
+    });
+}
+
+#[test]
+fn set_user_expires_after_boundary() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let owner = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1);
+        assert_eq!(Base::user_expires(&e, 1), 0);
+    });
+
+    let expires = e.ledger().sequence() + 50;
+    client.set_user(&owner, &1, &user, &expires);
+
+    let event_assert = EventAssertion::new(&e, address.clone());
+    event_assert.assert_event_count(1);
+    event_assert.assert_event_from(&address, "update_user");
+
+    // the user is still set at the `expires` ledger
+    e.ledger().with_mut(|l| l.sequence_number = expires);
+    e.as_contract(&address, || {
+        assert_eq!(Base::user_of(&e, 1), Some(user.clone()));
+        assert_eq!(Base::user_expires(&e, 1), expires);
+    });
+
+    e.ledger().with_mut(|l| l.sequence_number = expires + 1);
+    e.as_contract(&address, || {
+        assert_eq!(Base::user_of(&e, 1), None);
+        assert_eq!(Base::user_expires(&e, 1), 0);
+    });
+}
+
+#[test]
+fn set_user_by_approved_and_clear_on_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let owner = Address::generate(&e);
+    let approved = Address::generate(&e);
+    let user = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1);
+        Base::approve(&e, &owner, &approved, 1, 1000);
+    });
+
+    client.set_user(&approved, &1, &user, &100);
+
+    e.as_contract(&address, || {
+        assert_eq!(Base::user_of(&e, 1), Some(user.clone()));
+
+        <Rentable as ContractOverrides>::transfer(&e, &owner, &recipient, 1);
+
+        assert_eq!(Base::user_of(&e, 1), None);
+        assert_eq!(Base::user_expires(&e, 1), 0);
+    });
+}
+
+#[test]
+fn burn_clears_user_and_rental_terms() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let owner = Address::generate(&e);
+    let user = Address::generate(&e);
+    let payment_token = create_payment_token(&e, &owner, 0);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1);
+    });
+
+    client.set_rental_terms(&owner, &1, &terms(&payment_token, 3, 200));
+    client.set_user(&owner, &1, &user, &100);
+
+    e.as_contract(&address, || {
+        <Rentable as BurnableContractType>::burn(&e, &owner, 1);
+
+        assert_eq!(Base::user_of(&e, 1), None);
+        assert_eq!(Base::rental_terms(&e, 1), None);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #302)")]
+fn set_user_without_approval_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1);
+    });
+
+    client.set_user(&operator, &1, &user, &100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #304)")]
+fn set_user_with_past_expiry_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let owner = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    e.ledger().with_mut(|l| l.sequence_number = 100);
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1);
+    });
+
+    client.set_user(&owner, &1, &user, &99);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #322)")]
+fn set_user_during_paid_rental_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let owner = Address::generate(&e);
+    let renter = Address::generate(&e);
+    let payment_token = create_payment_token(&e, &renter, 1000);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1);
+    });
+    client.set_rental_terms(&owner, &1, &terms(&payment_token, 3, 200));
+    client.rent(&renter, &1, &100, &payment_token, &300);
+
+    client.set_user(&owner, &1, &owner, &(e.ledger().sequence() + 50));
+}
+
+#[test]
+fn set_user_after_paid_rental_expired_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let owner = Address::generate(&e);
+    let renter = Address::generate(&e);
+    let user = Address::generate(&e);
+    let payment_token = create_payment_token(&e, &renter, 1000);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1);
+    });
+    client.set_rental_terms(&owner, &1, &terms(&payment_token, 3, 200));
+    client.rent(&renter, &1, &100, &payment_token, &300);
+
+    e.ledger().with_mut(|l| l.sequence_number += 101);
+    client.set_user(&owner, &1, &user, &(e.ledger().sequence() + 50));
+
+    e.as_contract(&address, || {
+        assert_eq!(Base::user_of(&e, 1), Some(user.clone()));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #304)")]
+fn set_user_beyond_max_ttl_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let client = MockContractClient::new(&e, &address);
+    let owner = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let max_ttl = e.as_contract(&address, || {
+        Base::mint(&e, &owner, 1);
+        e.storage().max_ttl()
+    });
+
+    client.set_user(&owner, &1, &user, &(e.ledger().sequence() + max_ttl + 1));
+}
@@ -0,0 +1,26 @@
+//! # Royalty Extension for Non-Fungible Token
+//!
+//! Marketplaces need a standard way to read the royalty terms of a token. This
+//! extension, inspired by ERC-2981, stores a default royalty for the whole
+//! collection and optional per-token royalties overriding it. Royalties are
+//! expressed in basis points of the sale price. The royalty of a token is not
+//! cleared by burns: contracts setting per-token royalties are expected to
+//! call [`crate::Base::remove_token_royalty`] when burning a token.
+//!
+//! Royalties can also be enforced on-chain with
+//! [`crate::ContractOverrides::transfer_with_royalty`], which transfers the
+//! token with the `transfer` of the contract type, so that extensions such as
+//! `Lockable` still apply, and pays the royalty to its receiver atomically: if
+//! the payment fails, the whole transfer reverts. Whether the buyer or the
+//! seller pays the royalty is configured with
+//! [`crate::Base::set_royalty_payer`].
+//!
+//! ## Usage
+//!
+//! The royalty setters don't handle authorization, so the implementor is
+//! expected to wrap them with the appropriate checks (e.g. only the creator of
+//! the collection), or to call them in the constructor.
+mod storage;
+pub use storage::{RoyaltyData, RoyaltyPayer, StorageKey, FEE_DENOMINATOR};
+
+mod test;
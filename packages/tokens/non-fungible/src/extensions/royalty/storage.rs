@@ -0,0 +1,218 @@
+use soroban_sdk::{contracttype, panic_with_error, token, Address, Env};
+
+use crate::{Base, NonFungibleTokenError, TokenId};
+
+/// The denominator of royalty fees, which are expressed in basis points.
+pub const FEE_DENOMINATOR: u32 = 10_000;
+
+/// Storage container for the royalty terms of a token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoyaltyData {
+    pub receiver: Address,
+    pub fee_basis_points: u32,
+}
+
+/// The party paying the royalty in [`Base::transfer_with_royalty`].
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoyaltyPayer {
+    /// The recipient of the token, i.e. the buyer.
+    Buyer,
+    /// The current owner of the token, i.e. the seller.
+    Seller,
+}
+
+/// Storage keys for the data associated with the royalty extension.
+#[contracttype]
+pub enum StorageKey {
+    DefaultRoyalty,
+    TokenRoyalty(TokenId),
+    Payer,
+}
+
+impl Base {
+    /// Returns the receiver of the royalty for `token_id` and the royalty
+    /// amount owed for a sale at `sale_price`. The royalty of the token takes
+    /// precedence over the default one.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `sale_price` - The price the token is sold for.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::MathOverflow`] - When the royalty amount
+    ///   can't be computed without overflowing.
+    ///
+    /// # Notes
+    ///
+    /// When no royalty is configured, the current contract address is
+    /// returned as the receiver along with a royalty amount of `0`.
+    pub fn royalty_info(e: &Env, token_id: TokenId, sale_price: i128) -> (Address, i128) {
+        let royalty = e
+            .storage()
+            .persistent()
+            .get::<_, RoyaltyData>(&StorageKey::TokenRoyalty(token_id))
+            .or_else(|| e.storage().instance().get(&StorageKey::DefaultRoyalty));
+
+        let Some(RoyaltyData { receiver, fee_basis_points }) = royalty else {
+            return (e.current_contract_address(), 0);
+        };
+        let Some(amount) = sale_price.checked_mul(fee_basis_points as i128) else {
+            panic_with_error!(e, NonFungibleTokenError::MathOverflow);
+        };
+        (receiver, amount / FEE_DENOMINATOR as i128)
+    }
+
+    /// Returns the party paying the royalty in
+    /// [`Base::transfer_with_royalty`]. Defaults to [`RoyaltyPayer::Buyer`].
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn royalty_payer(e: &Env) -> RoyaltyPayer {
+        e.storage().instance().get(&StorageKey::Payer).unwrap_or(RoyaltyPayer::Buyer)
+    }
+
+    /// Sets the royalty applying to every token without a royalty of its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `receiver` - The address receiving the royalties.
+    /// * `fee_basis_points` - The royalty fee, in basis points of the sale
+    ///   price.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidRoyaltyFee`] - If `fee_basis_points`
+    ///   is greater than `10000`.
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used internally or in trusted contexts.
+    pub fn set_default_royalty(e: &Env, receiver: &Address, fee_basis_points: u32) {
+        let royalty = Base::royalty_data(e, receiver, fee_basis_points);
+        e.storage().instance().set(&StorageKey::DefaultRoyalty, &royalty);
+    }
+
+    /// Sets the royalty of `token_id`, overriding the default royalty.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `receiver` - The address receiving the royalties.
+    /// * `fee_basis_points` - The royalty fee, in basis points of the sale
+    ///   price.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidRoyaltyFee`] - If `fee_basis_points`
+    ///   is greater than `10000`.
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used internally or in trusted contexts.
+    pub fn set_token_royalty(
+        e: &Env,
+        token_id: TokenId,
+        receiver: &Address,
+        fee_basis_points: u32,
+    ) {
+        let royalty = Base::royalty_data(e, receiver, fee_basis_points);
+        e.storage().persistent().set(&StorageKey::TokenRoyalty(token_id), &royalty);
+    }
+
+    /// Removes the royalty of `token_id`, if any, so that the default royalty
+    /// applies to it again.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Notes
+    ///
+    /// Burns don't clear royalties. Contracts setting per-token royalties are
+    /// expected to call this function in their `burn` and `burn_from`, so that
+    /// a token minted again with the same identifier doesn't inherit it.
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used internally or in trusted contexts.
+    pub fn remove_token_royalty(e: &Env, token_id: TokenId) {
+        e.storage().persistent().remove(&StorageKey::TokenRoyalty(token_id));
+    }
+
+    /// Sets the party paying the royalty in
+    /// [`crate::ContractOverrides::transfer_with_royalty`].
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `payer` - The party paying the royalty.
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used internally or in trusted contexts.
+    pub fn set_royalty_payer(e: &Env, payer: RoyaltyPayer) {
+        e.storage().instance().set(&StorageKey::Payer, &payer);
+    }
+
+    /// Pays the royalty owed for the sale of `token_id` from `from` to `to`
+    /// at `sale_price` in `payment_token` to the royalty receiver. Used by
+    /// [`crate::ContractOverrides::transfer_with_royalty`] once the token is
+    /// transferred.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The seller's address.
+    /// * `to` - The buyer's address.
+    /// * `token_id` - The identifier of the token being sold.
+    /// * `sale_price` - The price the token is sold for.
+    /// * `payment_token` - The address of the token contract used to pay the
+    ///   royalty.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::royalty_info`] errors.
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for the payer (see [`Base::royalty_payer`]) is required
+    ///   by `payment_token` to transfer the royalty.
+    pub(crate) fn pay_royalty(
+        e: &Env,
+        from: &Address,
+        to: &Address,
+        token_id: TokenId,
+        sale_price: i128,
+        payment_token: &Address,
+    ) {
+        let (receiver, amount) = Base::royalty_info(e, token_id, sale_price);
+        if amount > 0 {
+            let payer = match Base::royalty_payer(e) {
+                RoyaltyPayer::Buyer => to,
+                RoyaltyPayer::Seller => from,
+            };
+            token::Client::new(e, payment_token).transfer(payer, &receiver, &amount);
+        }
+    }
+
+    /// Builds the royalty terms, validating `fee_basis_points`.
+    fn royalty_data(e: &Env, receiver: &Address, fee_basis_points: u32) -> RoyaltyData {
+        if fee_basis_points > FEE_DENOMINATOR {
+            panic_with_error!(e, NonFungibleTokenError::InvalidRoyaltyFee);
+        }
+        RoyaltyData { receiver: receiver.clone(), fee_basis_points }
+    }
+}
@@ -0,0 +1,177 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, token, Address, Env};
+
+use crate::{lockable::Lockable, royalty::RoyaltyPayer, Base, ContractOverrides};
+
+#[contract]
+struct MockContract;
+
+fn create_payment_token(e: &Env, holder: &Address, amount: i128) -> Address {
+    let admin = Address::generate(e);
+    let payment_token = e.register_stellar_asset_contract_v2(admin).address();
+    token::StellarAssetClient::new(e, &payment_token).mint(holder, &amount);
+    payment_token
+}
+
+#[test]
+fn royalty_info_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let creator = Address::generate(&e);
+    let artist = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        assert_eq!(Base::royalty_info(&e, 1, 1000), (address.clone(), 0));
+
+        Base::set_default_royalty(&e, &creator, 500);
+        assert_eq!(Base::royalty_info(&e, 1, 1000), (creator.clone(), 50));
+
+        // the royalty of the token overrides the default one
+        Base::set_token_royalty(&e, 2, &artist, 1000);
+        assert_eq!(Base::royalty_info(&e, 2, 1000), (artist.clone(), 100));
+        assert_eq!(Base::royalty_info(&e, 1, 1000), (creator.clone(), 50));
+    });
+}
+
+#[test]
+fn remove_token_royalty_on_burn_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let creator = Address::generate(&e);
+    let artist = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_default_royalty(&e, &creator, 500);
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::set_token_royalty(&e, token_id, &artist, 1000);
+        assert_eq!(Base::royalty_info(&e, token_id, 1000), (artist.clone(), 100));
+
+        Base::burn(&e, &owner, token_id);
+        Base::remove_token_royalty(&e, token_id);
+        assert_eq!(Base::royalty_info(&e, token_id, 1000), (creator.clone(), 50));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #317)")]
+fn set_token_royalty_with_invalid_fee_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let artist = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_token_royalty(&e, 1, &artist, 10_001);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #317)")]
+fn set_default_royalty_with_invalid_fee_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let creator = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_default_royalty(&e, &creator, 10_001);
+    });
+}
+
+#[test]
+fn transfer_with_royalty_works() {
+    let e = Env::default();
+    e.mock_all_auths_allowing_non_root_auth();
+    let address = e.register(MockContract, ());
+    let seller = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let creator = Address::generate(&e);
+    let payment_token = create_payment_token(&e, &buyer, 1000);
+
+    e.as_contract(&address, || {
+        Base::set_default_royalty(&e, &creator, 500);
+        Base::mint(&e, &seller, 1);
+    });
+
+    e.as_contract(&address, || {
+        Base::transfer_with_royalty(&e, &seller, &buyer, 1, 1000, &payment_token);
+        assert_eq!(Base::owner_of(&e, 1), buyer);
+    });
+
+    let payment_client = token::Client::new(&e, &payment_token);
+    assert_eq!(payment_client.balance(&buyer), 950);
+    assert_eq!(payment_client.balance(&creator), 50);
+}
+
+#[test]
+fn transfer_with_royalty_paid_by_seller_works() {
+    let e = Env::default();
+    e.mock_all_auths_allowing_non_root_auth();
+    let address = e.register(MockContract, ());
+    let seller = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let creator = Address::generate(&e);
+    let payment_token = create_payment_token(&e, &seller, 1000);
+
+    e.as_contract(&address, || {
+        Base::set_default_royalty(&e, &creator, 500);
+        Base::set_royalty_payer(&e, RoyaltyPayer::Seller);
+        Base::mint(&e, &seller, 1);
+    });
+
+    e.as_contract(&address, || {
+        Base::transfer_with_royalty(&e, &seller, &buyer, 1, 1000, &payment_token);
+        assert_eq!(Base::owner_of(&e, 1), buyer);
+    });
+
+    let payment_client = token::Client::new(&e, &payment_token);
+    assert_eq!(payment_client.balance(&seller), 950);
+    assert_eq!(payment_client.balance(&creator), 50);
+}
+
+#[test]
+// the payment token fails with its insufficient balance error
+#[should_panic(expected = "Error(Contract, #10)")]
+fn transfer_with_royalty_reverts_when_royalty_is_not_covered() {
+    let e = Env::default();
+    e.mock_all_auths_allowing_non_root_auth();
+    let address = e.register(MockContract, ());
+    let seller = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let creator = Address::generate(&e);
+    let payment_token = create_payment_token(&e, &buyer, 10);
+
+    e.as_contract(&address, || {
+        Base::set_default_royalty(&e, &creator, 500);
+        Base::mint(&e, &seller, 1);
+    });
+
+    e.as_contract(&address, || {
+        Base::transfer_with_royalty(&e, &seller, &buyer, 1, 1000, &payment_token);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #312)")]
+fn transfer_with_royalty_of_locked_token_fails() {
+    let e = Env::default();
+    e.mock_all_auths_allowing_non_root_auth();
+    let address = e.register(MockContract, ());
+    let seller = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let creator = Address::generate(&e);
+    let payment_token = create_payment_token(&e, &buyer, 1000);
+
+    e.as_contract(&address, || {
+        Base::set_default_royalty(&e, &creator, 500);
+        Base::mint(&e, &seller, 1);
+        Lockable::lock(&e, 1);
+    });
+
+    e.as_contract(&address, || {
+        Lockable::transfer_with_royalty(&e, &seller, &buyer, 1, 1000, &payment_token);
+    });
+}
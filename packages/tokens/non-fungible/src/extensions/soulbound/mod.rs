@@ -0,0 +1,18 @@
+//! # Soulbound Extension for Non-Fungible Token
+//!
+//! Credentials, badges and other proofs of achievement are bound to the
+//! account they were issued to: they can be minted and burned, but never
+//! transferred.
+//!
+//! ## Usage
+//!
+//! A contract opts in by setting `type ContractType = Soulbound`. Transfers
+//! and approvals then revert with
+//! [`crate::NonFungibleTokenError::NonTransferable`], while the queries (e.g.
+//! `owner_of` and `token_uri`) are inherited from [`crate::Base`]. The
+//! lifecycle of the tokens is still managed with the functions of `Base`,
+//! e.g. `Base::sequential_mint` and `Base::burn`.
+pub mod storage;
+pub use storage::Soulbound;
+
+mod test;
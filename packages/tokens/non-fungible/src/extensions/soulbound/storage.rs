@@ -0,0 +1,37 @@
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use crate::{ContractOverrides, NonFungibleTokenError, TokenId};
+
+pub struct Soulbound;
+
+impl ContractOverrides for Soulbound {
+    fn transfer(e: &Env, _from: &Address, _to: &Address, _token_id: TokenId) {
+        panic_with_error!(e, NonFungibleTokenError::NonTransferable);
+    }
+
+    fn transfer_from(
+        e: &Env,
+        _spender: &Address,
+        _from: &Address,
+        _to: &Address,
+        _token_id: TokenId,
+    ) {
+        panic_with_error!(e, NonFungibleTokenError::NonTransferable);
+    }
+
+    fn approve(
+        e: &Env,
+        _approver: &Address,
+        _approved: &Address,
+        _token_id: TokenId,
+        _live_until_ledger: u32,
+    ) {
+        panic_with_error!(e, NonFungibleTokenError::NonTransferable);
+    }
+
+    // NOTE: operator approvals would be pointless as well, since they only
+    // grant the right to transfer.
+    fn approve_for_all(e: &Env, _owner: &Address, _operator: &Address, _live_until_ledger: u32) {
+        panic_with_error!(e, NonFungibleTokenError::NonTransferable);
+    }
+}
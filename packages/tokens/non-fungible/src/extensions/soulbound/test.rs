@@ -0,0 +1,113 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Bytes, Env, String};
+
+use crate::{soulbound::Soulbound, Base, ContractOverrides};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn mint_and_burn_work() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_metadata(
+            &e,
+            String::from_str(&e, "https://example.com/"),
+            String::from_str(&e, "Badge"),
+            String::from_str(&e, "BDG"),
+        );
+        let token_id = Base::sequential_mint(&e, &owner);
+
+        assert_eq!(Soulbound::owner_of(&e, token_id), owner);
+        assert_eq!(Soulbound::balance(&e, &owner), 1);
+        assert_eq!(
+            Soulbound::token_uri(&e, token_id),
+            String::from_str(&e, "https://example.com/0")
+        );
+
+        Base::burn(&e, &owner, token_id);
+        assert_eq!(Soulbound::balance(&e, &owner), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #324)")]
+fn transfer_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Soulbound::transfer(&e, &owner, &recipient, token_id);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #324)")]
+fn safe_transfer_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Soulbound::safe_transfer(&e, &owner, &recipient, token_id, Bytes::new(&e));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #324)")]
+fn transfer_from_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Soulbound::transfer_from(&e, &spender, &owner, &recipient, token_id);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #324)")]
+fn approve_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let approved = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Soulbound::approve(&e, &owner, &approved, token_id, 1000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #324)")]
+fn approve_for_all_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Soulbound::approve_for_all(&e, &owner, &operator, 1000);
+    });
+}
@@ -0,0 +1,62 @@
+//! # Staking Extension for Non-Fungible Token
+//!
+//! Allows token owners to stake their tokens in place, without transferring
+//! them to a staking contract. [`crate::lockable::Lockable::stake`] locks the
+//! token and records the ledger at which the stake started, from which
+//! [`crate::lockable::Lockable::pending_rewards`] computes the rewards accrued
+//! so far. [`crate::lockable::Lockable::unstake`] releases the token.
+//!
+//! ## Usage
+//!
+//! Staking builds on the [`crate::lockable`] extension: staked tokens are
+//! locked, so the contract must use [`crate::lockable::Lockable`] as its
+//! `ContractType` for the transfers and the burns of staked tokens to be
+//! rejected. A staked token must therefore be unstaked before it is burned.
+//!
+//! The extension only accounts for the rewards, it doesn't pay them out.
+//! Unstaking resets the accrual, therefore the implementor is expected to pay
+//! the pending rewards (e.g. by minting a fungible reward token) before
+//! calling [`crate::lockable::Lockable::unstake`].
+mod storage;
+use soroban_sdk::{symbol_short, Address, Env};
+pub use storage::StorageKey;
+
+use crate::TokenId;
+
+mod test;
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating a token has been staked.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `owner` - The owner of the token.
+/// * `token_id` - The identifier of the staked token.
+///
+/// # Events
+///
+/// * topics - `["stake", owner: Address]`
+/// * data - `[token_id: TokenId]`
+pub fn emit_stake(e: &Env, owner: &Address, token_id: TokenId) {
+    let topics = (symbol_short!("stake"), owner);
+    e.events().publish(topics, token_id)
+}
+
+/// Emits an event indicating a token has been unstaked.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `owner` - The owner of the token.
+/// * `token_id` - The identifier of the unstaked token.
+///
+/// # Events
+///
+/// * topics - `["unstake", owner: Address]`
+/// * data - `[token_id: TokenId]`
+pub fn emit_unstake(e: &Env, owner: &Address, token_id: TokenId) {
+    let topics = (symbol_short!("unstake"), owner);
+    e.events().publish(topics, token_id)
+}
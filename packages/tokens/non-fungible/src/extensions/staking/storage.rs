@@ -0,0 +1,142 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+use stellar_constants::{TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD};
+
+use crate::{
+    lockable::Lockable,
+    staking::{emit_stake, emit_unstake},
+    Base, NonFungibleTokenError, TokenId,
+};
+
+/// Storage keys for the data associated with the staking extension.
+#[contracttype]
+pub enum StorageKey {
+    StakedAt(TokenId),
+}
+
+impl Lockable {
+    // ################## QUERY STATE ##################
+
+    /// Returns the ledger at which `token_id` was staked, or `None` if the
+    /// token is not staked.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn staked_at(e: &Env, token_id: TokenId) -> Option<u32> {
+        let key = StorageKey::StakedAt(token_id);
+        e.storage().persistent().get::<_, u32>(&key).inspect(|_| {
+            e.storage().persistent().extend_ttl(&key, TOKEN_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT);
+        })
+    }
+
+    /// Returns the rewards accrued by `token_id` since it was staked, earning
+    /// `rate` per elapsed ledger. Returns `0` if the token is not staked.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `rate` - The rewards earned per ledger.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::MathOverflow`] - When the rewards overflow.
+    pub fn pending_rewards(e: &Env, token_id: TokenId, rate: i128) -> i128 {
+        let Some(start) = Lockable::staked_at(e, token_id) else {
+            return 0;
+        };
+        let elapsed = e.ledger().sequence() - start;
+        rate.checked_mul(elapsed as i128)
+            .unwrap_or_else(|| panic_with_error!(e, NonFungibleTokenError::MathOverflow))
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Stakes `token_id`, locking it and starting the accrual of its rewards.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The owner of the token.
+    /// * `token_id` - The identifier of the token to stake.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::IncorrectOwner`] - If `owner` doesn't own the
+    ///   token.
+    /// * [`NonFungibleTokenError::TokenAlreadyStaked`] - If the token is
+    ///   already staked.
+    /// * refer to [`Base::owner_of`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["lock", token_id: TokenId]`
+    /// * data - `[]`
+    ///
+    /// * topics - `["stake", owner: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `owner` is required.
+    pub fn stake(e: &Env, owner: &Address, token_id: TokenId) {
+        owner.require_auth();
+
+        if Base::owner_of(e, token_id) != *owner {
+            panic_with_error!(e, NonFungibleTokenError::IncorrectOwner);
+        }
+        if Lockable::staked_at(e, token_id).is_some() {
+            panic_with_error!(e, NonFungibleTokenError::TokenAlreadyStaked);
+        }
+
+        Lockable::lock(e, token_id);
+        e.storage().persistent().set(&StorageKey::StakedAt(token_id), &e.ledger().sequence());
+        emit_stake(e, owner, token_id);
+    }
+
+    /// Unstakes `token_id`, unlocking it and stopping the accrual of its
+    /// rewards.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The owner of the token.
+    /// * `token_id` - The identifier of the token to unstake.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::IncorrectOwner`] - If `owner` doesn't own the
+    ///   token.
+    /// * [`NonFungibleTokenError::TokenNotStaked`] - If the token is not
+    ///   staked.
+    /// * refer to [`Base::owner_of`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["unlock", token_id: TokenId]`
+    /// * data - `[]`
+    ///
+    /// * topics - `["unstake", owner: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `owner` is required.
+    /// * The pending rewards are discarded, so they must be paid out before
+    ///   calling this function.
+    pub fn unstake(e: &Env, owner: &Address, token_id: TokenId) {
+        owner.require_auth();
+
+        if Base::owner_of(e, token_id) != *owner {
+            panic_with_error!(e, NonFungibleTokenError::IncorrectOwner);
+        }
+        if Lockable::staked_at(e, token_id).is_none() {
+            panic_with_error!(e, NonFungibleTokenError::TokenNotStaked);
+        }
+
+        e.storage().persistent().remove(&StorageKey::StakedAt(token_id));
+        Lockable::unlock(e, token_id);
+        emit_unstake(e, owner, token_id);
+    }
+}
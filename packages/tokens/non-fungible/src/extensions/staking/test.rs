@@ -0,0 +1,208 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger as _},
+    Address, Env, FromVal,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{lockable::Lockable, Base, TokenId};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn stake_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.ledger().set_sequence_number(100);
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        assert_eq!(Lockable::staked_at(&e, token_id), None);
+
+        Lockable::stake(&e, &owner, token_id);
+
+        assert_eq!(Lockable::staked_at(&e, token_id), Some(100));
+        assert!(Lockable::is_locked(&e, token_id));
+        assert_eq!(Base::owner_of(&e, token_id), owner);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        // mint + lock + stake
+        event_assert.assert_event_count(3);
+        let (topics, data) = event_assert.assert_event_from(&address, "stake");
+        assert_eq!(Address::from_val(&e, &topics.get_unchecked(1)), owner);
+        assert_eq!(TokenId::from_val(&e, &data), token_id);
+    });
+}
+
+#[test]
+fn pending_rewards_accrue_over_ledgers() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.ledger().set_sequence_number(100);
+    let token_id = e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        assert_eq!(Lockable::pending_rewards(&e, token_id, 5), 0);
+
+        Lockable::stake(&e, &owner, token_id);
+        assert_eq!(Lockable::pending_rewards(&e, token_id, 5), 0);
+        token_id
+    });
+
+    e.ledger().set_sequence_number(110);
+    e.as_contract(&address, || {
+        assert_eq!(Lockable::pending_rewards(&e, token_id, 5), 50);
+    });
+
+    e.ledger().set_sequence_number(250);
+    e.as_contract(&address, || {
+        assert_eq!(Lockable::pending_rewards(&e, token_id, 5), 750);
+        assert_eq!(Lockable::pending_rewards(&e, token_id, 2), 300);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #312)")]
+fn transfer_staked_token_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Lockable::stake(&e, &owner, token_id);
+        token_id
+    });
+
+    e.as_contract(&address, || {
+        Lockable::transfer(&e, &owner, &recipient, token_id);
+    });
+}
+
+#[test]
+fn unstake_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.ledger().set_sequence_number(100);
+    let token_id = e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Lockable::stake(&e, &owner, token_id);
+        token_id
+    });
+
+    e.ledger().set_sequence_number(120);
+    e.as_contract(&address, || {
+        Lockable::unstake(&e, &owner, token_id);
+
+        assert_eq!(Lockable::staked_at(&e, token_id), None);
+        assert!(!Lockable::is_locked(&e, token_id));
+        assert_eq!(Lockable::pending_rewards(&e, token_id, 5), 0);
+    });
+
+    e.as_contract(&address, || {
+        Lockable::transfer(&e, &owner, &recipient, token_id);
+        assert_eq!(Base::owner_of(&e, token_id), recipient);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #312)")]
+fn burn_staked_token_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Lockable::stake(&e, &owner, token_id);
+        token_id
+    });
+
+    e.as_contract(&address, || Lockable::burn(&e, &owner, token_id));
+}
+
+#[test]
+fn burn_unstaked_token_leaves_no_stake() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Lockable::stake(&e, &owner, token_id);
+        token_id
+    });
+
+    e.as_contract(&address, || Lockable::unstake(&e, &owner, token_id));
+    e.as_contract(&address, || Base::burn(&e, &owner, token_id));
+
+    e.as_contract(&address, || {
+        assert_eq!(Lockable::staked_at(&e, token_id), None);
+        assert!(!Lockable::is_locked(&e, token_id));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #301)")]
+fn stake_by_non_owner_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let other = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Lockable::stake(&e, &other, token_id);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #330)")]
+fn stake_twice_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Lockable::stake(&e, &owner, token_id);
+        token_id
+    });
+
+    e.as_contract(&address, || {
+        Lockable::stake(&e, &owner, token_id);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #331)")]
+fn unstake_not_staked_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Lockable::unstake(&e, &owner, token_id);
+    });
+}
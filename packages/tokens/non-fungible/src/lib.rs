@@ -35,10 +35,33 @@
 //! The following optional extensions are available:
 //!
 //! - *Burnable* enables token holders to destroy their non-fungible tokens.
+//! - *Capped* sets a maximum limit on the number of tokens in circulation.
+//! - *Commit-Reveal* allows minting in two steps, committing first and
+//!   revealing after a delay, to prevent mint sniping.
 //! - *Enumerable* allows for enumeration of all the token IDs in the contract
 //!   as well as all the token IDs owned by each account.
 //! - *Consecutive* is useful for efficiently minting multiple tokens in a
 //!   single transaction.
+//! - *Fractional* allows co-owning a token through fractional shares backed by
+//!   a fungible token.
+//! - *Holding Period* prevents the resale of tokens before a minimum number of
+//!   ledgers elapsed since their acquisition, discouraging flipping.
+//! - *Lazy Mint* allows minting tokens on their first sale, authorized by a
+//!   signature of the creator.
+//! - *Lockable* allows freezing individual tokens, preventing their transfer
+//!   and approval while locked.
+//! - *Metadata* attaches a map of attributes (traits) to each token, as
+//!   displayed by marketplaces.
+//! - *Permit* allows granting operator approvals with a signature of the owner,
+//!   without the owner submitting the transaction.
+//! - *Rental* allows delegating the usage of a token until a given ledger,
+//!   without transferring its ownership.
+//! - *Royalty* exposes the royalty terms of tokens to marketplaces and allows
+//!   enforcing royalty payments on transfers.
+//! - *Soulbound* makes tokens non-transferable: they can only be minted and
+//!   burned.
+//! - *Staking* allows staking tokens in place, locking them while they accrue
+//!   rewards.
 //!
 //! ## Compatibility and Compliance
 //!
@@ -49,8 +72,11 @@
 //!   Token interface, and also it is a simpler (thus, cheaper and faster)
 //!   version of `transferFrom()`, which may become handy depending on the
 //!   context.
-//! - `safeTransfer` mechanism is not present in the base module, (will be
-//!   provided as an extension)
+//! - `safeTransfer` is available as [`ContractOverrides::safe_transfer`] and
+//!   [`ContractOverrides::safe_transfer_from`], which go through the `transfer`
+//!   of the contract type, so that extensions such as `Lockable` or
+//!   `Enumerable` keep enforcing their rules. Contract recipients are expected
+//!   to implement [`NonFungibleReceiver`], while wallets are never checked.
 //! - `name()`, `symbol()` and `token_uri()` functionalities are made available
 //!   to be consistent with fungible tokens as well.
 //!
@@ -63,6 +89,9 @@
 //! - **Composable Design**: The modular structure encourages developers to
 //!   extend functionality by combining provided primitives or creating custom
 //!   extensions.
+//! - **Pausing**: With the `pausable` feature, the [`PausableBase`] marker
+//!   guards transfers and approvals with a pause check. Mints and burns remain
+//!   to be guarded by the contract, e.g. with the `#[when_not_paused]` macro.
 #![no_std]
 
 mod extensions;
@@ -71,13 +100,19 @@ mod overrides;
 mod storage;
 mod utils;
 
-pub use extensions::{burnable, consecutive, enumerable};
+pub use extensions::{
+    burnable, capped, commit_reveal, consecutive, enumerable, fractional, holding_period,
+    lazy_mint, lockable, metadata, permit, rental, royalty, soulbound, staking,
+};
+#[cfg(feature = "transfer_batch_event")]
+pub use non_fungible::emit_transfer_batch;
 pub use non_fungible::{
-    emit_approve, emit_approve_for_all, emit_transfer, Balance, NonFungibleToken,
-    NonFungibleTokenError, TokenId,
+    emit_approvals_cleared, emit_approve, emit_approve_for_all, emit_transfer, Balance,
+    NonFungibleReceiver, NonFungibleReceiverClient, NonFungibleToken, NonFungibleTokenError,
+    TokenId,
 };
 pub use overrides::*;
-pub use storage::{ApprovalData, ApprovalForAllData, StorageKey};
+pub use storage::{ApprovalData, ApprovalForAllData, CollectionInfo, StorageKey};
 pub use utils::sequential;
 
 mod test;
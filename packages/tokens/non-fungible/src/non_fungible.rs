@@ -1,25 +1,33 @@
-use soroban_sdk::{contracterror, symbol_short, Address, Env, String, Symbol};
+use soroban_sdk::{
+    contractclient, contracterror, symbol_short, Address, Bytes, Env, String, Symbol, Vec,
+};
 
 use crate::ContractOverrides;
 
 /// Max. allowed length for a base uri.
 pub const MAX_BASE_URI_LEN: usize = 200;
 
-#[cfg(feature = "token_u32")]
+// The `TokenId` width is selected through the `token_u32` (default),
+// `token_u64` and `token_u128` features. When several of them are enabled at
+// once, e.g. through feature unification across a workspace, the widest one
+// wins, so every `TokenId` produced by the narrower widths is still
+// representable.
+
+#[cfg(all(feature = "token_u32", not(any(feature = "token_u64", feature = "token_u128"))))]
 pub type TokenId = u32;
 /// u32::MAX == 4294967295
-#[cfg(feature = "token_u32")]
+#[cfg(all(feature = "token_u32", not(any(feature = "token_u64", feature = "token_u128"))))]
 pub const MAX_NUM_DIGITS: usize = 10;
 
-#[cfg(feature = "token_u64")]
+#[cfg(all(feature = "token_u64", not(feature = "token_u128")))]
 pub type TokenId = u64;
 /// u64::MAX == 18446744073709551615
-#[cfg(feature = "token_u64")]
+#[cfg(all(feature = "token_u64", not(feature = "token_u128")))]
 pub const MAX_NUM_DIGITS: usize = 20;
 
 #[cfg(feature = "token_u128")]
 pub type TokenId = u128;
-/// u128::MAX == 18446744073709551615
+/// u128::MAX == 340282366920938463463374607431768211455
 #[cfg(feature = "token_u128")]
 pub const MAX_NUM_DIGITS: usize = 39;
 
@@ -63,6 +71,9 @@ pub type Balance = TokenId;
 /// This trait is implemented for the following Contract Types:
 /// * [`crate::Base`] (covering the vanilla case, and compatible with
 ///   [`crate::extensions::burnable::NonFungibleBurnable`]) trait
+/// * `crate::PausableBase` (with the `pausable` feature, guarding transfers and
+///   approvals with a pause check, and compatible with
+///   [`crate::extensions::burnable::NonFungibleBurnable`]) trait
 /// * [`crate::extensions::enumerable::Enumerable`] (enabling the compatibility
 ///   and overrides for
 ///   [`crate::extensions::enumerable::NonFungibleEnumerable`]) trait,
@@ -281,6 +292,36 @@ pub trait NonFungibleToken {
     fn token_uri(e: &Env, token_id: TokenId) -> String;
 }
 
+/// Receiver Trait for Non-Fungible Token
+///
+/// The `NonFungibleReceiver` trait is expected to be implemented by the
+/// contracts willing to receive tokens through [`crate::Base::safe_transfer`]
+/// and [`crate::Base::safe_transfer_from`]. It mirrors the
+/// `onERC721Received` hook of ERC-721.
+#[contractclient(name = "NonFungibleReceiverClient")]
+pub trait NonFungibleReceiver {
+    /// Handles the receipt of a non-fungible token. Called on the recipient
+    /// after the token has been transferred to it.
+    ///
+    /// Returns `true` to confirm the token has been accepted. Any other
+    /// outcome, including a failure of the call, reverts the transfer.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `operator` - The address which initiated the transfer.
+    /// * `from` - The previous owner of the token.
+    /// * `token_id` - The identifier of the received token.
+    /// * `data` - Additional data with no specified format, forwarded as is.
+    fn on_non_fungible_received(
+        e: Env,
+        operator: Address,
+        from: Address,
+        token_id: TokenId,
+        data: Bytes,
+    ) -> bool;
+}
+
 // ################## ERRORS ##################
 
 #[contracterror]
@@ -313,6 +354,74 @@ pub enum NonFungibleTokenError {
     UnsetMetadata = 310,
     /// Indicates the length of the base URI exceeds the maximum allowed
     BaseUriMaxLenExceeded = 311,
+    /// Indicates an operation on a locked token.
+    TokenLocked = 312,
+    /// Indicates the balance recomputed from the ownership records differs
+    /// from the stored balance.
+    BalanceMismatch = 313,
+    /// Indicates a lazy mint submitted after its deadline.
+    LazyMintExpired = 314,
+    /// Indicates the signer authorizing lazy mints has not been set.
+    LazyMintSignerNotSet = 315,
+    /// Indicates a refund greater than the deposit recorded for the token.
+    RefundExceedsDeposit = 316,
+    /// Indicates a royalty fee greater than `10000` basis points.
+    InvalidRoyaltyFee = 317,
+    /// Indicates a permit signed with a nonce other than the current one.
+    InvalidPermitNonce = 318,
+    /// Indicates a permit for an address that can't sign permits.
+    InvalidPermitSigner = 319,
+    /// Indicates that the lengths of the supplied vectors don't match.
+    LengthMismatch = 320,
+    /// Indicates a contract recipient that didn't confirm the receipt of a
+    /// token.
+    UnsafeRecipient = 321,
+    /// Indicates an attempt to rent a token which is already rented.
+    TokenAlreadyRented = 322,
+    /// Indicates an invalid amount of tokens to mint.
+    InvalidAmount = 323,
+    /// Indicates an attempt to transfer or approve a soulbound token.
+    NonTransferable = 324,
+    /// Indicates that the operation would have caused the supply to exceed the
+    /// `cap`.
+    ExceededCap = 325,
+    /// Indicates the cap was not set.
+    CapNotSet = 326,
+    /// Indicates that the maximum number of tokens burned within the current
+    /// ledger has been reached.
+    BurnQuotaExceeded = 327,
+    /// Indicates an attempt to redeem a token without holding all its shares.
+    InsufficientShares = 328,
+    /// Indicates an attempt to redeem a token which is not fractionalized.
+    NotFractionalized = 329,
+    /// Indicates an attempt to stake a token which is already staked.
+    TokenAlreadyStaked = 330,
+    /// Indicates an attempt to unstake a token which is not staked.
+    TokenNotStaked = 331,
+    /// Indicates a mint commitment which has already been recorded.
+    CommitmentAlreadyExists = 332,
+    /// Indicates a reveal without a matching mint commitment.
+    CommitmentNotFound = 333,
+    /// Indicates a reveal before the end of the reveal delay.
+    RevealTooEarly = 334,
+    /// Indicates an attempt to set the first sequential `token_id` after
+    /// sequential minting has begun.
+    SequentialMintStarted = 335,
+    /// Indicates an attempt to transfer a token before the end of its minimum
+    /// holding period.
+    HoldingPeriodActive = 336,
+    /// Indicates an attempt to rent a token which is not listed for rental,
+    /// or not with the given payment token.
+    RentalNotListed = 338,
+    /// Indicates rental terms, or a rental, not satisfying the terms: an empty
+    /// maximum duration, a negative price, a duration out of range or a rent
+    /// lower than the price.
+    InvalidRentalTerms = 339,
+    /// Indicates an attempt to burn a token holding a deposit other than with
+    /// a refund.
+    DepositNotRefunded = 340,
+    /// Indicates a lazy mint voucher which has already been redeemed.
+    LazyMintVoucherUsed = 341,
 }
 
 // ################## EVENTS ##################
@@ -335,6 +444,27 @@ pub fn emit_transfer(e: &Env, from: &Address, to: &Address, token_id: TokenId) {
     e.events().publish(topics, token_id)
 }
 
+/// Emits a single event for the transfer of several tokens to the same
+/// recipient. Only available with the `transfer_batch_event` feature, as it
+/// deviates from the standard `transfer` event.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `token_ids` - The identifiers of the transferred tokens.
+///
+/// # Events
+///
+/// * topics - `["transfer_batch", from: Address, to: Address]`
+/// * data - `[token_ids: Vec<TokenId>]`
+#[cfg(feature = "transfer_batch_event")]
+pub fn emit_transfer_batch(e: &Env, from: &Address, to: &Address, token_ids: &Vec<TokenId>) {
+    let topics = (Symbol::new(e, "transfer_batch"), from, to);
+    e.events().publish(topics, token_ids.clone())
+}
+
 /// Emits an event when `approver` enables `approved` to manage the `token_id`
 /// token.
 ///
@@ -383,6 +513,23 @@ pub fn emit_approve_for_all(e: &Env, owner: &Address, operator: &Address, live_u
     e.events().publish(topics, (operator, live_until_ledger))
 }
 
+/// Emits an event when `owner` clears the approvals of multiple tokens.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - Address of the owner of the tokens.
+/// * `token_ids` - The identifiers of the tokens whose approvals are cleared.
+///
+/// # Events
+///
+/// * topics - `["approvals_cleared", owner: Address]`
+/// * data - `[token_ids: Vec<TokenId>]`
+pub fn emit_approvals_cleared(e: &Env, owner: &Address, token_ids: &Vec<TokenId>) {
+    let topics = (Symbol::new(e, "approvals_cleared"), owner);
+    e.events().publish(topics, token_ids.clone())
+}
+
 /// Emits an event indicating a mint of a token.
 ///
 /// # Arguments
@@ -399,3 +546,21 @@ pub fn emit_mint(e: &Env, to: &Address, token_id: TokenId) {
     let topics = (symbol_short!("mint"), to);
     e.events().publish(topics, token_id)
 }
+
+/// Emits an event indicating a mint of a batch of tokens with sequential ids.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `to` - The address receiving the new tokens.
+/// * `first_token_id` - First token id of the batch.
+/// * `last_token_id` - Last token id of the batch.
+///
+/// # Events
+///
+/// * topics - `["mint", to: Address]`
+/// * data - `[first_token_id: TokenId, last_token_id: TokenId]`
+pub fn emit_mint_batch(e: &Env, to: &Address, first_token_id: TokenId, last_token_id: TokenId) {
+    let topics = (symbol_short!("mint"), to);
+    e.events().publish(topics, (first_token_id, last_token_id))
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, Env, String};
+use soroban_sdk::{Address, Bytes, Env, String};
 
 use crate::{Balance, TokenId};
 
@@ -101,6 +101,74 @@ pub trait ContractOverrides {
         Base::transfer_from(e, spender, from, to, token_id);
     }
 
+    /// Transfers `token_id` with the `transfer` of this contract type, so that
+    /// the checks and the bookkeeping of the extension apply, then makes sure
+    /// that a contract recipient is able to receive it. See
+    /// [`Base::safe_transfer`].
+    fn safe_transfer(e: &Env, from: &Address, to: &Address, token_id: TokenId, data: Bytes) {
+        Self::transfer(e, from, to, token_id);
+        Base::check_on_received(e, from, from, to, token_id, data);
+    }
+
+    /// Transfers `token_id` with the `transfer_from` of this contract type, so
+    /// that the checks and the bookkeeping of the extension apply, then makes
+    /// sure that a contract recipient is able to receive it. See
+    /// [`Base::safe_transfer_from`].
+    fn safe_transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_id: TokenId,
+        data: Bytes,
+    ) {
+        Self::transfer_from(e, spender, from, to, token_id);
+        Base::check_on_received(e, spender, from, to, token_id, data);
+    }
+
+    /// Transfers `token_id` from `from` to `to` with the `transfer` of this
+    /// contract type, and pays the royalty owed for a sale at `sale_price` in
+    /// `payment_token` to the royalty receiver. The whole call reverts if the
+    /// royalty can't be paid.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_id` - The identifier of the token being transferred.
+    /// * `sale_price` - The price the token is sold for.
+    /// * `payment_token` - The address of the token contract used to pay the
+    ///   royalty.
+    ///
+    /// # Errors
+    ///
+    /// * refer to the `transfer` errors of this contract type.
+    /// * refer to [`Base::royalty_info`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `from` is required.
+    /// * Authorization for the payer (see [`Base::royalty_payer`]) is required
+    ///   by `payment_token` to transfer the royalty.
+    /// * The sale price itself is not transferred by this function.
+    fn transfer_with_royalty(
+        e: &Env,
+        from: &Address,
+        to: &Address,
+        token_id: TokenId,
+        sale_price: i128,
+        payment_token: &Address,
+    ) {
+        Self::transfer(e, from, to, token_id);
+        Base::pay_royalty(e, from, to, token_id, sale_price, payment_token);
+    }
+
     fn approve(
         e: &Env,
         approver: &Address,
@@ -141,3 +209,38 @@ pub struct Base;
 
 // No override required for the `Base` contract type.
 impl ContractOverrides for Base {}
+
+/// Marker type guarding `transfer`, `transfer_from` and `approve` with
+/// [`stellar_pausable::when_not_paused`], so that they panic while the
+/// contract is paused. The queries, e.g. `owner_of`, are not guarded.
+///
+/// `approve_for_all` is not guarded either, so that owners can still revoke
+/// their operators while the contract is paused. Mints and burns are not part
+/// of [`ContractOverrides`], they must be guarded by the implementor, e.g. with
+/// the `#[when_not_paused]` macro.
+#[cfg(feature = "pausable")]
+pub struct PausableBase;
+
+#[cfg(feature = "pausable")]
+impl ContractOverrides for PausableBase {
+    fn transfer(e: &Env, from: &Address, to: &Address, token_id: TokenId) {
+        stellar_pausable::when_not_paused(e);
+        Base::transfer(e, from, to, token_id);
+    }
+
+    fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, token_id: TokenId) {
+        stellar_pausable::when_not_paused(e);
+        Base::transfer_from(e, spender, from, to, token_id);
+    }
+
+    fn approve(
+        e: &Env,
+        approver: &Address,
+        approved: &Address,
+        token_id: TokenId,
+        live_until_ledger: u32,
+    ) {
+        stellar_pausable::when_not_paused(e);
+        Base::approve(e, approver, approved, token_id, live_until_ledger);
+    }
+}
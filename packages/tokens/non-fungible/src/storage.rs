@@ -1,17 +1,22 @@
-use soroban_sdk::{contracttype, panic_with_error, Address, Env, Map, String};
+use soroban_sdk::{contracttype, panic_with_error, Address, Bytes, Env, Map, String, Vec};
 use stellar_constants::{
     BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD, OWNER_EXTEND_AMOUNT, OWNER_TTL_THRESHOLD,
+    TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD,
 };
 
 use crate::{
     non_fungible::{
-        emit_approve, emit_approve_for_all, emit_mint, emit_transfer, Balance,
-        NonFungibleTokenError, TokenId, MAX_BASE_URI_LEN, MAX_NUM_DIGITS,
+        emit_approvals_cleared, emit_approve, emit_approve_for_all, emit_mint, emit_mint_batch,
+        emit_transfer, Balance, NonFungibleReceiverClient, NonFungibleTokenError, TokenId,
+        MAX_BASE_URI_LEN, MAX_NUM_DIGITS,
     },
-    sequential::increment_token_id,
+    sequential::{increment_token_id, next_token_id, token_id_start},
     Base,
 };
 
+/// Length of the strkey representation of an [`Address`].
+const STRKEY_LEN: usize = 56;
+
 /// Storage container for the token for which an approval is granted
 /// and the ledger number at which this approval expires.
 #[contracttype]
@@ -34,6 +39,19 @@ pub struct Metadata {
     pub symbol: String,
 }
 
+/// Bundles the collection metadata together with the total supply, so that
+/// they can be fetched in a single call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollectionInfo {
+    pub name: String,
+    pub symbol: String,
+    /// `None` if the base URI is empty.
+    pub base_uri: Option<String>,
+    /// `None` if the contract doesn't keep track of its total supply.
+    pub total_supply: Option<Balance>,
+}
+
 /// Storage keys for the data associated with `FungibleToken`
 #[contracttype]
 pub enum StorageKey {
@@ -42,6 +60,12 @@ pub enum StorageKey {
     Approval(TokenId),
     ApprovalForAll(Address),
     Metadata,
+    TokenUri(TokenId),
+    UriTemplate,
+    // NOTE: not named `TotalSupply`, which would collide with the key of the
+    // same name maintained by the enumerable extension.
+    Supply,
+    PreviousOwner(TokenId),
 }
 
 impl Base {
@@ -86,6 +110,59 @@ impl Base {
         }
     }
 
+    /// Returns the number of tokens minted with [`Base::sequential_mint`]. As
+    /// it is derived from the token ID counter, it is not affected by burns.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Notes
+    ///
+    /// Tokens minted with an explicit `token_id` (see [`Base::mint`]) don't
+    /// increment the counter, and are therefore not accounted for.
+    pub fn total_minted(e: &Env) -> TokenId {
+        // NOTE: can't underflow, as the counter starts at `token_id_start`.
+        next_token_id(e) - token_id_start(e)
+    }
+
+    /// Returns the number of existing tokens, i.e. the minted tokens that
+    /// haven't been burned.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Notes
+    ///
+    /// Only available with the `countable_supply` feature, which makes
+    /// [`Base::update`] keep track of the supply.
+    #[cfg(feature = "countable_supply")]
+    pub fn total_supply(e: &Env) -> Balance {
+        e.storage().instance().get(&StorageKey::Supply).unwrap_or(0)
+    }
+
+    /// Returns the owner of `token_id` before its last transfer, or `None` if
+    /// the token was never transferred since it was minted.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Notes
+    ///
+    /// Only available with the `previous_owner` feature, which makes
+    /// [`Base::update`] keep track of the previous owner. The previous owner
+    /// is forgotten when the token is burned.
+    #[cfg(feature = "previous_owner")]
+    pub fn previous_owner_of(e: &Env, token_id: TokenId) -> Option<Address> {
+        let key = StorageKey::PreviousOwner(token_id);
+        e.storage().persistent().get::<_, Address>(&key).inspect(|_| {
+            e.storage().persistent().extend_ttl(&key, OWNER_TTL_THRESHOLD, OWNER_EXTEND_AMOUNT);
+        })
+    }
+
     /// Returns the address approved for the specified token:
     /// * `Some(Address)` - The approved address if there is a valid,
     ///   non-expired approval
@@ -193,6 +270,28 @@ impl Base {
         Base::get_metadata(e).base_uri
     }
 
+    /// Returns the collection name, symbol and base URI in a single struct.
+    /// The total supply is only tracked by the base module with the
+    /// `countable_supply` feature, so it is left unset otherwise (see
+    /// [`crate::enumerable::Enumerable::collection_info`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`get_metadata`] errors.
+    pub fn collection_info(e: &Env) -> CollectionInfo {
+        let Metadata { base_uri, name, symbol } = Base::get_metadata(e);
+        let base_uri = (!base_uri.is_empty()).then_some(base_uri);
+        #[cfg(feature = "countable_supply")]
+        let total_supply = Some(Base::total_supply(e));
+        #[cfg(not(feature = "countable_supply"))]
+        let total_supply = None;
+        CollectionInfo { name, symbol, base_uri, total_supply }
+    }
+
     /// Returns the URI for a specific `token_id`.
     ///
     /// # Arguments
@@ -204,10 +303,34 @@ impl Base {
     ///
     /// * refer to [`owner_of`] errors.
     /// * refer to [`base_uri`] errors.
+    ///
+    /// # Notes
+    ///
+    /// If a URI was explicitly set for `token_id` (see
+    /// [`Base::set_token_uri`]), it is returned as is. Otherwise, the URI is
+    /// composed from the URI template if one is set (see
+    /// [`Base::set_uri_template`]), or from the base URI and the `token_id`.
     pub fn token_uri(e: &Env, token_id: TokenId) -> String {
         let _ = Base::owner_of(e, token_id);
-        let base_uri = Base::base_uri(e);
-        Base::compose_uri_for_token(e, base_uri, token_id)
+
+        if let Some(uri) = Base::stored_token_uri(e, token_id) {
+            return uri;
+        }
+
+        match Base::uri_template(e) {
+            Some(template) => Base::compose_uri_from_template(e, template, token_id),
+            None => Base::compose_uri_for_token(e, Base::base_uri(e), token_id),
+        }
+    }
+
+    /// Returns the URI template of the collection, if any (see
+    /// [`Base::set_uri_template`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn uri_template(e: &Env) -> Option<String> {
+        e.storage().instance().get(&StorageKey::UriTemplate)
     }
 
     /// Composes and returns a the URI for a specific `token_id`, without
@@ -243,6 +366,37 @@ impl Base {
         }
     }
 
+    /// Composes and returns the URI for a specific `token_id` by replacing the
+    /// first `{id}` placeholder of `template` with the `token_id`, without
+    /// checking its ownership. If `template` has no placeholder, the
+    /// `token_id` is appended to it as in [`Base::compose_uri_for_token`].
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `template` - The URI template, e.g. `https://api.com/{id}.json`.
+    /// * `token_id` - The identifier of the token.
+    pub fn compose_uri_from_template(e: &Env, template: String, token_id: TokenId) -> String {
+        let len = template.len() as usize;
+        let template_bytes = &mut [0u8; MAX_BASE_URI_LEN];
+        template.copy_into_slice(&mut template_bytes[..len]);
+
+        let Some(start) = template_bytes[..len].windows(4).position(|w| w == b"{id}") else {
+            return Base::compose_uri_for_token(e, template, token_id);
+        };
+        let rest = &template_bytes[start + 4..len];
+
+        let uri = &mut [0u8; MAX_BASE_URI_LEN + MAX_NUM_DIGITS];
+        let (id, digits) = Base::token_id_to_string(e, token_id);
+
+        uri[..start].copy_from_slice(&template_bytes[..start]);
+        id.copy_into_slice(&mut uri[start..start + digits]);
+        let end = start + digits + rest.len();
+        uri[start + digits..end].copy_from_slice(rest);
+
+        String::from_bytes(e, &uri[..end])
+    }
+
     // ################## CHANGE STATE ##################
 
     /// Transfers a non-fungible token (NFT), ensuring ownership checks.
@@ -274,6 +428,74 @@ impl Base {
         emit_transfer(e, from, to, token_id);
     }
 
+    /// Transfers each token of `token_ids` from `from` to the recipient at
+    /// the same index in `recipients`, ensuring ownership checks.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment reference.
+    /// * `from` - The current owner's address.
+    /// * `recipients` - The recipients' addresses.
+    /// * `token_ids` - The identifiers of the tokens being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::LengthMismatch`] - If `recipients` and
+    ///   `token_ids` have different lengths.
+    /// * refer to [`update`] errors.
+    ///
+    /// # Events
+    ///
+    /// For each transferred token:
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// With the `transfer_batch_event` feature, one event per recipient
+    /// instead:
+    ///
+    /// * topics - `["transfer_batch", from: Address, to: Address]`
+    /// * data - `[token_ids: Vec<TokenId>]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `from` is required.
+    /// * **IMPORTANT**: If a recipient is unable to receive, the NFTs may get
+    ///   lost.
+    pub fn transfer_batch(
+        e: &Env,
+        from: &Address,
+        recipients: Vec<Address>,
+        token_ids: Vec<TokenId>,
+    ) {
+        from.require_auth();
+
+        if recipients.len() != token_ids.len() {
+            panic_with_error!(e, NonFungibleTokenError::LengthMismatch);
+        }
+
+        #[cfg(feature = "transfer_batch_event")]
+        let mut batches: Map<Address, Vec<TokenId>> = Map::new(e);
+
+        for (to, token_id) in recipients.iter().zip(token_ids.iter()) {
+            Base::update(e, Some(from), Some(&to), token_id);
+
+            #[cfg(not(feature = "transfer_batch_event"))]
+            emit_transfer(e, from, &to, token_id);
+            #[cfg(feature = "transfer_batch_event")]
+            {
+                let mut batch = batches.get(to.clone()).unwrap_or_else(|| Vec::new(e));
+                batch.push_back(token_id);
+                batches.set(to, batch);
+            }
+        }
+
+        #[cfg(feature = "transfer_batch_event")]
+        for (to, batch) in batches.iter() {
+            crate::emit_transfer_batch(e, from, &to, &batch);
+        }
+    }
+
     /// Transfers a non-fungible token (NFT), ensuring ownership and approval
     /// checks.
     ///
@@ -313,6 +535,82 @@ impl Base {
         emit_transfer(e, from, to, token_id);
     }
 
+    /// Transfers a non-fungible token (NFT), ensuring ownership checks, and
+    /// makes sure that a contract recipient is able to receive it.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment reference.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_id` - The identifier of the token being transferred.
+    /// * `data` - Additional data forwarded to the recipient.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`transfer`] errors.
+    /// * refer to [`check_on_received`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `from` is required.
+    /// * This goes through [`Base::transfer`] only. Contracts using an
+    ///   extension should call `ContractType::safe_transfer` instead, so that
+    ///   the extension's own `transfer` applies.
+    /// * The receiver can't call back into this contract from its callback, as
+    ///   the host forbids re-entering a contract that is on the call stack, so
+    ///   no reentrancy guard is needed.
+    pub fn safe_transfer(e: &Env, from: &Address, to: &Address, token_id: TokenId, data: Bytes) {
+        Base::transfer(e, from, to, token_id);
+        Base::check_on_received(e, from, from, to, token_id, data);
+    }
+
+    /// Transfers a non-fungible token (NFT), ensuring ownership and approval
+    /// checks, and makes sure that a contract recipient is able to receive
+    /// it.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment reference.
+    /// * `spender` - The address attempting to transfer the token.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_id` - The identifier of the token being transferred.
+    /// * `data` - Additional data forwarded to the recipient.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`transfer_from`] errors.
+    /// * refer to [`check_on_received`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `spender` is required.
+    /// * This goes through [`Base::transfer_from`] only. Contracts using an
+    ///   extension should call `ContractType::safe_transfer_from` instead, so
+    ///   that the extension's own `transfer_from` applies.
+    pub fn safe_transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_id: TokenId,
+        data: Bytes,
+    ) {
+        Base::transfer_from(e, spender, from, to, token_id);
+        Base::check_on_received(e, spender, from, to, token_id, data);
+    }
+
     /// Approves an address to transfer a specific token.
     ///
     /// # Arguments
@@ -377,6 +675,78 @@ impl Base {
     pub fn approve_for_all(e: &Env, owner: &Address, operator: &Address, live_until_ledger: u32) {
         owner.require_auth();
 
+        Base::set_approval_for_all(e, owner, operator, live_until_ledger);
+    }
+
+    /// Sets operator approvals of `owner` for all of `operators` at once, each
+    /// of them expiring at `live_until_ledger`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address granting approval for all their tokens.
+    /// * `operators` - The addresses being granted or revoked approval.
+    /// * `live_until_ledger` - The ledger number at which the allowances
+    ///   expire. If `live_until_ledger` is `0`, the approvals are revoked.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::set_approval_for_all`] errors.
+    ///
+    /// # Events
+    ///
+    /// For each operator:
+    /// * topics - `["approve_for_all", owner: Address]`
+    /// * data - `[operator: Address, live_until_ledger: u32]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `owner` is required, once for all the operators.
+    pub fn approve_operators(
+        e: &Env,
+        owner: &Address,
+        operators: Vec<Address>,
+        live_until_ledger: u32,
+    ) {
+        owner.require_auth();
+
+        for operator in operators.iter() {
+            Base::set_approval_for_all(e, owner, &operator, live_until_ledger);
+        }
+    }
+
+    /// Low-level function for setting or removing the operator approval of
+    /// `operator` over all the tokens of `owner`, without checking
+    /// authorization.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address granting approval for all their tokens.
+    /// * `operator` - The address being granted or revoked approval.
+    /// * `live_until_ledger` - The ledger number at which the allowance
+    ///   expires. If `live_until_ledger` is `0`, the approval is revoked.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidLiveUntilLedger`] - If the ledger
+    ///   number is less than the current ledger number.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve", owner: Address]`
+    /// * data - `[operator: Address, live_until_ledger: u32]`
+    ///
+    /// # Notes
+    ///
+    /// This function does not enforce authorization. Ensure that authorization
+    /// is handled at a higher level.
+    pub fn set_approval_for_all(
+        e: &Env,
+        owner: &Address,
+        operator: &Address,
+        live_until_ledger: u32,
+    ) {
         let key = StorageKey::ApprovalForAll(owner.clone());
 
         // If revoking approval (live_until_ledger == 0)
@@ -385,9 +755,9 @@ impl Base {
                 e.storage().temporary().get::<_, ApprovalForAllData>(&key)
             {
                 approval_data.operators.remove(operator.clone());
+                // NOTE: the TTL of the entry is left untouched, as it is still
+                // bound to the remaining operators.
                 e.storage().temporary().set(&key, &approval_data);
-                let live_for = live_until_ledger - e.ledger().sequence();
-                e.storage().temporary().extend_ttl(&key, live_for, live_for);
             }
             emit_approve_for_all(e, owner, operator, live_until_ledger);
             return;
@@ -419,6 +789,44 @@ impl Base {
         emit_approve_for_all(e, owner, operator, live_until_ledger);
     }
 
+    /// Clears the per-token approvals of `token_ids`, all owned by `owner`, to
+    /// reclaim the temporary storage used by them.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address owning the tokens.
+    /// * `token_ids` - The identifiers of the tokens whose approvals are
+    ///   cleared.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::IncorrectOwner`] - If `owner` is not the
+    ///   owner of one of the tokens.
+    /// * refer to [`owner_of`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approvals_cleared", owner: Address]`
+    /// * data - `[token_ids: Vec<TokenId>]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `owner` is required.
+    /// * Operator approvals (`approve_for_all`) are not affected.
+    pub fn clear_all_approvals(e: &Env, owner: &Address, token_ids: Vec<TokenId>) {
+        owner.require_auth();
+
+        for token_id in token_ids.iter() {
+            if Base::owner_of(e, token_id) != *owner {
+                panic_with_error!(e, NonFungibleTokenError::IncorrectOwner);
+            }
+            e.storage().temporary().remove(&StorageKey::Approval(token_id));
+        }
+
+        emit_approvals_cleared(e, owner, &token_ids);
+    }
+
     /// Low-level function for handling transfers, mints and burns of an NFT,
     /// without handling authorization. Updates ownership records, adjusts
     /// balances, and clears existing approvals.
@@ -452,10 +860,15 @@ impl Base {
             let approval_key = StorageKey::Approval(token_id);
             e.storage().temporary().remove(&approval_key);
         } else {
-            // nothing to do for the `None` case, since we don't track
-            // `total_supply`
+            // Minting: `from` is None. The supply is only tracked with the
+            // `countable_supply` feature.
+            #[cfg(feature = "countable_supply")]
+            Base::increase_supply(e, 1);
         }
 
+        #[cfg(feature = "previous_owner")]
+        Base::record_previous_owner(e, from, to, token_id);
+
         if let Some(to_address) = to {
             Base::increase_balance(e, to_address, 1);
 
@@ -464,9 +877,120 @@ impl Base {
         } else {
             // Burning: `to` is None
             e.storage().persistent().remove(&StorageKey::Owner(token_id));
+
+            #[cfg(feature = "countable_supply")]
+            Base::decrease_supply(e, 1);
+        }
+    }
+
+    /// Low-level function for increasing the number of existing tokens when
+    /// `amount` tokens are minted.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `amount` - The number of minted tokens.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::MathOverflow`] - If the supply would
+    ///   overflow.
+    #[cfg(feature = "countable_supply")]
+    pub fn increase_supply(e: &Env, amount: Balance) {
+        let Some(total_supply) = Base::total_supply(e).checked_add(amount) else {
+            panic_with_error!(e, NonFungibleTokenError::MathOverflow);
+        };
+        e.storage().instance().set(&StorageKey::Supply, &total_supply);
+    }
+
+    /// Low-level function for decreasing the number of existing tokens when
+    /// `amount` tokens are burned.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `amount` - The number of burned tokens.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::MathOverflow`] - If the supply would
+    ///   underflow.
+    #[cfg(feature = "countable_supply")]
+    pub fn decrease_supply(e: &Env, amount: Balance) {
+        let Some(total_supply) = Base::total_supply(e).checked_sub(amount) else {
+            panic_with_error!(e, NonFungibleTokenError::MathOverflow);
+        };
+        e.storage().instance().set(&StorageKey::Supply, &total_supply);
+    }
+
+    /// Low-level function that records `from` as the previous owner of
+    /// `token_id` when the token is transferred, and forgets it when the
+    /// token is burned. Minting leaves it untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address of the current token owner.
+    /// * `to` - The address of the token recipient.
+    /// * `token_id` - The identifier of the token being updated.
+    #[cfg(feature = "previous_owner")]
+    pub(crate) fn record_previous_owner(
+        e: &Env,
+        from: Option<&Address>,
+        to: Option<&Address>,
+        token_id: TokenId,
+    ) {
+        let key = StorageKey::PreviousOwner(token_id);
+        match (from, to) {
+            (Some(from_address), Some(_)) => e.storage().persistent().set(&key, from_address),
+            (Some(_), None) => e.storage().persistent().remove(&key),
+            (None, _) => {}
         }
     }
 
+    /// Approves an address to transfer a specific token, until the operator
+    /// approval of `approver` expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `approver` - The address of the approver, an operator of the owner.
+    /// * `approved` - The address receiving the approval.
+    /// * `token_id` - The identifier of the token to be approved.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidApprover`] - If `approver` is not a
+    ///   valid operator of the owner of the token.
+    /// * refer to [`owner_of`] errors.
+    /// * refer to [`approve_for_owner`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve", owner: Address, token_id: TokenId]`
+    /// * data - `[approved: Address, live_until_ledger: u32]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `approver` is required.
+    /// * The approval gets the `live_until_ledger` of the operator approval at
+    ///   the time of the call. Later changes to the operator approval don't
+    ///   affect it.
+    pub fn approve_inherit_ttl(e: &Env, approver: &Address, approved: &Address, token_id: TokenId) {
+        approver.require_auth();
+
+        let owner = Base::owner_of(e, token_id);
+        let live_until_ledger = e
+            .storage()
+            .temporary()
+            .get::<_, ApprovalForAllData>(&StorageKey::ApprovalForAll(owner.clone()))
+            .and_then(|approval_data| approval_data.operators.get(approver.clone()))
+            .filter(|live_until_ledger| *live_until_ledger >= e.ledger().sequence())
+            .unwrap_or_else(|| panic_with_error!(e, NonFungibleTokenError::InvalidApprover));
+
+        Base::approve_for_owner(e, &owner, approver, approved, token_id, live_until_ledger);
+    }
+
     /// Low-level function for approving `token_id` without checking its
     /// ownership and without handling authorization.
     ///
@@ -607,13 +1131,143 @@ impl Base {
         e.storage().instance().set(&StorageKey::Metadata, &metadata);
     }
 
+    /// Sets the URI template of the collection, taking precedence over the
+    /// base URI when composing token URIs. The first `{id}` placeholder of
+    /// the template is replaced with the token ID, e.g. the template
+    /// `https://api.com/{id}.json` results in `https://api.com/7.json` for the
+    /// token `7`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `template` - The URI template.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::BaseUriMaxLenExceeded`] - If the length of
+    ///   `template` exceeds the maximum allowed.
+    ///
+    /// # Notes
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. You want to
+    /// invoke it most likely from a constructor or from another function with
+    /// admin-only authorization.
+    pub fn set_uri_template(e: &Env, template: String) {
+        if template.len() as usize > MAX_BASE_URI_LEN {
+            panic_with_error!(e, NonFungibleTokenError::BaseUriMaxLenExceeded)
+        }
+
+        e.storage().instance().set(&StorageKey::UriTemplate, &template);
+    }
+
+    /// Sets the URI of `token_id`, taking precedence over the URI composed
+    /// from the base URI.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `uri` - The URI of the token.
+    ///
+    /// # Notes
+    ///
+    /// The URI is not cleared by burns. Contracts setting per-token URIs are
+    /// expected to call [`Base::remove_token_uri`] in their `burn` and
+    /// `burn_from`, so that a token minted again with the same identifier
+    /// doesn't inherit it.
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used internally or in trusted contexts.
+    pub fn set_token_uri(e: &Env, token_id: TokenId, uri: String) {
+        e.storage().persistent().set(&StorageKey::TokenUri(token_id), &uri);
+    }
+
+    /// Removes the URI set for `token_id`, if any, so that its URI is composed
+    /// from the base URI again.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function bypasses authorization checks and should
+    /// only be used internally or in trusted contexts.
+    pub fn remove_token_uri(e: &Env, token_id: TokenId) {
+        e.storage().persistent().remove(&StorageKey::TokenUri(token_id));
+    }
+
     // ################## INTERNAL HELPERS ##################
 
+    /// Returns the URI explicitly set for `token_id`, if any, extending the
+    /// TTL of its storage entry.
+    pub(crate) fn stored_token_uri(e: &Env, token_id: TokenId) -> Option<String> {
+        let key = StorageKey::TokenUri(token_id);
+        let uri = e.storage().persistent().get::<_, String>(&key);
+        if uri.is_some() {
+            e.storage().persistent().extend_ttl(&key, TOKEN_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT);
+        }
+        uri
+    }
+
+    /// Invokes [`crate::NonFungibleReceiver::on_non_fungible_received`] on
+    /// `to` if it is a contract, and ensures the receipt is confirmed. Does
+    /// nothing if `to` is not a contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `operator` - The address which initiated the transfer.
+    /// * `from` - The previous owner of the token.
+    /// * `to` - The recipient of the token.
+    /// * `token_id` - The identifier of the transferred token.
+    /// * `data` - Additional data forwarded to the recipient.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::UnsafeRecipient`] - If `to` is a contract
+    ///   which doesn't return `true`, or fails to handle the call.
+    pub fn check_on_received(
+        e: &Env,
+        operator: &Address,
+        from: &Address,
+        to: &Address,
+        token_id: TokenId,
+        data: Bytes,
+    ) {
+        if !Base::is_contract(e, to) {
+            return;
+        }
+
+        let client = NonFungibleReceiverClient::new(e, to);
+        if !matches!(
+            client.try_on_non_fungible_received(operator, from, &token_id, &data),
+            Ok(Ok(true))
+        ) {
+            panic_with_error!(e, NonFungibleTokenError::UnsafeRecipient);
+        }
+    }
+
+    /// Returns `true` if `address` is a contract address, i.e. if its strkey
+    /// starts with `C`, as opposed to `G` for accounts.
+    fn is_contract(_e: &Env, address: &Address) -> bool {
+        let strkey = address.to_string();
+        let mut buf = [0u8; STRKEY_LEN];
+        if strkey.len() as usize != STRKEY_LEN {
+            return false;
+        }
+        strkey.copy_into_slice(&mut buf);
+        buf[0] == b'C'
+    }
+
     /// Converts a numeric `TokenId` to `String` and returns it alongside the
     /// number of digits.
     fn token_id_to_string(e: &Env, value: TokenId) -> (String, usize) {
         if value == 0 {
-            return (String::from_str(e, "0"), 0);
+            return (String::from_str(e, "0"), 1);
         }
 
         let mut digits: usize = 0;
@@ -684,6 +1338,55 @@ impl Base {
         token_id
     }
 
+    /// Creates `amount` tokens with the next available `token_id`s and assigns
+    /// them to `to`. Returns the first and the last `token_id` of the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the new tokens.
+    /// * `amount` - The number of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidAmount`] - If `amount` is `0`.
+    /// * refer to [`increment_token_id`] errors.
+    /// * refer to [`update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[first_token_id: TokenId, last_token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// Unlike [`crate::consecutive::Consecutive::batch_mint`], the owner of
+    /// every token is stored explicitly, so the minted tokens are compatible
+    /// with the `Base` implementation of every other function.
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can execute
+    /// minting operations.
+    pub fn sequential_mint_batch(e: &Env, to: &Address, amount: Balance) -> (TokenId, TokenId) {
+        if amount == 0 {
+            panic_with_error!(e, NonFungibleTokenError::InvalidAmount);
+        }
+
+        let first_id = increment_token_id(e, amount);
+        // NOTE: can't overflow, as `first_id + amount` was checked above.
+        let last_id = first_id + (amount - 1);
+        for token_id in first_id..=last_id {
+            Base::update(e, None, Some(to), token_id);
+        }
+        emit_mint_batch(e, to, first_id, last_id);
+
+        (first_id, last_id)
+    }
+
     /// Creates a token with the provided `token_id` and assigns it to
     /// `to`.
     ///
@@ -3,17 +3,52 @@
 extern crate std;
 
 use soroban_sdk::{
-    contract,
+    contract, contractimpl,
     testutils::{Address as _, Ledger as _},
-    Address, Env, Map, String,
+    vec, Address, Bytes, Env, IntoVal, Map, String,
 };
 use stellar_event_assertion::EventAssertion;
 
-use crate::{non_fungible::Balance, ApprovalForAllData, Base, StorageKey};
+use crate::{
+    non_fungible::Balance, ApprovalData, ApprovalForAllData, Base, NonFungibleReceiver, StorageKey,
+    TokenId,
+};
 
 #[contract]
 struct MockContract;
 
+#[contract]
+struct AcceptingReceiver;
+
+#[contractimpl]
+impl NonFungibleReceiver for AcceptingReceiver {
+    fn on_non_fungible_received(
+        _e: Env,
+        _operator: Address,
+        _from: Address,
+        _token_id: TokenId,
+        _data: Bytes,
+    ) -> bool {
+        true
+    }
+}
+
+#[contract]
+struct RejectingReceiver;
+
+#[contractimpl]
+impl NonFungibleReceiver for RejectingReceiver {
+    fn on_non_fungible_received(
+        _e: Env,
+        _operator: Address,
+        _from: Address,
+        _token_id: TokenId,
+        _data: Bytes,
+    ) -> bool {
+        false
+    }
+}
+
 #[test]
 fn metadata_works() {
     let e = Env::default();
@@ -41,6 +76,104 @@ fn metadata_works() {
     });
 }
 
+#[test]
+fn token_uri_with_template_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_uri_template(&e, String::from_str(&e, "https://api.com/{id}.json"));
+
+        for token_id in [0, 7, 4294967295] {
+            e.storage().persistent().set(&StorageKey::Owner(token_id), &owner);
+        }
+
+        assert_eq!(Base::token_uri(&e, 0), String::from_str(&e, "https://api.com/0.json"));
+        assert_eq!(Base::token_uri(&e, 7), String::from_str(&e, "https://api.com/7.json"));
+        assert_eq!(
+            Base::token_uri(&e, 4294967295),
+            String::from_str(&e, "https://api.com/4294967295.json")
+        );
+    });
+}
+
+#[test]
+fn token_uri_with_template_without_placeholder_appends_id() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_uri_template(&e, String::from_str(&e, "https://api.com/items"));
+        e.storage().persistent().set(&StorageKey::Owner(7), &owner);
+
+        assert_eq!(Base::token_uri(&e, 7), String::from_str(&e, "https://api.com/items/7"));
+    });
+}
+
+#[test]
+fn token_uri_prefers_explicit_uri() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_metadata(
+            &e,
+            String::from_str(&e, "https://smth.com/"),
+            String::from_str(&e, "My NFT collection"),
+            String::from_str(&e, "NFT"),
+        );
+        Base::mint(&e, &owner, 1);
+        Base::mint(&e, &owner, 2);
+        Base::set_token_uri(&e, 1, String::from_str(&e, "ipfs://bafy-one"));
+
+        assert_eq!(Base::token_uri(&e, 1), String::from_str(&e, "ipfs://bafy-one"));
+        // tokens without an explicit URI still use the base URI
+        assert_eq!(Base::token_uri(&e, 2), String::from_str(&e, "https://smth.com/2"));
+    });
+}
+
+#[test]
+fn remove_token_uri_on_burn_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_metadata(
+            &e,
+            String::from_str(&e, "https://smth.com/"),
+            String::from_str(&e, "My NFT collection"),
+            String::from_str(&e, "NFT"),
+        );
+        Base::mint(&e, &owner, 1);
+        Base::set_token_uri(&e, 1, String::from_str(&e, "ipfs://bafy-one"));
+
+        Base::burn(&e, &owner, 1);
+        Base::remove_token_uri(&e, 1);
+        assert!(!e.storage().persistent().has(&StorageKey::TokenUri(1)));
+
+        Base::mint(&e, &owner, 1);
+        assert_eq!(Base::token_uri(&e, 1), String::from_str(&e, "https://smth.com/1"));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #311)")]
+fn set_uri_template_too_long_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        let template = std::format!("https://api.com/{}{{id}}", "a".repeat(200));
+        Base::set_uri_template(&e, String::from_str(&e, &template));
+    });
+}
+
 #[test]
 fn approve_for_all_works() {
     let e = Env::default();
@@ -61,6 +194,27 @@ fn approve_for_all_works() {
     });
 }
 
+#[test]
+fn approve_operators_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operators = vec![&e, Address::generate(&e), Address::generate(&e), Address::generate(&e)];
+
+    e.as_contract(&address, || {
+        Base::approve_operators(&e, &owner, operators.clone(), 1000);
+
+        for operator in operators.iter() {
+            assert!(Base::is_approved_for_all(&e, &owner, &operator));
+        }
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count_by_symbol("approve_for_all", 3);
+    });
+    assert_eq!(e.auths().len(), 1);
+}
+
 #[test]
 fn revoke_approve_for_all_works() {
     let e = Env::default();
@@ -114,6 +268,96 @@ fn approve_nft_works() {
     });
 }
 
+#[test]
+fn clear_all_approvals_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let approved = Address::generate(&e);
+    let token_ids = vec![&e, 1, 2, 3];
+
+    for token_id in token_ids.iter() {
+        e.as_contract(&address, || {
+            e.storage().persistent().set(&StorageKey::Owner(token_id), &owner);
+            Base::approve(&e, &owner, &approved, token_id, 1000);
+        });
+    }
+
+    e.as_contract(&address, || {
+        Base::clear_all_approvals(&e, &owner, token_ids.clone());
+
+        for token_id in token_ids.iter() {
+            assert_eq!(Base::get_approved(&e, token_id), None);
+        }
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #301)")]
+fn clear_all_approvals_with_incorrect_owner_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let other = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        e.storage().persistent().set(&StorageKey::Owner(1), &owner);
+        e.storage().persistent().set(&StorageKey::Owner(2), &other);
+
+        Base::clear_all_approvals(&e, &owner, vec![&e, 1, 2]);
+    });
+}
+
+#[test]
+fn approve_inherit_ttl_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let approved = Address::generate(&e);
+    let token_id = 1;
+
+    e.as_contract(&address, || {
+        e.storage().persistent().set(&StorageKey::Owner(token_id), &owner);
+        Base::approve_for_all(&e, &owner, &operator, 1000);
+    });
+
+    e.as_contract(&address, || {
+        Base::approve_inherit_ttl(&e, &operator, &approved, token_id);
+
+        let approval: ApprovalData =
+            e.storage().temporary().get(&StorageKey::Approval(token_id)).unwrap();
+        assert_eq!(approval.approved, approved);
+        assert_eq!(approval.live_until_ledger, 1000);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_non_fungible_approve(&operator, &approved, token_id, 1000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #303)")]
+fn approve_inherit_ttl_without_operator_approval_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let approved = Address::generate(&e);
+    let token_id = 1;
+
+    e.as_contract(&address, || {
+        e.storage().persistent().set(&StorageKey::Owner(token_id), &owner);
+        Base::approve_inherit_ttl(&e, &owner, &approved, token_id);
+    });
+}
+
 #[test]
 fn approve_with_operator_works() {
     let e = Env::default();
@@ -435,15 +679,61 @@ fn test_counter_works() {
 
     e.as_contract(&address, || {
         let token_id1 = Base::sequential_mint(&e, &owner);
-        let _token_id2 = Base::sequential_mint(&e, &owner);
+        let token_id2 = Base::sequential_mint(&e, &owner);
 
         let event_assert = EventAssertion::new(&e, address.clone());
         event_assert.assert_event_count(2);
-        event_assert.assert_non_fungible_mint(&owner, token_id1);
+        event_assert.assert_non_fungible_mint_at(0, &owner, token_id1);
+        event_assert.assert_non_fungible_mint_at(1, &owner, token_id2);
+    });
+}
+
+#[test]
+fn sequential_mint_batch_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::sequential_mint(&e, &account);
+
+        let (first_id, last_id) = Base::sequential_mint_batch(&e, &account, 5);
+        assert_eq!((first_id, last_id), (1, 5));
+        assert_eq!(Base::balance(&e, &account), 6);
+        for token_id in first_id..=last_id {
+            assert!(e.storage().persistent().has(&StorageKey::Owner(token_id)));
+            assert_eq!(Base::owner_of(&e, token_id), account);
+        }
+        assert_eq!(Base::sequential_mint(&e, &account), 6);
+    });
+}
+
+#[test]
+fn sequential_mint_batch_emits_single_event() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::sequential_mint_batch(&e, &account, 3);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        let (_, data) = event_assert.assert_event_from(&address, "mint");
+        let (first_id, last_id): (TokenId, TokenId) = data.into_val(&e);
+        assert_eq!((first_id, last_id), (0, 2));
+    });
+}
 
-        // TODO: below fails because the same event is read by the
-        // `event_assert`, not the next one. event_assert.
-        // assert_non_fungible_mint(&owner, token_id2);
+#[test]
+#[should_panic(expected = "Error(Contract, #323)")]
+fn sequential_mint_batch_zero_amount_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::sequential_mint_batch(&e, &account, 0);
     });
 }
 
@@ -470,3 +760,413 @@ fn mint_base_implementation_has_no_auth() {
         assert_eq!(Base::balance(&e, &account), 1);
     });
 }
+
+#[test]
+fn collection_info_matches_individual_getters() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::set_metadata(
+            &e,
+            String::from_str(&e, "https://smth.com/"),
+            String::from_str(&e, "My Token"),
+            String::from_str(&e, "TKN"),
+        );
+
+        let info = Base::collection_info(&e);
+        assert_eq!(info.name, Base::name(&e));
+        assert_eq!(info.symbol, Base::symbol(&e));
+        assert_eq!(info.base_uri, Some(Base::base_uri(&e)));
+        #[cfg(not(feature = "countable_supply"))]
+        assert_eq!(info.total_supply, None);
+        #[cfg(feature = "countable_supply")]
+        assert_eq!(info.total_supply, Some(0));
+    });
+}
+
+#[test]
+fn collection_info_without_base_uri_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::set_metadata(
+            &e,
+            String::from_str(&e, ""),
+            String::from_str(&e, "My Token"),
+            String::from_str(&e, "TKN"),
+        );
+
+        let info = Base::collection_info(&e);
+        assert_eq!(info.name, String::from_str(&e, "My Token"));
+        assert_eq!(info.base_uri, None);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #310)")]
+fn collection_info_with_unset_metadata_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::collection_info(&e);
+    });
+}
+
+#[test]
+fn transfer_batch_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        for token_id in 0..5 {
+            Base::mint(&e, &owner, token_id);
+        }
+    });
+
+    e.as_contract(&address, || {
+        let recipients = vec![&e, alice.clone(), bob.clone(), alice.clone(), bob.clone()];
+        Base::transfer_batch(&e, &owner, recipients, vec![&e, 0, 1, 2, 3]);
+
+        assert_eq!(Base::owner_of(&e, 0), alice);
+        assert_eq!(Base::owner_of(&e, 1), bob);
+        assert_eq!(Base::owner_of(&e, 2), alice);
+        assert_eq!(Base::owner_of(&e, 3), bob);
+        assert_eq!(Base::balance(&e, &owner), 1);
+        assert_eq!(Base::balance(&e, &alice), 2);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        #[cfg(not(feature = "transfer_batch_event"))]
+        event_assert.assert_event_count(4);
+        #[cfg(feature = "transfer_batch_event")]
+        {
+            event_assert.assert_event_count(2);
+            event_assert.assert_nft_transfer_batch(&owner, &alice, &[0, 2]);
+            event_assert.assert_nft_transfer_batch(&owner, &bob, &[1, 3]);
+        }
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #320)")]
+fn transfer_batch_with_length_mismatch_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let alice = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::mint(&e, &owner, 0);
+        Base::transfer_batch(&e, &owner, vec![&e, alice], vec![&e, 0, 1]);
+    });
+}
+
+#[cfg(feature = "transfer_batch_event")]
+#[test]
+fn transfer_batch_groups_many_tokens_by_recipient() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipients: std::vec::Vec<Address> = (0..3).map(|_| Address::generate(&e)).collect();
+
+    e.as_contract(&address, || {
+        for token_id in 0..12 {
+            Base::mint(&e, &owner, token_id);
+        }
+    });
+
+    e.as_contract(&address, || {
+        let mut to = vec![&e];
+        let mut token_ids = vec![&e];
+        for token_id in 0..12 {
+            to.push_back(recipients[token_id as usize % 3].clone());
+            token_ids.push_back(token_id);
+        }
+        Base::transfer_batch(&e, &owner, to, token_ids);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(3);
+        for (index, recipient) in recipients.iter().enumerate() {
+            let expected: std::vec::Vec<crate::TokenId> =
+                (0..12).filter(|id| *id as usize % 3 == index).collect();
+            event_assert.assert_nft_transfer_batch(&owner, recipient, &expected);
+            assert_eq!(Base::balance(&e, recipient), 4);
+        }
+    });
+}
+
+#[cfg(feature = "previous_owner")]
+#[test]
+fn previous_owner_tracks_prior_holder() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        // a freshly minted token has no previous owner
+        assert_eq!(Base::previous_owner_of(&e, token_id), None);
+
+        Base::transfer(&e, &owner, &alice, token_id);
+        assert_eq!(Base::previous_owner_of(&e, token_id), Some(owner.clone()));
+    });
+
+    e.as_contract(&address, || {
+        Base::transfer(&e, &alice, &bob, 0);
+        assert_eq!(Base::previous_owner_of(&e, 0), Some(alice.clone()));
+    });
+
+    e.as_contract(&address, || {
+        Base::burn(&e, &bob, 0);
+        assert_eq!(Base::previous_owner_of(&e, 0), None);
+    });
+}
+
+#[test]
+fn safe_transfer_to_accepting_contract_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let receiver = e.register(AcceptingReceiver, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::safe_transfer(&e, &owner, &receiver, token_id, Bytes::new(&e));
+        assert_eq!(Base::owner_of(&e, token_id), receiver);
+    });
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::approve(&e, &owner, &spender, token_id, 1000);
+    });
+
+    e.as_contract(&address, || {
+        Base::safe_transfer_from(&e, &spender, &owner, &receiver, 1, Bytes::new(&e));
+        assert_eq!(Base::owner_of(&e, 1), receiver);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #321)")]
+fn safe_transfer_to_rejecting_contract_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let receiver = e.register(RejectingReceiver, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::safe_transfer(&e, &owner, &receiver, token_id, Bytes::new(&e));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #321)")]
+fn safe_transfer_to_non_receiver_contract_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let receiver = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::safe_transfer(&e, &owner, &receiver, token_id, Bytes::new(&e));
+    });
+}
+
+#[test]
+fn safe_transfer_to_wallet_skips_receiver_check() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let wallet = Address::from_str(&e, "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF");
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::safe_transfer(&e, &owner, &wallet, token_id, Bytes::new(&e));
+        assert_eq!(Base::owner_of(&e, token_id), wallet);
+    });
+}
+
+#[cfg(feature = "countable_supply")]
+#[test]
+fn total_supply_tracks_mints_and_burns_but_not_transfers() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        assert_eq!(Base::total_supply(&e), 0);
+
+        Base::sequential_mint(&e, &owner);
+        Base::sequential_mint(&e, &owner);
+        assert_eq!(Base::total_supply(&e), 2);
+    });
+
+    e.as_contract(&address, || {
+        Base::transfer(&e, &owner, &recipient, 1);
+        assert_eq!(Base::total_supply(&e), 2);
+
+        Base::update(&e, Some(&recipient), None, 1);
+        assert_eq!(Base::total_supply(&e), 1);
+    });
+}
+
+#[cfg(feature = "pausable")]
+mod pausable {
+    use soroban_sdk::{
+        contract, contractimpl, testutils::Address as _, Address, Bytes, Env, String,
+    };
+
+    use crate::{
+        burnable::NonFungibleBurnable, Balance, Base, ContractOverrides, NonFungibleToken,
+        PausableBase, TokenId,
+    };
+
+    #[contract]
+    struct PausableToken;
+
+    #[contractimpl]
+    impl PausableToken {
+        pub fn mint(e: &Env, to: Address) -> TokenId {
+            stellar_pausable::when_not_paused(e);
+            Base::sequential_mint(e, &to)
+        }
+
+        pub fn pause(e: &Env, caller: Address) {
+            stellar_pausable::pause(e, &caller);
+        }
+
+        pub fn safe_transfer(e: &Env, from: Address, to: Address, token_id: TokenId, data: Bytes) {
+            PausableBase::safe_transfer(e, &from, &to, token_id, data);
+        }
+    }
+
+    #[contractimpl]
+    impl NonFungibleToken for PausableToken {
+        type ContractType = PausableBase;
+
+        fn balance(e: &Env, owner: Address) -> Balance {
+            Self::ContractType::balance(e, &owner)
+        }
+
+        fn owner_of(e: &Env, token_id: TokenId) -> Address {
+            Self::ContractType::owner_of(e, token_id)
+        }
+
+        fn transfer(e: &Env, from: Address, to: Address, token_id: TokenId) {
+            Self::ContractType::transfer(e, &from, &to, token_id);
+        }
+
+        fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, token_id: TokenId) {
+            Self::ContractType::transfer_from(e, &spender, &from, &to, token_id);
+        }
+
+        fn approve(
+            e: &Env,
+            approver: Address,
+            approved: Address,
+            token_id: TokenId,
+            live_until_ledger: u32,
+        ) {
+            Self::ContractType::approve(e, &approver, &approved, token_id, live_until_ledger);
+        }
+
+        fn approve_for_all(e: &Env, owner: Address, operator: Address, live_until_ledger: u32) {
+            Self::ContractType::approve_for_all(e, &owner, &operator, live_until_ledger);
+        }
+
+        fn get_approved(e: &Env, token_id: TokenId) -> Option<Address> {
+            Self::ContractType::get_approved(e, token_id)
+        }
+
+        fn is_approved_for_all(e: &Env, owner: Address, operator: Address) -> bool {
+            Self::ContractType::is_approved_for_all(e, &owner, &operator)
+        }
+
+        fn name(e: &Env) -> String {
+            Self::ContractType::name(e)
+        }
+
+        fn symbol(e: &Env) -> String {
+            Self::ContractType::symbol(e)
+        }
+
+        fn token_uri(e: &Env, token_id: TokenId) -> String {
+            Self::ContractType::token_uri(e, token_id)
+        }
+    }
+
+    // `PausableBase` composes with the burnable extension, the burns being
+    // guarded by the contract itself.
+    #[contractimpl]
+    impl NonFungibleBurnable for PausableToken {
+        fn burn(e: &Env, from: Address, token_id: TokenId) {
+            stellar_pausable::when_not_paused(e);
+            Base::burn(e, &from, token_id);
+        }
+
+        fn burn_from(e: &Env, spender: Address, from: Address, token_id: TokenId) {
+            stellar_pausable::when_not_paused(e);
+            Base::burn_from(e, &spender, &from, token_id);
+        }
+    }
+
+    #[test]
+    fn pausable_base_works_while_unpaused() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let address = e.register(PausableToken, ());
+        let client = PausableTokenClient::new(&e, &address);
+        let owner = Address::generate(&e);
+        let recipient = Address::generate(&e);
+
+        let token_id = client.mint(&owner);
+        client.transfer(&owner, &recipient, &token_id);
+        assert_eq!(client.owner_of(&token_id), recipient);
+
+        client.burn(&recipient, &token_id);
+        assert_eq!(client.balance(&recipient), 0);
+    }
+
+    #[test]
+    fn pausable_base_blocks_transfers_but_not_queries() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let address = e.register(PausableToken, ());
+        let client = PausableTokenClient::new(&e, &address);
+        let owner = Address::generate(&e);
+        let recipient = Address::generate(&e);
+
+        let token_id = client.mint(&owner);
+        client.pause(&owner);
+
+        assert!(client.try_transfer(&owner, &recipient, &token_id).is_err());
+        assert!(client.try_transfer_from(&owner, &owner, &recipient, &token_id).is_err());
+        assert!(client.try_safe_transfer(&owner, &recipient, &token_id, &Bytes::new(&e)).is_err());
+        assert!(client.try_approve(&owner, &recipient, &token_id, &1000).is_err());
+        assert!(client.try_burn(&owner, &token_id).is_err());
+        assert!(client.try_mint(&owner).is_err());
+
+        assert_eq!(client.owner_of(&token_id), owner);
+        assert_eq!(client.balance(&owner), 1);
+    }
+}
@@ -1,4 +1,6 @@
 mod storage;
 mod test;
 
-pub use self::storage::{increment_token_id, next_token_id, StorageKey};
+pub use self::storage::{
+    increment_token_id, next_token_id, set_token_id_start, token_id_start, StorageKey,
+};
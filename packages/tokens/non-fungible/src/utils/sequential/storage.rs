@@ -5,16 +5,51 @@ use crate::{NonFungibleTokenError, TokenId};
 #[contracttype]
 pub enum StorageKey {
     TokenIdCounter,
+    TokenIdStart,
 }
 
 /// Get the current token counter value to determine the next token_id.
-/// The returned value is the next available token_id.
+/// The returned value is the next available token_id. Before the first mint,
+/// it is the start set with [`set_token_id_start`].
 ///
 /// # Arguments
 ///
 /// * `e` - Access to the Soroban environment.
 pub fn next_token_id(e: &Env) -> TokenId {
-    e.storage().instance().get(&StorageKey::TokenIdCounter).unwrap_or(0)
+    e.storage().instance().get(&StorageKey::TokenIdCounter).unwrap_or_else(|| token_id_start(e))
+}
+
+/// Returns the first token_id assigned by the counter. Defaults to `0`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+pub fn token_id_start(e: &Env) -> TokenId {
+    e.storage().instance().get(&StorageKey::TokenIdStart).unwrap_or(0)
+}
+
+/// Sets the first token_id assigned by the counter, e.g. to start from `1`
+/// or to reserve low token_ids.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `start` - The first token_id to assign.
+///
+/// # Errors
+///
+/// * [`crate::NonFungibleTokenError::SequentialMintStarted`] - When the counter
+///   has already been incremented.
+///
+/// # Notes
+///
+/// We recommend calling this function in the constructor of your smart
+/// contract.
+pub fn set_token_id_start(e: &Env, start: TokenId) {
+    if e.storage().instance().has(&StorageKey::TokenIdCounter) {
+        panic_with_error!(e, NonFungibleTokenError::SequentialMintStarted);
+    }
+    e.storage().instance().set(&StorageKey::TokenIdStart, &start);
 }
 
 /// Return the next free token ID, then increment the counter.
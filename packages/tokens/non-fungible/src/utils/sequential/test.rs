@@ -2,11 +2,13 @@
 
 extern crate std;
 
-use soroban_sdk::{contract, Env};
+use soroban_sdk::{contract, testutils::Address as _, Address, Env};
 
 use crate::{
-    sequential::{increment_token_id, next_token_id, StorageKey},
-    TokenId,
+    sequential::{
+        increment_token_id, next_token_id, set_token_id_start, token_id_start, StorageKey,
+    },
+    Base, TokenId,
 };
 
 #[contract]
@@ -41,3 +43,35 @@ fn sequential_increment_token_id_fails_on_overflow() {
         let _ = increment_token_id(&e, 1);
     });
 }
+
+#[test]
+fn sequential_mint_respects_token_id_start() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_token_id_start(&e, 1000);
+        assert_eq!(token_id_start(&e), 1000);
+        assert_eq!(next_token_id(&e), 1000);
+
+        let token_id = Base::sequential_mint(&e, &owner);
+        assert_eq!(token_id, 1000);
+        assert_eq!(Base::owner_of(&e, 1000), owner);
+        assert_eq!(next_token_id(&e), 1001);
+        assert_eq!(Base::total_minted(&e), 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #335)")]
+fn set_token_id_start_after_mint_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::sequential_mint(&e, &owner);
+        set_token_id_start(&e, 1000);
+    });
+}